@@ -0,0 +1,109 @@
+use na::Point2;
+use ncollide2d::shape::{ConvexPolygon, Polyline};
+use ncollide2d::transformation;
+use std::f64::consts::PI;
+
+fn area(points: &[Point2<f64>]) -> f64 {
+    let mut res = 0.0;
+
+    for i in 0..points.len() {
+        let a = points[i];
+        let b = points[(i + 1) % points.len()];
+        res += a.x * b.y - a.y * b.x;
+    }
+
+    res / 2.0
+}
+
+// The parts are convex by construction, so their total area matches the area of the polygon
+// only if they do not cover anything outside of it.
+fn total_area(parts: &[ConvexPolygon<f64>]) -> f64 {
+    parts.iter().map(|part| area(part.points()).abs()).sum()
+}
+
+fn l_shape() -> Vec<Point2<f64>> {
+    vec![
+        Point2::new(0.0, 0.0),
+        Point2::new(2.0, 0.0),
+        Point2::new(2.0, 1.0),
+        Point2::new(1.0, 1.0),
+        Point2::new(1.0, 2.0),
+        Point2::new(0.0, 2.0),
+    ]
+}
+
+#[test]
+fn decompose_simple_polygons() {
+    let parts = transformation::convex_decomposition(&l_shape());
+    assert_eq!(parts.len(), 2);
+    assert_relative_eq!(total_area(&parts), 3.0, epsilon = 1.0e-10);
+
+    // Clockwise input.
+    let mut reversed = l_shape();
+    reversed.reverse();
+    let parts = transformation::convex_decomposition(&reversed);
+    assert_eq!(parts.len(), 2);
+    assert_relative_eq!(total_area(&parts), 3.0, epsilon = 1.0e-10);
+
+    let hexagon: Vec<_> = (0..6)
+        .map(|i| {
+            let angle = i as f64 * PI / 3.0;
+            Point2::new(angle.cos(), angle.sin())
+        })
+        .collect();
+    let parts = transformation::convex_decomposition(&hexagon);
+    assert_eq!(parts.len(), 1);
+    assert_relative_eq!(total_area(&parts), area(&hexagon), epsilon = 1.0e-10);
+}
+
+#[test]
+fn decompose_star_and_comb() {
+    let star: Vec<_> = (0..20)
+        .map(|i| {
+            let angle = i as f64 * PI / 10.0;
+            let radius = if i % 2 == 0 { 2.0 } else { 0.5 };
+            Point2::new(angle.cos() * radius, angle.sin() * radius)
+        })
+        .collect();
+    let parts = transformation::convex_decomposition(&star);
+    // Each of the ten reflex vertices requires at least one split.
+    assert!(parts.len() > 1 && parts.len() <= 40);
+    assert_relative_eq!(total_area(&parts), area(&star), epsilon = 1.0e-10);
+
+    let mut comb = vec![Point2::new(0.0, 0.0), Point2::new(9.0, 0.0)];
+    for i in (0..5).rev() {
+        let x = i as f64 * 2.0;
+        comb.push(Point2::new(x + 1.0, 3.0));
+        comb.push(Point2::new(x, 3.0));
+        if i != 0 {
+            comb.push(Point2::new(x, 1.0));
+            comb.push(Point2::new(x - 1.0, 1.0));
+        }
+    }
+    let parts = transformation::convex_decomposition(&comb);
+    assert!(parts.len() >= 5 && parts.len() <= 16);
+    assert_relative_eq!(total_area(&parts), area(&comb), epsilon = 1.0e-10);
+
+    let compound = transformation::convex_decomposition_compound(&comb);
+    assert_eq!(compound.shapes().len(), parts.len());
+}
+
+#[test]
+fn decompose_polylines() {
+    // Closed by an edge from the last vertex to the first one.
+    let indices = (0..6).map(|i| Point2::new(i, (i + 1) % 6)).collect();
+    let polyline = Polyline::new(l_shape(), Some(indices));
+    let parts = transformation::convex_decomposition_polyline(&polyline).unwrap();
+    assert_eq!(parts.len(), 2);
+    assert_relative_eq!(total_area(&parts), 3.0, epsilon = 1.0e-10);
+
+    // Closed by a last vertex identical to the first one.
+    let mut points = l_shape();
+    points.push(points[0]);
+    let parts = transformation::convex_decomposition_polyline(&Polyline::new(points, None));
+    assert_eq!(parts.unwrap().len(), 2);
+
+    // Open polylines are rejected.
+    let polyline = Polyline::new(l_shape(), None);
+    assert!(transformation::convex_decomposition_polyline(&polyline).is_none());
+}
@@ -0,0 +1,38 @@
+use na::{Unit, Vector2};
+use ncollide2d::shape::ConvexPolygon;
+use ncollide2d::transformation::{HalfSpace, HalfSpaceIntersectionError};
+
+fn half_plane(x: f32, y: f32, offset: f32) -> HalfSpace<f32> {
+    HalfSpace::new(Unit::new_normalize(Vector2::new(x, y)), offset)
+}
+
+#[test]
+fn convex_polygon_from_half_spaces() {
+    // A triangle.
+    let half_spaces = [
+        half_plane(0.0, -1.0, 0.0),
+        half_plane(-1.0, 0.0, 0.0),
+        half_plane(1.0, 1.0, 2.0f32.sqrt()),
+    ];
+
+    let polygon = ConvexPolygon::try_from_half_spaces(&half_spaces).unwrap();
+    assert_eq!(polygon.points().len(), 3);
+
+    for pt in polygon.points() {
+        assert!(half_spaces
+            .iter()
+            .all(|h| h.signed_distance_to_point(pt) < 1.0e-5));
+    }
+
+    assert_eq!(
+        ConvexPolygon::try_from_half_spaces(&half_spaces[..2]).err(),
+        Some(HalfSpaceIntersectionError::Unbounded)
+    );
+
+    let mut half_spaces = half_spaces;
+    half_spaces[2].offset = -1.0;
+    assert_eq!(
+        ConvexPolygon::try_from_half_spaces(&half_spaces).err(),
+        Some(HalfSpaceIntersectionError::Infeasible)
+    );
+}
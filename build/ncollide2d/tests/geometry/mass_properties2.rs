@@ -0,0 +1,63 @@
+use na::{Isometry2, Point2, Vector2};
+use ncollide2d::mass_properties::MassProperties;
+use ncollide2d::shape::{Ball, Capsule, Compound, ConvexPolygon, Cuboid, Shape, ShapeHandle};
+use std::f64::consts::PI;
+
+fn assert_mprops_eq(a: &MassProperties<f64>, b: &MassProperties<f64>, eps: f64) {
+    assert_relative_eq!(a.mass, b.mass, epsilon = eps);
+    assert_relative_eq!(a.local_com, b.local_com, epsilon = eps);
+    assert_relative_eq!(a.angular_inertia, b.angular_inertia, epsilon = eps);
+}
+
+#[test]
+fn ball_and_capsule_mass_properties() {
+    let ball = Ball::new(2.0f64).mass_properties(3.0).unwrap();
+    assert_relative_eq!(ball.mass, 3.0 * PI * 4.0, epsilon = 1.0e-10);
+    assert_relative_eq!(ball.angular_inertia, ball.mass * 2.0, epsilon = 1.0e-10);
+
+    let capsule = Capsule::new(0.0, 2.0).mass_properties(3.0).unwrap();
+    assert_mprops_eq(&capsule, &ball, 1.0e-10);
+
+    // Compare with a fine polygonal approximation of the capsule.
+    let mut points = Vec::new();
+    for i in 0..1000 {
+        let angle = i as f64 * 2.0 * PI / 1000.0;
+        let y = if angle < PI { 1.5 } else { -1.5 };
+        points.push(Point2::new(angle.cos() * 0.5, angle.sin() * 0.5 + y));
+    }
+    let polygon = ConvexPolygon::try_from_points(&points).unwrap();
+    let polygon = polygon.mass_properties(1.0).unwrap();
+    let capsule = Capsule::new(1.5, 0.5).mass_properties(1.0).unwrap();
+    assert_mprops_eq(&capsule, &polygon, 1.0e-3);
+}
+
+#[test]
+fn cuboid_and_convex_polygon_mass_properties_match() {
+    let cuboid = Cuboid::new(Vector2::new(1.0, 2.0));
+    let mprops = cuboid.mass_properties(2.0).unwrap();
+    assert_relative_eq!(mprops.mass, 16.0);
+    assert_relative_eq!(mprops.angular_inertia, 16.0 * 20.0 / 12.0);
+
+    let shift = Vector2::new(3.0, -1.0);
+    let points: Vec<_> = [(1.0, 2.0), (-1.0, 2.0), (-1.0, -2.0), (1.0, -2.0)]
+        .iter()
+        .map(|p| Point2::new(p.0, p.1) + shift)
+        .collect();
+    let polygon = ConvexPolygon::try_from_points(&points).unwrap();
+    let expected = mprops.transform_by(&Isometry2::new(shift, 0.0));
+    assert_mprops_eq(&polygon.mass_properties(2.0).unwrap(), &expected, 1.0e-10);
+}
+
+#[test]
+fn compound_mass_properties2() {
+    let cuboid = ShapeHandle::new(Cuboid::new(Vector2::new(1.0, 0.5)));
+    let shapes = vec![
+        (Isometry2::new(Vector2::new(-1.0, 0.0), 0.0), cuboid.clone()),
+        (Isometry2::new(Vector2::new(1.0, 0.0), 0.0), cuboid),
+    ];
+    let compound = Compound::new(shapes).mass_properties(2.0).unwrap();
+    let expected = Cuboid::new(Vector2::new(2.0, 0.5))
+        .mass_properties(2.0)
+        .unwrap();
+    assert_mprops_eq(&compound, &expected, 1.0e-10);
+}
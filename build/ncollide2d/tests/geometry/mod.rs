@@ -1,6 +1,10 @@
 mod ball_ball_toi;
 mod ball_cuboid_contact;
 mod compound_penetration;
+mod convex_decomposition2;
 mod epa2;
+mod half_space_intersection2;
+mod mass_properties2;
 mod ray_cast;
 mod time_of_impact2;
+mod voxels2;
@@ -1,6 +1,6 @@
 use na::{self, Isometry2, Point2, Vector2};
 use ncollide2d::query::{Ray, RayCast};
-use ncollide2d::shape::{ConvexPolygon, Segment, Shape};
+use ncollide2d::shape::{Capsule, ConvexPolygon, FeatureId, Segment, Shape};
 
 #[test]
 fn issue_178_parallel_raycast() {
@@ -137,3 +137,43 @@ fn convexpoly_raycast_fuzz() {
         }
     }
 }
+
+#[test]
+fn capsule_raycast() {
+    let capsule = Capsule::new(1.0, 0.5);
+    let m = Isometry2::translation(1.0, 1.0);
+
+    let side = Ray::new(Point2::new(-2.0, 1.5), Vector2::new(1.0, 0.0));
+    let hit = capsule
+        .toi_and_normal_with_ray(&m, &side, 10.0, true)
+        .unwrap();
+    assert_relative_eq!(hit.toi, 2.5);
+    assert_relative_eq!(hit.normal, -Vector2::x());
+    assert_eq!(hit.feature, FeatureId::Face(3));
+
+    let side = Ray::new(Point2::new(4.0, 1.5), -Vector2::new(1.0, 0.0));
+    let hit = capsule
+        .toi_and_normal_with_ray(&m, &side, 10.0, true)
+        .unwrap();
+    assert_relative_eq!(hit.toi, 2.5);
+    assert_eq!(hit.feature, FeatureId::Face(2));
+
+    let up = Ray::new(Point2::new(1.3, -2.0), Vector2::new(0.0, 1.0));
+    let hit = capsule
+        .toi_and_normal_with_ray(&m, &up, 10.0, true)
+        .unwrap();
+    assert_relative_eq!(hit.toi, 1.6, epsilon = 1.0e-6);
+    assert_relative_eq!(hit.normal, Vector2::new(0.6, -0.8), epsilon = 1.0e-6);
+    assert_eq!(hit.feature, FeatureId::Face(0));
+
+    // Non-solid cast from the inside hits the exit point.
+    let inside = Ray::new(Point2::new(1.0, 1.0), Vector2::new(0.0, 1.0));
+    let hit = capsule
+        .toi_and_normal_with_ray(&m, &inside, 10.0, false)
+        .unwrap();
+    assert_relative_eq!(hit.toi, 1.5);
+    assert_eq!(hit.feature, FeatureId::Face(1));
+
+    let miss = Ray::new(Point2::new(1.6, -2.0), Vector2::new(0.0, 1.0));
+    assert!(capsule.toi_with_ray(&m, &miss, 10.0, true).is_none());
+}
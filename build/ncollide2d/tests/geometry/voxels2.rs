@@ -0,0 +1,55 @@
+use na::{Isometry2, Point2, Vector2};
+use ncollide2d::query::{PointQuery, Ray, RayCast};
+use ncollide2d::shape::Voxels;
+
+// An L-shaped set of unit voxels.
+fn voxels() -> Voxels<f32> {
+    let keys = [Point2::new(0, 0), Point2::new(1, 0), Point2::new(0, 1)];
+    Voxels::new(Vector2::repeat(1.0), &keys)
+}
+
+#[test]
+fn voxels_ray_cast() {
+    let voxels = voxels();
+    let m = Isometry2::new(Vector2::new(1.0, 2.0), 0.0);
+
+    let ray = Ray::new(Point2::new(6.0, 3.5), -Vector2::x());
+    let hit = voxels
+        .toi_and_normal_with_ray(&m, &ray, 10.0, true)
+        .unwrap();
+    assert_relative_eq!(hit.toi, 4.0);
+    assert_relative_eq!(hit.normal, Vector2::x());
+
+    let ray = Ray::new(Point2::new(2.5, 7.0), -Vector2::y());
+    let hit = voxels
+        .toi_and_normal_with_ray(&m, &ray, 10.0, true)
+        .unwrap();
+    assert_relative_eq!(hit.toi, 4.0);
+    assert_relative_eq!(hit.normal, Vector2::y());
+
+    // Leaves the notch of the L.
+    let ray = Ray::new(Point2::new(2.5, 3.5), Vector2::new(1.0, 1.0));
+    assert!(voxels.toi_with_ray(&m, &ray, 10.0, true).is_none());
+
+    // Hits the bottom of the L.
+    let ray = Ray::new(Point2::new(2.5, 3.5), -Vector2::y());
+    assert_eq!(voxels.toi_with_ray(&m, &ray, 10.0, true), Some(0.5));
+}
+
+#[test]
+fn voxels_point_projection() {
+    let voxels = voxels();
+    let m = Isometry2::identity();
+
+    assert!(voxels.contains_point(&m, &Point2::new(0.5, 0.9)));
+    assert!(!voxels.contains_point(&m, &Point2::new(1.5, 1.5)));
+
+    // The faces shared by adjacent voxels are ignored.
+    let proj = voxels.project_point(&m, &Point2::new(0.5, 0.9), false);
+    assert!(proj.is_inside);
+    assert_relative_eq!(proj.point, Point2::new(0.0, 0.9));
+
+    let proj = voxels.project_point(&m, &Point2::new(1.6, 1.5), true);
+    assert!(!proj.is_inside);
+    assert_relative_eq!(proj.point, Point2::new(1.6, 1.0));
+}
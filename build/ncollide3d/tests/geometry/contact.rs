@@ -1,6 +1,7 @@
-use na::{zero, Isometry3, Point3, Vector3};
+use na::{zero, DMatrix, Isometry3, Point3, Vector3};
 use ncollide3d::bounding_volume::*;
 use ncollide3d::pipeline::{CollisionGroups, CollisionWorld, GeometricQueryType};
+use ncollide3d::query::{self, Proximity};
 use ncollide3d::shape::*;
 
 // Issue #182.
@@ -24,3 +25,39 @@ fn just_touching_nan3d() {
 
     world.update();
 }
+
+#[test]
+fn contact_and_proximity_of_unsupported_shapes() {
+    let heightfield = HeightField::new(DMatrix::zeros(4, 4), Vector3::new(10.0f32, 1.0, 10.0));
+    let compound = Compound::new(vec![(
+        Isometry3::identity(),
+        ShapeHandle::new(heightfield.clone()),
+    )]);
+    let torus = Torus::new(2.0, 0.5);
+    let ball = Ball::new(0.5);
+    let id = Isometry3::identity();
+    let near = Isometry3::translation(0.0, 0.3, 0.0);
+    let far = Isometry3::translation(0.0, 10.0, 0.0);
+
+    assert!(query::contact_opt(&id, &heightfield, &near, &ball, 0.0).is_none());
+    assert!(query::contact_opt(&near, &ball, &id, &compound, 0.0).is_none());
+    assert!(query::contact_opt(&id, &torus, &id, &heightfield, 0.0).is_none());
+    assert!(query::proximity_opt(&id, &compound, &near, &ball, 0.0).is_none());
+    assert!(query::proximity_opt(&id, &heightfield, &near, &torus, 0.0).is_none());
+
+    // Only the parts of a composite shape close to the other shape need to be supported.
+    assert_eq!(
+        query::contact_opt(&id, &compound, &far, &ball, 0.0),
+        Some(None)
+    );
+    assert_eq!(
+        query::proximity_opt(&far, &ball, &id, &compound, 0.0),
+        Some(Proximity::Disjoint)
+    );
+
+    let contact = query::contact_opt(&id, &torus, &near, &ball, 0.0);
+    assert_eq!(
+        contact,
+        Some(query::contact(&id, &torus, &near, &ball, 0.0))
+    );
+}
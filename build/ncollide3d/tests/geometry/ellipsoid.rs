@@ -0,0 +1,164 @@
+use na::{Isometry3, Point3, Vector3};
+use ncollide3d::bounding_volume::{self, BoundingSphere, AABB};
+use ncollide3d::query::{PointQuery, Ray, RayCast};
+use ncollide3d::shape::{Ellipsoid, SupportMap};
+use ncollide3d::transformation::ToTriMesh;
+
+fn ellipsoid() -> Ellipsoid<f64> {
+    Ellipsoid::new(Vector3::new(3.0, 1.0, 2.0))
+}
+
+// The implicit equation of the surface, negative inside of the ellipsoid.
+fn implicit(e: &Ellipsoid<f64>, pt: &Point3<f64>) -> f64 {
+    pt.coords.component_div(&e.radii).norm_squared() - 1.0
+}
+
+fn random_point() -> Point3<f64> {
+    Point3::from((rand::random::<Vector3<f64>>() - Vector3::repeat(0.5)) * 8.0)
+}
+
+#[test]
+fn ellipsoid_support_point() {
+    let e = ellipsoid();
+    let surface = e.to_trimesh((20, 20));
+
+    for _ in 0..100 {
+        let dir = rand::random::<Vector3<f64>>() - Vector3::repeat(0.5);
+        let support = e.local_support_point(&dir);
+
+        assert!(implicit(&e, &support).abs() < 1.0e-10);
+        assert!(surface
+            .coords
+            .iter()
+            .all(|pt| pt.coords.dot(&dir) <= support.coords.dot(&dir) + 1.0e-10));
+    }
+}
+
+#[test]
+fn ellipsoid_bounding_volumes() {
+    let e = ellipsoid();
+    let m = Isometry3::translation(1.0, 2.0, 3.0);
+
+    let aabb: AABB<f64> = bounding_volume::aabb(&e, &m);
+    assert_relative_eq!(aabb.mins, Point3::new(-2.0, 1.0, 1.0), epsilon = 1.0e-10);
+    assert_relative_eq!(aabb.maxs, Point3::new(4.0, 3.0, 5.0), epsilon = 1.0e-10);
+
+    let sphere: BoundingSphere<f64> = bounding_volume::bounding_sphere(&e, &m);
+    assert_relative_eq!(*sphere.center(), Point3::new(1.0, 2.0, 3.0));
+    assert_relative_eq!(sphere.radius(), 3.0);
+}
+
+#[test]
+fn ellipsoid_ray_cast() {
+    let e = ellipsoid();
+
+    for _ in 0..1000 {
+        let origin = Point3::from(rand::random::<Vector3<f64>>().normalize() * 5.0);
+        let target = random_point() * 0.5;
+        let ray = Ray::new(origin, target - origin);
+        let m = Isometry3::new(
+            rand::random::<Vector3<f64>>(),
+            rand::random::<Vector3<f64>>(),
+        );
+        let ls_ray = ray.inverse_transform_by(&m);
+
+        match e.toi_and_normal_and_uv_with_ray(&m, &ray, f64::MAX, true) {
+            Some(hit) => {
+                let pt = m.inverse_transform_point(&ray.point_at(hit.toi));
+                let normal = m.inverse_transform_vector(&hit.normal);
+                let uvs = hit.uvs.unwrap();
+
+                assert!(implicit(&e, &pt).abs() < 1.0e-8);
+                assert!(implicit(&e, &(pt + normal * 1.0e-3)) > 0.0);
+                assert_relative_eq!(normal.norm(), 1.0, epsilon = 1.0e-8);
+                assert!(uvs.x >= 0.0 && uvs.x <= 1.0 && uvs.y >= 0.0 && uvs.y <= 1.0);
+            }
+            None => {
+                // The line misses the ellipsoid: its closest point to the center, in the space
+                // where the ellipsoid is a unit ball, is outside of this ball.
+                let o = ls_ray.origin.coords.component_div(&e.radii);
+                let d = ls_ray.dir.component_div(&e.radii);
+                let closest = o - d * (o.dot(&d) / d.norm_squared());
+                assert!(closest.norm() >= 1.0 - 1.0e-8);
+            }
+        }
+    }
+
+    // A ray starting inside of the ellipsoid.
+    let ray = Ray::new(Point3::origin(), Vector3::x());
+    let hit = e
+        .toi_and_normal_with_ray(&Isometry3::identity(), &ray, f64::MAX, false)
+        .unwrap();
+    assert_relative_eq!(hit.toi, 3.0);
+
+    let hit = e
+        .toi_and_normal_with_ray(&Isometry3::identity(), &ray, f64::MAX, true)
+        .unwrap();
+    assert_relative_eq!(hit.toi, 0.0);
+}
+
+#[test]
+fn ellipsoid_point_projection() {
+    let e = ellipsoid();
+    let samples = e.to_trimesh((100, 100)).coords;
+
+    for _ in 0..200 {
+        let pt = random_point();
+        let proj = e.project_point(&Isometry3::identity(), &pt, false);
+
+        assert_eq!(proj.is_inside, implicit(&e, &pt) <= 0.0);
+        assert!(implicit(&e, &proj.point).abs() < 1.0e-10);
+
+        // The projection is locally optimal: the displacement is normal to the surface.
+        let normal = e.local_normal_at(&proj.point).unwrap();
+        let dpt = pt - proj.point;
+        assert!(dpt.cross(&normal).norm() < 1.0e-8 * (1.0 + dpt.norm()));
+
+        // And globally optimal, up to the sampling error.
+        let dist = dpt.norm();
+        assert!(samples
+            .iter()
+            .all(|s| dist <= na::distance(s, &pt) + 1.0e-10));
+    }
+}
+
+#[test]
+fn ellipsoid_point_projection_degenerate() {
+    let e = ellipsoid();
+    let m = Isometry3::identity();
+
+    // The closest points to the center are at the tips of the smallest axis.
+    let proj = e.project_point(&m, &Point3::origin(), false);
+    assert!(proj.is_inside);
+    assert_relative_eq!(proj.point.coords.abs(), Vector3::y(), epsilon = 1.0e-10);
+    assert_relative_eq!(e.distance_to_point(&m, &Point3::origin(), false), -1.0);
+
+    // A point of the principal plane orthogonal to the smallest axis, close to the center.
+    let pt = Point3::new(0.5, 0.0, 0.0);
+    let proj = e.project_point(&m, &pt, false);
+    assert!(implicit(&e, &proj.point).abs() < 1.0e-10);
+    assert_relative_eq!(proj.point.x, 0.5 * 9.0 / 8.0, epsilon = 1.0e-10);
+    assert!(proj.point.y.abs() > 0.5);
+
+    // Points inside of a solid ellipsoid are their own projections.
+    let proj = e.project_point(&m, &pt, true);
+    assert_eq!(proj.point, pt);
+    assert!(e.contains_point(&m, &pt));
+    assert!(!e.contains_point(&m, &Point3::new(0.0, 1.1, 0.0)));
+}
+
+#[test]
+fn ellipsoid_to_trimesh() {
+    let e = ellipsoid();
+    let mesh = e.to_trimesh((10, 10));
+    let normals = mesh.normals.as_ref().unwrap();
+
+    for (pt, n) in mesh.coords.iter().zip(normals.iter()) {
+        assert!(implicit(&e, pt).abs() < 1.0e-10);
+        assert_relative_eq!(
+            *n,
+            e.local_normal_at(pt).unwrap().into_inner(),
+            epsilon = 1.0e-8
+        );
+    }
+}
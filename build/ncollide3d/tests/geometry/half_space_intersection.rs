@@ -0,0 +1,145 @@
+use na::{Isometry3, Point3, Unit, Vector3};
+use ncollide3d::bounding_volume::AABB;
+use ncollide3d::query::Frustum;
+use ncollide3d::shape::{Ball, ConvexHull, Cuboid, SupportMap};
+use ncollide3d::transformation::{self, HalfSpace, HalfSpaceIntersectionError};
+
+fn half_space(x: f64, y: f64, z: f64, offset: f64) -> HalfSpace<f64> {
+    HalfSpace::new(Unit::new_normalize(Vector3::new(x, y, z)), offset)
+}
+
+// The half-spaces of the cube `[-1, 1]^3`.
+fn cube() -> Vec<HalfSpace<f64>> {
+    vec![
+        half_space(1.0, 0.0, 0.0, 1.0),
+        half_space(-1.0, 0.0, 0.0, 1.0),
+        half_space(0.0, 1.0, 0.0, 1.0),
+        half_space(0.0, -1.0, 0.0, 1.0),
+        half_space(0.0, 0.0, 1.0, 1.0),
+        half_space(0.0, 0.0, -1.0, 1.0),
+    ]
+}
+
+#[test]
+fn convex_hull_from_half_spaces() {
+    let mut half_spaces = cube();
+    // A redundant half-space.
+    half_spaces.push(half_space(1.0, 1.0, 0.0, 5.0));
+
+    let hull = ConvexHull::try_from_half_spaces(&half_spaces).unwrap();
+    assert_eq!(hull.points().len(), 8);
+
+    let cuboid = Cuboid::new(Vector3::repeat(1.0));
+    for dir in &[Vector3::new(1.0, 2.0, -3.0), Vector3::new(-1.0, 0.5, 0.2)] {
+        assert_relative_eq!(
+            hull.local_support_point(dir),
+            cuboid.local_support_point(dir),
+            epsilon = 1.0e-7
+        );
+    }
+
+    // Cut a corner of the cube.
+    half_spaces.push(half_space(1.0, 1.0, 1.0, 2.0 / 3.0f64.sqrt()));
+    let hull = ConvexHull::try_from_half_spaces(&half_spaces).unwrap();
+    assert_eq!(hull.points().len(), 10);
+    assert_relative_eq!(
+        hull.local_support_point(&Vector3::new(1.0, 1.0, 1.0))
+            .coords
+            .sum(),
+        2.0,
+        epsilon = 1.0e-7
+    );
+}
+
+#[test]
+fn half_space_intersection_errors() {
+    // Opposite half-spaces that do not overlap.
+    let mut half_spaces = cube();
+    half_spaces[1].offset = -1.5;
+    assert_eq!(
+        transformation::half_space_intersection(&half_spaces),
+        Err(HalfSpaceIntersectionError::Infeasible)
+    );
+
+    // Same without the other faces of the cube.
+    assert_eq!(
+        transformation::half_space_intersection(&half_spaces[..2]),
+        Err(HalfSpaceIntersectionError::Infeasible)
+    );
+
+    // A cube without its top face.
+    let mut half_spaces = cube();
+    let _ = half_spaces.remove(2);
+    assert_eq!(
+        ConvexHull::try_from_half_spaces(&half_spaces).err(),
+        Some(HalfSpaceIntersectionError::Unbounded)
+    );
+
+    // A cube flattened into a square.
+    let mut half_spaces = cube();
+    half_spaces[0].offset = -1.0;
+    assert_eq!(
+        ConvexHull::try_from_half_spaces(&half_spaces).err(),
+        Some(HalfSpaceIntersectionError::Degenerate)
+    );
+}
+
+// A camera at the origin looking toward `-z`, with a field of view of 90 degrees.
+fn frustum() -> Frustum<f64> {
+    Frustum::perspective(
+        &Isometry3::identity(),
+        std::f64::consts::FRAC_PI_2,
+        1.0,
+        1.0,
+        10.0,
+    )
+    .unwrap()
+}
+
+#[test]
+fn frustum_points_and_aabbs() {
+    let frustum = frustum();
+
+    assert!(frustum.contains_point(&Point3::new(0.0, 0.0, -5.0)));
+    assert!(frustum.contains_point(&Point3::new(4.9, -4.9, -5.0)));
+    assert!(!frustum.contains_point(&Point3::new(0.0, 0.0, 5.0)));
+    assert!(!frustum.contains_point(&Point3::new(5.1, 0.0, -5.0)));
+    assert!(!frustum.contains_point(&Point3::new(0.0, 0.0, -0.5)));
+    assert!(!frustum.contains_point(&Point3::new(0.0, 0.0, -10.5)));
+
+    let inside = AABB::from_half_extents(Point3::new(0.0, 0.0, -5.0), Vector3::repeat(1.0));
+    assert!(frustum.intersects_aabb(&inside));
+    assert!(frustum.contains_aabb(&inside));
+
+    let behind = AABB::from_half_extents(Point3::new(0.0, 0.0, 5.0), Vector3::repeat(1.0));
+    assert!(!frustum.intersects_aabb(&behind));
+    assert!(!frustum.contains_aabb(&behind));
+
+    let across_near_plane =
+        AABB::from_half_extents(Point3::new(0.0, 0.0, -1.0), Vector3::repeat(0.5));
+    assert!(frustum.intersects_aabb(&across_near_plane));
+    assert!(!frustum.contains_aabb(&across_near_plane));
+
+    // The frustum vertices are the corners of the near and far rectangles.
+    let aabb = ncollide3d::bounding_volume::local_aabb(frustum.convex_hull());
+    assert_relative_eq!(
+        aabb.mins,
+        Point3::new(-10.0, -10.0, -10.0),
+        epsilon = 1.0e-7
+    );
+    assert_relative_eq!(aabb.maxs, Point3::new(10.0, 10.0, -1.0), epsilon = 1.0e-7);
+}
+
+#[test]
+fn frustum_shapes() {
+    let frustum = frustum();
+    let ball = Ball::new(1.0);
+
+    assert!(frustum.intersects_shape(&Isometry3::translation(6.0, 0.0, -5.0), &ball));
+    assert!(!frustum.intersects_shape(&Isometry3::translation(6.5, 0.0, -5.0), &ball));
+
+    // Close to an edge of the frustum: the AABB test alone is not conclusive.
+    let pos = Isometry3::translation(6.3, 6.3, -5.0);
+    assert!(frustum.intersects_aabb(&ncollide3d::bounding_volume::aabb(&ball, &pos)));
+    assert!(!frustum.intersects_shape(&pos, &ball));
+}
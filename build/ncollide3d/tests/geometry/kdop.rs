@@ -0,0 +1,194 @@
+use na::{Isometry3, Point3, Translation3, UnitQuaternion, Vector3};
+use ncollide3d::bounding_volume::{
+    BoundingVolume, HasBoundingVolume, AABB, KDOP, KDOP14, KDOP18, KDOP26,
+};
+use ncollide3d::partitioning::{DBVTLeaf, BVH, BVT, DBVT};
+use ncollide3d::query::visitors::{
+    BoundingVolumeInterferencesCollector, KDOPPointInterferencesCollector,
+    RayInterferencesCollector,
+};
+use ncollide3d::query::{PointQuery, Ray, RayCast};
+use ncollide3d::shape::{Ball, Capsule, Cuboid, TriMesh};
+
+fn random_position(range: f32) -> Isometry3<f32> {
+    let t = (rand::random::<Vector3<f32>>() - Vector3::repeat(0.5)) * range;
+    Isometry3::from_parts(Translation3::from(t), rand::random::<UnitQuaternion<f32>>())
+}
+
+fn random_cuboid() -> Cuboid<f32> {
+    Cuboid::new(rand::random::<Vector3<f32>>() + Vector3::repeat(0.1))
+}
+
+fn random_point(range: f32) -> Point3<f32> {
+    Point3::from((rand::random::<Vector3<f32>>() - Vector3::repeat(0.5)) * range)
+}
+
+#[test]
+fn kdop_of_diagonal_mesh_is_tighter_than_aabb() {
+    // A thin triangle strip along the diagonal of the unit cube.
+    let points = vec![
+        Point3::new(0.0, 0.0, 0.0),
+        Point3::new(0.1, 0.0, 0.0),
+        Point3::new(1.0, 1.0, 1.0),
+        Point3::new(1.1, 1.0, 1.0),
+    ];
+    let indices = vec![Point3::new(0, 1, 2), Point3::new(1, 3, 2)];
+    let mesh = TriMesh::new(points.clone(), indices, None);
+
+    let aabb: AABB<f32> = mesh.local_bounding_volume();
+    let kdop: KDOP14<f32> = mesh.local_bounding_volume();
+    assert_eq!(kdop.aabb(), aabb);
+
+    // This point is in the AABB but far from the diagonal.
+    let corner = Point3::new(1.0, 0.0, 0.0);
+    assert!(aabb.contains_local_point(&corner));
+    assert!(!kdop.contains_local_point(&corner));
+
+    for pt in &points {
+        assert!(kdop.contains_local_point(pt));
+    }
+
+    let kdop26: KDOP26<f32> = mesh.local_bounding_volume();
+    assert!(!kdop26.contains_local_point(&corner));
+    assert!(!kdop26.contains_local_point(&Point3::new(1.0, 1.0, 0.0)));
+}
+
+#[test]
+fn kdop_of_support_maps_contains_the_shape() {
+    for _ in 0..100 {
+        let pos = random_position(4.0);
+        let cuboid = random_cuboid();
+        let capsule = Capsule::new(0.5, 0.2);
+        let kdop1: KDOP18<f32> = cuboid.bounding_volume(&pos);
+        let kdop2: KDOP18<f32> = capsule.bounding_volume(&pos);
+        let aabb: AABB<f32> = cuboid.bounding_volume(&pos);
+
+        assert_relative_eq!(kdop1.aabb().mins, aabb.mins, epsilon = 1.0e-5);
+        assert_relative_eq!(kdop1.aabb().maxs, aabb.maxs, epsilon = 1.0e-5);
+
+        for _ in 0..20 {
+            let pt = random_point(6.0);
+            if cuboid.contains_point(&pos, &pt) {
+                assert!(kdop1.loosened(1.0e-4).contains_local_point(&pt));
+            }
+            if capsule.contains_point(&pos, &pt) {
+                assert!(kdop2.loosened(1.0e-4).contains_local_point(&pt));
+            }
+        }
+    }
+}
+
+#[test]
+fn kdop_merge_and_containment() {
+    for _ in 0..100 {
+        let kdop1: KDOP14<f32> = random_cuboid().bounding_volume(&random_position(4.0));
+        let kdop2: KDOP14<f32> = Ball::new(0.5).bounding_volume(&random_position(4.0));
+        let merged = kdop1.merged(&kdop2);
+
+        assert!(merged.contains(&kdop1));
+        assert!(merged.contains(&kdop2));
+        assert!(merged.intersects(&kdop1));
+        assert!(merged.intersects(&kdop2));
+        assert!(merged.loosened(0.1).contains(&merged));
+        assert!(!merged.tightened(0.01).contains(&merged));
+        assert_eq!(KDOP::new_invalid().merged(&kdop1), kdop1);
+    }
+}
+
+#[test]
+fn kdop_ray_cast() {
+    let kdop: KDOP14<f32> = Ball::new(1.0).local_bounding_volume();
+    let id = Isometry3::identity();
+
+    // The corners of the bounding cube are cut by the diagonal planes.
+    let ray = Ray::new(Point3::new(3.0, 3.0, 3.0), Vector3::new(-1.0, -1.0, -1.0));
+    let inter = kdop.toi_and_normal_with_ray(&id, &ray, 10.0, true).unwrap();
+    assert_relative_eq!(inter.toi, 3.0 - 1.0 / 3.0f32.sqrt(), epsilon = 1.0e-5);
+    assert_relative_eq!(
+        inter.normal,
+        Vector3::repeat(1.0).normalize(),
+        epsilon = 1.0e-5
+    );
+
+    let ray = Ray::new(Point3::origin(), Vector3::x());
+    assert_eq!(kdop.toi_with_ray(&id, &ray, 10.0, true), Some(0.0));
+    assert_eq!(kdop.toi_with_ray(&id, &ray, 10.0, false), Some(1.0));
+
+    let ray = Ray::new(Point3::new(3.0, 0.0, 0.0), Vector3::x());
+    assert_eq!(kdop.toi_with_ray(&id, &ray, 10.0, true), None);
+}
+
+#[test]
+fn bvt_and_dbvt_of_kdops() {
+    let shapes: Vec<_> = (0..100)
+        .map(|_| (random_position(20.0), random_cuboid()))
+        .collect();
+    let kdops: Vec<KDOP18<f32>> = shapes
+        .iter()
+        .map(|(pos, shape)| shape.bounding_volume(pos))
+        .collect();
+    let bvt = BVT::new_balanced(kdops.iter().cloned().enumerate().collect());
+    let mut dbvt = DBVT::new();
+
+    for (i, kdop) in kdops.iter().enumerate() {
+        let _ = dbvt.insert(DBVTLeaf::new(*kdop, i));
+    }
+
+    for _ in 0..50 {
+        let ray = Ray::new(random_point(30.0), random_point(1.0).coords);
+        let expected: Vec<_> = (0..kdops.len())
+            .filter(|i| kdops[*i].intersects_ray(&Isometry3::identity(), &ray, 100.0))
+            .collect();
+
+        for (i, (pos, shape)) in shapes.iter().enumerate() {
+            if shape.intersects_ray(pos, &ray, 100.0) {
+                assert!(expected.contains(&i));
+            }
+        }
+
+        let mut hits1 = Vec::new();
+        let mut hits2 = Vec::new();
+        bvt.visit(&mut RayInterferencesCollector::new(&ray, 100.0, &mut hits1));
+        dbvt.visit(&mut RayInterferencesCollector::new(&ray, 100.0, &mut hits2));
+        hits1.sort();
+        hits2.sort();
+        assert_eq!(hits1, expected);
+        assert_eq!(hits2, expected);
+
+        let point = random_point(20.0);
+        let expected: Vec<_> = (0..kdops.len())
+            .filter(|i| kdops[*i].contains_local_point(&point))
+            .collect();
+        let mut hits1 = Vec::new();
+        let mut hits2 = Vec::new();
+        bvt.visit(&mut KDOPPointInterferencesCollector::new(
+            &point, &mut hits1,
+        ));
+        dbvt.visit(&mut KDOPPointInterferencesCollector::new(
+            &point, &mut hits2,
+        ));
+        hits1.sort();
+        hits2.sort();
+        assert_eq!(hits1, expected);
+        assert_eq!(hits2, expected);
+
+        let query_kdop: KDOP18<f32> = random_cuboid().bounding_volume(&random_position(20.0));
+        let expected: Vec<_> = (0..kdops.len())
+            .filter(|i| kdops[*i].intersects(&query_kdop))
+            .collect();
+        let mut hits1 = Vec::new();
+        let mut hits2 = Vec::new();
+        bvt.visit(&mut BoundingVolumeInterferencesCollector::new(
+            &query_kdop,
+            &mut hits1,
+        ));
+        dbvt.visit(&mut BoundingVolumeInterferencesCollector::new(
+            &query_kdop,
+            &mut hits2,
+        ));
+        hits1.sort();
+        hits2.sort();
+        assert_eq!(hits1, expected);
+        assert_eq!(hits2, expected);
+    }
+}
@@ -0,0 +1,194 @@
+use na::{Isometry3, Matrix3, Point3, Vector3};
+use ncollide3d::mass_properties::MassProperties;
+use ncollide3d::procedural;
+use ncollide3d::shape::{
+    Ball, Capsule, Compound, Cone, ConvexHull, Cuboid, Cylinder, Plane, Shape, ShapeHandle, TriMesh,
+};
+use std::f64::consts::{FRAC_PI_2, PI};
+
+fn assert_mprops_eq(a: &MassProperties<f64>, b: &MassProperties<f64>, eps: f64) {
+    assert_relative_eq!(a.mass, b.mass, epsilon = eps);
+    assert_relative_eq!(a.local_com, b.local_com, epsilon = eps);
+    assert_relative_eq!(a.angular_inertia, b.angular_inertia, epsilon = eps);
+}
+
+fn cube_trimesh(half_extents: Vector3<f64>, shift: Vector3<f64>) -> TriMesh<f64> {
+    let mut points = Vec::new();
+
+    for i in 0..8 {
+        let sign = |bit: usize| if i & (1 << bit) == 0 { -1.0 } else { 1.0 };
+        let pt = Point3::new(sign(0), sign(1), sign(2));
+        points.push(pt.coords.component_mul(&half_extents) + shift);
+    }
+
+    let points = points.into_iter().map(Point3::from).collect();
+    // Outward-oriented faces of the cube.
+    let indices = vec![
+        Point3::new(0, 2, 3),
+        Point3::new(0, 3, 1),
+        Point3::new(4, 5, 7),
+        Point3::new(4, 7, 6),
+        Point3::new(0, 1, 5),
+        Point3::new(0, 5, 4),
+        Point3::new(2, 6, 7),
+        Point3::new(2, 7, 3),
+        Point3::new(0, 4, 6),
+        Point3::new(0, 6, 2),
+        Point3::new(1, 3, 7),
+        Point3::new(1, 7, 5),
+    ];
+
+    TriMesh::new(points, indices, None)
+}
+
+#[test]
+fn ball_mass_properties() {
+    let ball = Ball::new(2.0f64);
+    let mprops = ball.mass_properties(3.0).unwrap();
+
+    let mass = 3.0 * 4.0 / 3.0 * PI * 8.0;
+    assert_relative_eq!(mprops.mass, mass, epsilon = 1.0e-10);
+    assert_relative_eq!(mprops.local_com, Point3::origin());
+    assert_relative_eq!(
+        mprops.angular_inertia,
+        Matrix3::from_diagonal_element(mass * 2.0 / 5.0 * 4.0),
+        epsilon = 1.0e-10
+    );
+}
+
+#[test]
+fn cuboid_convex_hull_and_trimesh_mass_properties_match() {
+    let half_extents = Vector3::new(1.0, 2.0, 3.0);
+    let cuboid = Cuboid::new(half_extents).mass_properties(2.0).unwrap();
+
+    assert_relative_eq!(cuboid.mass, 2.0 * 48.0);
+    assert_relative_eq!(
+        cuboid.angular_inertia,
+        Matrix3::from_diagonal(&Vector3::new(
+            cuboid.mass * 52.0 / 12.0,
+            cuboid.mass * 40.0 / 12.0,
+            cuboid.mass * 20.0 / 12.0
+        )),
+        epsilon = 1.0e-10
+    );
+
+    let mesh = cube_trimesh(half_extents, Vector3::zeros());
+    let hull = ConvexHull::try_from_points(mesh.points()).unwrap();
+    let hull = hull.mass_properties(2.0).unwrap();
+    assert_mprops_eq(&hull, &cuboid, 1.0e-10);
+
+    let mesh = mesh.mass_properties(2.0).unwrap();
+    assert_mprops_eq(&mesh, &cuboid, 1.0e-10);
+}
+
+#[test]
+fn trimesh_mass_properties_center_of_mass_and_orientation() {
+    let half_extents = Vector3::new(1.0, 0.5, 2.0);
+    let shift = Vector3::new(3.0, -1.0, 2.0);
+    let mesh = cube_trimesh(half_extents, shift);
+    let expected = Cuboid::new(half_extents)
+        .mass_properties(1.0)
+        .unwrap()
+        .transform_by(&Isometry3::new(shift, Vector3::zeros()));
+
+    let mprops = mesh.mass_properties(1.0).unwrap();
+    assert_mprops_eq(&mprops, &expected, 1.0e-10);
+
+    // Inward-oriented meshes enclose the same solid.
+    let flipped: Vec<_> = mesh.faces().iter().map(|f| f.indices.zxy().yxz()).collect();
+    let flipped = TriMesh::new(mesh.points().to_vec(), flipped, None);
+    let mprops = flipped.mass_properties(1.0).unwrap();
+    assert_mprops_eq(&mprops, &expected, 1.0e-10);
+}
+
+#[test]
+fn open_trimesh_and_plane_have_no_mass_properties() {
+    let mesh = cube_trimesh(Vector3::repeat(1.0), Vector3::zeros());
+    let mut indices: Vec<_> = mesh.faces().iter().map(|f| f.indices).collect();
+    let _ = indices.pop();
+    let open = TriMesh::new(mesh.points().to_vec(), indices, None);
+
+    assert!(open.mass_properties(1.0).is_none());
+
+    // A triangle sharing an edge with two faces of the cube makes it non-manifold.
+    let mut indices: Vec<_> = mesh.faces().iter().map(|f| f.indices).collect();
+    let mut points = mesh.points().to_vec();
+    points.push(Point3::new(0.0, 0.0, 3.0));
+    indices.push(Point3::new(0, 2, 8));
+    indices.push(Point3::new(2, 0, 8));
+    let non_manifold = TriMesh::new(points, indices, None);
+
+    assert!(non_manifold.mass_properties(1.0).is_none());
+    assert!(Plane::new(Vector3::y_axis()).mass_properties(1.0).is_none());
+}
+
+#[test]
+fn cylinder_cone_and_capsule_mass_properties_match_their_hulls() {
+    let approx = |mesh: procedural::TriMesh<f64>| {
+        ConvexHull::try_from_points(&mesh.coords)
+            .unwrap()
+            .mass_properties(1.0)
+            .unwrap()
+    };
+
+    let cylinder = Cylinder::new(1.5, 0.5).mass_properties(1.0).unwrap();
+    let hull = approx(procedural::cylinder(1.0, 3.0, 200));
+    assert_mprops_eq(&cylinder, &hull, 1.0e-3);
+
+    let cone = Cone::new(1.5, 0.5).mass_properties(1.0).unwrap();
+    let hull = approx(procedural::cone(1.0, 3.0, 200));
+    assert_relative_eq!(cone.local_com, Point3::new(0.0, -0.75, 0.0));
+    assert_mprops_eq(&cone, &hull, 1.0e-3);
+
+    let capsule = Capsule::new(1.5, 0.5).mass_properties(1.0).unwrap();
+    let hull = approx(procedural::capsule(&1.0, &3.0, 200, 100));
+    assert_mprops_eq(&capsule, &hull, 1.0e-2);
+
+    // A capsule without height is a ball.
+    let capsule = Capsule::new(0.0, 0.5).mass_properties(1.0).unwrap();
+    let ball = Ball::new(0.5).mass_properties(1.0).unwrap();
+    assert_mprops_eq(&capsule, &ball, 1.0e-10);
+}
+
+#[test]
+fn compound_mass_properties() {
+    let cuboid = ShapeHandle::new(Cuboid::new(Vector3::new(1.0, 0.5, 0.5)));
+    let shapes = vec![
+        (
+            Isometry3::new(Vector3::new(-1.0, 0.0, 0.0), Vector3::zeros()),
+            cuboid.clone(),
+        ),
+        (
+            Isometry3::new(Vector3::new(1.0, 0.0, 0.0), Vector3::zeros()),
+            cuboid,
+        ),
+    ];
+    let compound = Compound::new(shapes).mass_properties(2.0).unwrap();
+    let expected = Cuboid::new(Vector3::new(2.0, 0.5, 0.5))
+        .mass_properties(2.0)
+        .unwrap();
+    assert_mprops_eq(&compound, &expected, 1.0e-10);
+
+    // Rotating a shape rotates its angular inertia.
+    let cuboid = Cuboid::new(Vector3::new(2.0, 0.5, 0.5));
+    let rot = Isometry3::new(Vector3::zeros(), Vector3::z() * FRAC_PI_2);
+    let shapes = vec![(rot, ShapeHandle::new(cuboid))];
+    let compound = Compound::new(shapes).mass_properties(1.0).unwrap();
+    let expected = Cuboid::new(Vector3::new(0.5, 2.0, 0.5))
+        .mass_properties(1.0)
+        .unwrap();
+    assert_mprops_eq(&compound, &expected, 1.0e-10);
+
+    // Compounds with shapes without mass properties have no mass properties.
+    let shapes = vec![
+        (Isometry3::identity(), ShapeHandle::new(Ball::new(1.0))),
+        (
+            Isometry3::identity(),
+            ShapeHandle::new(Plane::new(Vector3::y_axis())),
+        ),
+    ];
+    assert!(Compound::new(shapes).mass_properties(1.0).is_none());
+
+    let empty = MassProperties::<f64>::zero();
+    assert_eq!(empty.inv_mass(), 0.0);
+}
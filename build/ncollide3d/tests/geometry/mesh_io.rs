@@ -0,0 +1,250 @@
+use na::{Point2, Point3, Vector3};
+use ncollide3d::procedural;
+use ncollide3d::shape::{Shape, TriMesh};
+use ncollide3d::transformation::io::{self, MeshIoError, PlyFormat};
+
+fn cuboid_volume(mesh: procedural::TriMesh<f64>) -> f64 {
+    let mesh: TriMesh<f64> = mesh.into();
+    mesh.mass_properties(1.0).unwrap().mass
+}
+
+fn triangle_positions(mesh: &procedural::TriMesh<f64>) -> Vec<Point3<f64>> {
+    mesh.flat_indices()
+        .iter()
+        .map(|i| mesh.coords[*i as usize])
+        .collect()
+}
+
+#[test]
+fn obj_round_trip() {
+    let cuboid = procedural::cuboid::<f64>(&Vector3::new(1.0, 2.0, 3.0));
+    let mut data = Vec::new();
+    io::write_obj(&cuboid, &mut data).unwrap();
+
+    let mesh: procedural::TriMesh<f64> = io::read_obj(&data[..]).unwrap();
+    assert_eq!(mesh.coords, cuboid.coords);
+    assert_eq!(mesh.normals, cuboid.normals);
+    assert_eq!(mesh.uvs, cuboid.uvs);
+    assert_eq!(
+        mesh.indices.unwrap_split(),
+        cuboid.clone().indices.unwrap_split()
+    );
+}
+
+#[test]
+fn obj_polygons_and_relative_indices() {
+    let obj = "
+        # A unit square split into two triangles.
+        o square
+        v 0 0 0
+        v 1 0 0
+        v 1 1 0
+        v 0 1 0
+        vt 0 0
+        vt 1 0
+        vt 1 1
+        vt 0 1
+        vn 0 0 1
+        usemtl none
+        f -4/1/1 -3/2/1 -2/3/1 -1/4/1
+    ";
+    let mesh: procedural::TriMesh<f32> = io::read_obj(obj.as_bytes()).unwrap();
+
+    assert_eq!(mesh.coords.len(), 4);
+    assert_eq!(mesh.uvs.as_ref().unwrap()[2], Point2::new(1.0, 1.0));
+    assert_eq!(mesh.normals.as_ref().unwrap(), &[Vector3::z()]);
+    assert_eq!(
+        mesh.indices.unwrap_split(),
+        vec![
+            Point3::new(
+                Point3::new(0, 0, 0),
+                Point3::new(1, 0, 1),
+                Point3::new(2, 0, 2)
+            ),
+            Point3::new(
+                Point3::new(0, 0, 0),
+                Point3::new(2, 0, 2),
+                Point3::new(3, 0, 3)
+            ),
+        ]
+    );
+
+    // Without texture coordinates or normals, the index buffer is unified.
+    let mesh: procedural::TriMesh<f32> =
+        io::read_obj("v 0 0 0\nv 1 0 0\nv 0 1 0\nf 1 2 3".as_bytes()).unwrap();
+    assert!(!mesh.has_normals() && !mesh.has_uvs());
+    assert_eq!(mesh.indices.unwrap_unified(), vec![Point3::new(0, 1, 2)]);
+}
+
+#[test]
+fn malformed_obj() {
+    let res = io::read_obj::<f32, _>("v 0 0 0\nv 1 0 0\nf 1 2 3".as_bytes());
+    match res {
+        Err(MeshIoError::Malformed { line: Some(3), .. }) => {}
+        _ => panic!("the out-of-bounds index was not detected"),
+    }
+
+    let res = io::read_obj::<f32, _>("v 0 0 zero".as_bytes());
+    match res {
+        Err(MeshIoError::Malformed { line: Some(1), .. }) => {}
+        _ => panic!("the invalid number was not detected"),
+    }
+}
+
+#[test]
+fn stl_round_trip() {
+    let cuboid = procedural::cuboid::<f64>(&Vector3::new(1.0, 2.0, 3.0));
+
+    let mut ascii = Vec::new();
+    io::write_stl_ascii(&cuboid, &mut ascii).unwrap();
+    let mut binary = Vec::new();
+    io::write_stl_binary(&cuboid, &mut binary).unwrap();
+    assert_eq!(binary.len(), 84 + 12 * 50);
+
+    for data in &[ascii, binary] {
+        let mesh: procedural::TriMesh<f64> = io::read_stl(&data[..]).unwrap();
+        // Identical vertices are merged.
+        assert_eq!(mesh.coords.len(), 8);
+        assert_eq!(mesh.num_triangles(), 12);
+        assert_eq!(mesh.normals.as_ref().unwrap().len(), 12);
+        assert_relative_eq!(cuboid_volume(mesh), 6.0, epsilon = 1.0e-6);
+    }
+}
+
+#[test]
+fn malformed_stl() {
+    let mut binary = Vec::new();
+    io::write_stl_binary(&procedural::unit_cuboid::<f32>(), &mut binary).unwrap();
+    binary.truncate(binary.len() - 10);
+    assert!(io::read_stl::<f32, _>(&binary[..]).is_err());
+
+    let ascii = "solid\nfacet normal 0 0 1\nouter loop\nvertex 0 0 0\nvertex 1 0 0\nendloop\nendfacet\nendsolid\n";
+    match io::read_stl::<f32, _>(ascii.as_bytes()) {
+        Err(MeshIoError::Malformed { line: Some(7), .. }) => {}
+        _ => panic!("the facet with two vertices was not detected"),
+    }
+}
+
+#[test]
+fn ply_round_trip() {
+    let mut cuboid = procedural::cuboid::<f64>(&Vector3::new(1.0, 2.0, 3.0));
+    cuboid.unify_index_buffer();
+
+    for format in &[
+        PlyFormat::Ascii,
+        PlyFormat::BinaryLittleEndian,
+        PlyFormat::BinaryBigEndian,
+    ] {
+        let mut data = Vec::new();
+        io::write_ply(&cuboid, &mut data, *format).unwrap();
+        let mesh: procedural::TriMesh<f64> = io::read_ply(&data[..]).unwrap();
+
+        assert_eq!(mesh.coords, cuboid.coords);
+        assert_eq!(mesh.normals, cuboid.normals);
+        assert_eq!(mesh.uvs, cuboid.uvs);
+        assert_eq!(mesh.flat_indices(), cuboid.flat_indices());
+    }
+}
+
+#[test]
+fn ply_skips_unknown_elements_and_properties() {
+    let ply = "ply
+format ascii 1.0
+comment a unit square with colors
+element vertex 4
+property float x
+property float y
+property float z
+property uchar red
+element face 1
+property uchar flags
+property list uchar int vertex_index
+element edge 1
+property int vertex1
+property int vertex2
+end_header
+0 0 0 255
+1 0 0 255
+1 1 0 0
+0 1 0 0
+7 4 0 1 2 3
+0 1
+";
+    let mesh: procedural::TriMesh<f32> = io::read_ply(ply.as_bytes()).unwrap();
+    assert_eq!(mesh.coords[2], Point3::new(1.0, 1.0, 0.0));
+    assert!(!mesh.has_normals() && !mesh.has_uvs());
+    assert_eq!(
+        mesh.indices.unwrap_unified(),
+        vec![Point3::new(0, 1, 2), Point3::new(0, 2, 3)]
+    );
+
+    let bad = ply.replace("7 4 0 1 2 3", "7 4 0 1 2 9");
+    assert!(io::read_ply::<f32, _>(bad.as_bytes()).is_err());
+    let bad = ply.replace("0 1 0 0\n", "");
+    assert!(io::read_ply::<f32, _>(bad.as_bytes()).is_err());
+}
+
+#[test]
+fn malformed_ply_faces() {
+    let ply = |face: &str, index_type: &str| {
+        format!(
+            "ply
+format ascii 1.0
+element vertex 3
+property float x
+property float y
+property float z
+element face 1
+property list {} int vertex_indices
+end_header
+0 0 0
+1 0 0
+0 1 0
+{}
+",
+            index_type, face
+        )
+    };
+
+    assert!(io::read_ply::<f32, _>(ply("3 0 1 2", "uchar").as_bytes()).is_ok());
+
+    for (face, index_type) in &[
+        ("1e30 0 1 2", "float"),
+        ("4000000000 0 1 2", "uint"),
+        ("-3 0 1 2", "int"),
+        ("2.5 0 1 2", "float"),
+        ("3 0 1 NaN", "uchar"),
+        ("3 0 1 2.7", "uchar"),
+        ("3 0 1 1e12", "uchar"),
+    ] {
+        match io::read_ply::<f32, _>(ply(face, index_type).as_bytes()) {
+            Err(MeshIoError::Malformed { line: Some(13), .. }) => {}
+            _ => panic!("the invalid face `{}` was not detected", face),
+        }
+    }
+}
+
+#[test]
+fn load_and_save_files() {
+    let cuboid = procedural::cuboid::<f64>(&Vector3::new(1.0, 2.0, 3.0));
+    let dir = std::env::temp_dir();
+
+    for ext in &["obj", "STL", "ply"] {
+        let path = dir.join(format!("ncollide_mesh_io_test.{}", ext));
+        io::save(&cuboid, &path).unwrap();
+        let mesh: procedural::TriMesh<f64> = io::load(&path).unwrap();
+        std::fs::remove_file(&path).unwrap();
+
+        let positions = triangle_positions(&mesh);
+        let expected = triangle_positions(&cuboid);
+        assert_eq!(positions.len(), expected.len());
+        for (pt, expected) in positions.iter().zip(expected.iter()) {
+            assert_relative_eq!(pt, expected, epsilon = 1.0e-6);
+        }
+    }
+
+    match io::save(&cuboid, dir.join("ncollide_mesh_io_test.fbx")) {
+        Err(MeshIoError::Unsupported(_)) => {}
+        _ => panic!("the unknown extension was not detected"),
+    }
+}
@@ -3,9 +3,24 @@ mod ball_triangle_toi;
 mod contact;
 mod cuboid_ray_cast;
 mod cylinder_cuboid_contact;
+mod ellipsoid;
 mod epa3;
 mod first_interference_with_ray;
+mod half_space_intersection;
 mod interferences_with_ray;
+mod kdop;
+mod mass_properties;
+mod mesh_io;
+mod obb;
+mod polygon_triangulation;
+mod quadric_ray_cast;
+mod round_shape;
+mod scaled;
+mod shape_combinators;
+mod simplify;
 mod still_objects_toi;
 mod time_of_impact3;
+mod torus;
 mod trimesh_trimesh_toi;
+mod vhacd;
+mod voxels;
@@ -0,0 +1,163 @@
+use na::{Isometry3, Point3, Translation3, UnitQuaternion, Vector3};
+use ncollide3d::bounding_volume::{self, BoundingVolume, HasBoundingVolume, AABB, OBB};
+use ncollide3d::partitioning::{BVH, BVT};
+use ncollide3d::query::visitors::{
+    BoundingVolumeInterferencesCollector, RayInterferencesCollector,
+};
+use ncollide3d::query::{self, PointQuery, Proximity, Ray, RayCast};
+use ncollide3d::shape::{ConvexHull, Cuboid, Shape, ShapeHandle, TriMesh};
+
+fn random_position(range: f32) -> Isometry3<f32> {
+    let t = (rand::random::<Vector3<f32>>() - Vector3::repeat(0.5)) * range;
+    Isometry3::from_parts(Translation3::from(t), rand::random::<UnitQuaternion<f32>>())
+}
+
+fn random_cuboid() -> Cuboid<f32> {
+    Cuboid::new(rand::random::<Vector3<f32>>() + Vector3::repeat(0.1))
+}
+
+fn volume(half_extents: &Vector3<f32>) -> f32 {
+    half_extents.x * half_extents.y * half_extents.z * 8.0
+}
+
+#[test]
+fn obb_intersection_matches_cuboid_proximity() {
+    for _ in 0..2000 {
+        let (pos1, pos2) = (random_position(4.0), random_position(4.0));
+        let (cuboid1, cuboid2) = (random_cuboid(), random_cuboid());
+        let obb1 = bounding_volume::obb(&cuboid1, &pos1);
+        let obb2 = bounding_volume::obb(&cuboid2, &pos2);
+
+        // Skip the configurations where the boxes are almost touching.
+        let dist = query::distance(&pos1, &cuboid1, &pos2, &cuboid2);
+        if dist > 0.0 && dist < 1.0e-3 {
+            continue;
+        }
+
+        let expected =
+            query::proximity(&pos1, &cuboid1, &pos2, &cuboid2, 0.0) != Proximity::Disjoint;
+        assert_eq!(obb1.intersects(&obb2), expected);
+        assert_eq!(obb2.intersects(&obb1), expected);
+    }
+}
+
+#[test]
+fn obb_of_rotated_beam_is_tight() {
+    let rot = UnitQuaternion::from_euler_angles(0.3, 0.7, -1.1);
+    let beam = Cuboid::new(Vector3::new(5.0, 0.1, 0.2));
+    let points: Vec<_> = beam
+        .local_obb()
+        .vertices()
+        .iter()
+        .map(|pt| rot * pt)
+        .collect();
+    let hull = ConvexHull::try_from_points(&points).unwrap();
+
+    let obb: OBB<f32> = hull.local_bounding_volume();
+    let aabb: AABB<f32> = hull.local_bounding_volume();
+    assert_relative_eq!(volume(obb.half_extents()), 0.8, epsilon = 1.0e-3);
+    assert!(volume(&aabb.half_extents()) > 10.0 * volume(obb.half_extents()));
+
+    for pt in &points {
+        assert!(obb.loosened(1.0e-4).contains_local_point(pt));
+    }
+
+    let indices = vec![
+        Point3::new(0, 1, 2),
+        Point3::new(0, 2, 3),
+        Point3::new(4, 5, 6),
+    ];
+    let mesh = TriMesh::new(points.clone(), indices, None);
+    let mesh_obb: OBB<f32> = mesh.local_bounding_volume();
+    assert!(volume(mesh_obb.half_extents()) <= volume(obb.half_extents()) + 1.0e-3);
+}
+
+#[test]
+fn obb_merge_and_containment() {
+    for _ in 0..100 {
+        let obb1 = bounding_volume::obb(&random_cuboid(), &random_position(4.0));
+        let obb2 = bounding_volume::obb(&random_cuboid(), &random_position(4.0));
+        let merged = obb1.merged(&obb2).loosened(1.0e-4);
+
+        assert!(merged.contains(&obb1));
+        assert!(merged.contains(&obb2));
+        assert!(merged.intersects(&obb1));
+        assert_eq!(merged.position().rotation, obb1.position().rotation);
+    }
+}
+
+#[test]
+fn obb_ray_and_point_queries_match_cuboid() {
+    for _ in 0..200 {
+        let cuboid = random_cuboid();
+        let pos = random_position(2.0);
+        let obb = bounding_volume::obb(&cuboid, &pos);
+        let origin = Point3::from((rand::random::<Vector3<f32>>() - Vector3::repeat(0.5)) * 8.0);
+        let ray = Ray::new(origin, Point3::origin() - origin);
+        let id = Isometry3::identity();
+
+        let toi1 = obb.toi_with_ray(&id, &ray, 10.0, true);
+        let toi2 = cuboid.toi_with_ray(&pos, &ray, 10.0, true);
+        assert_eq!(toi1.is_some(), toi2.is_some());
+        if let (Some(toi1), Some(toi2)) = (toi1, toi2) {
+            assert_relative_eq!(toi1, toi2, epsilon = 1.0e-4);
+        }
+
+        assert_eq!(
+            obb.contains_point(&id, &origin),
+            cuboid.contains_point(&pos, &origin)
+        );
+        assert_relative_eq!(
+            obb.distance_to_point(&id, &origin, true),
+            cuboid.distance_to_point(&pos, &origin, true),
+            epsilon = 1.0e-4
+        );
+    }
+}
+
+#[test]
+fn bvt_of_obbs() {
+    let shapes: Vec<_> = (0..100)
+        .map(|_| (random_position(20.0), ShapeHandle::new(random_cuboid())))
+        .collect();
+    let leaves = shapes
+        .iter()
+        .enumerate()
+        .map(|(i, (pos, shape))| (i, shape.obb(pos)))
+        .collect();
+    let bvt = BVT::new_balanced(leaves);
+
+    for _ in 0..20 {
+        let origin = Point3::from((rand::random::<Vector3<f32>>() - Vector3::repeat(0.5)) * 30.0);
+        let ray = Ray::new(
+            origin,
+            rand::random::<Vector3<f32>>() - Vector3::repeat(0.5),
+        );
+        let mut hits = Vec::new();
+        bvt.visit(&mut RayInterferencesCollector::new(&ray, 100.0, &mut hits));
+        hits.sort();
+
+        let expected: Vec<_> = (0..shapes.len())
+            .filter(|i| {
+                let (pos, shape) = &shapes[*i];
+                shape
+                    .as_ray_cast()
+                    .unwrap()
+                    .intersects_ray(pos, &ray, 100.0)
+            })
+            .collect();
+        assert_eq!(hits, expected);
+
+        let query_obb = bounding_volume::obb(&random_cuboid(), &random_position(20.0));
+        let mut hits = Vec::new();
+        bvt.visit(&mut BoundingVolumeInterferencesCollector::new(
+            &query_obb, &mut hits,
+        ));
+        hits.sort();
+
+        let expected: Vec<_> = (0..shapes.len())
+            .filter(|i| shapes[*i].1.obb(&shapes[*i].0).intersects(&query_obb))
+            .collect();
+        assert_eq!(hits, expected);
+    }
+}
@@ -0,0 +1,151 @@
+use na::{Point2, Point3};
+use ncollide3d::procedural::TriMesh;
+use ncollide3d::transformation;
+
+// The signed areas of the triangles, which are positive for counterclockwise triangles.
+fn triangle_areas(mesh: &TriMesh<f64>) -> Vec<f64> {
+    mesh.flat_indices()
+        .chunks(3)
+        .map(|t| {
+            let a = mesh.coords[t[0] as usize];
+            let b = mesh.coords[t[1] as usize];
+            let c = mesh.coords[t[2] as usize];
+            (b - a).cross(&(c - a)).z / 2.0
+        })
+        .collect()
+}
+
+fn triangle_centers(mesh: &TriMesh<f64>) -> Vec<Point3<f64>> {
+    mesh.flat_indices()
+        .chunks(3)
+        .map(|t| {
+            let a = mesh.coords[t[0] as usize];
+            let b = mesh.coords[t[1] as usize];
+            let c = mesh.coords[t[2] as usize];
+            Point3::from((a.coords + b.coords + c.coords) / 3.0)
+        })
+        .collect()
+}
+
+fn has_edge(mesh: &TriMesh<f64>, a: Point3<f64>, b: Point3<f64>) -> bool {
+    mesh.flat_indices().chunks(3).any(|t| {
+        (0..3).any(|k| {
+            let p = mesh.coords[t[k] as usize];
+            let q = mesh.coords[t[(k + 1) % 3] as usize];
+            (p == a && q == b) || (p == b && q == a)
+        })
+    })
+}
+
+fn square(min: f64, max: f64) -> Vec<Point2<f64>> {
+    vec![
+        Point2::new(min, min),
+        Point2::new(max, min),
+        Point2::new(max, max),
+        Point2::new(min, max),
+    ]
+}
+
+#[test]
+fn triangulate_concave_polygon() {
+    // An L-shape given clockwise.
+    let outline = [
+        Point2::new(0.0, 0.0),
+        Point2::new(0.0, 2.0),
+        Point2::new(1.0, 2.0),
+        Point2::new(1.0, 1.0),
+        Point2::new(2.0, 1.0),
+        Point2::new(2.0, 0.0),
+    ];
+    let mesh = transformation::triangulate_polygon(&outline, &[], &[]);
+    let areas = triangle_areas(&mesh);
+
+    assert_eq!(mesh.num_triangles(), 4);
+    assert!(areas.iter().all(|a| *a > 0.0));
+    assert_relative_eq!(areas.iter().sum::<f64>(), 3.0, epsilon = 1.0e-10);
+
+    for center in triangle_centers(&mesh) {
+        assert!(center.x < 1.0 || center.y < 1.0);
+    }
+}
+
+#[test]
+fn triangulate_polygon_with_holes() {
+    let holes = vec![square(1.0, 2.0), square(2.5, 3.5)];
+    let mesh = transformation::triangulate_polygon(&square(0.0, 4.0), &holes, &[]);
+    let areas = triangle_areas(&mesh);
+
+    assert!(areas.iter().all(|a| *a > 0.0));
+    assert_relative_eq!(areas.iter().sum::<f64>(), 14.0, epsilon = 1.0e-10);
+
+    for center in triangle_centers(&mesh) {
+        for hole in &holes {
+            assert!(
+                center.x < hole[0].x
+                    || center.x > hole[2].x
+                    || center.y < hole[0].y
+                    || center.y > hole[2].y
+            );
+        }
+    }
+}
+
+#[test]
+fn triangulate_polygon_with_forced_edges() {
+    // The two diagonals intersect at the center of the square.
+    let forced_edges = [
+        (Point2::new(0.0, 0.0), Point2::new(2.0, 2.0)),
+        (Point2::new(0.0, 2.0), Point2::new(2.0, 0.0)),
+        // Partially outside of the polygon.
+        (Point2::new(1.0, 1.5), Point2::new(1.0, 3.0)),
+    ];
+    let mesh = transformation::triangulate_polygon(&square(0.0, 2.0), &[], &forced_edges);
+    let areas = triangle_areas(&mesh);
+
+    assert!(areas.iter().all(|a| *a > 0.0));
+    assert_relative_eq!(areas.iter().sum::<f64>(), 4.0, epsilon = 1.0e-10);
+
+    let center = Point3::new(1.0, 1.0, 0.0);
+    assert!(has_edge(&mesh, Point3::origin(), center));
+    assert!(has_edge(&mesh, Point3::new(2.0, 2.0, 0.0), center));
+    assert!(has_edge(&mesh, Point3::new(0.0, 2.0, 0.0), center));
+    assert!(has_edge(&mesh, Point3::new(2.0, 0.0, 0.0), center));
+    assert!(has_edge(
+        &mesh,
+        Point3::new(1.0, 1.5, 0.0),
+        Point3::new(1.0, 2.0, 0.0)
+    ));
+}
+
+#[test]
+fn triangulate_degenerate_polygons() {
+    // Duplicate and collinear points.
+    let outline = [
+        Point2::new(0.0, 0.0),
+        Point2::new(0.0, 0.0),
+        Point2::new(1.0, 0.0),
+        Point2::new(2.0, 0.0),
+        Point2::new(3.0, 0.0),
+        Point2::new(3.0, 1.0),
+        Point2::new(3.0, 1.0),
+        Point2::new(0.0, 1.0),
+        Point2::new(0.0, 0.0),
+    ];
+    let mesh = transformation::triangulate_polygon(&outline, &[], &[]);
+    let areas = triangle_areas(&mesh);
+
+    assert_eq!(mesh.coords.len(), 6);
+    assert!(areas.iter().all(|a| *a > 0.0));
+    assert_relative_eq!(areas.iter().sum::<f64>(), 3.0, epsilon = 1.0e-10);
+
+    let collinear = [
+        Point2::new(0.0, 0.0),
+        Point2::new(1.0, 1.0),
+        Point2::new(2.0, 2.0),
+    ];
+    let mesh = transformation::triangulate_polygon(&collinear, &[], &[]);
+    assert_eq!(mesh.num_triangles(), 0);
+
+    let (points, triangles) = transformation::triangulate_polygon_idx(&collinear[..1], &[], &[]);
+    assert!(points.is_empty() && triangles.is_empty());
+}
@@ -0,0 +1,186 @@
+use na::{Isometry3, Point3, Vector3};
+use ncollide3d::query::algorithms::VoronoiSimplex;
+use ncollide3d::query::{self, Ray, RayCast};
+use ncollide3d::shape::{Capsule, Cone, Cylinder, FeatureId, SupportMap};
+
+// Checks the analytic ray casts against the implicit equation `f(p) = 0` of the shape surface,
+// with `f` negative inside of the shape, and against the GJK ray cast. This uses `f64` because
+// rays grazing the apex of a cone hit it at a nearly double root, with half the precision lost.
+fn check_against_implicit_surface<S, F>(shape: &S, f: F)
+where
+    S: RayCast<f64> + SupportMap<f64>,
+    F: Fn(&Point3<f64>) -> f64,
+{
+    for _ in 0..1000 {
+        let origin = Point3::from(rand::random::<Vector3<f64>>().normalize() * 5.0);
+        let target = Point3::from((rand::random::<Vector3<f64>>() - Vector3::repeat(0.5)) * 2.0);
+        let ray = Ray::new(origin, target - origin);
+        let m = Isometry3::new(
+            rand::random::<Vector3<f64>>(),
+            rand::random::<Vector3<f64>>(),
+        );
+
+        let analytic = shape.toi_and_normal_with_ray(&m, &ray, f64::MAX, true);
+        let gjk = query::ray_intersection_with_support_map_with_params(
+            &m,
+            shape,
+            &mut VoronoiSimplex::new(),
+            &ray,
+            f64::MAX,
+            true,
+        );
+
+        match analytic {
+            Some(hit) => {
+                let pt = m.inverse_transform_point(&ray.point_at(hit.toi));
+                let normal = m.inverse_transform_vector(&hit.normal);
+                assert!(f(&pt).abs() < 1.0e-4);
+                assert!(f(&(pt + normal * 1.0e-3)) > 0.0);
+                assert_relative_eq!(normal.norm(), 1.0, epsilon = 1.0e-5);
+            }
+            None => {
+                // GJK may only report rays grazing the surface, because of its tolerance.
+                if let Some(hit) = gjk {
+                    let pt = m.inverse_transform_point(&ray.point_at(hit.toi));
+                    assert!(f(&pt) > -1.0e-3);
+                }
+            }
+        }
+    }
+}
+
+#[test]
+fn cylinder_ray_cast_features() {
+    let cylinder = Cylinder::new(1.0, 0.5);
+    let m = Isometry3::identity();
+
+    let down = Ray::new(Point3::new(0.2, 3.0, 0.1), -Vector3::y());
+    let hit = cylinder
+        .toi_and_normal_and_uv_with_ray(&m, &down, 10.0, true)
+        .unwrap();
+    assert_relative_eq!(hit.toi, 2.0);
+    assert_relative_eq!(hit.normal, Vector3::y());
+    assert_eq!(hit.feature, FeatureId::Face(0));
+    assert!(hit.uvs.unwrap().y < 0.5 / 3.0);
+
+    let up = Ray::new(Point3::new(0.2, -3.0, 0.1), Vector3::y());
+    let hit = cylinder
+        .toi_and_normal_with_ray(&m, &up, 10.0, true)
+        .unwrap();
+    assert_relative_eq!(hit.toi, 2.0);
+    assert_relative_eq!(hit.normal, -Vector3::y());
+    assert_eq!(hit.feature, FeatureId::Face(1));
+
+    let side = Ray::new(Point3::new(-3.0, 0.9, 0.0), Vector3::x());
+    let hit = cylinder
+        .toi_and_normal_and_uv_with_ray(&m, &side, 10.0, true)
+        .unwrap();
+    assert_relative_eq!(hit.toi, 2.5);
+    assert_relative_eq!(hit.normal, -Vector3::x());
+    assert_eq!(hit.feature, FeatureId::Face(2));
+    assert_relative_eq!(hit.uvs.unwrap().y, 0.6 / 3.0, epsilon = 1.0e-6);
+
+    // Non-solid cast from the inside hits the exit point.
+    let inside = Ray::new(Point3::origin(), Vector3::x());
+    let hit = cylinder
+        .toi_and_normal_with_ray(&m, &inside, 10.0, false)
+        .unwrap();
+    assert_relative_eq!(hit.toi, 0.5);
+    assert_relative_eq!(hit.normal, Vector3::x());
+    let hit = cylinder
+        .toi_and_normal_with_ray(&m, &inside, 10.0, true)
+        .unwrap();
+    assert_eq!(hit.toi, 0.0);
+
+    // Rays passing above the top cap.
+    let miss = Ray::new(Point3::new(-3.0, 1.1, 0.0), Vector3::x());
+    assert!(cylinder
+        .toi_and_normal_with_ray(&m, &miss, 10.0, true)
+        .is_none());
+    assert!(cylinder
+        .toi_and_normal_with_ray(&m, &side, 2.0, true)
+        .is_none());
+}
+
+#[test]
+fn cone_ray_cast_features() {
+    let cone = Cone::new(1.0, 0.5);
+    let m = Isometry3::translation(1.0, 2.0, 3.0);
+
+    let up = Ray::new(Point3::new(1.1, -3.0, 3.0), Vector3::y());
+    let hit = cone.toi_and_normal_with_ray(&m, &up, 10.0, true).unwrap();
+    assert_relative_eq!(hit.toi, 4.0);
+    assert_relative_eq!(hit.normal, -Vector3::y());
+    assert_eq!(hit.feature, FeatureId::Face(0));
+
+    // The lateral surface at mid-height has a radius of 0.25.
+    let side = Ray::new(Point3::new(-3.0, 2.0, 3.0), Vector3::x());
+    let hit = cone
+        .toi_and_normal_and_uv_with_ray(&m, &side, 10.0, true)
+        .unwrap();
+    assert_relative_eq!(hit.toi, 3.75, epsilon = 1.0e-6);
+    assert_eq!(hit.feature, FeatureId::Face(1));
+    let expected = Vector3::new(-2.0, 0.5, 0.0).normalize();
+    assert_relative_eq!(hit.normal, expected, epsilon = 1.0e-6);
+    let slant = (4.0f32 + 0.25).sqrt();
+    assert_relative_eq!(
+        hit.uvs.unwrap().y,
+        slant / 2.0 / (slant + 0.5),
+        epsilon = 1.0e-6
+    );
+
+    // Rays along the axis, and passing above the apex.
+    let down = Ray::new(Point3::new(1.0, 5.0, 3.0), -Vector3::y());
+    let hit = cone.toi_and_normal_with_ray(&m, &down, 10.0, true).unwrap();
+    assert_relative_eq!(hit.toi, 2.0, epsilon = 1.0e-6);
+    let miss = Ray::new(Point3::new(-3.0, 3.1, 3.0), Vector3::x());
+    assert!(cone
+        .toi_and_normal_with_ray(&m, &miss, 10.0, true)
+        .is_none());
+}
+
+#[test]
+fn capsule_ray_cast_features() {
+    let capsule = Capsule::new(1.0, 0.5);
+    let m = Isometry3::identity();
+
+    let down = Ray::new(Point3::new(0.0, 3.0, 0.0), -Vector3::y());
+    let hit = capsule
+        .toi_and_normal_and_uv_with_ray(&m, &down, 10.0, true)
+        .unwrap();
+    assert_relative_eq!(hit.toi, 1.5);
+    assert_relative_eq!(hit.normal, Vector3::y());
+    assert_eq!(hit.feature, FeatureId::Face(1));
+    assert_relative_eq!(hit.uvs.unwrap().y, 0.0);
+
+    let side = Ray::new(Point3::new(0.0, -0.5, -3.0), Vector3::z());
+    let hit = capsule
+        .toi_and_normal_with_ray(&m, &side, 10.0, true)
+        .unwrap();
+    assert_relative_eq!(hit.toi, 2.5);
+    assert_relative_eq!(hit.normal, -Vector3::z());
+    assert_eq!(hit.feature, FeatureId::Face(2));
+
+    let bottom = Ray::new(Point3::new(0.3, -3.0, 0.0), Vector3::y());
+    let hit = capsule
+        .toi_and_normal_with_ray(&m, &bottom, 10.0, true)
+        .unwrap();
+    assert_eq!(hit.feature, FeatureId::Face(0));
+    assert_relative_eq!(hit.normal, Vector3::new(0.6, -0.8, 0.0), epsilon = 1.0e-6);
+}
+
+#[test]
+fn quadric_ray_casts_hit_the_surface() {
+    let radial = |p: &Point3<f64>| Vector3::new(p.x, 0.0, p.z).norm();
+
+    check_against_implicit_surface(&Cylinder::new(1.0, 0.5), |p| {
+        (radial(p) - 0.5).max(p.y.abs() - 1.0)
+    });
+    check_against_implicit_surface(&Cone::new(1.0, 0.5), |p| {
+        (radial(p) - 0.25 * (1.0 - p.y)).max(-1.0 - p.y)
+    });
+    check_against_implicit_surface(&Capsule::new(1.0, 0.5), |p| {
+        let center = Point3::new(0.0, p.y.clamp(-1.0, 1.0), 0.0);
+        na::distance(p, &center) - 0.5
+    });
+}
@@ -0,0 +1,136 @@
+use na::{Isometry3, Point3, Vector3};
+use ncollide3d::bounding_volume::{self, BoundingSphere, AABB, KDOP14};
+use ncollide3d::query::{PointQuery, Ray, RayCast};
+use ncollide3d::shape::{Cuboid, RoundShape, SupportMap};
+
+fn round_cuboid() -> RoundShape<f64, Cuboid<f64>> {
+    RoundShape::new(Cuboid::new(Vector3::new(1.0, 2.0, 3.0)), 0.5)
+}
+
+#[test]
+fn round_shape_support_point() {
+    let shape = round_cuboid();
+    let dir = Vector3::new(1.0, -1.0, 1.0);
+    let expected = Point3::new(1.0, -2.0, 3.0) + dir.normalize() * 0.5;
+
+    assert_relative_eq!(shape.local_support_point(&dir), expected, epsilon = 1.0e-10);
+    assert_relative_eq!(
+        shape.local_support_point(&Vector3::x()),
+        Point3::new(1.5, 2.0, 3.0)
+    );
+}
+
+#[test]
+fn round_shape_bounding_volumes() {
+    let shape = round_cuboid();
+    let m = Isometry3::translation(1.0, 0.0, 0.0);
+
+    let aabb: AABB<f64> = bounding_volume::aabb(&shape, &m);
+    assert_relative_eq!(aabb.mins, Point3::new(-0.5, -2.5, -3.5));
+    assert_relative_eq!(aabb.maxs, Point3::new(2.5, 2.5, 3.5));
+
+    let sphere: BoundingSphere<f64> = bounding_volume::bounding_sphere(&shape, &m);
+    assert_relative_eq!(sphere.radius(), 14.0f64.sqrt() + 0.5, epsilon = 1.0e-10);
+
+    let kdop: KDOP14<f64> = bounding_volume::HasBoundingVolume::bounding_volume(&shape, &m);
+    assert_relative_eq!(kdop.aabb().mins, aabb.mins, epsilon = 1.0e-10);
+    assert!(kdop.contains_local_point(&Point3::new(2.5, 0.0, 0.0)));
+    assert!(!kdop.contains_local_point(&Point3::new(2.45, 2.45, 3.45)));
+}
+
+#[test]
+fn round_shape_ray_cast() {
+    let shape = round_cuboid();
+    let m = Isometry3::identity();
+
+    // Ray toward a face.
+    let ray = Ray::new(Point3::new(5.0, 0.0, 0.0), -Vector3::x());
+    let hit = shape
+        .toi_and_normal_with_ray(&m, &ray, f64::MAX, true)
+        .unwrap();
+    assert_relative_eq!(hit.toi, 3.5, epsilon = 1.0e-6);
+    assert_relative_eq!(hit.normal, Vector3::x(), epsilon = 1.0e-6);
+
+    // Ray toward a rounded corner.
+    let dir = Vector3::new(1.0, 1.0, 1.0).normalize();
+    let corner = Point3::new(1.0, 2.0, 3.0);
+    let ray = Ray::new(corner + dir * 5.0, -dir);
+    let hit = shape
+        .toi_and_normal_with_ray(&m, &ray, f64::MAX, true)
+        .unwrap();
+    assert_relative_eq!(hit.toi, 4.5, epsilon = 1.0e-6);
+    assert_relative_eq!(hit.normal, dir, epsilon = 1.0e-6);
+
+    // Ray missing the border.
+    let ray = Ray::new(Point3::new(5.0, 2.6, 0.0), -Vector3::x());
+    assert!(shape
+        .toi_and_normal_with_ray(&m, &ray, f64::MAX, true)
+        .is_none());
+
+    // Ray from the inside.
+    let ray = Ray::new(Point3::origin(), Vector3::y());
+    let hit = shape
+        .toi_and_normal_with_ray(&m, &ray, f64::MAX, false)
+        .unwrap();
+    assert_relative_eq!(hit.toi, 2.5, epsilon = 1.0e-3);
+}
+
+#[test]
+fn round_shape_point_projection() {
+    let shape = round_cuboid();
+    let m = Isometry3::translation(1.0, 2.0, 3.0);
+
+    // Outside of a face.
+    let pt = m * Point3::new(3.0, 0.0, 0.0);
+    let proj = shape.project_point(&m, &pt, true);
+    assert!(!proj.is_inside);
+    assert_relative_eq!(
+        proj.point,
+        m * Point3::new(1.5, 0.0, 0.0),
+        epsilon = 1.0e-10
+    );
+
+    // Outside of a corner.
+    let dir = Vector3::new(1.0, 1.0, 1.0).normalize();
+    let pt = m * (Point3::new(1.0, 2.0, 3.0) + dir * 2.0);
+    assert_relative_eq!(
+        shape.distance_to_point(&m, &pt, true),
+        1.5,
+        epsilon = 1.0e-10
+    );
+
+    // Inside of the border.
+    let pt = m * Point3::new(1.2, 0.0, 0.0);
+    let proj = shape.project_point(&m, &pt, false);
+    assert!(proj.is_inside);
+    assert_relative_eq!(
+        proj.point,
+        m * Point3::new(1.5, 0.0, 0.0),
+        epsilon = 1.0e-10
+    );
+    assert_eq!(shape.project_point(&m, &pt, true).point, pt);
+
+    // Inside of the inner shape.
+    let pt = m * Point3::new(0.5, 0.0, 0.0);
+    let proj = shape.project_point(&m, &pt, false);
+    assert!(proj.is_inside);
+    assert_relative_eq!(
+        proj.point,
+        m * Point3::new(1.5, 0.0, 0.0),
+        epsilon = 1.0e-10
+    );
+    assert_relative_eq!(
+        shape.distance_to_point(&m, &pt, false),
+        -1.0,
+        epsilon = 1.0e-10
+    );
+
+    // On the boundary of the inner shape.
+    let pt = m * Point3::new(1.0, 0.0, 0.0);
+    let proj = shape.project_point(&m, &pt, false);
+    assert!(proj.is_inside);
+    assert_relative_eq!(proj.point, m * Point3::new(1.5, 0.0, 0.0), epsilon = 1.0e-6);
+
+    assert!(shape.contains_point(&m, &(m * Point3::new(1.4, 2.2, 3.2))));
+    assert!(!shape.contains_point(&m, &(m * Point3::new(1.4, 2.4, 3.4))));
+}
@@ -0,0 +1,152 @@
+use std::sync::Arc;
+
+use na::{Isometry3, Point3, Vector3};
+use ncollide3d::bounding_volume::{self, AABB};
+use ncollide3d::query::{PointQuery, Ray, RayCast};
+use ncollide3d::shape::{Ball, Cuboid, Ellipsoid, Scaled, SupportMap, TriMesh};
+use ncollide3d::transformation::ToTriMesh;
+
+fn cube_mesh() -> TriMesh<f64> {
+    Cuboid::new(Vector3::repeat(1.0)).to_trimesh(()).into()
+}
+
+#[test]
+fn scaled_ball_is_an_ellipsoid() {
+    let radii = Vector3::new(3.0, 1.0, 2.0);
+    let scaled = Scaled::new(Ball::new(1.0), radii);
+    let ellipsoid = Ellipsoid::new(radii);
+    let m = Isometry3::new(Vector3::new(1.0, 2.0, 3.0), Vector3::new(0.3, 0.2, 0.1));
+
+    for i in 0..100 {
+        let t = i as f64 * 0.37;
+        let dir = Vector3::new(t.cos(), (t * 0.3).sin(), t.sin());
+        assert_relative_eq!(
+            scaled.local_support_point(&dir),
+            ellipsoid.local_support_point(&dir),
+            epsilon = 1.0e-10
+        );
+
+        let origin = m * Point3::from(dir * 5.0);
+        let target = m * Point3::new(0.5 * t.sin(), 0.3 * t.cos(), 0.0);
+        let ray = Ray::new(origin, target - origin);
+        let hit1 = scaled
+            .toi_and_normal_with_ray(&m, &ray, f64::MAX, true)
+            .unwrap();
+        let hit2 = ellipsoid
+            .toi_and_normal_with_ray(&m, &ray, f64::MAX, true)
+            .unwrap();
+        assert_relative_eq!(hit1.toi, hit2.toi, epsilon = 1.0e-10);
+        assert_relative_eq!(hit1.normal, hit2.normal, epsilon = 1.0e-10);
+
+        let pt = m * Point3::from(dir * 1.5);
+        assert_eq!(
+            scaled.contains_point(&m, &pt),
+            ellipsoid.contains_point(&m, &pt)
+        );
+    }
+
+    let aabb: AABB<f64> = bounding_volume::aabb(&scaled, &Isometry3::identity());
+    assert_relative_eq!(aabb.mins, Point3::from(-radii));
+    assert_relative_eq!(aabb.maxs, Point3::from(radii));
+}
+
+#[test]
+fn scaled_trimesh_instances_share_the_mesh() {
+    let mesh = Arc::new(cube_mesh());
+    let scaled1 = Scaled::from_arc(mesh.clone(), Vector3::new(2.0, 1.0, 1.0));
+    let scaled2 = scaled1.with_scale(Vector3::new(1.0, 3.0, 1.0));
+
+    assert!(Arc::ptr_eq(scaled1.shape_arc(), &mesh));
+    assert!(Arc::ptr_eq(scaled2.shape_arc(), &mesh));
+
+    let m = Isometry3::translation(0.0, 0.0, 1.0);
+    let ray = Ray::new(Point3::new(10.0, 0.0, 1.0), -Vector3::x());
+
+    let hit = scaled1
+        .toi_and_normal_with_ray(&m, &ray, f64::MAX, true)
+        .unwrap();
+    assert_relative_eq!(hit.toi, 8.0, epsilon = 1.0e-10);
+    assert_relative_eq!(hit.normal, Vector3::x(), epsilon = 1.0e-10);
+
+    let hit = scaled2
+        .toi_and_normal_with_ray(&m, &ray, f64::MAX, true)
+        .unwrap();
+    assert_relative_eq!(hit.toi, 9.0, epsilon = 1.0e-10);
+
+    let aabb: AABB<f64> = bounding_volume::aabb(&scaled2, &m);
+    assert_relative_eq!(aabb.mins, Point3::new(-1.0, -3.0, 0.0));
+    assert_relative_eq!(aabb.maxs, Point3::new(1.0, 3.0, 2.0));
+}
+
+#[test]
+fn scaled_shape_normals() {
+    // A sheared-looking box: the normal of a scaled slanted face is not the scaled normal.
+    let scaled = Scaled::new(cube_mesh(), Vector3::new(4.0, 1.0, 1.0));
+    let ray = Ray::new(Point3::new(0.0, 10.0, 0.0), -Vector3::y());
+    let hit = scaled
+        .toi_and_normal_with_ray(&Isometry3::identity(), &ray, f64::MAX, true)
+        .unwrap();
+    assert_relative_eq!(hit.toi, 9.0, epsilon = 1.0e-10);
+    assert_relative_eq!(hit.normal, Vector3::y(), epsilon = 1.0e-10);
+
+    // Mirroring keeps the normals pointing outward.
+    let mirrored = Scaled::new(Ball::new(1.0), Vector3::new(-2.0, 1.0, 1.0));
+    let ray = Ray::new(Point3::new(-5.0, 0.0, 0.0), Vector3::x());
+    let hit = mirrored
+        .toi_and_normal_with_ray(&Isometry3::identity(), &ray, f64::MAX, true)
+        .unwrap();
+    assert_relative_eq!(hit.toi, 3.0, epsilon = 1.0e-10);
+    assert_relative_eq!(hit.normal, -Vector3::x(), epsilon = 1.0e-10);
+}
+
+#[test]
+fn scaled_shape_point_projection() {
+    let mesh = Arc::new(cube_mesh());
+    let m = Isometry3::translation(1.0, 0.0, 0.0);
+
+    // Uniform scaling: the projection is exact.
+    let uniform = Scaled::from_arc(mesh.clone(), Vector3::repeat(2.0));
+    let proj = uniform.project_point(&m, &Point3::new(5.0, 1.0, 0.5), true);
+    assert!(!proj.is_inside);
+    assert_relative_eq!(proj.point, Point3::new(3.0, 1.0, 0.5), epsilon = 1.0e-10);
+    assert_relative_eq!(
+        uniform.distance_to_point(&m, &Point3::new(1.0, 5.0, 0.0), true),
+        3.0,
+        epsilon = 1.0e-10
+    );
+
+    // Non-uniform scaling: the projection lies on the boundary.
+    let stretched = Scaled::from_arc(mesh, Vector3::new(3.0, 1.0, 1.0));
+    let proj = stretched.project_point(&m, &Point3::new(5.0, 0.5, 0.0), true);
+    assert_relative_eq!(proj.point, Point3::new(4.0, 0.5, 0.0), epsilon = 1.0e-10);
+
+    let cuboid = Scaled::new(
+        Cuboid::new(Vector3::repeat(1.0)),
+        Vector3::new(3.0, 1.0, 1.0),
+    );
+    assert!(cuboid.contains_point(&m, &Point3::new(3.9, 0.9, 0.9)));
+    assert!(!cuboid.contains_point(&m, &Point3::new(1.0, 1.1, 0.0)));
+
+    // Non-uniform scaling of a support map: the projection is exact. Projecting on the unit ball
+    // and then scaling would give the point of the ellipse along the input direction instead.
+    let radii = Vector3::new(3.0, 1.0, 1.0);
+    let scaled = Scaled::new(Ball::new(1.0), radii);
+    let ellipsoid = Ellipsoid::new(radii);
+    for pt in &[
+        Point3::new(5.0, 2.0, 0.0),
+        Point3::new(-2.0, 3.0, 1.0),
+        Point3::new(3.5, 0.0, 0.0),
+    ] {
+        let pt = m * pt;
+        let proj1 = scaled.project_point(&m, &pt, true);
+        let proj2 = ellipsoid.project_point(&m, &pt, true);
+        assert!(!proj1.is_inside);
+        assert_relative_eq!(proj1.point, proj2.point, epsilon = 1.0e-3);
+    }
+
+    let inside = m * Point3::new(2.0, 0.1, 0.0);
+    let proj1 = scaled.project_point(&m, &inside, false);
+    let proj2 = ellipsoid.project_point(&m, &inside, false);
+    assert!(proj1.is_inside);
+    assert_relative_eq!(proj1.point, proj2.point, epsilon = 1.0e-2);
+}
@@ -0,0 +1,134 @@
+use na::{Isometry3, Point3, Vector3};
+use ncollide3d::bounding_volume::{self, BoundingSphere, AABB};
+use ncollide3d::pipeline::{CollisionGroups, CollisionWorld, GeometricQueryType};
+use ncollide3d::query::{PointQuery, Proximity, Ray, RayCast};
+use ncollide3d::shape::{
+    Ball, Capsule, ConvexHullOf, Cuboid, MinkowskiSum, ShapeHandle, SupportMap,
+};
+
+// The volume swept by a ball moving from `-y` to `+y` is a capsule.
+fn swept_ball() -> ConvexHullOf<f64, Ball<f64>, Ball<f64>> {
+    ConvexHullOf::new(
+        Isometry3::translation(0.0, -1.0, 0.0),
+        Ball::new(0.5),
+        Isometry3::translation(0.0, 1.0, 0.0),
+        Ball::new(0.5),
+    )
+}
+
+#[test]
+fn minkowski_sum_support_and_aabb() {
+    let sum = MinkowskiSum::new(
+        Isometry3::translation(1.0, 0.0, 0.0),
+        Cuboid::new(Vector3::new(1.0, 2.0, 3.0)),
+        Isometry3::translation(0.0, 0.0, 1.0),
+        Ball::new(0.5),
+    );
+
+    let dir = Vector3::new(1.0, 1.0, -1.0);
+    let expected = Point3::new(2.0, 2.0, -3.0) + Vector3::z() + dir.normalize() * 0.5;
+    assert_relative_eq!(sum.local_support_point(&dir), expected, epsilon = 1.0e-10);
+
+    let m = Isometry3::translation(0.0, 1.0, 0.0);
+    let aabb: AABB<f64> = bounding_volume::aabb(&sum, &m);
+    assert_relative_eq!(aabb.mins, Point3::new(-0.5, -1.5, -2.5), epsilon = 1.0e-10);
+    assert_relative_eq!(aabb.maxs, Point3::new(2.5, 3.5, 4.5), epsilon = 1.0e-10);
+
+    let sphere: BoundingSphere<f64> = bounding_volume::bounding_sphere(&sum, &m);
+    assert_relative_eq!(
+        *sphere.center(),
+        Point3::new(1.0, 1.0, 1.0),
+        epsilon = 1.0e-10
+    );
+    assert_relative_eq!(sphere.radius(), 14.0f64.sqrt() + 0.5, epsilon = 1.0e-10);
+
+    let ray = Ray::new(Point3::new(10.0, 1.0, 1.0), -Vector3::x());
+    let hit = sum
+        .toi_and_normal_with_ray(&m, &ray, f64::MAX, true)
+        .unwrap();
+    assert_relative_eq!(hit.toi, 7.5, epsilon = 1.0e-6);
+    assert_relative_eq!(hit.normal, Vector3::x(), epsilon = 1.0e-6);
+}
+
+#[test]
+fn convex_hull_of_two_poses() {
+    let hull = swept_ball();
+    let capsule = Capsule::new(1.0, 0.5);
+    let m = Isometry3::new(Vector3::new(1.0, 2.0, 3.0), Vector3::new(0.1, 0.2, 0.3));
+
+    let aabb: AABB<f64> = bounding_volume::aabb(&hull, &m);
+    let capsule_aabb: AABB<f64> = bounding_volume::aabb(&capsule, &m);
+    assert_relative_eq!(aabb.mins, capsule_aabb.mins, epsilon = 1.0e-10);
+    assert_relative_eq!(aabb.maxs, capsule_aabb.maxs, epsilon = 1.0e-10);
+
+    let sphere: BoundingSphere<f64> = bounding_volume::bounding_sphere(&hull, &m);
+    assert_relative_eq!(sphere.radius(), 1.5, epsilon = 1.0e-10);
+
+    for i in 0..100 {
+        let t = i as f64 * 0.37;
+        let origin = m * Point3::new(3.0 * t.cos(), 2.0 * (t * 0.3).sin(), 3.0 * t.sin());
+        let target = m * Point3::new(0.2 * t.sin(), 0.8 * t.cos(), 0.0);
+        let ray = Ray::new(origin, target - origin);
+
+        let toi1 = hull.toi_with_ray(&m, &ray, f64::MAX, true).unwrap();
+        let toi2 = capsule.toi_with_ray(&m, &ray, f64::MAX, true).unwrap();
+        assert_relative_eq!(toi1, toi2, epsilon = 1.0e-5);
+
+        let dist1 = hull.distance_to_point(&m, &origin, true);
+        let dist2 = capsule.distance_to_point(&m, &origin, true);
+        assert_relative_eq!(dist1, dist2, epsilon = 1.0e-5);
+    }
+
+    assert!(hull.contains_point(&m, &(m * Point3::new(0.0, 1.2, 0.0))));
+    assert!(!hull.contains_point(&m, &(m * Point3::new(0.4, 1.4, 0.0))));
+}
+
+#[test]
+fn swept_volume_in_collision_world() {
+    let mut world = CollisionWorld::new(0.0);
+    let groups = CollisionGroups::new();
+    let query = GeometricQueryType::Proximity(0.0);
+
+    let swept = world
+        .add(
+            Isometry3::identity(),
+            ShapeHandle::new(swept_ball()),
+            groups,
+            query,
+            (),
+        )
+        .0;
+    let hit = world
+        .add(
+            Isometry3::translation(0.7, 0.5, 0.0),
+            ShapeHandle::new(Ball::new(0.25)),
+            groups,
+            query,
+            (),
+        )
+        .0;
+    let missed = world
+        .add(
+            Isometry3::translation(0.0, 2.0, 0.0),
+            ShapeHandle::new(Cuboid::new(Vector3::repeat(0.2))),
+            groups,
+            query,
+            (),
+        )
+        .0;
+    world.update();
+
+    let proximity = |h| world.proximity_pair(swept, h, false).map(|p| p.3);
+    assert_eq!(proximity(hit), Some(Proximity::Intersecting));
+    assert_ne!(proximity(missed), Some(Proximity::Intersecting));
+
+    let ray = Ray::new(Point3::new(-5.0, -1.2, 0.0), Vector3::x());
+    let hits: Vec<_> = world
+        .interferences_with_ray(&ray, f64::MAX, &groups)
+        .map(|(h, _, inter)| (h, inter.toi))
+        .collect();
+    assert_eq!(hits.len(), 1);
+    assert_eq!(hits[0].0, swept);
+    // The ray crosses the ball at the first pose 0.2 below its center.
+    assert_relative_eq!(hits[0].1, 5.0 - 0.21f64.sqrt(), epsilon = 1.0e-5);
+}
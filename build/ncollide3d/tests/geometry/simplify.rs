@@ -0,0 +1,120 @@
+use na::{Isometry3, Point3};
+use ncollide3d::procedural::{self, TriMesh};
+use ncollide3d::query::PointQuery;
+use ncollide3d::shape::ConvexHull;
+use ncollide3d::transformation::{self, SimplificationParameters};
+
+fn area(mesh: &TriMesh<f64>) -> f64 {
+    mesh.flat_indices()
+        .chunks(3)
+        .map(|t| {
+            let a = mesh.coords[t[0] as usize];
+            let b = mesh.coords[t[1] as usize];
+            let c = mesh.coords[t[2] as usize];
+            (b - a).cross(&(c - a)).norm() / 2.0
+        })
+        .sum()
+}
+
+#[test]
+fn simplify_flat_mesh() {
+    let quad = procedural::quad::<f64>(2.0, 2.0, 10, 10);
+    let simplified = transformation::simplify(&quad, &SimplificationParameters::default());
+
+    assert!(simplified.num_triangles() < quad.num_triangles());
+    assert_relative_eq!(area(&simplified), 4.0, epsilon = 1.0e-6);
+    assert!(simplified.has_normals());
+
+    // The boundary vertices are kept, and the texture coordinates are not distorted.
+    let uvs = simplified.uvs.as_ref().unwrap();
+    let original_uvs = quad.uvs.as_ref().unwrap();
+    let num_boundary = quad
+        .coords
+        .iter()
+        .filter(|pt| pt.x.abs() == 1.0 || pt.y.abs() == 1.0)
+        .count();
+    let num_kept_boundary = simplified
+        .coords
+        .iter()
+        .filter(|pt| pt.x.abs() == 1.0 || pt.y.abs() == 1.0)
+        .count();
+    assert_eq!(num_kept_boundary, num_boundary);
+
+    for (pt, uv) in simplified.coords.iter().zip(uvs.iter()) {
+        let i = quad.coords.iter().position(|orig| orig == pt).unwrap();
+        assert_eq!(*uv, original_uvs[i]);
+    }
+
+    let params = SimplificationParameters {
+        preserve_boundaries: false,
+        preserve_uvs: false,
+        ..SimplificationParameters::default()
+    };
+    let collapsed = transformation::simplify(&quad, &params);
+
+    assert!(collapsed.num_triangles() < simplified.num_triangles());
+    assert!(!collapsed.has_uvs());
+    assert_relative_eq!(area(&collapsed), 4.0, epsilon = 1.0e-6);
+}
+
+#[test]
+fn simplify_to_target_triangles() {
+    let sphere = procedural::sphere::<f64>(2.0, 32, 32, false);
+    let params = SimplificationParameters {
+        target_triangles: 200,
+        max_error: f64::MAX,
+        ..SimplificationParameters::default()
+    };
+    let simplified = transformation::simplify(&sphere, &params);
+
+    assert!(simplified.num_triangles() <= 200);
+    assert!(simplified.num_triangles() > 150);
+
+    for pt in &simplified.coords {
+        assert!((pt.coords.norm() - 1.0).abs() < 0.1);
+    }
+}
+
+#[test]
+fn simplify_with_max_error() {
+    let sphere = procedural::sphere::<f64>(2.0, 32, 32, false);
+    let params = SimplificationParameters {
+        max_error: 0.01,
+        ..SimplificationParameters::default()
+    };
+    let simplified = transformation::simplify(&sphere, &params);
+
+    assert!(simplified.num_triangles() < sphere.num_triangles());
+
+    for pt in &simplified.coords {
+        assert!((pt.coords.norm() - 1.0).abs() < 0.01);
+    }
+}
+
+#[test]
+fn simplify_convex_hull_contains_original_points() {
+    let sphere = procedural::sphere::<f64>(2.0, 32, 32, false);
+
+    for max_vertices in &[4, 8, 20, 60] {
+        let vertices = transformation::simplify_convex_hull(&sphere.coords, *max_vertices);
+        assert!(vertices.len() <= *max_vertices);
+
+        let hull = ConvexHull::try_from_points(&vertices).unwrap();
+
+        for pt in &sphere.coords {
+            assert!(hull.distance_to_point(&Isometry3::identity(), pt, true) < 1.0e-6);
+        }
+    }
+
+    // Small point sets are left untouched.
+    let points = [
+        Point3::origin(),
+        Point3::new(1.0, 0.0, 0.0),
+        Point3::new(0.0, 1.0, 0.0),
+        Point3::new(0.0, 0.0, 1.0),
+    ];
+    assert_eq!(
+        transformation::simplify_convex_hull(&points, 4),
+        points.to_vec()
+    );
+}
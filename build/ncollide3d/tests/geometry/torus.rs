@@ -0,0 +1,200 @@
+use na::{Isometry3, Point3, Vector3};
+use ncollide3d::bounding_volume::{BoundingSphere, HasBoundingVolume, AABB};
+use ncollide3d::query::{self, PointQuery, Proximity, Ray, RayCast};
+use ncollide3d::shape::{Ball, Cuboid, FeatureId, Torus};
+use ncollide3d::transformation::ToTriMesh;
+use std::f64::consts::{FRAC_PI_2, PI};
+
+#[test]
+fn torus_ray_cast() {
+    let torus = Torus::new(2.0f64, 0.5);
+    let m = Isometry3::identity();
+
+    // Through the tube, along its diameter.
+    let ray = Ray::new(Point3::new(-5.0, 0.0, 0.0), Vector3::x());
+    let res = torus
+        .toi_and_normal_with_ray(&m, &ray, 100.0, true)
+        .unwrap();
+    assert_relative_eq!(res.toi, 2.5, epsilon = 1.0e-7);
+    assert_relative_eq!(res.normal, -Vector3::x(), epsilon = 1.0e-7);
+
+    // From the top, the ray hits the tube on its top.
+    let ray = Ray::new(Point3::new(0.0, 3.0, 2.0), -Vector3::y() * 2.0);
+    let res = torus
+        .toi_and_normal_with_ray(&m, &ray, 100.0, true)
+        .unwrap();
+    assert_relative_eq!(res.toi, 1.25, epsilon = 1.0e-7);
+    assert_relative_eq!(res.normal, Vector3::y(), epsilon = 1.0e-7);
+    assert_eq!(res.feature, FeatureId::Face(Torus::<f64>::NSLICES / 4));
+
+    // Through the hole.
+    let ray = Ray::new(Point3::new(0.0, 3.0, 0.0), -Vector3::y());
+    assert!(torus.toi_with_ray(&m, &ray, 100.0, true).is_none());
+
+    // Grazing the top of the tube.
+    let ray = Ray::new(Point3::new(-5.0, 0.5, 0.0), Vector3::x());
+    let toi = torus.toi_with_ray(&m, &ray, 100.0, true).unwrap();
+    assert_relative_eq!(toi, 3.0, epsilon = 1.0e-3);
+
+    // From inside of the tube.
+    let ray = Ray::new(Point3::new(2.0, 0.0, 0.0), Vector3::x());
+    assert_eq!(torus.toi_with_ray(&m, &ray, 100.0, true), Some(0.0));
+    let res = torus
+        .toi_and_normal_with_ray(&m, &ray, 100.0, false)
+        .unwrap();
+    assert_relative_eq!(res.toi, 0.5, epsilon = 1.0e-7);
+    assert_relative_eq!(res.normal, -Vector3::x(), epsilon = 1.0e-7);
+
+    // With a transformed torus and a far away origin.
+    let m = Isometry3::new(Vector3::new(1.0, 2.0, 3.0), Vector3::x() * FRAC_PI_2);
+    let ray = Ray::new(Point3::new(1.0, 2.0, 1000.0), -Vector3::z());
+    let toi = torus.toi_with_ray(&m, &ray, 10000.0, true);
+    assert!(toi.is_none());
+    let ray = Ray::new(Point3::new(3.0, 2.0, 1000.0), -Vector3::z());
+    let toi = torus.toi_with_ray(&m, &ray, 10000.0, true).unwrap();
+    assert_relative_eq!(toi, 1000.0 - 3.0 - 0.5, epsilon = 1.0e-6);
+}
+
+#[test]
+fn torus_point_projection() {
+    let torus = Torus::new(2.0f64, 0.5);
+    let m = Isometry3::new(Vector3::new(1.0, 0.0, 0.0), Vector3::z() * PI);
+
+    // The center of the torus is outside of it.
+    let proj = torus.project_point(&m, &Point3::new(1.0, 0.0, 0.0), true);
+    assert!(!proj.is_inside);
+    assert_relative_eq!(na::distance(&proj.point, &Point3::new(1.0, 0.0, 0.0)), 1.5);
+
+    let pt = Point3::new(1.0, 3.0, 2.0);
+    let proj = torus.project_point(&m, &pt, true);
+    assert!(!proj.is_inside);
+    let expected = Point3::new(1.0, 0.0, 2.0) + Vector3::new(0.0, 3.0, 0.0).normalize() * 0.5;
+    assert_relative_eq!(proj.point, expected, epsilon = 1.0e-7);
+    assert_relative_eq!(
+        torus.distance_to_point(&m, &pt, true),
+        2.5,
+        epsilon = 1.0e-7
+    );
+
+    // Inside of the tube.
+    let pt = Point3::new(1.0, 0.2, -2.0);
+    assert!(torus.contains_point(&m, &pt));
+    assert_relative_eq!(torus.distance_to_point(&m, &pt, true), 0.0);
+    assert_relative_eq!(
+        torus.distance_to_point(&m, &pt, false),
+        -0.3,
+        epsilon = 1.0e-7
+    );
+    let proj = torus.project_point(&m, &pt, false);
+    assert!(proj.is_inside);
+    assert_relative_eq!(proj.point, Point3::new(1.0, 0.5, -2.0), epsilon = 1.0e-7);
+}
+
+#[test]
+fn torus_contact_and_proximity() {
+    let torus = Torus::new(2.0f64, 0.5);
+    let m = Isometry3::new(Vector3::new(1.0, 0.0, 0.0), Vector3::z() * PI);
+    let ball = Ball::new(0.5);
+
+    // On top of the tube.
+    let ball_pos = Isometry3::translation(1.0, -0.9, 2.0);
+    let contact = query::contact(&m, &torus, &ball_pos, &ball, 0.0).unwrap();
+    assert_relative_eq!(contact.depth, 0.1, epsilon = 1.0e-4);
+    assert_relative_eq!(*contact.normal, -Vector3::y(), epsilon = 1.0e-3);
+    assert_relative_eq!(
+        contact.world1,
+        Point3::new(1.0, -0.5, 2.0),
+        epsilon = 1.0e-3
+    );
+
+    let flipped = query::contact(&ball_pos, &ball, &m, &torus, 0.0).unwrap();
+    assert_relative_eq!(flipped.depth, contact.depth);
+    assert_relative_eq!(*flipped.normal, -*contact.normal);
+    assert_eq!(
+        query::proximity(&ball_pos, &ball, &m, &torus, 0.0),
+        Proximity::Intersecting
+    );
+
+    // In the hole.
+    let ball_pos = Isometry3::translation(1.0, 0.0, 0.0);
+    assert!(query::contact(&m, &torus, &ball_pos, &ball, 0.5).is_none());
+    assert_eq!(
+        query::proximity(&m, &torus, &ball_pos, &ball, 0.5),
+        Proximity::Disjoint
+    );
+    assert_eq!(
+        query::proximity(&m, &torus, &ball_pos, &ball, 1.5),
+        Proximity::WithinMargin
+    );
+
+    // A cuboid lying on both sides of the tube.
+    let cuboid = Cuboid::new(Vector3::new(3.0, 0.5, 0.5));
+    let cuboid_pos = Isometry3::translation(1.0, 0.95, 0.0);
+    let contact = query::contact(&m, &torus, &cuboid_pos, &cuboid, 0.0).unwrap();
+    assert_relative_eq!(contact.depth, 0.05, epsilon = 1.0e-4);
+    assert_relative_eq!(*contact.normal, Vector3::y(), epsilon = 1.0e-3);
+}
+
+#[test]
+fn torus_bounding_volumes() {
+    let torus = Torus::new(2.0f64, 0.5);
+
+    let aabb: AABB<f64> = torus.local_bounding_volume();
+    assert_relative_eq!(aabb.mins, Point3::new(-2.5, -0.5, -2.5));
+    assert_relative_eq!(aabb.maxs, Point3::new(2.5, 0.5, 2.5));
+
+    // Once rotated, the axis of the torus is along `x`.
+    let m = Isometry3::new(Vector3::new(1.0, 2.0, 3.0), Vector3::z() * FRAC_PI_2);
+    let aabb: AABB<f64> = torus.bounding_volume(&m);
+    assert_relative_eq!(aabb.mins, Point3::new(0.5, -0.5, 0.5), epsilon = 1.0e-7);
+    assert_relative_eq!(aabb.maxs, Point3::new(1.5, 4.5, 5.5), epsilon = 1.0e-7);
+
+    // The AABB is exact for any orientation.
+    let m = Isometry3::new(Vector3::zeros(), Vector3::new(0.3, -0.7, 1.1));
+    let aabb: AABB<f64> = torus.bounding_volume(&m);
+    let mesh = torus.to_trimesh((256, 64));
+    let coords: Vec<_> = mesh.coords.iter().map(|pt| m * pt).collect();
+    let mesh_aabb = AABB::from_points(&coords);
+    assert_relative_eq!(aabb.mins, mesh_aabb.mins, epsilon = 1.0e-3);
+    assert_relative_eq!(aabb.maxs, mesh_aabb.maxs, epsilon = 1.0e-3);
+
+    let sphere: BoundingSphere<f64> = torus.bounding_volume(&m);
+    assert_relative_eq!(sphere.radius(), 2.5);
+}
+
+#[test]
+fn torus_to_trimesh() {
+    let torus = Torus::new(2.0f64, 0.5);
+    let mesh = torus.to_trimesh((64, 32));
+
+    // The mesh is closed and its triangles are oriented outward, so its signed volume is close
+    // to the volume `2π²Rr²` of the torus.
+    let indices = mesh.indices.clone().unwrap_unified();
+    let volume: f64 = indices
+        .iter()
+        .map(|t| {
+            let (a, b, c) = (
+                mesh.coords[t.x as usize].coords,
+                mesh.coords[t.y as usize].coords,
+                mesh.coords[t.z as usize].coords,
+            );
+            a.dot(&b.cross(&c)) / 6.0
+        })
+        .sum();
+    assert_relative_eq!(volume, 2.0 * PI * PI * 2.0 * 0.25, max_relative = 1.0e-2);
+
+    let m = Isometry3::identity();
+
+    for (pt, n) in mesh.coords.iter().zip(mesh.normals.as_ref().unwrap()) {
+        assert_relative_eq!(
+            torus.distance_to_point(&m, pt, false),
+            0.0,
+            epsilon = 1.0e-7
+        );
+        assert_relative_eq!(
+            torus.distance_to_point(&m, &(pt + n * 0.1), false),
+            0.1,
+            epsilon = 1.0e-7
+        );
+    }
+}
@@ -0,0 +1,88 @@
+use na::{Point3, Vector3};
+use ncollide3d::procedural;
+use ncollide3d::shape::Shape;
+use ncollide3d::transformation::{self, VHACDParameters};
+
+fn params(resolution: u32) -> VHACDParameters<f64> {
+    VHACDParameters {
+        resolution,
+        plane_downsampling: 2,
+        ..VHACDParameters::default()
+    }
+}
+
+// An L-shaped solid made of three unit cubes.
+fn l_shape() -> (Vec<Point3<f64>>, Vec<Point3<u32>>) {
+    let mut points = Vec::new();
+    let mut indices = Vec::new();
+
+    for offset in &[
+        Vector3::new(0.5, 0.5, 0.5),
+        Vector3::new(1.5, 0.5, 0.5),
+        Vector3::new(0.5, 1.5, 0.5),
+    ] {
+        let mut cube = procedural::unit_cuboid::<f64>();
+        cube.translate_by(&na::Translation3::from(*offset));
+        let base = points.len() as u32;
+        points.extend(cube.coords.iter().cloned());
+        indices.extend(
+            cube.flat_indices()
+                .chunks(3)
+                .map(|t| Point3::new(t[0] + base, t[1] + base, t[2] + base)),
+        );
+    }
+
+    (points, indices)
+}
+
+#[test]
+fn vhacd_convex_mesh() {
+    let mut cube = procedural::unit_cuboid::<f64>();
+    cube.unify_index_buffer();
+    let indices = cube.indices.clone().unwrap_unified();
+
+    let hulls = transformation::vhacd(&cube.coords, &indices, &params(16));
+    assert_eq!(hulls.len(), 1);
+
+    let mprops = hulls[0].mass_properties(1.0).unwrap();
+    assert_relative_eq!(mprops.mass, 1.0, epsilon = 1.0e-6);
+}
+
+#[test]
+fn vhacd_concave_mesh() {
+    let (points, indices) = l_shape();
+    let hulls = transformation::vhacd(&points, &indices, &params(32));
+    assert!(hulls.len() >= 2);
+
+    let volume: f64 = hulls
+        .iter()
+        .map(|hull| hull.mass_properties(1.0).unwrap().mass)
+        .sum();
+    assert_relative_eq!(volume, 3.0, epsilon = 0.1);
+
+    let compound = transformation::vhacd_compound(&points, &indices, &params(32));
+    assert_eq!(compound.shapes().len(), hulls.len());
+}
+
+#[test]
+fn vhacd_limits() {
+    let (points, indices) = l_shape();
+    let limited = VHACDParameters {
+        max_convex_hulls: 1,
+        ..params(16)
+    };
+    assert_eq!(transformation::vhacd(&points, &indices, &limited).len(), 1);
+
+    let mut sphere = procedural::sphere::<f64>(2.0, 32, 32, false);
+    sphere.unify_index_buffer();
+    let indices = sphere.indices.clone().unwrap_unified();
+    let limited = VHACDParameters {
+        concavity: 1.0,
+        max_vertices_per_hull: 10,
+        ..params(16)
+    };
+    let hulls = transformation::vhacd(&sphere.coords, &indices, &limited);
+
+    assert_eq!(hulls.len(), 1);
+    assert_eq!(hulls[0].points().len(), 10);
+}
@@ -0,0 +1,155 @@
+use na::{Isometry3, Point3, Vector3};
+use ncollide3d::query::{PointQuery, Ray, RayCast};
+use ncollide3d::shape::{CompositeShape, FeatureId, Voxels};
+
+// A 4x1x4 floor with a 2x2x1 block on top of one of its corners.
+fn voxels() -> Voxels<f64> {
+    let mut keys = Vec::new();
+
+    for i in 0..4 {
+        for k in 0..4 {
+            keys.push(Point3::new(i, 0, k));
+        }
+    }
+
+    for i in 0..2 {
+        for k in 0..2 {
+            keys.push(Point3::new(i, 1, k));
+        }
+    }
+
+    // Duplicates are ignored.
+    keys.push(Point3::new(0, 0, 0));
+
+    Voxels::new(Vector3::new(0.5, 1.0, 0.5), &keys)
+}
+
+// The smallest time of impact with the voxels, computed by casting the ray on each cuboid.
+fn brute_force_toi(voxels: &Voxels<f64>, m: &Isometry3<f64>, ray: &Ray<f64>) -> Option<f64> {
+    let mut best: Option<f64> = None;
+
+    for i in 0..voxels.nparts() {
+        voxels.map_part_at(i, m, &mut |pos, cuboid| {
+            if let Some(toi) = cuboid.toi_with_ray(pos, ray, f64::MAX, true) {
+                best = Some(best.map_or(toi, |b| b.min(toi)));
+            }
+        })
+    }
+
+    best
+}
+
+#[test]
+fn voxels_construction() {
+    let voxels = voxels();
+
+    assert_eq!(voxels.voxels().len(), 20);
+    assert_eq!(*voxels.mins_key(), Point3::new(0, 0, 0));
+    assert_eq!(*voxels.maxs_key(), Point3::new(3, 1, 3));
+    assert!(voxels.is_filled(&Point3::new(1, 1, 1)));
+    assert!(!voxels.is_filled(&Point3::new(2, 1, 2)));
+
+    let aabb = voxels.aabb();
+    assert_relative_eq!(aabb.mins, Point3::origin());
+    assert_relative_eq!(aabb.maxs, Point3::new(2.0, 2.0, 2.0));
+
+    let from_points = Voxels::from_points(
+        Vector3::repeat(1.0),
+        &[Point3::new(0.5, 0.5, 0.5), Point3::new(-0.5, 0.2, 0.9)],
+    );
+    assert_eq!(
+        from_points.voxels(),
+        &[Point3::new(0, 0, 0), Point3::new(-1, 0, 0)]
+    );
+}
+
+#[test]
+fn voxels_ray_cast_matches_cuboids() {
+    let voxels = voxels();
+    let m = Isometry3::new(Vector3::new(1.0, -2.0, 0.5), Vector3::new(0.1, 0.7, -0.3));
+    let center = m * Point3::new(1.0, 1.0, 1.0);
+
+    for i in 0..200 {
+        let t = i as f64 * 0.61;
+        let origin = center + Vector3::new(t.cos() * 4.0, (t * 0.7).sin() * 4.0, t.sin() * 4.0);
+        let target = center + Vector3::new((t * 1.3).sin(), (t * 0.5).cos(), (t * 2.1).cos());
+        let ray = Ray::new(origin, target - origin);
+
+        let toi = voxels.toi_with_ray(&m, &ray, f64::MAX, true);
+        let expected = brute_force_toi(&voxels, &m, &ray);
+
+        match (toi, expected) {
+            (Some(toi), Some(expected)) => assert_relative_eq!(toi, expected, epsilon = 1.0e-8),
+            (None, None) => {}
+            _ => panic!("Ray cast mismatch: {:?} vs. {:?}", toi, expected),
+        }
+    }
+}
+
+#[test]
+fn voxels_ray_cast_normals() {
+    let voxels = voxels();
+    let m = Isometry3::identity();
+
+    // Hits the top of the block.
+    let ray = Ray::new(Point3::new(0.3, 5.0, 0.3), -Vector3::y());
+    let hit = voxels
+        .toi_and_normal_with_ray(&m, &ray, f64::MAX, true)
+        .unwrap();
+    assert_relative_eq!(hit.toi, 3.0);
+    assert_relative_eq!(hit.normal, Vector3::y());
+    let (part, feature) = voxels.subshape_feature_id(hit.feature);
+    assert_eq!(voxels.voxels()[part], Point3::new(0, 1, 0));
+    assert_eq!(feature, FeatureId::Face(1));
+
+    // Passes above the floor and hits the side of the block.
+    let ray = Ray::new(Point3::new(5.0, 1.5, 0.7), -Vector3::x());
+    let hit = voxels
+        .toi_and_normal_with_ray(&m, &ray, f64::MAX, true)
+        .unwrap();
+    assert_relative_eq!(hit.toi, 4.0);
+    assert_relative_eq!(hit.normal, Vector3::x());
+
+    // Misses because of `max_toi`.
+    assert!(voxels.toi_with_ray(&m, &ray, 3.9, true).is_none());
+
+    // Starts inside of the floor.
+    let ray = Ray::new(Point3::new(0.1, 0.5, 0.1), Vector3::x());
+    assert_eq!(voxels.toi_with_ray(&m, &ray, f64::MAX, true), Some(0.0));
+    let hit = voxels
+        .toi_and_normal_with_ray(&m, &ray, f64::MAX, false)
+        .unwrap();
+    assert_relative_eq!(hit.toi, 1.9);
+    assert_relative_eq!(hit.normal, -Vector3::x());
+}
+
+#[test]
+fn voxels_point_projection() {
+    let voxels = voxels();
+    let m = Isometry3::translation(0.0, 1.0, 0.0);
+
+    // Outside.
+    let proj = voxels.project_point(&m, &Point3::new(0.5, 3.5, 0.5), true);
+    assert!(!proj.is_inside);
+    assert_relative_eq!(proj.point, Point3::new(0.5, 3.0, 0.5));
+
+    // Inside, close to a face shared by the floor and the block: the projection is on the
+    // closest exposed face instead.
+    let pt = Point3::new(0.4, 1.9, 0.5);
+    assert!(voxels.contains_point(&m, &pt));
+
+    let proj = voxels.project_point(&m, &pt, true);
+    assert!(proj.is_inside);
+    assert_eq!(proj.point, pt);
+
+    let proj = voxels.project_point(&m, &pt, false);
+    assert!(proj.is_inside);
+    assert_relative_eq!(proj.point, Point3::new(0.0, 1.9, 0.5));
+
+    assert!(!voxels.contains_point(&m, &Point3::new(1.5, 2.5, 1.5)));
+    assert_relative_eq!(
+        voxels.distance_to_point(&m, &Point3::new(3.0, 2.5, 3.0), true),
+        1.5,
+        epsilon = 1.0e-10
+    );
+}
@@ -0,0 +1,83 @@
+use na::{Isometry3, Point3, Vector3};
+use ncollide3d::pipeline::{CollisionGroups, CollisionWorld, GeometricQueryType, SAPBroadPhase};
+use ncollide3d::query::Ray;
+use ncollide3d::shape::{Ball, Cuboid, ShapeHandle};
+
+fn world(sap: bool) -> CollisionWorld<f32, ()> {
+    let mut world = CollisionWorld::new(0.02);
+    if sap {
+        world.broad_phase = Box::new(SAPBroadPhase::new(0.02));
+    }
+
+    let ball = ShapeHandle::new(Ball::new(0.4f32));
+    let cuboid = ShapeHandle::new(Cuboid::new(Vector3::new(0.3f32, 0.2, 0.3)));
+    let groups = CollisionGroups::new();
+    let query = GeometricQueryType::Contacts(0.0, 0.0);
+
+    for i in 0..200 {
+        let pos = Isometry3::translation(
+            (i % 10) as f32 * 1.3,
+            ((i / 10) % 5) as f32 * 1.1,
+            (i / 50) as f32 * 1.7,
+        );
+        let shape = if i % 3 == 0 { &cuboid } else { &ball };
+        let _ = world.add(pos, shape.clone(), groups, query, ());
+    }
+
+    world.update();
+    world
+}
+
+fn rays() -> Vec<Ray<f32>> {
+    (0..500)
+        .map(|i| {
+            let t = i as f32 * 0.37;
+            let origin = Point3::new(-2.0, 2.5 + t.sin() * 3.0, 2.5 + t.cos() * 3.0);
+            let dir = Vector3::new(1.0, (t * 0.3).sin() * 0.2, (t * 0.7).cos() * 0.2);
+            Ray::new(origin, dir.normalize())
+        })
+        .collect()
+}
+
+#[test]
+fn cast_rays_matches_first_interference_with_ray() {
+    let groups = CollisionGroups::new();
+    let rays = rays();
+
+    for &sap in &[false, true] {
+        let world = world(sap);
+        let hits = world.cast_rays(&rays, 100.0, &groups);
+        assert_eq!(hits.len(), rays.len());
+        assert!(hits.iter().any(|hit| hit.is_some()));
+        assert!(hits.iter().any(|hit| hit.is_none()));
+
+        for (ray, hit) in rays.iter().zip(hits.iter()) {
+            let expected = world.first_interference_with_ray(ray, 100.0, &groups);
+
+            match (expected, hit) {
+                (Some(expected), Some(hit)) => {
+                    assert_relative_eq!(expected.inter.toi, hit.1.toi, epsilon = 1.0e-5)
+                }
+                (None, None) => {}
+                (expected, hit) => panic!(
+                    "Mismatch: {:?} vs. {:?}",
+                    expected.map(|e| e.handle),
+                    hit.map(|h| h.0)
+                ),
+            }
+        }
+
+        let mut buffer = Vec::new();
+        world.cast_rays_into(&rays[..10], 100.0, &groups, &mut buffer);
+        world.cast_rays_into(&rays[10..], 100.0, &groups, &mut buffer);
+        assert_eq!(buffer.len(), rays.len() - 10);
+
+        #[cfg(feature = "parallel")]
+        {
+            let par_hits = world.par_cast_rays(&rays, 100.0, &groups);
+            for (hit, par_hit) in hits.iter().zip(par_hits.iter()) {
+                assert_eq!(hit.as_ref().map(|h| h.0), par_hit.as_ref().map(|h| h.0));
+            }
+        }
+    }
+}
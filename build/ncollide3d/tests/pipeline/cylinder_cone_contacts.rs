@@ -0,0 +1,256 @@
+use super::contacts;
+use na::{Isometry3, Point3, Unit, UnitQuaternion, Vector3};
+use ncollide3d::query::Contact;
+use ncollide3d::shape::{
+    Ball, Compound, Cone, Cuboid, Cylinder, FeatureId, Plane, Shape, ShapeHandle, Tetrahedron,
+};
+
+fn assert_normals(contacts: &[Contact<f32>], normal: Vector3<f32>) {
+    for c in contacts {
+        assert_relative_eq!(c.normal.into_inner(), normal, epsilon = 1.0e-3);
+    }
+}
+
+#[test]
+fn cylinder_standing_on_plane() {
+    let plane = Plane::new(Vector3::y_axis());
+    let cylinder = Cylinder::new(0.5, 0.5);
+    let pos = Isometry3::translation(0.0, 0.49, 0.0);
+
+    let res = contacts(Isometry3::identity(), plane.clone(), pos, cylinder);
+    assert!(res.len() >= 3);
+    assert_normals(&res, Vector3::y());
+
+    for c in &res {
+        assert_relative_eq!(c.depth, 0.01, epsilon = 1.0e-5);
+        assert_relative_eq!(c.world2.y, -0.01, epsilon = 1.0e-5);
+    }
+
+    // Same with the shapes swapped.
+    let res = contacts(pos, cylinder, Isometry3::identity(), plane);
+    assert!(res.len() >= 3);
+    assert_normals(&res, -Vector3::y());
+}
+
+#[test]
+fn cylinder_lying_on_plane() {
+    let plane = Plane::new(Vector3::y_axis());
+    let cylinder = Cylinder::new(1.0, 0.5);
+    let rot = UnitQuaternion::from_axis_angle(&Vector3::z_axis(), std::f32::consts::FRAC_PI_2);
+    let pos = Isometry3::from_parts(Vector3::new(0.0, 0.49, 0.0).into(), rot);
+
+    let res = contacts(Isometry3::identity(), plane, pos, cylinder);
+    assert_eq!(res.len(), 2);
+    assert_normals(&res, Vector3::y());
+}
+
+#[test]
+fn tilted_cylinder_on_plane() {
+    let plane = Plane::new(Vector3::y_axis());
+    let cylinder = Cylinder::new(0.5, 0.5);
+    let rot = UnitQuaternion::from_axis_angle(&Vector3::z_axis(), 0.8);
+    let pos = Isometry3::from_parts(Vector3::new(0.0, 0.7061, 0.0).into(), rot);
+
+    let res = contacts(Isometry3::identity(), plane, pos, cylinder);
+    assert_eq!(res.len(), 1);
+    assert_normals(&res, Vector3::y());
+}
+
+#[test]
+fn cylinder_and_cone_caps_on_cuboid() {
+    let cuboid = Cuboid::new(Vector3::new(2.0, 0.5, 2.0));
+    let cylinder = Cylinder::new(0.5, 0.5);
+    let cone = Cone::new(0.5, 0.5);
+    let pos = Isometry3::translation(0.3, 0.99, -0.2);
+
+    let res = contacts(Isometry3::identity(), cuboid, pos, cylinder);
+    assert!(res.len() >= 3);
+    assert_normals(&res, Vector3::y());
+
+    let res = contacts(pos, cone, Isometry3::identity(), cuboid);
+    assert!(res.len() >= 3);
+    assert_normals(&res, -Vector3::y());
+
+    for c in &res {
+        assert_relative_eq!(c.depth, 0.01, epsilon = 1.0e-4);
+    }
+
+    // A cone standing on its apex only touches the cuboid at one point.
+    let flipped = Isometry3::from_parts(
+        Vector3::new(0.0, 0.99, 0.0).into(),
+        UnitQuaternion::from_axis_angle(&Vector3::x_axis(), std::f32::consts::PI),
+    );
+    let res = contacts(Isometry3::identity(), cuboid, flipped, cone);
+    assert_eq!(res.len(), 1);
+    assert_normals(&res, Vector3::y());
+}
+
+#[test]
+fn cylinder_cap_on_cylinder_cap() {
+    let cylinder = Cylinder::new(0.5, 0.5);
+    let res = contacts(
+        Isometry3::identity(),
+        cylinder,
+        Isometry3::translation(0.2, 0.99, 0.0),
+        cylinder,
+    );
+    assert!(res.len() >= 3);
+    assert_normals(&res, Vector3::y());
+}
+
+#[test]
+fn ball_on_cylinder_cap() {
+    let res = contacts(
+        Isometry3::identity(),
+        Cylinder::new(0.5, 0.5),
+        Isometry3::translation(0.1, 0.99, 0.0),
+        Ball::new(0.5),
+    );
+    assert_eq!(res.len(), 1);
+    assert_normals(&res, Vector3::y());
+    assert_relative_eq!(res[0].depth, 0.01, epsilon = 1.0e-4);
+}
+
+#[test]
+fn tetrahedron_face_on_cuboid() {
+    let tetrahedron = Tetrahedron::new(
+        Point3::new(0.0, 0.0, 0.0),
+        Point3::new(1.0, 0.0, 0.0),
+        Point3::new(0.0, 0.0, 1.0),
+        Point3::new(0.0, 1.0, 0.0),
+    );
+    let cuboid = Cuboid::new(Vector3::new(2.0, 0.5, 2.0));
+
+    let res = contacts(
+        Isometry3::identity(),
+        cuboid,
+        Isometry3::translation(0.0, 0.49, 0.0),
+        tetrahedron,
+    );
+    assert_eq!(res.len(), 3);
+    assert_normals(&res, Vector3::y());
+}
+
+#[test]
+fn cylinder_and_cone_in_compound() {
+    let compound = Compound::new(vec![
+        (
+            Isometry3::translation(-1.0, 0.0, 0.0),
+            ShapeHandle::new(Cylinder::new(0.5, 0.5)),
+        ),
+        (
+            Isometry3::translation(1.0, 0.0, 0.0),
+            ShapeHandle::new(Cone::new(0.5, 0.5)),
+        ),
+    ]);
+    let plane = Plane::new(Unit::new_normalize(Vector3::y()));
+
+    let res = contacts(
+        Isometry3::identity(),
+        plane,
+        Isometry3::translation(0.0, 0.49, 0.0),
+        compound,
+    );
+    assert!(res.len() >= 6);
+    assert_normals(&res, Vector3::y());
+}
+
+#[test]
+fn cylinder_and_cone_tangent_cones() {
+    let m = Isometry3::new(Vector3::new(1.0, 2.0, 3.0), Vector3::new(0.3, 0.7, -0.2));
+    let contains = |shape: &dyn Shape<f32>, fid, pt: Point3<f32>, dir: Vector3<f32>| {
+        let dir = Unit::new_normalize(m * dir);
+        shape.tangent_cone_at_point_contains_dir(fid, &m, None, &pt, &dir)
+    };
+
+    let cylinder = Cylinder::new(1.0, 0.5);
+    let lateral = Point3::new(0.5, 0.0, 0.0);
+    assert!(contains(
+        &cylinder,
+        FeatureId::Face(2),
+        lateral,
+        -Vector3::x()
+    ));
+    assert!(contains(
+        &cylinder,
+        FeatureId::Face(2),
+        lateral,
+        Vector3::y()
+    ));
+    assert!(!contains(
+        &cylinder,
+        FeatureId::Face(2),
+        lateral,
+        Vector3::x()
+    ));
+
+    // The tangent cones of the rims are bounded by both the caps and the lateral surface.
+    let top_rim = Point3::new(0.0, 1.0, 0.5);
+    let bottom_rim = Point3::new(0.0, -1.0, -0.5);
+    let rim_vertex = Point3::new(0.5, 1.0, 0.0);
+    assert!(contains(
+        &cylinder,
+        FeatureId::Edge(0),
+        top_rim,
+        Vector3::new(0.0, -1.0, -1.0)
+    ));
+    assert!(!contains(
+        &cylinder,
+        FeatureId::Edge(0),
+        top_rim,
+        Vector3::new(0.0, 1.0, -1.0)
+    ));
+    assert!(!contains(
+        &cylinder,
+        FeatureId::Edge(0),
+        top_rim,
+        Vector3::new(0.0, -1.0, 1.0)
+    ));
+    assert!(contains(
+        &cylinder,
+        FeatureId::Edge(1),
+        bottom_rim,
+        Vector3::new(0.0, 1.0, 1.0)
+    ));
+    assert!(!contains(
+        &cylinder,
+        FeatureId::Edge(1),
+        bottom_rim,
+        Vector3::new(0.0, 1.0, -1.0)
+    ));
+    assert!(contains(
+        &cylinder,
+        FeatureId::Vertex(0),
+        rim_vertex,
+        Vector3::new(-1.0, -1.0, 0.0)
+    ));
+    assert!(!contains(
+        &cylinder,
+        FeatureId::Vertex(0),
+        rim_vertex,
+        Vector3::new(1.0, -1.0, 0.0)
+    ));
+
+    let cone = Cone::new(1.0, 0.5);
+    let lateral = Point3::new(0.25, 0.0, 0.0);
+    let to_apex = Vector3::new(-0.25, 1.0, 0.0);
+    assert!(contains(&cone, FeatureId::Face(1), lateral, -Vector3::x()));
+    assert!(contains(&cone, FeatureId::Face(1), lateral, to_apex));
+    assert!(!contains(&cone, FeatureId::Face(1), lateral, Vector3::x()));
+    assert!(!contains(&cone, FeatureId::Face(1), lateral, Vector3::y()));
+
+    let rim = Point3::new(0.0, -1.0, 0.5);
+    assert!(contains(
+        &cone,
+        FeatureId::Edge(0),
+        rim,
+        Vector3::new(0.0, 1.0, -1.0)
+    ));
+    assert!(!contains(&cone, FeatureId::Edge(0), rim, Vector3::y()));
+    assert!(!contains(
+        &cone,
+        FeatureId::Edge(0),
+        rim,
+        Vector3::new(0.0, -1.0, -1.0)
+    ));
+}
@@ -0,0 +1,63 @@
+use na::{Isometry3, Vector3};
+use ncollide3d::pipeline::{CollisionGroups, CollisionWorld, GeometricQueryType};
+use ncollide3d::query::ClosestPoints;
+use ncollide3d::shape::{Ball, Cuboid, ShapeHandle};
+
+#[test]
+fn distance_pairs_within_limit() {
+    let mut world = CollisionWorld::new(0.0);
+    let groups = CollisionGroups::new();
+
+    let link = ShapeHandle::new(Ball::new(0.5f32));
+    let obstacle = ShapeHandle::new(Cuboid::new(Vector3::new(1.0f32, 1.0, 1.0)));
+
+    let h1 = world
+        .add(
+            Isometry3::translation(2.0, 0.0, 0.0),
+            link,
+            groups,
+            GeometricQueryType::Distance(1.0),
+            (),
+        )
+        .0;
+    let h2 = world
+        .add(
+            Isometry3::identity(),
+            obstacle,
+            groups,
+            GeometricQueryType::Contacts(0.0, 0.0),
+            (),
+        )
+        .0;
+
+    world.update();
+
+    let (_, _, _, pts) = world.distance_pair(h1, h2, true).unwrap();
+    match pts {
+        ClosestPoints::WithinMargin(p1, p2) => {
+            assert_relative_eq!((p2 - p1).norm(), 0.5, epsilon = 1.0e-3)
+        }
+        _ => panic!("Unexpected closest points: {:?}", pts),
+    }
+    assert_eq!(world.distance_pairs(true).count(), 1);
+    assert_eq!(world.contact_pairs(false).count(), 0);
+
+    // Penetration.
+    world
+        .get_mut(h1)
+        .unwrap()
+        .set_position(Isometry3::translation(1.2, 0.0, 0.0));
+    world.update();
+    assert_eq!(
+        world.distances_with(h1, true).unwrap().next().unwrap().3,
+        ClosestPoints::Intersecting
+    );
+
+    // Out of the distance limit.
+    world
+        .get_mut(h1)
+        .unwrap()
+        .set_position(Isometry3::translation(5.0, 0.0, 0.0));
+    world.update();
+    assert!(world.distance_pair(h1, h2, true).is_none());
+}
@@ -0,0 +1,112 @@
+use na::{Isometry3, Point3, Vector3};
+use ncollide3d::pipeline::{
+    CollisionGroups, CollisionObjectSlabHandle, CollisionWorld, GeometricQueryType, GridBroadPhase,
+};
+use ncollide3d::query::Ray;
+use ncollide3d::shape::{Ball, Plane, ShapeHandle};
+use std::collections::HashSet;
+
+fn positions(step: usize) -> Vec<Isometry3<f32>> {
+    (0..64)
+        .map(|i| {
+            let t = (step as f32) * 0.3 + i as f32;
+            let base = Vector3::new((i % 4) as f32, ((i / 4) % 4) as f32, (i / 16) as f32) * 1.5;
+            Isometry3::translation(base.x + t.sin(), base.y + t.cos(), base.z + (t * 0.7).sin())
+        })
+        .collect()
+}
+
+fn pairs(
+    world: &CollisionWorld<f32, ()>,
+) -> HashSet<(CollisionObjectSlabHandle, CollisionObjectSlabHandle)> {
+    world
+        .interaction_pairs(false)
+        .map(|(h1, h2, _)| if h1 < h2 { (h1, h2) } else { (h2, h1) })
+        .collect()
+}
+
+#[test]
+fn grid_matches_dbvt_broad_phase() {
+    let mut dbvt_world = CollisionWorld::new(0.02);
+    let mut grid_world = CollisionWorld::new(0.02);
+    grid_world.broad_phase = Box::new(GridBroadPhase::new(1.0, 0.02));
+
+    let shape = ShapeHandle::new(Ball::new(0.5f32));
+    let ground = ShapeHandle::new(Plane::new(Vector3::y_axis()));
+    let groups = CollisionGroups::new();
+    let query = GeometricQueryType::Contacts(0.0, 0.0);
+    let mut handles = Vec::new();
+
+    for pos in positions(0) {
+        let h1 = dbvt_world.add(pos, shape.clone(), groups, query, ()).0;
+        let h2 = grid_world.add(pos, shape.clone(), groups, query, ()).0;
+        assert_eq!(h1, h2);
+        handles.push(h1);
+    }
+
+    let _ = dbvt_world.add(Isometry3::identity(), ground.clone(), groups, query, ());
+    let _ = grid_world.add(Isometry3::identity(), ground, groups, query, ());
+
+    for step in 0..20 {
+        if step == 10 {
+            dbvt_world.remove(&handles[..8]);
+            grid_world.remove(&handles[..8]);
+        }
+
+        for (i, pos) in positions(step).into_iter().enumerate() {
+            if let Some(co) = dbvt_world.get_mut(handles[i]) {
+                co.set_position(pos);
+            }
+            if let Some(co) = grid_world.get_mut(handles[i]) {
+                co.set_position(pos);
+            }
+        }
+
+        dbvt_world.update();
+        grid_world.update();
+
+        assert!(!pairs(&grid_world).is_empty());
+        assert_eq!(pairs(&dbvt_world), pairs(&grid_world));
+    }
+
+    let target = dbvt_world
+        .collision_object(handles[20])
+        .unwrap()
+        .position()
+        .translation
+        .vector;
+
+    for dir in &[Vector3::x(), -Vector3::x(), Vector3::new(1.0, -0.2, 0.3)] {
+        let ray = Ray::new(Point3::from(target) - dir * 20.0, *dir);
+        let first_dbvt = dbvt_world
+            .first_interference_with_ray(&ray, 100.0, &groups)
+            .unwrap();
+        let first_grid = grid_world
+            .first_interference_with_ray(&ray, 100.0, &groups)
+            .unwrap();
+        assert_eq!(first_dbvt.handle, first_grid.handle);
+
+        let hits_dbvt: HashSet<_> = dbvt_world
+            .interferences_with_ray(&ray, 100.0, &groups)
+            .map(|hit| hit.0)
+            .collect();
+        let hits_grid: HashSet<_> = grid_world
+            .interferences_with_ray(&ray, 100.0, &groups)
+            .map(|hit| hit.0)
+            .collect();
+        assert!(hits_grid.contains(&handles[20]));
+        assert_eq!(hits_dbvt, hits_grid);
+    }
+
+    let point = Point3::from(target);
+    let points_dbvt: HashSet<_> = dbvt_world
+        .interferences_with_point(&point, &groups)
+        .map(|hit| hit.0)
+        .collect();
+    let points_grid: HashSet<_> = grid_world
+        .interferences_with_point(&point, &groups)
+        .map(|hit| hit.0)
+        .collect();
+    assert!(points_grid.contains(&handles[20]));
+    assert_eq!(points_dbvt, points_grid);
+}
@@ -0,0 +1,147 @@
+use na::{DMatrix, Isometry3, Vector3};
+use ncollide3d::pipeline::{CollisionGroups, CollisionWorld, GeometricQueryType};
+use ncollide3d::shape::{Ball, Compound, Cuboid, HeightField, Plane, ShapeHandle, Torus};
+use std::collections::HashSet;
+
+#[test]
+fn interferences_with_shape() {
+    let mut world = CollisionWorld::new(0.02);
+    let ball = ShapeHandle::new(Ball::new(0.5f32));
+    let groups = CollisionGroups::new();
+    let query = GeometricQueryType::Contacts(0.0, 0.0);
+
+    let inside = world
+        .add(Isometry3::identity(), ball.clone(), groups, query, ())
+        .0;
+    let touching = world
+        .add(
+            Isometry3::translation(1.4, 0.0, 0.0),
+            ball.clone(),
+            groups,
+            query,
+            (),
+        )
+        .0;
+    // The AABBs of this one overlap but the shapes don't.
+    let corner = world
+        .add(
+            Isometry3::translation(1.4, 1.4, 1.4),
+            ball.clone(),
+            groups,
+            query,
+            (),
+        )
+        .0;
+    let _ = world.add(
+        Isometry3::translation(5.0, 0.0, 0.0),
+        ball.clone(),
+        groups,
+        query,
+        (),
+    );
+    world.update();
+
+    let cuboid = Cuboid::new(Vector3::new(1.0f32, 1.0, 1.0));
+    let pos = Isometry3::identity();
+
+    let hits: HashSet<_> = world
+        .interferences_with_shape(&cuboid, &pos, &groups)
+        .map(|hit| hit.0)
+        .collect();
+    assert_eq!(hits, [inside, touching].iter().cloned().collect());
+
+    let contacts: Vec<_> = world
+        .contacts_with_shape(&cuboid, &pos, 0.0, &groups)
+        .filter(|hit| hit.0 == touching)
+        .collect();
+    assert_eq!(contacts.len(), 1);
+    assert_relative_eq!(contacts[0].2.depth, 0.1, epsilon = 1.0e-5);
+    assert_relative_eq!(
+        contacts[0].2.normal.into_inner(),
+        Vector3::x(),
+        epsilon = 1.0e-5
+    );
+
+    let predicted: HashSet<_> = world
+        .contacts_with_shape(&cuboid, &pos, 1.0, &groups)
+        .map(|hit| hit.0)
+        .collect();
+    assert!(predicted.contains(&corner));
+
+    let blacklisted = CollisionGroups::new().with_blacklist(&[0]);
+    assert_eq!(
+        world
+            .interferences_with_shape(&cuboid, &pos, &blacklisted)
+            .count(),
+        0
+    );
+}
+
+#[test]
+fn unsupported_shapes_are_ignored() {
+    let mut world = CollisionWorld::new(0.02);
+    let groups = CollisionGroups::new();
+    let query = GeometricQueryType::Contacts(0.0, 0.0);
+
+    let heightfield = HeightField::new(DMatrix::zeros(4, 4), Vector3::new(10.0f32, 1.0, 10.0));
+    let _ = world.add(
+        Isometry3::identity(),
+        ShapeHandle::new(heightfield.clone()),
+        groups,
+        query,
+        (),
+    );
+    let compound = Compound::new(vec![(Isometry3::identity(), ShapeHandle::new(heightfield))]);
+    let _ = world.add(
+        Isometry3::identity(),
+        ShapeHandle::new(compound),
+        groups,
+        query,
+        (),
+    );
+    let torus = world
+        .add(
+            Isometry3::translation(0.0, 0.4, 0.0),
+            ShapeHandle::new(Torus::new(2.0, 0.5)),
+            groups,
+            query,
+            (),
+        )
+        .0;
+    world.update();
+
+    let ball = Ball::new(0.5);
+    let pos = Isometry3::translation(2.0, 0.3, 0.0);
+
+    let hits: Vec<_> = world
+        .interferences_with_shape(&ball, &pos, &groups)
+        .map(|hit| hit.0)
+        .collect();
+    assert_eq!(hits, vec![torus]);
+
+    let contacts: Vec<_> = world
+        .contacts_with_shape(&ball, &pos, 0.0, &groups)
+        .collect();
+    assert_eq!(contacts.len(), 1);
+    assert_eq!(contacts[0].0, torus);
+    assert_relative_eq!(contacts[0].2.depth, 0.9, epsilon = 1.0e-4);
+
+    // Two planes are not supported either.
+    let plane = Plane::new(Vector3::y_axis());
+    let plane_handle = world
+        .add(
+            Isometry3::identity(),
+            ShapeHandle::new(plane.clone()),
+            groups,
+            GeometricQueryType::Proximity(0.0),
+            (),
+        )
+        .0;
+    world.update();
+    let hits: Vec<_> = world
+        .interferences_with_shape(&plane, &Isometry3::identity(), &groups)
+        .map(|hit| hit.0)
+        .collect();
+    assert!(!hits.contains(&plane_handle));
+    assert!(hits.contains(&torus));
+}
@@ -1,3 +1,54 @@
+use na::Isometry3;
+use ncollide3d::pipeline::{CollisionGroups, CollisionWorld, GeometricQueryType};
+use ncollide3d::query::Contact;
+use ncollide3d::shape::{Shape, ShapeHandle};
+
+mod cast_rays;
 mod contact_pairs;
+mod cylinder_cone_contacts;
+mod distance_pairs;
 mod duplicate_trimesh_on_world;
+mod grid_broad_phase;
+mod interferences_with_shape;
 mod is_send_sync;
+mod narrow_phase_events;
+mod round_shape_contacts;
+mod sap_broad_phase;
+mod shape_cast;
+mod torus_contacts;
+mod voxels_contacts;
+#[cfg(feature = "serde-serialize")]
+mod world_snapshot;
+
+/// Computes the contacts between two shapes, with normals oriented from the first to the second.
+pub fn contacts<S1: Shape<f32>, S2: Shape<f32>>(
+    pos1: Isometry3<f32>,
+    shape1: S1,
+    pos2: Isometry3<f32>,
+    shape2: S2,
+) -> Vec<Contact<f32>> {
+    let mut world = CollisionWorld::new(0.0);
+    let query = GeometricQueryType::Contacts(0.01, 0.0);
+    let groups = CollisionGroups::new();
+    let h1 = world
+        .add(pos1, ShapeHandle::new(shape1), groups, query, ())
+        .0;
+    let h2 = world
+        .add(pos2, ShapeHandle::new(shape2), groups, query, ())
+        .0;
+    world.update();
+
+    match world.contact_pair(h1, h2, true) {
+        Some((ha, _, _, manifold)) => manifold
+            .contacts()
+            .map(|c| {
+                let mut contact = c.contact;
+                if ha != h1 {
+                    contact.flip();
+                }
+                contact
+            })
+            .collect(),
+        None => Vec::new(),
+    }
+}
@@ -0,0 +1,71 @@
+use na::{Isometry3, Vector3};
+use ncollide3d::pipeline::{CollisionGroups, CollisionWorld, GeometricQueryType};
+use ncollide3d::shape::{Ball, ShapeHandle};
+
+// Returns the debug representation of every event emitted during `step` updates of a world
+// customized by `setup`.
+fn step_world(
+    step: usize,
+    setup: impl FnOnce(&mut CollisionWorld<f32, ()>),
+) -> (Vec<String>, Vec<String>) {
+    let mut world = CollisionWorld::new(0.02);
+    setup(&mut world);
+    let shape = ShapeHandle::new(Ball::new(0.5f32));
+    let groups = CollisionGroups::new();
+    let mut handles = Vec::new();
+
+    for i in 0..48 {
+        let query = if i % 3 == 0 {
+            GeometricQueryType::Proximity(0.0)
+        } else {
+            GeometricQueryType::Contacts(0.0, 0.0)
+        };
+        let pos = Isometry3::translation((i % 4) as f32, ((i / 4) % 4) as f32, (i / 16) as f32);
+        handles.push(world.add(pos, shape.clone(), groups, query, ()).0);
+    }
+
+    let mut contact_events = Vec::new();
+    let mut proximity_events = Vec::new();
+
+    for k in 0..step {
+        for (i, handle) in handles.iter().enumerate() {
+            let t = (k as f32) * 0.4 + i as f32;
+            let base = Vector3::new((i % 4) as f32, ((i / 4) % 4) as f32, (i / 16) as f32);
+            let pos = Isometry3::translation(
+                base.x + t.sin() * 0.3,
+                base.y + t.cos() * 0.3,
+                base.z + (t * 0.7).sin() * 0.3,
+            );
+            world.get_mut(*handle).unwrap().set_position(pos);
+        }
+
+        world.update();
+        contact_events.extend(world.contact_events().iter().map(|e| format!("{:?}", e)));
+        proximity_events.extend(world.proximity_events().iter().map(|e| format!("{:?}", e)));
+    }
+
+    (contact_events, proximity_events)
+}
+
+#[test]
+fn narrow_phase_events_are_deterministic() {
+    let (contacts1, proximities1) = step_world(30, |_| {});
+    let (contacts2, proximities2) = step_world(30, |_| {});
+
+    assert!(!contacts1.is_empty());
+    assert!(!proximities1.is_empty());
+    assert_eq!(contacts1, contacts2);
+    assert_eq!(proximities1, proximities2);
+}
+
+#[cfg(feature = "parallel")]
+#[test]
+fn parallel_and_sequential_narrow_phase_events_match() {
+    let (contacts1, proximities1) = step_world(30, |_| {});
+    let (contacts2, proximities2) = step_world(30, |world| world.narrow_phase.set_parallel(false));
+
+    assert!(!contacts1.is_empty());
+    assert!(!proximities1.is_empty());
+    assert_eq!(contacts1, contacts2);
+    assert_eq!(proximities1, proximities2);
+}
@@ -0,0 +1,116 @@
+use super::contacts;
+use na::{Isometry3, Translation3, UnitQuaternion, Vector3};
+use ncollide3d::pipeline::{CollisionGroups, CollisionWorld, GeometricQueryType};
+use ncollide3d::shape::{Ball, Capsule, Cuboid, Cylinder, Plane, RoundShape, ShapeHandle};
+
+fn round_cuboid() -> RoundShape<f32, Cuboid<f32>> {
+    RoundShape::new(Cuboid::new(Vector3::repeat(0.5)), 0.1)
+}
+
+#[test]
+fn round_cuboid_resting_on_plane() {
+    let plane = Plane::new(Vector3::y_axis());
+    let pos = Isometry3::translation(0.0, 0.59, 0.0);
+
+    let res = contacts(Isometry3::identity(), plane.clone(), pos, round_cuboid());
+    assert_eq!(res.len(), 4);
+
+    for c in &res {
+        assert_relative_eq!(c.normal.into_inner(), Vector3::y(), epsilon = 1.0e-5);
+        assert_relative_eq!(c.depth, 0.01, epsilon = 1.0e-5);
+        assert_relative_eq!(c.world2.y, -0.01, epsilon = 1.0e-5);
+    }
+
+    // Same with the shapes swapped.
+    let res = contacts(pos, round_cuboid(), Isometry3::identity(), plane);
+    assert_eq!(res.len(), 4);
+
+    for c in &res {
+        assert_relative_eq!(c.normal.into_inner(), -Vector3::y(), epsilon = 1.0e-5);
+        assert_relative_eq!(c.world1.y, -0.01, epsilon = 1.0e-5);
+    }
+}
+
+#[test]
+fn round_cuboid_stacked_on_round_cuboid() {
+    let pos = Isometry3::translation(0.2, 1.19, 0.0);
+    let res = contacts(Isometry3::identity(), round_cuboid(), pos, round_cuboid());
+
+    assert_eq!(res.len(), 4);
+
+    for c in &res {
+        assert_relative_eq!(c.normal.into_inner(), Vector3::y(), epsilon = 1.0e-5);
+        assert_relative_eq!(c.depth, 0.01, epsilon = 1.0e-5);
+    }
+}
+
+#[test]
+fn round_cuboid_resting_on_cuboid_edge() {
+    // The rounded cuboid lies on an edge of a cuboid rotated by 45 degrees.
+    let rot = Isometry3::rotation(Vector3::z() * std::f32::consts::FRAC_PI_4);
+    let cuboid = Cuboid::new(Vector3::repeat(0.5));
+    let top = 0.5 * 2.0f32.sqrt();
+    let pos = Isometry3::translation(0.0, top + 0.59, 0.0);
+
+    let res = contacts(rot, cuboid, pos, round_cuboid());
+
+    assert!(!res.is_empty());
+
+    for c in &res {
+        assert_relative_eq!(c.normal.into_inner(), Vector3::y(), epsilon = 1.0e-4);
+        assert_relative_eq!(c.depth, 0.01, epsilon = 1.0e-4);
+    }
+}
+
+#[test]
+fn round_ball_touching_round_cylinder() {
+    let ball = RoundShape::new(Ball::new(0.5), 0.25);
+    let cylinder = RoundShape::new(Cylinder::new(0.5, 0.5), 0.25);
+    let pos = Isometry3::translation(0.0, 1.49, 0.0);
+
+    let res = contacts(Isometry3::identity(), cylinder, pos, ball);
+    assert_eq!(res.len(), 1);
+    assert_relative_eq!(res[0].normal.into_inner(), Vector3::y(), epsilon = 1.0e-5);
+    assert_relative_eq!(res[0].depth, 0.01, epsilon = 1.0e-5);
+}
+
+#[test]
+fn round_capsule_contact_kinematic() {
+    // The dilations of the capsule and of the round shape must add up so that the contact
+    // kinematic recomputes the contacts on the boundary of the rounded capsule.
+    let mut world = CollisionWorld::new(0.0);
+    let query = GeometricQueryType::Contacts(0.01, 0.0);
+    let groups = CollisionGroups::new();
+    let plane = ShapeHandle::new(Plane::new(Vector3::y_axis()));
+    let capsule = ShapeHandle::new(RoundShape::new(Capsule::new(0.5, 0.2), 0.1));
+    let rot = UnitQuaternion::from_axis_angle(&Vector3::z_axis(), std::f32::consts::FRAC_PI_2);
+    let pos = Isometry3::from_parts(Translation3::new(0.0, 0.29, 0.0), rot);
+    let _ = world.add(Isometry3::identity(), plane, groups, query, ());
+    let _ = world.add(pos, capsule, groups, query, ());
+    world.update();
+
+    let (h1, h2, _, manifold) = world.contact_pairs(true).next().unwrap();
+    let co1 = world.collision_object(h1).unwrap();
+    let co2 = world.collision_object(h2).unwrap();
+    assert!(manifold.len() > 0);
+
+    for c in manifold.contacts() {
+        let dilation = c.kinematic.dilation1() + c.kinematic.dilation2();
+        assert_relative_eq!(dilation, 0.3, epsilon = 1.0e-5);
+        assert_relative_eq!(c.contact.depth, 0.01, epsilon = 1.0e-5);
+
+        let contact = c
+            .kinematic
+            .contact(
+                co1.position(),
+                co1.shape().as_ref(),
+                None,
+                co2.position(),
+                co2.shape().as_ref(),
+                None,
+                &c.contact.normal,
+            )
+            .unwrap();
+        assert_relative_eq!(contact.depth, 0.01, epsilon = 1.0e-5);
+    }
+}
@@ -0,0 +1,104 @@
+use na::{Isometry3, Point3, Vector3};
+use ncollide3d::pipeline::{
+    CollisionGroups, CollisionObjectSlabHandle, CollisionWorld, GeometricQueryType, SAPBroadPhase,
+};
+use ncollide3d::query::Ray;
+use ncollide3d::shape::{Ball, ShapeHandle};
+use std::collections::HashSet;
+
+fn positions(step: usize) -> Vec<Isometry3<f32>> {
+    (0..64)
+        .map(|i| {
+            let t = (step as f32) * 0.3 + i as f32;
+            let base = Vector3::new((i % 4) as f32, ((i / 4) % 4) as f32, (i / 16) as f32) * 1.5;
+            Isometry3::translation(base.x + t.sin(), base.y + t.cos(), base.z + (t * 0.7).sin())
+        })
+        .collect()
+}
+
+fn pairs(
+    world: &CollisionWorld<f32, ()>,
+) -> HashSet<(CollisionObjectSlabHandle, CollisionObjectSlabHandle)> {
+    world
+        .interaction_pairs(false)
+        .map(|(h1, h2, _)| if h1 < h2 { (h1, h2) } else { (h2, h1) })
+        .collect()
+}
+
+#[test]
+fn sap_matches_dbvt_broad_phase() {
+    let mut dbvt_world = CollisionWorld::new(0.02);
+    let mut sap_world = CollisionWorld::new(0.02);
+    sap_world.broad_phase = Box::new(SAPBroadPhase::new(0.02));
+
+    let shape = ShapeHandle::new(Ball::new(0.5f32));
+    let groups = CollisionGroups::new();
+    let query = GeometricQueryType::Contacts(0.0, 0.0);
+    let mut handles = Vec::new();
+
+    for pos in positions(0) {
+        let h1 = dbvt_world.add(pos, shape.clone(), groups, query, ()).0;
+        let h2 = sap_world.add(pos, shape.clone(), groups, query, ()).0;
+        assert_eq!(h1, h2);
+        handles.push(h1);
+    }
+
+    for step in 0..20 {
+        if step == 10 {
+            dbvt_world.remove(&handles[..8]);
+            sap_world.remove(&handles[..8]);
+        }
+
+        for (i, pos) in positions(step).into_iter().enumerate() {
+            if let Some(co) = dbvt_world.get_mut(handles[i]) {
+                co.set_position(pos);
+            }
+            if let Some(co) = sap_world.get_mut(handles[i]) {
+                co.set_position(pos);
+            }
+        }
+
+        dbvt_world.update();
+        sap_world.update();
+
+        assert!(!pairs(&sap_world).is_empty());
+        assert_eq!(pairs(&dbvt_world), pairs(&sap_world));
+    }
+
+    let target = dbvt_world
+        .collision_object(handles[20])
+        .unwrap()
+        .position()
+        .translation
+        .vector;
+    let ray = Ray::new(Point3::new(-10.0, target.y, target.z), Vector3::x());
+    let first_dbvt = dbvt_world
+        .first_interference_with_ray(&ray, 100.0, &groups)
+        .unwrap();
+    let first_sap = sap_world
+        .first_interference_with_ray(&ray, 100.0, &groups)
+        .unwrap();
+    assert_eq!(first_dbvt.handle, first_sap.handle);
+
+    let hits_dbvt: HashSet<_> = dbvt_world
+        .interferences_with_ray(&ray, 100.0, &groups)
+        .map(|hit| hit.0)
+        .collect();
+    let hits_sap: HashSet<_> = sap_world
+        .interferences_with_ray(&ray, 100.0, &groups)
+        .map(|hit| hit.0)
+        .collect();
+    assert_eq!(hits_dbvt, hits_sap);
+
+    let point = Point3::from(target);
+    let points_dbvt: HashSet<_> = dbvt_world
+        .interferences_with_point(&point, &groups)
+        .map(|hit| hit.0)
+        .collect();
+    let points_sap: HashSet<_> = sap_world
+        .interferences_with_point(&point, &groups)
+        .map(|hit| hit.0)
+        .collect();
+    assert!(points_sap.contains(&handles[20]));
+    assert_eq!(points_dbvt, points_sap);
+}
@@ -0,0 +1,94 @@
+use na::{Isometry3, Point3, Vector3};
+use ncollide3d::interpolation::ConstantVelocityRigidMotion;
+use ncollide3d::pipeline::{CollisionGroups, CollisionWorld, GeometricQueryType};
+use ncollide3d::shape::{Ball, ShapeHandle};
+
+#[test]
+fn shape_casts_are_sorted_by_toi() {
+    let mut world = CollisionWorld::new(0.02);
+    let ball = ShapeHandle::new(Ball::new(0.5f32));
+    let groups = CollisionGroups::new();
+    let query = GeometricQueryType::Contacts(0.0, 0.0);
+
+    let far = world
+        .add(
+            Isometry3::translation(6.0, 0.0, 0.0),
+            ball.clone(),
+            groups,
+            query,
+            (),
+        )
+        .0;
+    let near = world
+        .add(
+            Isometry3::translation(2.0, 0.0, 0.0),
+            ball.clone(),
+            groups,
+            query,
+            (),
+        )
+        .0;
+    let mid = world
+        .add(
+            Isometry3::translation(4.0, 0.0, 0.0),
+            ball.clone(),
+            groups,
+            query,
+            (),
+        )
+        .0;
+    let _ = world.add(
+        Isometry3::translation(4.0, 3.0, 0.0),
+        ball.clone(),
+        groups,
+        query,
+        (),
+    );
+    world.update();
+
+    let shape = Ball::new(0.5f32);
+    let pos = Isometry3::identity();
+    let vel = Vector3::x();
+
+    let hits = world.shape_cast_all(&pos, &vel, &shape, 10.0, 0.0, &groups);
+    let handles: Vec<_> = hits.iter().map(|hit| hit.0).collect();
+    assert_eq!(handles, vec![near, mid, far]);
+    assert_relative_eq!(hits[0].1.toi, 1.0, epsilon = 1.0e-5);
+    assert_relative_eq!(hits[1].1.toi, 3.0, epsilon = 1.0e-5);
+    assert_relative_eq!(hits[2].1.toi, 5.0, epsilon = 1.0e-5);
+    assert_relative_eq!(
+        hits[0].1.normal1.into_inner(),
+        Vector3::x(),
+        epsilon = 1.0e-5
+    );
+
+    let hits = world.shape_cast_all(&pos, &vel, &shape, 4.0, 0.0, &groups);
+    assert_eq!(hits.len(), 2);
+
+    let first = world
+        .first_shape_cast(&pos, &vel, &shape, 10.0, 0.0, &groups)
+        .unwrap();
+    assert_eq!(first.0, near);
+
+    let other_groups = CollisionGroups::new().with_blacklist(&[0]);
+    assert!(world
+        .first_shape_cast(&pos, &vel, &shape, 10.0, 0.0, &other_groups)
+        .is_none());
+
+    let motion = ConstantVelocityRigidMotion::new(
+        0.0,
+        pos,
+        Point3::origin(),
+        vel,
+        Vector3::new(0.0, 0.0, 2.0),
+    );
+    let hits = world.nonlinear_shape_cast_all(&motion, &shape, 10.0, 0.0, &groups);
+    let handles: Vec<_> = hits.iter().map(|hit| hit.0).collect();
+    assert_eq!(handles, vec![near, mid, far]);
+
+    let first = world
+        .first_nonlinear_shape_cast(&motion, &shape, 10.0, 0.0, &groups)
+        .unwrap();
+    assert_eq!(first.0, near);
+    assert_relative_eq!(first.1.toi, 1.0, epsilon = 1.0e-3);
+}
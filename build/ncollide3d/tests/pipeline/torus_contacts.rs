@@ -0,0 +1,81 @@
+use super::contacts;
+use na::{Isometry3, Vector3};
+use ncollide3d::shape::{Ball, Capsule, Cuboid, Torus};
+
+#[test]
+fn ball_on_torus() {
+    let res = contacts(
+        Isometry3::identity(),
+        Torus::new(2.0, 0.5),
+        Isometry3::translation(0.0, 0.9, 2.0),
+        Ball::new(0.5),
+    );
+
+    assert_eq!(res.len(), 1);
+    assert_relative_eq!(res[0].depth, 0.1, epsilon = 1.0e-4);
+    assert_relative_eq!(*res[0].normal, Vector3::y(), epsilon = 1.0e-3);
+}
+
+#[test]
+fn cuboid_across_torus() {
+    // The cuboid lies on both sides of the tube.
+    let cuboid = Cuboid::new(Vector3::new(3.0, 0.5, 0.5));
+    let pos = Isometry3::translation(0.0, 0.95, 0.0);
+
+    for flip in [false, true].iter() {
+        let res = if *flip {
+            let mut res = contacts(pos, cuboid, Isometry3::identity(), Torus::new(2.0, 0.5));
+            res.iter_mut().for_each(|c| c.flip());
+            res
+        } else {
+            contacts(Isometry3::identity(), Torus::new(2.0, 0.5), pos, cuboid)
+        };
+
+        assert_eq!(res.len(), 2);
+
+        for c in &res {
+            assert_relative_eq!(c.depth, 0.05, epsilon = 1.0e-4);
+            assert_relative_eq!(*c.normal, Vector3::y(), epsilon = 1.0e-4);
+            assert_relative_eq!(c.world1.x.abs(), 2.0, epsilon = 1.0e-3);
+        }
+
+        assert!(res[0].world1.x * res[1].world1.x < 0.0);
+    }
+}
+
+#[test]
+fn capsule_in_torus_hole() {
+    // The capsule touches the inner side of the tube all around.
+    let res = contacts(
+        Isometry3::identity(),
+        Torus::new(2.0, 0.5),
+        Isometry3::identity(),
+        Capsule::new(1.0, 1.6),
+    );
+
+    assert_eq!(res.len(), 4);
+
+    for c in &res {
+        assert_relative_eq!(c.depth, 0.1, epsilon = 1.0e-4);
+        assert_relative_eq!(c.normal.y, 0.0, epsilon = 1.0e-4);
+        // The normals point toward the axis of the torus.
+        assert_relative_eq!(
+            c.normal.dot(&c.world1.coords) / c.world1.coords.norm(),
+            -1.0,
+            epsilon = 1.0e-4
+        );
+    }
+}
+
+#[test]
+fn cuboid_in_torus_hole() {
+    // The AABBs of the shapes intersect but not the shapes themselves.
+    let res = contacts(
+        Isometry3::identity(),
+        Torus::new(2.0, 0.5),
+        Isometry3::identity(),
+        Cuboid::new(Vector3::repeat(0.5)),
+    );
+
+    assert!(res.is_empty());
+}
@@ -0,0 +1,72 @@
+use super::contacts;
+use na::{Isometry3, Point3, Vector3};
+use ncollide3d::shape::{Ball, Cuboid, Voxels};
+
+// A 4x4 floor of unit voxels, with its top at `y = 1`, and an optional wall on `x = 2`.
+fn floor(with_wall: bool) -> Voxels<f32> {
+    let mut keys = Vec::new();
+
+    for i in 0..4 {
+        for k in 0..4 {
+            keys.push(Point3::new(i, 0, k));
+
+            if with_wall && i == 2 {
+                keys.push(Point3::new(i, 1, k));
+            }
+        }
+    }
+
+    Voxels::new(Vector3::repeat(1.0), &keys)
+}
+
+#[test]
+fn cuboid_sunk_into_voxel_seam() {
+    // The cuboid penetrates the floor deeper than it overlaps the voxel at `x = 1`, so the
+    // contact with this voxel alone would push the cuboid sideways.
+    let pos = Isometry3::translation(2.3, 1.2, 0.5);
+
+    let res = contacts(
+        Isometry3::identity(),
+        floor(false),
+        pos,
+        Cuboid::new(Vector3::repeat(0.5)),
+    );
+    assert!(!res.is_empty());
+
+    for c in &res {
+        assert_relative_eq!(c.normal.into_inner(), Vector3::y(), epsilon = 1.0e-5);
+    }
+
+    // Same with the shapes swapped.
+    let res = contacts(
+        pos,
+        Cuboid::new(Vector3::repeat(0.5)),
+        Isometry3::identity(),
+        floor(false),
+    );
+    assert!(!res.is_empty());
+
+    for c in &res {
+        assert_relative_eq!(c.normal.into_inner(), -Vector3::y(), epsilon = 1.0e-5);
+    }
+}
+
+#[test]
+fn ball_against_voxel_wall() {
+    let pos = Isometry3::translation(1.55, 1.5, 2.0);
+
+    let res = contacts(Isometry3::identity(), floor(true), pos, Ball::new(0.5));
+    assert!(res.iter().any(|c| relative_eq!(
+        c.normal.into_inner(),
+        -Vector3::x(),
+        epsilon = 1.0e-5
+    ) && relative_eq!(c.depth, 0.05, epsilon = 1.0e-5)));
+
+    for c in &res {
+        let n = c.normal.into_inner();
+        assert!(
+            relative_eq!(n, -Vector3::x(), epsilon = 1.0e-5)
+                || relative_eq!(n, Vector3::y(), epsilon = 1.0e-5)
+        );
+    }
+}
@@ -0,0 +1,194 @@
+use na::{Isometry3, Vector3};
+use ncollide3d::pipeline::{
+    CollisionGroups, CollisionObjectSlabHandle, CollisionWorld, CollisionWorldSnapshot,
+    CollisionWorldSnapshotError, GeometricQueryType,
+};
+use ncollide3d::query::ContactId;
+use ncollide3d::shape::{Ball, Compound, Cuboid, ShapeHandle, ShapeRegistry};
+
+type Handle = CollisionObjectSlabHandle;
+
+fn contact_ids(world: &CollisionWorld<f32, usize>) -> Vec<(Handle, Handle, Vec<ContactId>)> {
+    let mut res: Vec<_> = world
+        .contact_pairs(true)
+        .map(|(h1, h2, _, manifold)| {
+            let mut ids: Vec<_> = manifold.contacts().map(|c| c.id).collect();
+            ids.sort();
+            if h1 < h2 {
+                (h1, h2, ids)
+            } else {
+                (h2, h1, ids)
+            }
+        })
+        .collect();
+    res.sort_by_key(|e| (e.0, e.1));
+    res
+}
+
+fn build_world() -> (CollisionWorld<f32, usize>, Vec<Handle>) {
+    let mut world = CollisionWorld::new(0.02);
+    let ball = ShapeHandle::new(Ball::new(0.5f32));
+    let cuboid = ShapeHandle::new(Cuboid::new(Vector3::new(0.4, 0.4, 0.4)));
+    let compound = ShapeHandle::new(Compound::new(vec![
+        (Isometry3::translation(-0.3, 0.0, 0.0), ball.clone()),
+        (Isometry3::translation(0.3, 0.0, 0.0), cuboid.clone()),
+    ]));
+    let groups = CollisionGroups::new();
+    let mut handles = Vec::new();
+
+    for i in 0..27 {
+        let shape = match i % 3 {
+            0 => ball.clone(),
+            1 => cuboid.clone(),
+            _ => compound.clone(),
+        };
+        let query = if i % 5 == 0 {
+            GeometricQueryType::Proximity(0.0)
+        } else {
+            GeometricQueryType::Contacts(0.05, 0.0)
+        };
+        let pos = Isometry3::translation(
+            (i % 3) as f32 * 0.8,
+            ((i / 3) % 3) as f32 * 0.8,
+            (i / 9) as f32 * 0.8,
+        );
+        handles.push(world.add(pos, shape, groups, query, i).0);
+    }
+
+    world.update();
+    world.remove(&handles[4..5]);
+    world.update();
+
+    (world, handles)
+}
+
+#[test]
+fn world_snapshot_serde_round_trip() {
+    let (mut world, handles) = build_world();
+    let registry = ShapeRegistry::new();
+
+    let snapshot = world.snapshot();
+    let mut json = Vec::new();
+    snapshot
+        .serialize(&registry, &mut serde_json::Serializer::new(&mut json))
+        .unwrap();
+    let snapshot: CollisionWorldSnapshot<f32, usize> = CollisionWorldSnapshot::deserialize(
+        &registry,
+        &mut serde_json::Deserializer::from_slice(&json),
+    )
+    .unwrap();
+    let mut restored = CollisionWorld::from_snapshot(snapshot).unwrap();
+    assert_eq!(restored.margin(), 0.02);
+
+    assert!(restored.collision_object(handles[4]).is_none());
+    for (i, handle) in handles.iter().enumerate().filter(|(i, _)| *i != 4) {
+        let co1 = world.collision_object(*handle).unwrap();
+        let co2 = restored.collision_object(*handle).unwrap();
+        assert_eq!(*co2.data(), i);
+        assert_eq!(co1.position(), co2.position());
+        assert_eq!(co1.query_type(), co2.query_type());
+    }
+
+    let ids = contact_ids(&world);
+    assert!(!ids.is_empty());
+    assert!(ids.iter().any(|e| !e.2.is_empty()));
+    assert_eq!(ids, contact_ids(&restored));
+
+    let proximities = |w: &CollisionWorld<f32, usize>| {
+        let mut res: Vec<_> = w
+            .proximity_pairs(true)
+            .map(|(h1, h2, _, prox)| (h1.min(h2), h1.max(h2), prox))
+            .collect();
+        res.sort_by_key(|e| (e.0, e.1));
+        res
+    };
+    assert_eq!(proximities(&world), proximities(&restored));
+
+    // Nothing moved so the next update must not generate any event.
+    restored.update();
+    assert_eq!(restored.contact_events().iter().count(), 0);
+    assert_eq!(restored.proximity_events().iter().count(), 0);
+    assert_eq!(ids, contact_ids(&restored));
+
+    // Both worlds must then detect the same interactions.
+    for handle in &handles[..3] {
+        world
+            .get_mut(*handle)
+            .unwrap()
+            .set_position(Isometry3::identity());
+        restored
+            .get_mut(*handle)
+            .unwrap()
+            .set_position(Isometry3::identity());
+    }
+    world.update();
+    restored.update();
+    let events = |w: &CollisionWorld<f32, usize>| {
+        let mut res: Vec<_> = w
+            .contact_events()
+            .iter()
+            .map(|e| format!("{:?}", e))
+            .collect();
+        res.sort();
+        res
+    };
+    assert!(!events(&world).is_empty());
+    assert_eq!(events(&world), events(&restored));
+
+    // The caches of the contact algorithms are not saved so only the number of contacts is compared.
+    let ncontacts = |w: &CollisionWorld<f32, usize>| {
+        contact_ids(w)
+            .into_iter()
+            .map(|e| (e.0, e.1, e.2.len()))
+            .collect::<Vec<_>>()
+    };
+    assert_eq!(ncontacts(&world), ncontacts(&restored));
+
+    let new_handle = restored
+        .add(
+            Isometry3::identity(),
+            ShapeHandle::new(Ball::new(0.5)),
+            CollisionGroups::new(),
+            GeometricQueryType::Contacts(0.0, 0.0),
+            100,
+        )
+        .0;
+    assert_eq!(new_handle, handles[4]);
+}
+
+#[test]
+fn invalid_world_snapshots() {
+    let (world, _) = build_world();
+    let registry = ShapeRegistry::new();
+    let mut data = Vec::new();
+    world
+        .snapshot()
+        .serialize(&registry, &mut serde_json::Serializer::new(&mut data))
+        .unwrap();
+    let json: serde_json::Value = serde_json::from_slice(&data).unwrap();
+
+    let deserialize = |json: &serde_json::Value| {
+        let data = serde_json::to_vec(json).unwrap();
+        CollisionWorldSnapshot::<f32, usize>::deserialize(
+            &registry,
+            &mut serde_json::Deserializer::from_slice(&data),
+        )
+    };
+
+    let restored = CollisionWorld::from_snapshot(deserialize(&json).unwrap()).unwrap();
+    assert_eq!(restored.margin(), 0.02);
+
+    let mut bad = json.clone();
+    bad[2][0]["shape"] = 99.into();
+    let err = deserialize(&bad).err().unwrap();
+    let expected = CollisionWorldSnapshotError::MissingShape(CollisionObjectSlabHandle(0));
+    assert!(err.to_string().starts_with(&expected.to_string()));
+
+    let mut bad = json.clone();
+    bad[2][1]["handle"] = bad[2][0]["handle"].clone();
+    assert!(deserialize(&bad).is_err());
+
+    let mut bad = json;
+    bad[0] = (-1.0).into();
+    assert!(deserialize(&bad).is_err());
+}
@@ -0,0 +1,16 @@
+use crate::bounding_volume::{BoundingVolume, HasBoundingVolume, AABB};
+use crate::math::Isometry;
+use crate::shape::{RoundShape, Shape};
+use na::RealField;
+
+impl<N: RealField, S: Shape<N>> HasBoundingVolume<N, AABB<N>> for RoundShape<N, S> {
+    #[inline]
+    fn bounding_volume(&self, m: &Isometry<N>) -> AABB<N> {
+        self.inner_shape.aabb(m).loosened(self.border_radius)
+    }
+
+    #[inline]
+    fn local_bounding_volume(&self) -> AABB<N> {
+        self.inner_shape.local_aabb().loosened(self.border_radius)
+    }
+}
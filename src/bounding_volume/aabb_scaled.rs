@@ -0,0 +1,25 @@
+use crate::bounding_volume::{HasBoundingVolume, AABB};
+use crate::math::{Isometry, Point};
+use crate::shape::{Scaled, Shape};
+use na::RealField;
+
+impl<N: RealField, S: Shape<N>> HasBoundingVolume<N, AABB<N>> for Scaled<N, S> {
+    #[inline]
+    fn bounding_volume(&self, m: &Isometry<N>) -> AABB<N> {
+        let bv: AABB<N> = self.local_bounding_volume();
+        bv.transform_by(m)
+    }
+
+    #[inline]
+    fn local_bounding_volume(&self) -> AABB<N> {
+        let aabb = self.shape().local_aabb();
+        let pt1 = self.scale_point(&aabb.mins);
+        let pt2 = self.scale_point(&aabb.maxs);
+
+        // Negative scaling factors swap the bounds.
+        AABB::new(
+            Point::from(pt1.coords.inf(&pt2.coords)),
+            Point::from(pt1.coords.sup(&pt2.coords)),
+        )
+    }
+}
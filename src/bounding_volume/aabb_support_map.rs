@@ -1,9 +1,9 @@
 use crate::bounding_volume;
 use crate::bounding_volume::{HasBoundingVolume, AABB};
-use crate::math::Isometry;
-use crate::shape::{Capsule, Segment};
+use crate::math::{Isometry, Point};
+use crate::shape::{Capsule, ConvexHullOf, Ellipsoid, MinkowskiSum, Segment, SupportMap};
 #[cfg(feature = "dim3")]
-use crate::shape::{Cone, Cylinder};
+use crate::shape::{Cone, Cylinder, Tetrahedron};
 use na::RealField;
 
 #[cfg(feature = "dim3")]
@@ -32,6 +32,19 @@ impl<N: RealField> HasBoundingVolume<N, AABB<N>> for Cylinder<N> {
     }
 }
 
+#[cfg(feature = "dim3")]
+impl<N: RealField> HasBoundingVolume<N, AABB<N>> for Tetrahedron<N> {
+    #[inline]
+    fn bounding_volume(&self, m: &Isometry<N>) -> AABB<N> {
+        bounding_volume::support_map_aabb(m, self)
+    }
+
+    #[inline]
+    fn local_bounding_volume(&self) -> AABB<N> {
+        bounding_volume::local_support_map_aabb(self)
+    }
+}
+
 impl<N: RealField> HasBoundingVolume<N, AABB<N>> for Capsule<N> {
     #[inline]
     fn bounding_volume(&self, m: &Isometry<N>) -> AABB<N> {
@@ -44,6 +57,19 @@ impl<N: RealField> HasBoundingVolume<N, AABB<N>> for Capsule<N> {
     }
 }
 
+impl<N: RealField> HasBoundingVolume<N, AABB<N>> for Ellipsoid<N> {
+    #[inline]
+    fn bounding_volume(&self, m: &Isometry<N>) -> AABB<N> {
+        bounding_volume::support_map_aabb(m, self)
+    }
+
+    #[inline]
+    fn local_bounding_volume(&self) -> AABB<N> {
+        let half_extents = self.radii;
+        AABB::new(Point::from(-half_extents), Point::from(half_extents))
+    }
+}
+
 impl<N: RealField> HasBoundingVolume<N, AABB<N>> for Segment<N> {
     #[inline]
     fn bounding_volume(&self, m: &Isometry<N>) -> AABB<N> {
@@ -56,3 +82,31 @@ impl<N: RealField> HasBoundingVolume<N, AABB<N>> for Segment<N> {
         bounding_volume::local_support_map_aabb(self)
     }
 }
+
+impl<N: RealField, A: SupportMap<N>, B: SupportMap<N>> HasBoundingVolume<N, AABB<N>>
+    for MinkowskiSum<N, A, B>
+{
+    #[inline]
+    fn bounding_volume(&self, m: &Isometry<N>) -> AABB<N> {
+        bounding_volume::support_map_aabb(m, self)
+    }
+
+    #[inline]
+    fn local_bounding_volume(&self) -> AABB<N> {
+        bounding_volume::local_support_map_aabb(self)
+    }
+}
+
+impl<N: RealField, A: SupportMap<N>, B: SupportMap<N>> HasBoundingVolume<N, AABB<N>>
+    for ConvexHullOf<N, A, B>
+{
+    #[inline]
+    fn bounding_volume(&self, m: &Isometry<N>) -> AABB<N> {
+        bounding_volume::support_map_aabb(m, self)
+    }
+
+    #[inline]
+    fn local_bounding_volume(&self) -> AABB<N> {
+        bounding_volume::local_support_map_aabb(self)
+    }
+}
@@ -0,0 +1,26 @@
+use crate::bounding_volume::{HasBoundingVolume, AABB};
+use crate::math::{Isometry, Point, Vector};
+use crate::shape::Torus;
+use na::RealField;
+
+impl<N: RealField> HasBoundingVolume<N, AABB<N>> for Torus<N> {
+    #[inline]
+    fn bounding_volume(&self, m: &Isometry<N>) -> AABB<N> {
+        // The extent of the circle at the center of the tube along the unit vector `e` is
+        // `R * sqrt(1 - (e . axis)²)` where `axis` is the axis of revolution of the torus.
+        let axis = m * Vector::y();
+        let half_extents = axis.map(|a| {
+            self.major_radius * (N::one() - a * a).max(N::zero()).sqrt() + self.minor_radius
+        });
+
+        AABB::from_half_extents(Point::from(m.translation.vector), half_extents)
+    }
+
+    #[inline]
+    fn local_bounding_volume(&self) -> AABB<N> {
+        let outer = self.major_radius + self.minor_radius;
+        let half_extents = Vector::new(outer, self.minor_radius, outer);
+
+        AABB::from_half_extents(Point::origin(), half_extents)
+    }
+}
@@ -0,0 +1,22 @@
+use crate::bounding_volume::{BoundingSphere, BoundingVolume, HasBoundingVolume};
+use crate::math::Isometry;
+use crate::shape::{ConvexHullOf, Shape};
+use na::RealField;
+
+impl<N: RealField, A: Shape<N>, B: Shape<N>> HasBoundingVolume<N, BoundingSphere<N>>
+    for ConvexHullOf<N, A, B>
+{
+    #[inline]
+    fn bounding_volume(&self, m: &Isometry<N>) -> BoundingSphere<N> {
+        let bv: BoundingSphere<N> = self.local_bounding_volume();
+        bv.transform_by(m)
+    }
+
+    #[inline]
+    fn local_bounding_volume(&self) -> BoundingSphere<N> {
+        let bs1 = self.shape1.bounding_sphere(&self.pos1);
+        let bs2 = self.shape2.bounding_sphere(&self.pos2);
+
+        bs1.merged(&bs2)
+    }
+}
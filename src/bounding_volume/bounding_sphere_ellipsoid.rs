@@ -0,0 +1,18 @@
+use simba::scalar::RealField;
+
+use crate::bounding_volume::{BoundingSphere, HasBoundingVolume};
+use crate::math::{Isometry, Point};
+use crate::shape::Ellipsoid;
+
+impl<N: RealField> HasBoundingVolume<N, BoundingSphere<N>> for Ellipsoid<N> {
+    #[inline]
+    fn bounding_volume(&self, m: &Isometry<N>) -> BoundingSphere<N> {
+        let bv: BoundingSphere<N> = self.local_bounding_volume();
+        bv.transform_by(m)
+    }
+
+    #[inline]
+    fn local_bounding_volume(&self) -> BoundingSphere<N> {
+        BoundingSphere::new(Point::origin(), self.radii.max())
+    }
+}
@@ -0,0 +1,23 @@
+use crate::bounding_volume::{BoundingSphere, HasBoundingVolume};
+use crate::math::{Isometry, Point};
+use crate::shape::{MinkowskiSum, Shape};
+use na::RealField;
+
+impl<N: RealField, A: Shape<N>, B: Shape<N>> HasBoundingVolume<N, BoundingSphere<N>>
+    for MinkowskiSum<N, A, B>
+{
+    #[inline]
+    fn bounding_volume(&self, m: &Isometry<N>) -> BoundingSphere<N> {
+        let bv: BoundingSphere<N> = self.local_bounding_volume();
+        bv.transform_by(m)
+    }
+
+    #[inline]
+    fn local_bounding_volume(&self) -> BoundingSphere<N> {
+        let bs1 = self.shape1.bounding_sphere(&self.pos1);
+        let bs2 = self.shape2.bounding_sphere(&self.pos2);
+        let center = Point::from(bs1.center().coords + bs2.center().coords);
+
+        BoundingSphere::new(center, bs1.radius() + bs2.radius())
+    }
+}
@@ -0,0 +1,20 @@
+use crate::bounding_volume::{BoundingSphere, BoundingVolume, HasBoundingVolume};
+use crate::math::Isometry;
+use crate::shape::{RoundShape, Shape};
+use na::RealField;
+
+impl<N: RealField, S: Shape<N>> HasBoundingVolume<N, BoundingSphere<N>> for RoundShape<N, S> {
+    #[inline]
+    fn bounding_volume(&self, m: &Isometry<N>) -> BoundingSphere<N> {
+        self.inner_shape
+            .bounding_sphere(m)
+            .loosened(self.border_radius)
+    }
+
+    #[inline]
+    fn local_bounding_volume(&self) -> BoundingSphere<N> {
+        self.inner_shape
+            .local_bounding_sphere()
+            .loosened(self.border_radius)
+    }
+}
@@ -0,0 +1,20 @@
+use crate::bounding_volume::{BoundingSphere, HasBoundingVolume};
+use crate::math::Isometry;
+use crate::shape::{Scaled, Shape};
+use na::RealField;
+
+impl<N: RealField, S: Shape<N>> HasBoundingVolume<N, BoundingSphere<N>> for Scaled<N, S> {
+    #[inline]
+    fn bounding_volume(&self, m: &Isometry<N>) -> BoundingSphere<N> {
+        let bv: BoundingSphere<N> = self.local_bounding_volume();
+        bv.transform_by(m)
+    }
+
+    #[inline]
+    fn local_bounding_volume(&self) -> BoundingSphere<N> {
+        let bs = self.shape().local_bounding_sphere();
+        let max_scale = self.scale().abs().max();
+
+        BoundingSphere::new(self.scale_point(bs.center()), bs.radius() * max_scale)
+    }
+}
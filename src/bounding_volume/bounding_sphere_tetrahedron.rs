@@ -0,0 +1,20 @@
+use crate::bounding_volume;
+use crate::bounding_volume::{BoundingSphere, HasBoundingVolume};
+use crate::math::Isometry;
+use crate::shape::Tetrahedron;
+use na::RealField;
+
+impl<N: RealField> HasBoundingVolume<N, BoundingSphere<N>> for Tetrahedron<N> {
+    #[inline]
+    fn bounding_volume(&self, m: &Isometry<N>) -> BoundingSphere<N> {
+        let bv: BoundingSphere<N> = self.local_bounding_volume();
+        bv.transform_by(m)
+    }
+
+    #[inline]
+    fn local_bounding_volume(&self) -> BoundingSphere<N> {
+        let (center, radius) = bounding_volume::point_cloud_bounding_sphere(&self.vertices()[..]);
+
+        BoundingSphere::new(center, radius)
+    }
+}
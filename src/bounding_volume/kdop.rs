@@ -0,0 +1,262 @@
+//! Discrete Oriented Polytope.
+
+use crate::bounding_volume::{BoundingVolume, AABB};
+use crate::math::{Isometry, Point, Vector, DIM};
+use crate::shape::SupportMap;
+use na::{self, RealField};
+
+#[cfg(feature = "dim2")]
+const AXES: [[i8; DIM]; 2] = [[1, 0], [0, 1]];
+#[cfg(feature = "dim2")]
+const DIAGONALS: [[i8; DIM]; 2] = [[1, 1], [1, -1]];
+
+#[cfg(feature = "dim3")]
+const AXES: [[i8; DIM]; 3] = [[1, 0, 0], [0, 1, 0], [0, 0, 1]];
+#[cfg(feature = "dim3")]
+const CORNERS: [[i8; DIM]; 4] = [[1, 1, 1], [1, -1, 1], [1, 1, -1], [1, -1, -1]];
+#[cfg(feature = "dim3")]
+const EDGES: [[i8; DIM]; 6] = [
+    [1, 1, 0],
+    [1, -1, 0],
+    [1, 0, 1],
+    [1, 0, -1],
+    [0, 1, 1],
+    [0, 1, -1],
+];
+
+#[cfg(feature = "dim2")]
+const DIRECTIONS_8: [[i8; DIM]; 4] = [AXES[0], AXES[1], DIAGONALS[0], DIAGONALS[1]];
+
+#[cfg(feature = "dim3")]
+const DIRECTIONS_14: [[i8; DIM]; 7] = [
+    AXES[0], AXES[1], AXES[2], CORNERS[0], CORNERS[1], CORNERS[2], CORNERS[3],
+];
+#[cfg(feature = "dim3")]
+const DIRECTIONS_18: [[i8; DIM]; 9] = [
+    AXES[0], AXES[1], AXES[2], EDGES[0], EDGES[1], EDGES[2], EDGES[3], EDGES[4], EDGES[5],
+];
+#[cfg(feature = "dim3")]
+const DIRECTIONS_26: [[i8; DIM]; 13] = [
+    AXES[0], AXES[1], AXES[2], CORNERS[0], CORNERS[1], CORNERS[2], CORNERS[3], EDGES[0], EDGES[1],
+    EDGES[2], EDGES[3], EDGES[4], EDGES[5],
+];
+
+/// A k-DOP with 8 faces.
+#[cfg(feature = "dim2")]
+pub type KDOP8<N> = KDOP<N, 8>;
+/// A k-DOP with 14 faces, orthogonal to the coordinate axes and to the diagonals of a cube.
+#[cfg(feature = "dim3")]
+pub type KDOP14<N> = KDOP<N, 14>;
+/// A k-DOP with 18 faces, orthogonal to the coordinate axes and to the diagonals of the faces of a cube.
+#[cfg(feature = "dim3")]
+pub type KDOP18<N> = KDOP<N, 18>;
+/// A k-DOP with 26 faces, combining the faces of `KDOP14` and `KDOP18`.
+#[cfg(feature = "dim3")]
+pub type KDOP26<N> = KDOP<N, 26>;
+
+/// A Discrete Oriented Polytope with `K` faces.
+///
+/// A k-DOP is the intersection of `K / 2` slabs. Each slab is bounded by two planes orthogonal to
+/// one of a fixed set of directions. The supported values of `K` are 4 and 8 in 2D, and 6, 14, 18
+/// and 26 in 3D. The first directions are always the coordinate axes so a k-DOP is never larger
+/// than the AABB of the same object.
+#[derive(Debug, PartialEq, Copy, Clone)]
+pub struct KDOP<N: RealField, const K: usize> {
+    support: [N; K],
+}
+
+impl<N: RealField, const K: usize> KDOP<N, K> {
+    #[cfg(feature = "dim2")]
+    const DIRECTIONS: &'static [[i8; DIM]] = match K {
+        4 => &AXES,
+        8 => &DIRECTIONS_8,
+        _ => panic!("Unsupported number of k-DOP faces: K must be 4 or 8."),
+    };
+
+    #[cfg(feature = "dim3")]
+    const DIRECTIONS: &'static [[i8; DIM]] = match K {
+        6 => &AXES,
+        14 => &DIRECTIONS_14,
+        18 => &DIRECTIONS_18,
+        26 => &DIRECTIONS_26,
+        _ => panic!("Unsupported number of k-DOP faces: K must be 6, 14, 18, or 26."),
+    };
+
+    /// Creates an invalid k-DOP, i.e., a k-DOP which does not contain any point.
+    ///
+    /// Merging an invalid k-DOP with another one gives the other k-DOP.
+    #[inline]
+    pub fn new_invalid() -> Self {
+        KDOP {
+            support: [-N::max_value(); K],
+        }
+    }
+
+    /// Computes the smallest k-DOP containing the given points.
+    pub fn from_points<'a, I>(pts: I) -> Self
+    where
+        I: IntoIterator<Item = &'a Point<N>>,
+    {
+        let mut res = Self::new_invalid();
+
+        for pt in pts {
+            res.take_point(pt);
+        }
+
+        res
+    }
+
+    /// Computes the smallest k-DOP containing the support-mapped shape `g` transformed by `m`.
+    pub fn from_support_map<G>(m: &Isometry<N>, g: &G) -> Self
+    where
+        G: ?Sized + SupportMap<N>,
+    {
+        let mut support = [N::zero(); K];
+
+        for (i, s) in support.iter_mut().enumerate() {
+            let dir = Self::direction(i);
+            *s = g.support_point(m, &dir).coords.dot(&dir);
+        }
+
+        KDOP { support }
+    }
+
+    /// The `i`-th direction of the normals of the faces of this k-DOP.
+    ///
+    /// This direction is not normalized. For `i >= K / 2`, it is the opposite of the
+    /// `(i - K / 2)`-th direction.
+    #[inline]
+    pub fn direction(i: usize) -> Vector<N> {
+        assert!(i < K, "Invalid k-DOP face index.");
+        let half = Self::DIRECTIONS.len();
+        let dir = Vector::from_iterator(
+            Self::DIRECTIONS[i % half]
+                .iter()
+                .map(|e| na::convert::<f64, N>(*e as f64)),
+        );
+
+        if i < half {
+            dir
+        } else {
+            -dir
+        }
+    }
+
+    /// The support values of this k-DOP.
+    ///
+    /// The `i`-th value is the maximum of `Self::direction(i).dot(p)` over the points `p` of
+    /// this k-DOP.
+    #[inline]
+    pub fn support_values(&self) -> &[N; K] {
+        &self.support
+    }
+
+    /// Enlarges this k-DOP so that it contains the given point.
+    #[inline]
+    pub fn take_point(&mut self, pt: &Point<N>) {
+        for (i, s) in self.support.iter_mut().enumerate() {
+            *s = s.max(Self::direction(i).dot(&pt.coords));
+        }
+    }
+
+    /// The smallest AABB containing this k-DOP.
+    #[inline]
+    pub fn aabb(&self) -> AABB<N> {
+        let half = K / 2;
+        let mins = Point::from(Vector::from_fn(|i, _| -self.support[half + i]));
+        let maxs = Point::from(Vector::from_fn(|i, _| self.support[i]));
+
+        AABB::new(mins, maxs)
+    }
+
+    /// Checks if this k-DOP contains the given point.
+    #[inline]
+    pub fn contains_local_point(&self, pt: &Point<N>) -> bool {
+        (0..K).all(|i| Self::direction(i).dot(&pt.coords) <= self.support[i])
+    }
+}
+
+impl<N: RealField, const K: usize> BoundingVolume<N> for KDOP<N, K> {
+    #[inline]
+    fn center(&self) -> Point<N> {
+        self.aabb().center()
+    }
+
+    #[inline]
+    fn intersects(&self, other: &Self) -> bool {
+        let half = K / 2;
+
+        (0..half).all(|i| {
+            self.support[i] >= -other.support[half + i]
+                && other.support[i] >= -self.support[half + i]
+        })
+    }
+
+    #[inline]
+    fn contains(&self, other: &Self) -> bool {
+        self.support
+            .iter()
+            .zip(other.support.iter())
+            .all(|(s1, s2)| s2 <= s1)
+    }
+
+    #[inline]
+    fn merge(&mut self, other: &Self) {
+        for (s1, s2) in self.support.iter_mut().zip(other.support.iter()) {
+            *s1 = s1.max(*s2);
+        }
+    }
+
+    #[inline]
+    fn merged(&self, other: &Self) -> Self {
+        let mut res = *self;
+
+        res.merge(other);
+
+        res
+    }
+
+    #[inline]
+    fn loosen(&mut self, amount: N) {
+        assert!(
+            amount >= na::zero(),
+            "The loosening margin must be positive."
+        );
+
+        for (i, s) in self.support.iter_mut().enumerate() {
+            *s += amount * Self::direction(i).norm();
+        }
+    }
+
+    #[inline]
+    fn loosened(&self, amount: N) -> Self {
+        let mut res = *self;
+
+        res.loosen(amount);
+
+        res
+    }
+
+    #[inline]
+    fn tighten(&mut self, amount: N) {
+        assert!(
+            amount >= na::zero(),
+            "The tightening margin must be positive."
+        );
+
+        for (i, s) in self.support.iter_mut().enumerate() {
+            *s -= amount * Self::direction(i).norm();
+        }
+
+        assert!(self.intersects(self), "The tightening margin is to large.");
+    }
+
+    #[inline]
+    fn tightened(&self, amount: N) -> Self {
+        let mut res = *self;
+
+        res.tighten(amount);
+
+        res
+    }
+}
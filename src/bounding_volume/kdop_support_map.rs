@@ -0,0 +1,66 @@
+use crate::bounding_volume::{HasBoundingVolume, KDOP};
+use crate::math::Isometry;
+#[cfg(feature = "dim2")]
+use crate::shape::ConvexPolygon;
+use crate::shape::{
+    Ball, Capsule, ConvexHullOf, Cuboid, Ellipsoid, MinkowskiSum, RoundShape, Segment, SupportMap,
+    Triangle,
+};
+#[cfg(feature = "dim3")]
+use crate::shape::{Cone, ConvexHull, Cylinder, Tetrahedron};
+use na::RealField;
+
+macro_rules! impl_kdop_support_map(
+    ($shape: ident) => {
+        impl<N: RealField, const K: usize> HasBoundingVolume<N, KDOP<N, K>> for $shape<N> {
+            #[inline]
+            fn bounding_volume(&self, m: &Isometry<N>) -> KDOP<N, K> {
+                KDOP::from_support_map(m, self)
+            }
+        }
+    }
+);
+
+impl_kdop_support_map!(Ball);
+impl_kdop_support_map!(Capsule);
+impl_kdop_support_map!(Cuboid);
+impl_kdop_support_map!(Ellipsoid);
+impl_kdop_support_map!(Segment);
+impl_kdop_support_map!(Triangle);
+#[cfg(feature = "dim2")]
+impl_kdop_support_map!(ConvexPolygon);
+#[cfg(feature = "dim3")]
+impl_kdop_support_map!(Cone);
+#[cfg(feature = "dim3")]
+impl_kdop_support_map!(ConvexHull);
+#[cfg(feature = "dim3")]
+impl_kdop_support_map!(Cylinder);
+#[cfg(feature = "dim3")]
+impl_kdop_support_map!(Tetrahedron);
+
+impl<N: RealField, S: SupportMap<N>, const K: usize> HasBoundingVolume<N, KDOP<N, K>>
+    for RoundShape<N, S>
+{
+    #[inline]
+    fn bounding_volume(&self, m: &Isometry<N>) -> KDOP<N, K> {
+        KDOP::from_support_map(m, self)
+    }
+}
+
+impl<N: RealField, A: SupportMap<N>, B: SupportMap<N>, const K: usize>
+    HasBoundingVolume<N, KDOP<N, K>> for MinkowskiSum<N, A, B>
+{
+    #[inline]
+    fn bounding_volume(&self, m: &Isometry<N>) -> KDOP<N, K> {
+        KDOP::from_support_map(m, self)
+    }
+}
+
+impl<N: RealField, A: SupportMap<N>, B: SupportMap<N>, const K: usize>
+    HasBoundingVolume<N, KDOP<N, K>> for ConvexHullOf<N, A, B>
+{
+    #[inline]
+    fn bounding_volume(&self, m: &Isometry<N>) -> KDOP<N, K> {
+        KDOP::from_support_map(m, self)
+    }
+}
@@ -0,0 +1,22 @@
+use crate::bounding_volume::{HasBoundingVolume, KDOP};
+use crate::math::Isometry;
+use crate::shape::TriMesh;
+use na::RealField;
+
+impl<N: RealField, const K: usize> HasBoundingVolume<N, KDOP<N, K>> for TriMesh<N> {
+    #[inline]
+    fn bounding_volume(&self, m: &Isometry<N>) -> KDOP<N, K> {
+        let mut res = KDOP::new_invalid();
+
+        for pt in self.points() {
+            res.take_point(&(m * pt));
+        }
+
+        res
+    }
+
+    #[inline]
+    fn local_bounding_volume(&self) -> KDOP<N, K> {
+        KDOP::from_points(self.points())
+    }
+}
@@ -17,6 +17,15 @@ pub use crate::bounding_volume::bounding_sphere_utils::{
 };
 #[doc(inline)]
 pub use crate::bounding_volume::bounding_volume::{BoundingVolume, HasBoundingVolume};
+#[doc(inline)]
+pub use crate::bounding_volume::kdop::KDOP;
+#[cfg(feature = "dim2")]
+pub use crate::bounding_volume::kdop::KDOP8;
+#[cfg(feature = "dim3")]
+pub use crate::bounding_volume::kdop::{KDOP14, KDOP18, KDOP26};
+#[doc(inline)]
+pub use crate::bounding_volume::obb::{local_obb, obb, OBB};
+pub use crate::bounding_volume::obb_utils::{local_point_cloud_obb, point_cloud_obb};
 
 #[doc(hidden)]
 pub mod bounding_volume;
@@ -33,12 +42,17 @@ mod aabb_cuboid;
 mod aabb_heightfield;
 mod aabb_plane;
 mod aabb_polyline;
+mod aabb_round_shape;
+mod aabb_scaled;
 mod aabb_shape;
 mod aabb_support_map;
+#[cfg(feature = "dim3")]
+mod aabb_torus;
 mod aabb_triangle;
 #[cfg(feature = "dim3")]
 mod aabb_trimesh;
 mod aabb_utils;
+mod aabb_voxels;
 
 #[doc(hidden)]
 pub mod bounding_sphere;
@@ -49,20 +63,70 @@ mod bounding_sphere_compound;
 mod bounding_sphere_cone;
 #[cfg(feature = "dim3")]
 mod bounding_sphere_convex;
+mod bounding_sphere_convex_hull_of;
 #[cfg(feature = "dim2")]
 mod bounding_sphere_convex_polygon;
 mod bounding_sphere_cuboid;
 #[cfg(feature = "dim3")]
 mod bounding_sphere_cylinder;
+mod bounding_sphere_ellipsoid;
 mod bounding_sphere_heightfield;
+mod bounding_sphere_minkowski_sum;
 mod bounding_sphere_plane;
 mod bounding_sphere_polyline;
+mod bounding_sphere_round_shape;
+mod bounding_sphere_scaled;
 mod bounding_sphere_segment;
 mod bounding_sphere_shape;
+#[cfg(feature = "dim3")]
+mod bounding_sphere_tetrahedron;
+#[cfg(feature = "dim3")]
+mod bounding_sphere_torus;
 mod bounding_sphere_triangle;
 #[cfg(feature = "dim3")]
 mod bounding_sphere_trimesh;
 mod bounding_sphere_utils;
+mod bounding_sphere_voxels;
+
+#[doc(hidden)]
+pub mod kdop;
+mod kdop_support_map;
+#[cfg(feature = "dim3")]
+mod kdop_trimesh;
+
+#[doc(hidden)]
+pub mod obb;
+mod obb_ball;
+mod obb_capsule;
+mod obb_compound;
+#[cfg(feature = "dim3")]
+mod obb_cone;
+#[cfg(feature = "dim3")]
+mod obb_convex;
+mod obb_convex_hull_of;
+#[cfg(feature = "dim2")]
+mod obb_convex_polygon;
+mod obb_cuboid;
+#[cfg(feature = "dim3")]
+mod obb_cylinder;
+mod obb_ellipsoid;
+mod obb_heightfield;
+mod obb_minkowski_sum;
+mod obb_plane;
+mod obb_polyline;
+mod obb_round_shape;
+mod obb_scaled;
+mod obb_segment;
+mod obb_shape;
+#[cfg(feature = "dim3")]
+mod obb_tetrahedron;
+#[cfg(feature = "dim3")]
+mod obb_torus;
+mod obb_triangle;
+#[cfg(feature = "dim3")]
+mod obb_trimesh;
+mod obb_utils;
+mod obb_voxels;
 
 pub(crate) mod circular_cone;
 mod spatialized_normal_cone;
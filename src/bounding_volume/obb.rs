@@ -0,0 +1,264 @@
+//! Oriented Bounding Box.
+
+use crate::bounding_volume::{self, BoundingVolume, HasBoundingVolume, AABB};
+use crate::math::{Isometry, Point, Translation, Vector, DIM};
+use crate::shape::Cuboid;
+use na::{self, RealField};
+
+// Seems useful to help type inference. See issue #84.
+/// Computes the oriented bounding box of a shape `g` transformed by `m`.
+///
+/// Same as `g.obb(m)`.
+#[inline]
+pub fn obb<N, G>(g: &G, m: &Isometry<N>) -> OBB<N>
+where
+    N: RealField,
+    G: ?Sized + HasBoundingVolume<N, OBB<N>>,
+{
+    g.bounding_volume(m)
+}
+
+// Seems useful to help type inference. See issue #84.
+/// Computes the oriented bounding box of a shape `g`.
+///
+/// Same as `g.local_obb(m)`.
+#[inline]
+pub fn local_obb<N, G>(g: &G) -> OBB<N>
+where
+    N: RealField,
+    G: ?Sized + HasBoundingVolume<N, OBB<N>>,
+{
+    g.local_bounding_volume()
+}
+
+/// An Oriented Bounding Box.
+///
+/// This is a box with arbitrary orientation, i.e., a cuboid with the half-extents
+/// `self.half_extents()` placed at `self.position()`.
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Debug, PartialEq, Copy, Clone)]
+pub struct OBB<N: RealField> {
+    position: Isometry<N>,
+    half_extents: Vector<N>,
+}
+
+impl<N: RealField> OBB<N> {
+    /// Creates a new OBB with the given position and half-extents.
+    #[inline]
+    pub fn new(position: Isometry<N>, half_extents: Vector<N>) -> OBB<N> {
+        assert!(
+            half_extents.iter().all(|e| *e >= N::zero()),
+            "The half-extents of an OBB must be positive."
+        );
+        OBB {
+            position,
+            half_extents,
+        }
+    }
+
+    /// Creates an OBB with the same extents as the given AABB.
+    #[inline]
+    pub fn from_aabb(aabb: &AABB<N>) -> OBB<N> {
+        OBB::new(
+            Isometry::from_parts(Translation::from(aabb.center().coords), na::one()),
+            aabb.half_extents(),
+        )
+    }
+
+    /// The position of the center of this OBB and the orientation of its axes.
+    #[inline]
+    pub fn position(&self) -> &Isometry<N> {
+        &self.position
+    }
+
+    /// The half-extents of this OBB along its local axes.
+    #[inline]
+    pub fn half_extents(&self) -> &Vector<N> {
+        &self.half_extents
+    }
+
+    /// The cuboid with the same extents as this OBB, in the local space of this OBB.
+    #[inline]
+    pub fn cuboid(&self) -> Cuboid<N> {
+        Cuboid::new(self.half_extents)
+    }
+
+    /// The vertices of this OBB.
+    pub fn vertices(&self) -> [Point<N>; 1 << DIM] {
+        let mut res = [Point::origin(); 1 << DIM];
+
+        for (i, vtx) in res.iter_mut().enumerate() {
+            let mut local = self.half_extents;
+
+            for k in 0..DIM {
+                if i & (1 << k) != 0 {
+                    local[k] = -local[k];
+                }
+            }
+
+            *vtx = self.position * Point::from(local);
+        }
+
+        res
+    }
+
+    /// The smallest AABB containing this OBB.
+    #[inline]
+    pub fn aabb(&self) -> AABB<N> {
+        bounding_volume::aabb(&self.cuboid(), &self.position)
+    }
+
+    /// Transforms this OBB by `m`.
+    #[inline]
+    pub fn transform_by(&self, m: &Isometry<N>) -> OBB<N> {
+        OBB::new(m * self.position, self.half_extents)
+    }
+
+    /// Checks if this OBB contains a point expressed in the same coordinate frame as `self.position()`.
+    #[inline]
+    pub fn contains_local_point(&self, point: &Point<N>) -> bool {
+        let local = self.position.inverse_transform_point(point);
+
+        for i in 0..DIM {
+            if local[i].abs() > self.half_extents[i] {
+                return false;
+            }
+        }
+
+        true
+    }
+}
+
+impl<N: RealField> BoundingVolume<N> for OBB<N> {
+    #[inline]
+    fn center(&self) -> Point<N> {
+        Point::from(self.position.translation.vector)
+    }
+
+    // Separating axis test, see "OBBTree: A Hierarchical Structure for Rapid Interference
+    // Detection", Gottschalk et al.
+    fn intersects(&self, other: &OBB<N>) -> bool {
+        let pos12 = self.position.inv_mul(&other.position);
+        let r = pos12.rotation.to_rotation_matrix().into_inner();
+        let t = pos12.translation.vector;
+        let a = &self.half_extents;
+        let b = &other.half_extents;
+        // Add an epsilon to prevent errors when two edges are parallel.
+        let abs_r = r.map(|e| e.abs() + N::default_epsilon());
+
+        // Axes of `self`.
+        for i in 0..DIM {
+            let rb = (0..DIM).fold(N::zero(), |acc, j| acc + b[j] * abs_r[(i, j)]);
+
+            if t[i].abs() > a[i] + rb {
+                return false;
+            }
+        }
+
+        // Axes of `other`.
+        for j in 0..DIM {
+            let ra = (0..DIM).fold(N::zero(), |acc, i| acc + a[i] * abs_r[(i, j)]);
+
+            if t.dot(&r.column(j)).abs() > ra + b[j] {
+                return false;
+            }
+        }
+
+        // Cross products of the axes of `self` with the axes of `other`.
+        #[cfg(feature = "dim3")]
+        for i in 0..3 {
+            let (i1, i2) = ((i + 1) % 3, (i + 2) % 3);
+
+            for j in 0..3 {
+                let (j1, j2) = ((j + 1) % 3, (j + 2) % 3);
+                let ra = a[i1] * abs_r[(i2, j)] + a[i2] * abs_r[(i1, j)];
+                let rb = b[j1] * abs_r[(i, j2)] + b[j2] * abs_r[(i, j1)];
+
+                if (t[i2] * r[(i1, j)] - t[i1] * r[(i2, j)]).abs() > ra + rb {
+                    return false;
+                }
+            }
+        }
+
+        true
+    }
+
+    #[inline]
+    fn contains(&self, other: &OBB<N>) -> bool {
+        other
+            .vertices()
+            .iter()
+            .all(|pt| self.contains_local_point(pt))
+    }
+
+    /// Merges this OBB with another one.
+    ///
+    /// The resulting OBB keeps the orientation of `self`.
+    #[inline]
+    fn merge(&mut self, other: &OBB<N>) {
+        let mut mins = -self.half_extents;
+        let mut maxs = self.half_extents;
+
+        for pt in other.vertices().iter() {
+            let local = self.position.inverse_transform_point(pt).coords;
+            mins = mins.inf(&local);
+            maxs = maxs.sup(&local);
+        }
+
+        let center = (mins + maxs) * na::convert::<_, N>(0.5);
+        self.position.translation.vector += self.position.rotation * center;
+        self.half_extents = (maxs - mins) * na::convert::<_, N>(0.5);
+    }
+
+    /// Merges this OBB with another one.
+    ///
+    /// The resulting OBB keeps the orientation of `self`.
+    #[inline]
+    fn merged(&self, other: &OBB<N>) -> OBB<N> {
+        let mut res = *self;
+
+        res.merge(other);
+
+        res
+    }
+
+    #[inline]
+    fn loosen(&mut self, amount: N) {
+        assert!(
+            amount >= na::zero(),
+            "The loosening margin must be positive."
+        );
+        self.half_extents = self.half_extents.add_scalar(amount);
+    }
+
+    #[inline]
+    fn loosened(&self, amount: N) -> OBB<N> {
+        assert!(
+            amount >= na::zero(),
+            "The loosening margin must be positive."
+        );
+        OBB::new(self.position, self.half_extents.add_scalar(amount))
+    }
+
+    #[inline]
+    fn tighten(&mut self, amount: N) {
+        assert!(
+            amount >= na::zero(),
+            "The tightening margin must be positive."
+        );
+        self.half_extents = self.half_extents.add_scalar(-amount);
+        assert!(
+            self.half_extents.iter().all(|e| *e >= N::zero()),
+            "The tightening margin is to large."
+        );
+    }
+
+    #[inline]
+    fn tightened(&self, amount: N) -> OBB<N> {
+        let mut res = *self;
+
+        res.tighten(amount);
+
+        res
+    }
+}
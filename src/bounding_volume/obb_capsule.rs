@@ -0,0 +1,20 @@
+use crate::bounding_volume::{HasBoundingVolume, OBB};
+use crate::math::{Isometry, Vector};
+use crate::shape::Capsule;
+use na::RealField;
+
+impl<N: RealField> HasBoundingVolume<N, OBB<N>> for Capsule<N> {
+    #[inline]
+    fn bounding_volume(&self, m: &Isometry<N>) -> OBB<N> {
+        let bv: OBB<N> = self.local_bounding_volume();
+        bv.transform_by(m)
+    }
+
+    #[inline]
+    fn local_bounding_volume(&self) -> OBB<N> {
+        let mut half_extents = Vector::repeat(self.radius);
+        half_extents[1] += self.half_height;
+
+        OBB::new(Isometry::identity(), half_extents)
+    }
+}
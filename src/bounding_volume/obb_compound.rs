@@ -0,0 +1,23 @@
+use crate::bounding_volume::{self, HasBoundingVolume, OBB};
+use crate::math::Isometry;
+use crate::shape::Compound;
+use na::RealField;
+
+impl<N: RealField> HasBoundingVolume<N, OBB<N>> for Compound<N> {
+    #[inline]
+    fn bounding_volume(&self, m: &Isometry<N>) -> OBB<N> {
+        let bv: OBB<N> = self.local_bounding_volume();
+        bv.transform_by(m)
+    }
+
+    #[inline]
+    fn local_bounding_volume(&self) -> OBB<N> {
+        let mut pts = Vec::new();
+
+        for (pos, shape) in self.shapes() {
+            pts.extend_from_slice(&shape.obb(pos).vertices()[..]);
+        }
+
+        bounding_volume::local_point_cloud_obb(&pts)
+    }
+}
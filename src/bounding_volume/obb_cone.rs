@@ -0,0 +1,18 @@
+use crate::bounding_volume::{HasBoundingVolume, OBB};
+use crate::math::{Isometry, Vector};
+use crate::shape::Cone;
+use na::RealField;
+
+impl<N: RealField> HasBoundingVolume<N, OBB<N>> for Cone<N> {
+    #[inline]
+    fn bounding_volume(&self, m: &Isometry<N>) -> OBB<N> {
+        let bv: OBB<N> = self.local_bounding_volume();
+        bv.transform_by(m)
+    }
+
+    #[inline]
+    fn local_bounding_volume(&self) -> OBB<N> {
+        let half_extents = Vector::new(self.radius, self.half_height, self.radius);
+        OBB::new(Isometry::identity(), half_extents)
+    }
+}
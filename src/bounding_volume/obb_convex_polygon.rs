@@ -0,0 +1,17 @@
+use crate::bounding_volume::{self, HasBoundingVolume, OBB};
+use crate::math::Isometry;
+use crate::shape::ConvexPolygon;
+use na::RealField;
+
+impl<N: RealField> HasBoundingVolume<N, OBB<N>> for ConvexPolygon<N> {
+    #[inline]
+    fn bounding_volume(&self, m: &Isometry<N>) -> OBB<N> {
+        let bv: OBB<N> = self.local_bounding_volume();
+        bv.transform_by(m)
+    }
+
+    #[inline]
+    fn local_bounding_volume(&self) -> OBB<N> {
+        bounding_volume::local_point_cloud_obb(self.points())
+    }
+}
@@ -0,0 +1,19 @@
+use crate::bounding_volume::{self, HasBoundingVolume, OBB};
+use crate::math::Isometry;
+use crate::shape::{MinkowskiSum, SupportMap};
+use na::RealField;
+
+impl<N: RealField, A: SupportMap<N>, B: SupportMap<N>> HasBoundingVolume<N, OBB<N>>
+    for MinkowskiSum<N, A, B>
+{
+    #[inline]
+    fn bounding_volume(&self, m: &Isometry<N>) -> OBB<N> {
+        let bv: OBB<N> = self.local_bounding_volume();
+        bv.transform_by(m)
+    }
+
+    #[inline]
+    fn local_bounding_volume(&self) -> OBB<N> {
+        OBB::from_aabb(&bounding_volume::local_support_map_aabb(self))
+    }
+}
@@ -0,0 +1,16 @@
+use crate::bounding_volume::{BoundingVolume, HasBoundingVolume, OBB};
+use crate::math::Isometry;
+use crate::shape::{RoundShape, Shape};
+use na::RealField;
+
+impl<N: RealField, S: Shape<N>> HasBoundingVolume<N, OBB<N>> for RoundShape<N, S> {
+    #[inline]
+    fn bounding_volume(&self, m: &Isometry<N>) -> OBB<N> {
+        self.inner_shape.obb(m).loosened(self.border_radius)
+    }
+
+    #[inline]
+    fn local_bounding_volume(&self) -> OBB<N> {
+        self.inner_shape.local_obb().loosened(self.border_radius)
+    }
+}
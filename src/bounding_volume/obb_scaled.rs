@@ -0,0 +1,18 @@
+use crate::bounding_volume::{HasBoundingVolume, AABB, OBB};
+use crate::math::Isometry;
+use crate::shape::{Scaled, Shape};
+use na::RealField;
+
+impl<N: RealField, S: Shape<N>> HasBoundingVolume<N, OBB<N>> for Scaled<N, S> {
+    #[inline]
+    fn bounding_volume(&self, m: &Isometry<N>) -> OBB<N> {
+        let bv: OBB<N> = self.local_bounding_volume();
+        bv.transform_by(m)
+    }
+
+    #[inline]
+    fn local_bounding_volume(&self) -> OBB<N> {
+        let aabb: AABB<N> = self.local_bounding_volume();
+        OBB::from_aabb(&aabb)
+    }
+}
@@ -0,0 +1,16 @@
+use crate::bounding_volume::{HasBoundingVolume, OBB};
+use crate::math::Isometry;
+use crate::shape::Shape;
+use na::RealField;
+
+impl<N: RealField> HasBoundingVolume<N, OBB<N>> for dyn Shape<N> {
+    #[inline]
+    fn bounding_volume(&self, m: &Isometry<N>) -> OBB<N> {
+        self.obb(m)
+    }
+
+    #[inline]
+    fn local_bounding_volume(&self) -> OBB<N> {
+        self.local_obb()
+    }
+}
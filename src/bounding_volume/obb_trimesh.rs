@@ -0,0 +1,21 @@
+use crate::bounding_volume::{self, HasBoundingVolume, OBB};
+use crate::math::Isometry;
+use crate::shape::TriMesh;
+use crate::transformation;
+use na::RealField;
+
+impl<N: RealField> HasBoundingVolume<N, OBB<N>> for TriMesh<N> {
+    #[inline]
+    fn bounding_volume(&self, m: &Isometry<N>) -> OBB<N> {
+        let bv: OBB<N> = self.local_bounding_volume();
+        bv.transform_by(m)
+    }
+
+    #[inline]
+    fn local_bounding_volume(&self) -> OBB<N> {
+        // The points of the convex hull are used so that the OBB does not depend on the
+        // density of the mesh vertices.
+        let hull = transformation::convex_hull(self.points());
+        bounding_volume::local_point_cloud_obb(&hull.coords)
+    }
+}
@@ -0,0 +1,68 @@
+use crate::bounding_volume::OBB;
+use crate::math::{Isometry, Matrix, Point, Rotation, Translation, Vector, DIM};
+use crate::utils;
+use na::{self, RealField};
+
+/// Computes the principal axes of a set of points, i.e., the eigenvectors of its covariance matrix.
+fn principal_axes<N: RealField>(pts: &[Point<N>], center: &Point<N>) -> Rotation<N> {
+    #[cfg(feature = "improved_fixed_point_support")]
+    {
+        let _ = (pts, center);
+        Rotation::identity()
+    }
+
+    #[cfg(not(feature = "improved_fixed_point_support"))]
+    {
+        let mut cov: Matrix<N> = na::zero();
+
+        for pt in pts {
+            let dpt = pt - center;
+            cov += dpt * dpt.transpose();
+        }
+
+        let mut axes = cov.symmetric_eigen().eigenvectors;
+
+        // Make sure the axes form a right-handed basis.
+        if axes.determinant() < N::zero() {
+            axes.column_mut(DIM - 1).neg_mut();
+        }
+
+        Rotation::from_rotation_matrix(&na::Rotation::<N, DIM>::from_matrix_unchecked(axes))
+    }
+}
+
+/// Computes an OBB of a set of points transformed by `m`.
+///
+/// The axes of the OBB are the principal axes of the point cloud.
+pub fn point_cloud_obb<N: RealField>(m: &Isometry<N>, pts: &[Point<N>]) -> OBB<N> {
+    local_point_cloud_obb(pts).transform_by(m)
+}
+
+/// Computes an OBB of a set of points.
+///
+/// The axes of the OBB are the principal axes of the point cloud.
+pub fn local_point_cloud_obb<N: RealField>(pts: &[Point<N>]) -> OBB<N> {
+    assert!(
+        !pts.is_empty(),
+        "Point cloud OBB construction: the input should contain at least one point."
+    );
+
+    let center = utils::center(pts);
+    let rot = principal_axes(pts, &center);
+    let mut mins = Vector::repeat(N::max_value());
+    let mut maxs = Vector::repeat(-N::max_value());
+
+    for pt in pts {
+        let local = rot.inverse_transform_vector(&(pt - center));
+        mins = mins.inf(&local);
+        maxs = maxs.sup(&local);
+    }
+
+    let local_center = (mins + maxs) * na::convert::<_, N>(0.5);
+    let translation = Translation::from(center.coords + rot * local_center);
+
+    OBB::new(
+        Isometry::from_parts(translation, rot),
+        (maxs - mins) * na::convert::<_, N>(0.5),
+    )
+}
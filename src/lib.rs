@@ -82,6 +82,7 @@ pub use crate::pipeline::{broad_phase, narrow_phase, world};
 
 pub mod bounding_volume;
 pub mod interpolation;
+pub mod mass_properties;
 pub mod partitioning;
 pub mod pipeline;
 pub mod procedural;
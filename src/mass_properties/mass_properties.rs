@@ -0,0 +1,135 @@
+use crate::math::{Isometry, Point, Vector};
+#[cfg(feature = "dim3")]
+use na::Matrix3;
+use na::{self, RealField};
+use std::iter::Sum;
+use std::ops::{Add, AddAssign};
+
+/// The angular inertia of a rigid body.
+///
+/// This is a scalar in 2D and a symmetric 3x3 matrix (the inertia tensor) in 3D.
+#[cfg(feature = "dim2")]
+pub type AngularInertia<N> = N;
+
+/// The angular inertia of a rigid body.
+///
+/// This is a scalar in 2D and a symmetric 3x3 matrix (the inertia tensor) in 3D.
+#[cfg(feature = "dim3")]
+pub type AngularInertia<N> = Matrix3<N>;
+
+/// The mass, center of mass, and angular inertia of a solid with uniform density.
+///
+/// The mass of a shape with a density equal to one is its volume (or its area in 2D).
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(PartialEq, Debug, Copy, Clone)]
+pub struct MassProperties<N: RealField> {
+    /// The center of mass, expressed in the local-space of the shape.
+    pub local_com: Point<N>,
+    /// The mass.
+    pub mass: N,
+    /// The angular inertia, relative to the center of mass and expressed in the local-space of the shape.
+    pub angular_inertia: AngularInertia<N>,
+}
+
+impl<N: RealField> MassProperties<N> {
+    /// Creates new mass properties from their components.
+    #[inline]
+    pub fn new(local_com: Point<N>, mass: N, angular_inertia: AngularInertia<N>) -> Self {
+        MassProperties {
+            local_com,
+            mass,
+            angular_inertia,
+        }
+    }
+
+    /// Mass properties with a zero mass, centered at the origin.
+    #[inline]
+    pub fn zero() -> Self {
+        Self::new(Point::origin(), N::zero(), na::zero())
+    }
+
+    /// The inverse of the mass, or zero if the mass is zero.
+    #[inline]
+    pub fn inv_mass(&self) -> N {
+        if self.mass.is_zero() {
+            N::zero()
+        } else {
+            N::one() / self.mass
+        }
+    }
+
+    /// The angular inertia of `self` relative to the point `pt` instead of the center of mass.
+    ///
+    /// This applies the parallel axis theorem. Both `pt` and the result are expressed in the
+    /// local-space of the shape.
+    #[inline]
+    pub fn angular_inertia_wrt_point(&self, pt: &Point<N>) -> AngularInertia<N> {
+        self.angular_inertia + shift_angular_inertia(self.mass, &(self.local_com - *pt))
+    }
+
+    /// The mass properties of `self` after it is moved by the isometry `m`.
+    #[cfg(feature = "dim2")]
+    #[inline]
+    pub fn transform_by(&self, m: &Isometry<N>) -> Self {
+        Self::new(m * self.local_com, self.mass, self.angular_inertia)
+    }
+
+    /// The mass properties of `self` after it is moved by the isometry `m`.
+    #[cfg(feature = "dim3")]
+    #[inline]
+    pub fn transform_by(&self, m: &Isometry<N>) -> Self {
+        let rot = m.rotation.to_rotation_matrix().into_inner();
+        let angular_inertia = rot * self.angular_inertia * rot.transpose();
+        Self::new(m * self.local_com, self.mass, angular_inertia)
+    }
+}
+
+/// The angular inertia that must be added to the one of a solid of mass `mass` when its
+/// reference point is moved by `shift`.
+#[cfg(feature = "dim2")]
+#[inline]
+pub(crate) fn shift_angular_inertia<N: RealField>(mass: N, shift: &Vector<N>) -> AngularInertia<N> {
+    shift.norm_squared() * mass
+}
+
+/// The angular inertia that must be added to the one of a solid of mass `mass` when its
+/// reference point is moved by `shift`.
+#[cfg(feature = "dim3")]
+#[inline]
+pub(crate) fn shift_angular_inertia<N: RealField>(mass: N, shift: &Vector<N>) -> AngularInertia<N> {
+    let diag = Matrix3::from_diagonal_element(shift.norm_squared());
+    (diag - shift * shift.transpose()) * mass
+}
+
+impl<N: RealField> Add for MassProperties<N> {
+    type Output = Self;
+
+    fn add(self, other: Self) -> Self {
+        let mass = self.mass + other.mass;
+
+        if mass.is_zero() {
+            return Self::zero();
+        }
+
+        let local_com = Point::from(
+            (self.local_com.coords * self.mass + other.local_com.coords * other.mass) / mass,
+        );
+        let angular_inertia = self.angular_inertia_wrt_point(&local_com)
+            + other.angular_inertia_wrt_point(&local_com);
+
+        Self::new(local_com, mass, angular_inertia)
+    }
+}
+
+impl<N: RealField> AddAssign for MassProperties<N> {
+    #[inline]
+    fn add_assign(&mut self, other: Self) {
+        *self = *self + other
+    }
+}
+
+impl<N: RealField> Sum for MassProperties<N> {
+    fn sum<I: Iterator<Item = Self>>(iter: I) -> Self {
+        iter.fold(Self::zero(), |acc, mprops| acc + mprops)
+    }
+}
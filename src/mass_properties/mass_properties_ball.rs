@@ -0,0 +1,31 @@
+use crate::mass_properties::MassProperties;
+use crate::math::Point;
+#[cfg(feature = "dim3")]
+use na::Matrix3;
+use na::{self, RealField};
+
+impl<N: RealField> MassProperties<N> {
+    /// The mass properties of a ball with the given density and radius.
+    #[cfg(feature = "dim2")]
+    pub fn from_ball(density: N, radius: N) -> Self {
+        let radius2 = radius * radius;
+        let mass = N::pi() * radius2 * density;
+        let angular_inertia = mass * radius2 * na::convert(0.5);
+
+        Self::new(Point::origin(), mass, angular_inertia)
+    }
+
+    /// The mass properties of a ball with the given density and radius.
+    #[cfg(feature = "dim3")]
+    pub fn from_ball(density: N, radius: N) -> Self {
+        let radius2 = radius * radius;
+        let mass = N::pi() * radius2 * radius * density * na::convert(4.0 / 3.0);
+        let angular_inertia = mass * radius2 * na::convert(2.0 / 5.0);
+
+        Self::new(
+            Point::origin(),
+            mass,
+            Matrix3::from_diagonal_element(angular_inertia),
+        )
+    }
+}
@@ -0,0 +1,58 @@
+use crate::mass_properties::MassProperties;
+use crate::math::Point;
+use na::{self, RealField};
+#[cfg(feature = "dim3")]
+use na::{Matrix3, Vector3};
+
+// The capsule is split into a cylinder (a rectangle in 2D) and a ball whose two halves are
+// moved away from the origin along the `y` axis. Each half-ball has its center of mass at a
+// distance `c` of its flat side, hence the `2 * half_height * c` terms below.
+impl<N: RealField> MassProperties<N> {
+    /// The mass properties of a capsule aligned with the `y` axis with the given density.
+    #[cfg(feature = "dim2")]
+    pub fn from_capsule(density: N, half_height: N, radius: N) -> Self {
+        let _2: N = na::convert(2.0);
+        let r2 = radius * radius;
+        let h2 = half_height * half_height;
+
+        let rect_mass = radius * half_height * density * na::convert(4.0);
+        let rect_inertia = rect_mass * (r2 + h2) / na::convert(3.0);
+
+        let ball_mass = N::pi() * r2 * density;
+        let c = radius * na::convert(4.0) / (N::pi() * na::convert(3.0));
+        let ball_inertia = ball_mass * (r2 / _2 + h2 + _2 * half_height * c);
+
+        Self::new(
+            Point::origin(),
+            rect_mass + ball_mass,
+            rect_inertia + ball_inertia,
+        )
+    }
+
+    /// The mass properties of a capsule aligned with the `y` axis with the given density.
+    #[cfg(feature = "dim3")]
+    pub fn from_capsule(density: N, half_height: N, radius: N) -> Self {
+        let _2: N = na::convert(2.0);
+        let r2 = radius * radius;
+        let h2 = half_height * half_height;
+
+        let cyl_mass = N::pi() * r2 * half_height * _2 * density;
+        let cyl_inertia_y = cyl_mass * r2 / _2;
+        let cyl_inertia_xz =
+            cyl_mass * (r2 * na::convert(3.0) + h2 * na::convert(4.0)) / na::convert(12.0);
+
+        let ball_mass = N::pi() * r2 * radius * density * na::convert(4.0 / 3.0);
+        let c = radius * na::convert(3.0 / 8.0);
+        let ball_inertia_y = ball_mass * r2 * na::convert(2.0 / 5.0);
+        let ball_inertia_xz = ball_mass * (r2 * na::convert(2.0 / 5.0) + h2 + _2 * half_height * c);
+
+        let inertia_xz = cyl_inertia_xz + ball_inertia_xz;
+        let diag = Vector3::new(inertia_xz, cyl_inertia_y + ball_inertia_y, inertia_xz);
+
+        Self::new(
+            Point::origin(),
+            cyl_mass + ball_mass,
+            Matrix3::from_diagonal(&diag),
+        )
+    }
+}
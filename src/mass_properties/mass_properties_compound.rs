@@ -0,0 +1,19 @@
+use crate::mass_properties::MassProperties;
+use crate::math::Isometry;
+use crate::shape::ShapeHandle;
+use na::RealField;
+
+impl<N: RealField> MassProperties<N> {
+    /// The mass properties of the union of the given shapes, all with the same density.
+    ///
+    /// The angular inertia of each shape is moved to the common center of mass using the
+    /// parallel axis theorem. Returns `None` if the mass properties of one of the shapes
+    /// cannot be computed. Overlapping shapes are not handled specifically, so their
+    /// intersection is counted several times.
+    pub fn from_compound(density: N, shapes: &[(Isometry<N>, ShapeHandle<N>)]) -> Option<Self> {
+        shapes
+            .iter()
+            .map(|(m, shape)| Some(shape.mass_properties(density)?.transform_by(m)))
+            .sum()
+    }
+}
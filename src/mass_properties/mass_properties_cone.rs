@@ -0,0 +1,22 @@
+use crate::mass_properties::MassProperties;
+use crate::math::Point;
+use na::{self, Matrix3, RealField, Vector3};
+
+impl<N: RealField> MassProperties<N> {
+    /// The mass properties of a cone aligned with the `y` axis with the given density.
+    ///
+    /// The apex of the cone is at `(0, half_height, 0)`, so its center of mass is at
+    /// `(0, -half_height / 2, 0)`.
+    pub fn from_cone(density: N, half_height: N, radius: N) -> Self {
+        let r2 = radius * radius;
+        let h2 = half_height * half_height;
+        let mass = N::pi() * r2 * half_height * density * na::convert(2.0 / 3.0);
+
+        let inertia_y = mass * r2 * na::convert(3.0 / 10.0);
+        let inertia_xz = mass * (r2 + h2) * na::convert(3.0 / 20.0);
+        let diag = Vector3::new(inertia_xz, inertia_y, inertia_xz);
+        let local_com = Point::new(N::zero(), -half_height * na::convert(0.5), N::zero());
+
+        Self::new(local_com, mass, Matrix3::from_diagonal(&diag))
+    }
+}
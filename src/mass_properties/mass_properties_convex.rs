@@ -0,0 +1,11 @@
+use super::mass_properties_trimesh::tetrahedra_mass_properties;
+use crate::mass_properties::MassProperties;
+use crate::shape::ConvexHull;
+use na::RealField;
+
+impl<N: RealField> MassProperties<N> {
+    /// The mass properties of a convex polyhedron with the given density.
+    pub fn from_convex_hull(density: N, convex: &ConvexHull<N>) -> Self {
+        tetrahedra_mass_properties(density, convex.points(), convex.triangulated_faces(), true)
+    }
+}
@@ -0,0 +1,42 @@
+use crate::mass_properties::MassProperties;
+use crate::math::{Point, Vector};
+use crate::utils;
+use na::{self, RealField};
+
+impl<N: RealField> MassProperties<N> {
+    /// The mass properties of the convex polygon with the given density and vertices.
+    ///
+    /// The polygon is decomposed into triangles joining each of its edges to its center. The
+    /// vertices may be given in clockwise or counterclockwise order.
+    pub fn from_convex_polygon(density: N, points: &[Point<N>]) -> Self {
+        if points.is_empty() {
+            return Self::zero();
+        }
+
+        let origin = utils::center(points);
+        let mut area = N::zero();
+        let mut com = Vector::zeros();
+        // The polar second moment of area ∫ |x|² dA, relative to `origin`.
+        let mut polar_moment = N::zero();
+
+        for i in 0..points.len() {
+            let a = points[i] - origin;
+            let b = points[(i + 1) % points.len()] - origin;
+            let tri_area = a.perp(&b).abs() * na::convert(0.5);
+
+            area += tri_area;
+            com += (a + b) * (tri_area / na::convert(3.0));
+            polar_moment +=
+                (a.norm_squared() + b.norm_squared() + a.dot(&b)) * tri_area / na::convert(6.0);
+        }
+
+        if area.is_zero() {
+            return Self::zero();
+        }
+
+        let com = com / area;
+        let angular_inertia = (polar_moment - com.norm_squared() * area) * density;
+
+        Self::new(origin + com, area * density, angular_inertia)
+    }
+}
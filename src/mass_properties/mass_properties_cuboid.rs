@@ -0,0 +1,28 @@
+use crate::mass_properties::MassProperties;
+use crate::math::{Point, Vector};
+#[cfg(feature = "dim3")]
+use na::Matrix3;
+use na::{self, RealField};
+
+impl<N: RealField> MassProperties<N> {
+    /// The mass properties of a cuboid with the given density and half-extents.
+    #[cfg(feature = "dim2")]
+    pub fn from_cuboid(density: N, half_extents: &Vector<N>) -> Self {
+        let extents = half_extents * na::convert::<_, N>(2.0);
+        let mass = extents.x * extents.y * density;
+        let angular_inertia = mass * extents.norm_squared() / na::convert(12.0);
+
+        Self::new(Point::origin(), mass, angular_inertia)
+    }
+
+    /// The mass properties of a cuboid with the given density and half-extents.
+    #[cfg(feature = "dim3")]
+    pub fn from_cuboid(density: N, half_extents: &Vector<N>) -> Self {
+        let extents = half_extents * na::convert::<_, N>(2.0);
+        let mass = extents.x * extents.y * extents.z * density;
+        let sq = extents.component_mul(&extents);
+        let diag = Vector::new(sq.y + sq.z, sq.x + sq.z, sq.x + sq.y) * (mass / na::convert(12.0));
+
+        Self::new(Point::origin(), mass, Matrix3::from_diagonal(&diag))
+    }
+}
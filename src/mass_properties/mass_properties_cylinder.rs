@@ -0,0 +1,18 @@
+use crate::mass_properties::MassProperties;
+use crate::math::Point;
+use na::{self, Matrix3, RealField, Vector3};
+
+impl<N: RealField> MassProperties<N> {
+    /// The mass properties of a cylinder aligned with the `y` axis with the given density.
+    pub fn from_cylinder(density: N, half_height: N, radius: N) -> Self {
+        let r2 = radius * radius;
+        let h2 = half_height * half_height;
+        let mass = N::pi() * r2 * half_height * density * na::convert(2.0);
+
+        let inertia_y = mass * r2 * na::convert(0.5);
+        let inertia_xz = mass * (r2 * na::convert(3.0) + h2 * na::convert(4.0)) / na::convert(12.0);
+        let diag = Vector3::new(inertia_xz, inertia_y, inertia_xz);
+
+        Self::new(Point::origin(), mass, Matrix3::from_diagonal(&diag))
+    }
+}
@@ -0,0 +1,99 @@
+use crate::mass_properties::MassProperties;
+use crate::math::{Point, Vector};
+use crate::shape::TriMesh;
+use crate::utils::{self, DeterministicState};
+use na::{self, Matrix3, Point3, RealField};
+use std::collections::HashMap;
+
+impl<N: RealField> MassProperties<N> {
+    /// The mass properties of the solid bounded by the closed triangle mesh `mesh`.
+    ///
+    /// The mesh is decomposed into signed tetrahedra joining each triangle to a common point, so
+    /// its triangles must be consistently oriented. Both inward and outward orientations are
+    /// supported. Returns `None` if the mesh is not a closed 2-manifold, i.e., if one of its
+    /// edges is not shared by exactly two triangles.
+    pub fn from_trimesh(density: N, mesh: &TriMesh<N>) -> Option<Self> {
+        // The edges of the mesh only keep track of two adjacent faces so the faces sharing
+        // each edge are counted here.
+        let mut num_adj_faces = HashMap::with_hasher(DeterministicState::new());
+
+        for face in mesh.faces() {
+            let idx = face.indices;
+
+            for (a, b) in [(idx.x, idx.y), (idx.y, idx.z), (idx.z, idx.x)].iter() {
+                *num_adj_faces.entry((*a.min(b), *a.max(b))).or_insert(0) += 1;
+            }
+        }
+
+        if num_adj_faces.values().any(|n| *n != 2) {
+            return None;
+        }
+
+        let triangles = mesh.faces().iter().map(|f| f.indices);
+        Some(tetrahedra_mass_properties(
+            density,
+            mesh.points(),
+            triangles,
+            false,
+        ))
+    }
+}
+
+/// Computes the mass properties of the solid bounded by the given triangles.
+///
+/// Each triangle forms a tetrahedron with the center of `points`. If `convex` is `true`, the
+/// orientation of the triangles is ignored and all the tetrahedra are given a positive volume.
+pub(crate) fn tetrahedra_mass_properties<N: RealField>(
+    density: N,
+    points: &[Point<N>],
+    triangles: impl Iterator<Item = Point3<usize>>,
+    convex: bool,
+) -> MassProperties<N> {
+    if points.is_empty() {
+        return MassProperties::zero();
+    }
+
+    let origin = utils::center(points);
+    let mut volume = N::zero();
+    let mut com = Vector::zeros();
+    // The second moment of volume ∫ x xᵀ dV, relative to `origin`.
+    let mut covariance = Matrix3::zeros();
+
+    for t in triangles {
+        let a = points[t.x] - origin;
+        let b = points[t.y] - origin;
+        let c = points[t.z] - origin;
+
+        let mut det = a.dot(&b.cross(&c));
+
+        if convex {
+            det = det.abs();
+        }
+
+        let sum = a + b + c;
+        volume += det;
+        com += sum * det;
+        covariance +=
+            (a * a.transpose() + b * b.transpose() + c * c.transpose() + sum * sum.transpose())
+                * det;
+    }
+
+    // Fix the orientation of inward-oriented meshes.
+    if volume.is_negative() {
+        volume = -volume;
+        com = -com;
+        covariance = -covariance;
+    }
+
+    if volume.is_zero() {
+        return MassProperties::zero();
+    }
+
+    let com = com / (volume * na::convert(4.0));
+    let volume = volume / na::convert(6.0);
+    let covariance = covariance / na::convert::<_, N>(120.0) - com * com.transpose() * volume;
+    let angular_inertia =
+        (Matrix3::from_diagonal_element(covariance.trace()) - covariance) * density;
+
+    MassProperties::new(origin + com, volume * density, angular_inertia)
+}
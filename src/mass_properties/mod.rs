@@ -0,0 +1,21 @@
+//! Mass properties (mass, center of mass and angular inertia) of shapes.
+
+#[doc(inline)]
+pub use self::mass_properties::{AngularInertia, MassProperties};
+
+#[doc(hidden)]
+pub mod mass_properties;
+mod mass_properties_ball;
+mod mass_properties_capsule;
+mod mass_properties_compound;
+#[cfg(feature = "dim3")]
+mod mass_properties_cone;
+#[cfg(feature = "dim3")]
+mod mass_properties_convex;
+#[cfg(feature = "dim2")]
+mod mass_properties_convex_polygon;
+mod mass_properties_cuboid;
+#[cfg(feature = "dim3")]
+mod mass_properties_cylinder;
+#[cfg(feature = "dim3")]
+mod mass_properties_trimesh;
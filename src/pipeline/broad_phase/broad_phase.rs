@@ -83,4 +83,20 @@ pub trait BroadPhase<N: RealField, BV, T>: Any + Sync + Send {
         max_toi: N,
         cost_fn: &'a dyn Fn(T, &'b Ray<N>, N) -> Option<(T, RayIntersection<N>)>,
     ) -> Option<(T, RayIntersection<N>)>;
+
+    /// Pushes to `out` the first interference of each ray of `rays`, in the same order.
+    ///
+    /// The default implementation calls `first_interference_with_ray` for each ray.
+    fn first_interferences_with_rays(
+        &self,
+        rays: &[Ray<N>],
+        max_toi: N,
+        cost_fn: &dyn Fn(T, &Ray<N>, N) -> Option<(T, RayIntersection<N>)>,
+        out: &mut Vec<Option<(T, RayIntersection<N>)>>,
+    ) {
+        out.extend(
+            rays.iter()
+                .map(|ray| self.first_interference_with_ray(ray, max_toi, cost_fn)),
+        )
+    }
 }
@@ -1,6 +1,6 @@
 use crate::bounding_volume::BoundingVolume;
-use crate::math::Point;
-use crate::partitioning::{DBVTLeaf, DBVTLeafId, BVH, DBVT};
+use crate::math::{Isometry, Point};
+use crate::partitioning::{DBVTLeaf, DBVTLeafId, DBVTNodeId, BVH, DBVT};
 use crate::pipeline::broad_phase::{
     BroadPhase, BroadPhaseInterferenceHandler, BroadPhaseProxyHandle,
 };
@@ -49,6 +49,8 @@ impl<T> DBVTBroadPhaseProxy<T> {
 }
 
 const DEACTIVATION_THRESHOLD: usize = 100;
+// Number of rays traversing the trees together in `first_interferences_with_rays`.
+const RAY_PACKET_SIZE: usize = 64;
 
 /// Broad phase based on a Dynamic Bounding Volume Tree.
 ///
@@ -165,6 +167,78 @@ where
     }
 }
 
+impl<N, BV, T> DBVTBroadPhase<N, BV, T>
+where
+    N: RealField,
+    BV: BoundingVolume<N> + RayCast<N> + Any + Send + Sync + Clone,
+    T: Any + Send + Sync + Clone,
+{
+    // Depth-first traversal of `tree` by a packet of rays. Each node is only tested against the
+    // rays of its parent that hit the parent before reaching their current best hit.
+    //
+    // `active[start..end]` holds the indices of the rays that must visit a node of the stack.
+    fn cast_ray_packet(
+        &self,
+        tree: &DBVT<N, BroadPhaseProxyHandle, BV>,
+        rays: &[Ray<N>],
+        max_toi: N,
+        cost_fn: &dyn Fn(T, &Ray<N>, N) -> Option<(T, RayIntersection<N>)>,
+        best: &mut [Option<(T, RayIntersection<N>)>],
+        active: &mut Vec<usize>,
+        stack: &mut Vec<(DBVTNodeId, usize, usize)>,
+    ) {
+        let root = match tree.root() {
+            Some(root) => root,
+            None => return,
+        };
+
+        active.clear();
+        active.extend(0..rays.len());
+        stack.clear();
+        stack.push((root, 0, rays.len()));
+
+        while let Some((node, start, end)) = stack.pop() {
+            // The ranges are allocated in depth-first order so everything after `end` is
+            // no longer referenced by the stack.
+            active.truncate(end);
+            let (bv, data) = tree.content(node);
+            let new_start = active.len();
+
+            for k in start..end {
+                let i = active[k];
+                let limit = best[i].as_ref().map(|hit| hit.1.toi).unwrap_or(max_toi);
+
+                if bv
+                    .toi_with_ray(&Isometry::identity(), &rays[i], limit, true)
+                    .is_none()
+                {
+                    continue;
+                }
+
+                if let Some(handle) = data {
+                    let leaf_data = self.proxies[handle.uid()].data.clone();
+
+                    if let Some(hit) = cost_fn(leaf_data, &rays[i], max_toi) {
+                        if best[i].is_none() || hit.1.toi < limit {
+                            best[i] = Some(hit)
+                        }
+                    }
+                } else {
+                    active.push(i)
+                }
+            }
+
+            let new_end = active.len();
+
+            if new_end != new_start {
+                for c in 0..tree.num_children(node) {
+                    stack.push((tree.child(c, node), new_start, new_end))
+                }
+            }
+        }
+    }
+}
+
 impl<N, BV, T> BroadPhase<N, BV, T> for DBVTBroadPhase<N, BV, T>
 where
     N: RealField,
@@ -458,4 +532,40 @@ where
             None
         }
     }
+
+    fn first_interferences_with_rays(
+        &self,
+        rays: &[Ray<N>],
+        max_toi: N,
+        cost_fn: &dyn Fn(T, &Ray<N>, N) -> Option<(T, RayIntersection<N>)>,
+        out: &mut Vec<Option<(T, RayIntersection<N>)>>,
+    ) {
+        let mut active = Vec::new();
+        let mut stack = Vec::new();
+
+        for packet in rays.chunks(RAY_PACKET_SIZE) {
+            let start = out.len();
+            out.extend(packet.iter().map(|_| None));
+            let best = &mut out[start..];
+
+            self.cast_ray_packet(
+                &self.tree,
+                packet,
+                max_toi,
+                cost_fn,
+                best,
+                &mut active,
+                &mut stack,
+            );
+            self.cast_ray_packet(
+                &self.stree,
+                packet,
+                max_toi,
+                cost_fn,
+                best,
+                &mut active,
+                &mut stack,
+            );
+        }
+    }
 }
@@ -0,0 +1,588 @@
+use crate::bounding_volume::{BoundingVolume, AABB};
+use crate::math::{Isometry, Point, DIM};
+use crate::pipeline::broad_phase::{
+    BroadPhase, BroadPhaseInterferenceHandler, BroadPhaseProxyHandle,
+};
+use crate::query::{Ray, RayCast, RayIntersection};
+use crate::utils::{DeterministicState, SortedPair};
+use na::RealField;
+use slab::Slab;
+use std::any::Any;
+use std::collections::{HashMap, HashSet};
+
+type GridCell = [i64; DIM];
+
+// Proxies overlapping more cells than this are not stored on the grid.
+const MAX_CELLS_PER_PROXY: f64 = 512.0;
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+enum ProxyStatus {
+    Detached,
+    // The proxy is registered into all the cells between the two given ones.
+    OnGrid(GridCell, GridCell),
+    // The proxy is too large to be registered into the grid cells.
+    Oversized,
+    Deleted,
+}
+
+struct GridBroadPhaseProxy<N: RealField, T> {
+    aabb: AABB<N>,
+    data: T,
+    status: ProxyStatus,
+    updated: bool,
+}
+
+/// Broad phase based on a uniform grid with hashed cells.
+///
+/// Each proxy is registered into all the grid cells its AABB overlaps. Only proxies sharing
+/// at least one cell are tested for interference. This is very efficient when all the objects
+/// have similar sizes, close to the cell size, e.g., for particle-like scenes. Proxies
+/// overlapping too many cells (like planes) are kept aside and tested against every other proxy.
+pub struct GridBroadPhase<N: RealField, T> {
+    proxies: Slab<GridBroadPhaseProxy<N, T>>,
+    cells: HashMap<GridCell, Vec<usize>, DeterministicState>,
+    oversized: Vec<usize>,
+    // Pairs detected.
+    pairs: HashSet<SortedPair<BroadPhaseProxyHandle>, DeterministicState>,
+    cell_size: N,
+    // The margin added to each bounding volume.
+    margin: N,
+    // AABB enclosing all the proxies stored on the grid.
+    bounds: AABB<N>,
+    purge_all: bool,
+    proxies_to_update: Vec<(BroadPhaseProxyHandle, AABB<N>)>,
+
+    // Just to avoid dynamic allocations.
+    updated: Vec<usize>,
+    collector: Vec<usize>,
+}
+
+impl<N: RealField, T> GridBroadPhase<N, T> {
+    /// Creates a new broad phase based on a grid with the given cell size.
+    ///
+    /// For best performances, the cell size should be of the order of the size of the objects.
+    pub fn new(cell_size: N, margin: N) -> GridBroadPhase<N, T> {
+        assert!(
+            cell_size > N::zero(),
+            "The cell size of a grid broad phase must be positive."
+        );
+
+        GridBroadPhase {
+            proxies: Slab::new(),
+            cells: HashMap::with_hasher(DeterministicState::new()),
+            oversized: Vec::new(),
+            pairs: HashSet::with_hasher(DeterministicState::new()),
+            cell_size,
+            margin,
+            bounds: AABB::new_invalid(),
+            purge_all: false,
+            proxies_to_update: Vec::new(),
+            updated: Vec::new(),
+            collector: Vec::new(),
+        }
+    }
+
+    /// The size of the cells of this grid.
+    #[inline]
+    pub fn cell_size(&self) -> N {
+        self.cell_size
+    }
+
+    /// Number of interferences detected by this broad phase.
+    #[inline]
+    pub fn num_interferences(&self) -> usize {
+        self.pairs.len()
+    }
+
+    fn cell_containing_point(&self, pt: &Point<N>) -> GridCell {
+        let mut cell = [0; DIM];
+
+        for i in 0..DIM {
+            cell[i] = na::convert_unchecked::<N, f64>((pt[i] / self.cell_size).floor()) as i64;
+        }
+
+        cell
+    }
+
+    // Computes the range of cells overlapped by the given AABB, or `None` if it covers too many cells.
+    fn cell_range(&self, aabb: &AABB<N>) -> Option<(GridCell, GridCell)> {
+        let mut num_cells = 1.0;
+
+        for i in 0..DIM {
+            let extent = na::convert_unchecked::<N, f64>(aabb.maxs[i] - aabb.mins[i]);
+            num_cells *= extent / na::convert_unchecked::<N, f64>(self.cell_size) + 1.0;
+        }
+
+        if num_cells > MAX_CELLS_PER_PROXY {
+            None
+        } else {
+            let mins = self.cell_containing_point(&aabb.mins);
+            let maxs = self.cell_containing_point(&aabb.maxs);
+            Some((mins, maxs))
+        }
+    }
+
+    fn detach(&mut self, id: usize) {
+        match self.proxies[id].status {
+            ProxyStatus::OnGrid(mins, maxs) => {
+                let cells = &mut self.cells;
+                foreach_cell(&mins, &maxs, |cell| {
+                    if let Some(content) = cells.get_mut(&cell) {
+                        if let Some(i) = content.iter().position(|e| *e == id) {
+                            let _ = content.swap_remove(i);
+                        }
+
+                        if content.is_empty() {
+                            let _ = cells.remove(&cell);
+                        }
+                    }
+                });
+            }
+            ProxyStatus::Oversized => {
+                if let Some(i) = self.oversized.iter().position(|e| *e == id) {
+                    let _ = self.oversized.swap_remove(i);
+                }
+            }
+            _ => {}
+        }
+
+        self.proxies[id].status = ProxyStatus::Detached;
+    }
+
+    fn attach(&mut self, id: usize) {
+        let status = match self.cell_range(&self.proxies[id].aabb) {
+            Some((mins, maxs)) => {
+                let cells = &mut self.cells;
+                foreach_cell(&mins, &maxs, |cell| cells.entry(cell).or_default().push(id));
+                ProxyStatus::OnGrid(mins, maxs)
+            }
+            None => {
+                self.oversized.push(id);
+                ProxyStatus::Oversized
+            }
+        };
+
+        self.proxies[id].status = status;
+    }
+
+    fn update_bounds(&mut self) {
+        self.bounds = AABB::new_invalid();
+
+        for (_, proxy) in self.proxies.iter() {
+            if let ProxyStatus::OnGrid(..) = proxy.status {
+                self.bounds.merge(&proxy.aabb);
+            }
+        }
+    }
+
+    // Collects the proxies that may intersect the given one.
+    fn collect_neighbors(&mut self, id: usize) {
+        self.collector.clear();
+
+        match self.proxies[id].status {
+            ProxyStatus::OnGrid(mins, maxs) => {
+                let cells = &self.cells;
+                let collector = &mut self.collector;
+                foreach_cell(&mins, &maxs, |cell| {
+                    if let Some(content) = cells.get(&cell) {
+                        collector.extend_from_slice(content)
+                    }
+                });
+                self.collector.extend_from_slice(&self.oversized);
+            }
+            ProxyStatus::Oversized => {
+                for (other, proxy) in self.proxies.iter() {
+                    match proxy.status {
+                        ProxyStatus::OnGrid(..) | ProxyStatus::Oversized => {
+                            self.collector.push(other)
+                        }
+                        _ => {}
+                    }
+                }
+            }
+            _ => {}
+        }
+
+        self.collector.sort_unstable();
+        self.collector.dedup();
+    }
+
+    // Calls `f` on each cell traversed by the ray, in order. The traversal stops if `f` returns `false`.
+    // The second argument of `f` is the time of impact at which the ray leaves the cell.
+    fn traverse_ray(&self, ray: &Ray<N>, max_toi: N, mut f: impl FnMut(&GridCell, N) -> bool) {
+        if self.cells.is_empty() {
+            return;
+        }
+
+        let (min_t, max_t) = try_ret!(self.bounds.clip_ray_parameters(ray));
+
+        if min_t > max_toi {
+            return;
+        }
+
+        let max_t = max_t.min(max_toi);
+        let mut cell = self.cell_containing_point(&ray.point_at(min_t));
+        let mut step = [0; DIM];
+        let mut next_toi = [N::max_value(); DIM];
+        let mut delta_toi = [N::max_value(); DIM];
+
+        // 3D-DDA initialization.
+        for i in 0..DIM {
+            if ray.dir[i] > N::zero() {
+                let boundary = na::convert::<f64, N>((cell[i] + 1) as f64) * self.cell_size;
+                step[i] = 1;
+                next_toi[i] = (boundary - ray.origin[i]) / ray.dir[i];
+                delta_toi[i] = self.cell_size / ray.dir[i];
+            } else if ray.dir[i] < N::zero() {
+                let boundary = na::convert::<f64, N>(cell[i] as f64) * self.cell_size;
+                step[i] = -1;
+                next_toi[i] = (boundary - ray.origin[i]) / ray.dir[i];
+                delta_toi[i] = -self.cell_size / ray.dir[i];
+            }
+        }
+
+        loop {
+            let mut axis = 0;
+
+            for i in 1..DIM {
+                if next_toi[i] < next_toi[axis] {
+                    axis = i;
+                }
+            }
+
+            if !f(&cell, next_toi[axis]) || next_toi[axis] > max_t {
+                return;
+            }
+
+            cell[axis] += step[axis];
+            next_toi[axis] += delta_toi[axis];
+        }
+    }
+}
+
+fn foreach_cell(mins: &GridCell, maxs: &GridCell, mut f: impl FnMut(GridCell)) {
+    let mut cell = *mins;
+
+    loop {
+        f(cell);
+
+        let mut i = 0;
+
+        loop {
+            if i == DIM {
+                return;
+            }
+
+            if cell[i] < maxs[i] {
+                cell[i] += 1;
+                break;
+            }
+
+            cell[i] = mins[i];
+            i += 1;
+        }
+    }
+}
+
+impl<N, T> BroadPhase<N, AABB<N>, T> for GridBroadPhase<N, T>
+where
+    N: RealField,
+    T: Any + Send + Sync + Clone,
+{
+    fn update(&mut self, handler: &mut dyn BroadPhaseInterferenceHandler<T>) {
+        /*
+         * Move the updated proxies to their new cells.
+         */
+        let mut proxies_to_update = std::mem::take(&mut self.proxies_to_update);
+
+        for (handle, aabb) in proxies_to_update.drain(..) {
+            if self.proxies.contains(handle.uid()) {
+                self.detach(handle.uid());
+                self.proxies[handle.uid()].aabb = aabb;
+                self.attach(handle.uid());
+
+                if !self.proxies[handle.uid()].updated {
+                    self.proxies[handle.uid()].updated = true;
+                    self.updated.push(handle.uid());
+                }
+            }
+        }
+
+        self.proxies_to_update = proxies_to_update;
+
+        if self.updated.is_empty() {
+            return;
+        }
+
+        self.update_bounds();
+
+        /*
+         * Find the new interferences.
+         */
+        for i in 0..self.updated.len() {
+            let id1 = self.updated[i];
+            self.collect_neighbors(id1);
+
+            let proxy1 = &self.proxies[id1];
+            for id2 in self.collector.iter().cloned() {
+                if id2 == id1 {
+                    continue;
+                }
+
+                let proxy2 = &self.proxies[id2];
+                let pair = SortedPair::new(BroadPhaseProxyHandle(id1), BroadPhaseProxyHandle(id2));
+
+                if !self.pairs.contains(&pair)
+                    && proxy1.aabb.intersects(&proxy2.aabb)
+                    && handler.is_interference_allowed(&proxy1.data, &proxy2.data)
+                {
+                    let _ = self.pairs.insert(pair);
+                    handler.interference_started(&proxy1.data, &proxy2.data);
+                }
+            }
+        }
+
+        /*
+         * Remove the interferences that stopped.
+         */
+        let purge_all = self.purge_all;
+        let proxies = &self.proxies;
+        self.pairs.retain(|pair| {
+            let proxy1 = &proxies[pair.0.uid()];
+            let proxy2 = &proxies[pair.1.uid()];
+
+            if (purge_all || proxy1.updated || proxy2.updated)
+                && (!handler.is_interference_allowed(&proxy1.data, &proxy2.data)
+                    || !proxy1.aabb.intersects(&proxy2.aabb))
+            {
+                handler.interference_stopped(&proxy1.data, &proxy2.data);
+                false
+            } else {
+                true
+            }
+        });
+
+        for id in self.updated.drain(..) {
+            self.proxies[id].updated = false;
+        }
+
+        self.purge_all = false;
+    }
+
+    /// Retrieves the bounding volume and data associated to the given proxy.
+    fn proxy(&self, handle: BroadPhaseProxyHandle) -> Option<(&AABB<N>, &T)> {
+        let proxy = self.proxies.get(handle.uid())?;
+
+        match proxy.status {
+            ProxyStatus::OnGrid(..) | ProxyStatus::Oversized => Some((&proxy.aabb, &proxy.data)),
+            _ => None,
+        }
+    }
+
+    fn create_proxy(&mut self, aabb: AABB<N>, data: T) -> BroadPhaseProxyHandle {
+        let proxy = GridBroadPhaseProxy {
+            aabb,
+            data,
+            status: ProxyStatus::Detached,
+            updated: false,
+        };
+        let handle = BroadPhaseProxyHandle(self.proxies.insert(proxy));
+        self.proxies_to_update.push((handle, aabb));
+        handle
+    }
+
+    fn remove(&mut self, handles: &[BroadPhaseProxyHandle], handler: &mut dyn FnMut(&T, &T)) {
+        for handle in handles {
+            if self.proxies.contains(handle.uid()) {
+                self.detach(handle.uid());
+                self.proxies[handle.uid()].status = ProxyStatus::Deleted;
+            } else {
+                panic!("Attempting to remove an object that does not exist.");
+            }
+        }
+
+        {
+            let proxies = &self.proxies;
+            self.pairs.retain(|pair| {
+                let proxy1 = &proxies[pair.0.uid()];
+                let proxy2 = &proxies[pair.1.uid()];
+
+                if proxy1.status == ProxyStatus::Deleted || proxy2.status == ProxyStatus::Deleted {
+                    handler(&proxy1.data, &proxy2.data);
+                    false
+                } else {
+                    true
+                }
+            });
+
+            self.proxies_to_update
+                .retain(|(h, _)| proxies[h.uid()].status != ProxyStatus::Deleted);
+        }
+
+        for handle in handles {
+            let _ = self.proxies.remove(handle.uid());
+        }
+    }
+
+    fn deferred_set_bounding_volume(&mut self, handle: BroadPhaseProxyHandle, aabb: AABB<N>) {
+        if let Some(proxy) = self.proxies.get(handle.uid()) {
+            let needs_update = match proxy.status {
+                ProxyStatus::OnGrid(..) | ProxyStatus::Oversized => !proxy.aabb.contains(&aabb),
+                ProxyStatus::Detached => true,
+                ProxyStatus::Deleted => {
+                    panic!("Grid broad phase: internal error, proxy not found.")
+                }
+            };
+
+            if needs_update {
+                let new_aabb = aabb.loosened(self.margin);
+                self.proxies_to_update.push((handle, new_aabb));
+            }
+        } else {
+            panic!("Attempting to set the bounding volume of an object that does not exist.");
+        }
+    }
+
+    fn deferred_recompute_all_proximities_with(&mut self, handle: BroadPhaseProxyHandle) {
+        if let Some(proxy) = self.proxies.get(handle.uid()) {
+            match proxy.status {
+                ProxyStatus::OnGrid(..) | ProxyStatus::Oversized => {
+                    self.proxies_to_update.push((handle, proxy.aabb))
+                }
+                _ => {}
+            }
+        }
+    }
+
+    fn deferred_recompute_all_proximities(&mut self) {
+        for (id, proxy) in self.proxies.iter() {
+            match proxy.status {
+                ProxyStatus::OnGrid(..) | ProxyStatus::Oversized => self
+                    .proxies_to_update
+                    .push((BroadPhaseProxyHandle(id), proxy.aabb)),
+                _ => {}
+            }
+        }
+
+        self.purge_all = true;
+    }
+
+    fn interferences_with_bounding_volume<'a>(&'a self, aabb: &AABB<N>, out: &mut Vec<&'a T>) {
+        let mut candidates = self.oversized.clone();
+
+        match self.cell_range(aabb) {
+            Some((mins, maxs)) => foreach_cell(&mins, &maxs, |cell| {
+                if let Some(content) = self.cells.get(&cell) {
+                    candidates.extend_from_slice(content)
+                }
+            }),
+            None => {
+                for content in self.cells.values() {
+                    candidates.extend_from_slice(content)
+                }
+            }
+        }
+
+        candidates.sort_unstable();
+        candidates.dedup();
+
+        for id in candidates {
+            let proxy = &self.proxies[id];
+
+            if proxy.aabb.intersects(aabb) {
+                out.push(&proxy.data)
+            }
+        }
+    }
+
+    fn interferences_with_ray<'a>(&'a self, ray: &Ray<N>, max_toi: N, out: &mut Vec<&'a T>) {
+        let mut visited = HashSet::new();
+        let mut test = |id: usize| {
+            if visited.insert(id) {
+                let proxy = &self.proxies[id];
+
+                if proxy
+                    .aabb
+                    .intersects_ray(&Isometry::identity(), ray, max_toi)
+                {
+                    out.push(&proxy.data)
+                }
+            }
+        };
+
+        for id in &self.oversized {
+            test(*id)
+        }
+
+        self.traverse_ray(ray, max_toi, |cell, _| {
+            if let Some(content) = self.cells.get(cell) {
+                for id in content {
+                    test(*id)
+                }
+            }
+
+            true
+        });
+    }
+
+    fn interferences_with_point<'a>(&'a self, point: &Point<N>, out: &mut Vec<&'a T>) {
+        let cell = self.cell_containing_point(point);
+        let content = self.cells.get(&cell).map(|c| &c[..]).unwrap_or(&[]);
+
+        for id in content.iter().chain(self.oversized.iter()) {
+            let proxy = &self.proxies[*id];
+
+            if proxy.aabb.contains_local_point(point) {
+                out.push(&proxy.data)
+            }
+        }
+    }
+
+    /// Returns the first object that interferes with a ray.
+    fn first_interference_with_ray<'a, 'b>(
+        &'a self,
+        ray: &'b Ray<N>,
+        max_toi: N,
+        cost_fn: &'a dyn Fn(T, &'b Ray<N>, N) -> Option<(T, RayIntersection<N>)>,
+    ) -> Option<(T, RayIntersection<N>)> {
+        let mut best: Option<(T, RayIntersection<N>)> = None;
+        let mut visited = HashSet::new();
+        let mut test = |id: usize, best: &mut Option<(T, RayIntersection<N>)>| {
+            if visited.insert(id) {
+                let proxy = &self.proxies[id];
+
+                if proxy
+                    .aabb
+                    .intersects_ray(&Isometry::identity(), ray, max_toi)
+                {
+                    if let Some(result) = cost_fn(proxy.data.clone(), ray, max_toi) {
+                        if best
+                            .as_ref()
+                            .map(|b| result.1.toi < b.1.toi)
+                            .unwrap_or(true)
+                        {
+                            *best = Some(result);
+                        }
+                    }
+                }
+            }
+        };
+
+        for id in &self.oversized {
+            test(*id, &mut best)
+        }
+
+        self.traverse_ray(ray, max_toi, |cell, exit_toi| {
+            if let Some(content) = self.cells.get(cell) {
+                for id in content {
+                    test(*id, &mut best)
+                }
+            }
+
+            // Any object not tested yet can only be hit after `exit_toi`.
+            best.as_ref().map(|b| b.1.toi > exit_toi).unwrap_or(true)
+        });
+
+        best
+    }
+}
@@ -4,9 +4,13 @@
 pub use self::broad_phase::{BroadPhase, BroadPhaseInterferenceHandler, BroadPhaseProxyHandle};
 pub use self::broad_phase_pair_filter::BroadPhasePairFilter;
 pub use self::dbvt_broad_phase::DBVTBroadPhase;
+pub use self::grid_broad_phase::GridBroadPhase;
+pub use self::sap_broad_phase::SAPBroadPhase;
 
 #[doc(hidden)]
 pub mod broad_phase;
 #[doc(hidden)]
 pub mod broad_phase_pair_filter;
 mod dbvt_broad_phase;
+mod grid_broad_phase;
+mod sap_broad_phase;
@@ -0,0 +1,491 @@
+use crate::bounding_volume::{BoundingVolume, AABB};
+use crate::math::{Isometry, Point, DIM};
+use crate::pipeline::broad_phase::{
+    BroadPhase, BroadPhaseInterferenceHandler, BroadPhaseProxyHandle,
+};
+use crate::query::{Ray, RayCast, RayIntersection};
+use crate::utils::{DeterministicState, SortedPair};
+use na::RealField;
+use slab::Slab;
+use std::any::Any;
+use std::cmp::Ordering;
+use std::collections::HashSet;
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+enum ProxyStatus {
+    // The proxy endpoints are not yet on the sorted lists.
+    Detached,
+    Attached,
+    Deleted,
+}
+
+struct SAPBroadPhaseProxy<N: RealField, T> {
+    aabb: AABB<N>,
+    data: T,
+    status: ProxyStatus,
+}
+
+#[derive(Copy, Clone, Debug)]
+struct SAPEndpoint<N: RealField> {
+    value: N,
+    proxy: usize,
+    is_max: bool,
+}
+
+impl<N: RealField> SAPEndpoint<N> {
+    // Endpoints with equal values are ordered with lower bounds first so that touching
+    // intervals are seen as overlapping, consistently with `AABB::intersects`.
+    #[inline]
+    fn lt(&self, other: &Self) -> bool {
+        self.value < other.value || (self.value == other.value && !self.is_max && other.is_max)
+    }
+
+    #[inline]
+    fn cmp(&self, other: &Self) -> Ordering {
+        if self.lt(other) {
+            Ordering::Less
+        } else if other.lt(self) {
+            Ordering::Greater
+        } else {
+            Ordering::Equal
+        }
+    }
+}
+
+/// Broad phase based on an incremental Sweep-and-Prune.
+///
+/// The lower and upper bounds of the AABB of every proxy are kept sorted along each axis.
+/// Those lists are updated with an insertion sort at each update so that only the endpoints
+/// that actually moved past each other are processed. This makes it very efficient for scenes
+/// with many objects that move only slightly from one frame to another.
+///
+/// To use it on a `CollisionWorld`, replace its broad phase before any collision object is added:
+/// `world.broad_phase = Box::new(SAPBroadPhase::new(margin))`.
+pub struct SAPBroadPhase<N: RealField, T> {
+    proxies: Slab<SAPBroadPhaseProxy<N, T>>,
+    // Endpoints sorted along each axis.
+    endpoints: [Vec<SAPEndpoint<N>>; DIM],
+    // Pairs detected.
+    pairs: HashSet<SortedPair<BroadPhaseProxyHandle>, DeterministicState>,
+    // The margin added to each bounding volume.
+    margin: N,
+    purge_all: bool,
+    proxies_to_update: Vec<(BroadPhaseProxyHandle, AABB<N>)>,
+    proxies_to_recompute: Vec<BroadPhaseProxyHandle>,
+
+    // Just to avoid dynamic allocations.
+    active: Vec<usize>,
+}
+
+impl<N: RealField, T> SAPBroadPhase<N, T> {
+    /// Creates a new broad phase based on the Sweep-and-Prune algorithm.
+    pub fn new(margin: N) -> SAPBroadPhase<N, T> {
+        SAPBroadPhase {
+            proxies: Slab::new(),
+            endpoints: Default::default(),
+            pairs: HashSet::with_hasher(DeterministicState::new()),
+            margin,
+            purge_all: false,
+            proxies_to_update: Vec::new(),
+            proxies_to_recompute: Vec::new(),
+            active: Vec::new(),
+        }
+    }
+
+    // The endpoints along the first axis that may belong to an AABB with a lower bound smaller
+    // than or equal to `max`. Only those AABBs can intersect something that does not extend past
+    // `max` along this axis.
+    fn endpoints_below(&self, max: N) -> &[SAPEndpoint<N>] {
+        let endpoints = &self.endpoints[0];
+        let end = endpoints.partition_point(|e| e.value <= max);
+        &endpoints[..end]
+    }
+
+    /// Number of interferences detected by this broad phase.
+    #[inline]
+    pub fn num_interferences(&self) -> usize {
+        self.pairs.len()
+    }
+
+    fn refresh_endpoint_values(&mut self) {
+        for (axis, endpoints) in self.endpoints.iter_mut().enumerate() {
+            for endpoint in endpoints.iter_mut() {
+                let aabb = &self.proxies[endpoint.proxy].aabb;
+                endpoint.value = if endpoint.is_max {
+                    aabb.maxs[axis]
+                } else {
+                    aabb.mins[axis]
+                };
+            }
+        }
+    }
+
+    // Insertion-sort of the endpoints. Every time a lower bound and an upper bound switch their
+    // order, the overlap state of the corresponding pair is re-evaluated.
+    fn sort_incrementally(&mut self, handler: &mut dyn BroadPhaseInterferenceHandler<T>) {
+        for endpoints in self.endpoints.iter_mut() {
+            for i in 1..endpoints.len() {
+                let mut j = i;
+
+                while j > 0 && endpoints[j].lt(&endpoints[j - 1]) {
+                    let e1 = endpoints[j];
+                    let e2 = endpoints[j - 1];
+
+                    if e1.is_max != e2.is_max {
+                        update_pair(&self.proxies, &mut self.pairs, e1.proxy, e2.proxy, handler);
+                    }
+
+                    endpoints.swap(j, j - 1);
+                    j -= 1;
+                }
+            }
+        }
+    }
+
+    // Sorts all the endpoints from scratch and finds all the interferences with a sweep along
+    // the first axis. This is faster than the incremental sort when a lot of proxies are added
+    // at once.
+    fn sort_and_sweep(&mut self, handler: &mut dyn BroadPhaseInterferenceHandler<T>) {
+        for endpoints in self.endpoints.iter_mut() {
+            endpoints.sort_unstable_by(|e1, e2| e1.cmp(e2));
+        }
+
+        let mut found = Vec::new();
+        self.active.clear();
+
+        for endpoint in &self.endpoints[0] {
+            if endpoint.is_max {
+                if let Some(i) = self.active.iter().position(|p| *p == endpoint.proxy) {
+                    let _ = self.active.swap_remove(i);
+                }
+            } else {
+                let aabb1 = &self.proxies[endpoint.proxy].aabb;
+
+                for other in &self.active {
+                    if aabb1.intersects(&self.proxies[*other].aabb) {
+                        found.push(SortedPair::new(
+                            BroadPhaseProxyHandle(endpoint.proxy),
+                            BroadPhaseProxyHandle(*other),
+                        ));
+                    }
+                }
+
+                self.active.push(endpoint.proxy);
+            }
+        }
+
+        let purge_all = self.purge_all;
+        let proxies = &self.proxies;
+        let found_set: HashSet<_> = found.iter().cloned().collect();
+        self.pairs.retain(|pair| {
+            let proxy1 = &proxies[pair.0.uid()];
+            let proxy2 = &proxies[pair.1.uid()];
+
+            if found_set.contains(pair)
+                && (!purge_all || handler.is_interference_allowed(&proxy1.data, &proxy2.data))
+            {
+                true
+            } else {
+                handler.interference_stopped(&proxy1.data, &proxy2.data);
+                false
+            }
+        });
+
+        for pair in found {
+            if !self.pairs.contains(&pair) {
+                let proxy1 = &self.proxies[pair.0.uid()];
+                let proxy2 = &self.proxies[pair.1.uid()];
+
+                if handler.is_interference_allowed(&proxy1.data, &proxy2.data) {
+                    let _ = self.pairs.insert(pair);
+                    handler.interference_started(&proxy1.data, &proxy2.data);
+                }
+            }
+        }
+    }
+
+    fn recompute_proximities_with(
+        &mut self,
+        handle: BroadPhaseProxyHandle,
+        handler: &mut dyn BroadPhaseInterferenceHandler<T>,
+    ) {
+        let proxy1 = match self.proxies.get(handle.uid()) {
+            Some(proxy) if proxy.status == ProxyStatus::Attached => proxy,
+            _ => return,
+        };
+
+        for (id2, proxy2) in self.proxies.iter() {
+            if id2 == handle.uid() || proxy2.status != ProxyStatus::Attached {
+                continue;
+            }
+
+            let pair = SortedPair::new(handle, BroadPhaseProxyHandle(id2));
+
+            if proxy1.aabb.intersects(&proxy2.aabb)
+                && handler.is_interference_allowed(&proxy1.data, &proxy2.data)
+            {
+                if self.pairs.insert(pair) {
+                    handler.interference_started(&proxy1.data, &proxy2.data);
+                }
+            } else if self.pairs.remove(&pair) {
+                handler.interference_stopped(&proxy1.data, &proxy2.data);
+            }
+        }
+    }
+}
+
+fn update_pair<N: RealField, T>(
+    proxies: &Slab<SAPBroadPhaseProxy<N, T>>,
+    pairs: &mut HashSet<SortedPair<BroadPhaseProxyHandle>, DeterministicState>,
+    id1: usize,
+    id2: usize,
+    handler: &mut dyn BroadPhaseInterferenceHandler<T>,
+) {
+    let proxy1 = &proxies[id1];
+    let proxy2 = &proxies[id2];
+    let pair = SortedPair::new(BroadPhaseProxyHandle(id1), BroadPhaseProxyHandle(id2));
+
+    if proxy1.aabb.intersects(&proxy2.aabb) {
+        if !pairs.contains(&pair) && handler.is_interference_allowed(&proxy1.data, &proxy2.data) {
+            let _ = pairs.insert(pair);
+            handler.interference_started(&proxy1.data, &proxy2.data);
+        }
+    } else if pairs.remove(&pair) {
+        handler.interference_stopped(&proxy1.data, &proxy2.data);
+    }
+}
+
+impl<N, T> BroadPhase<N, AABB<N>, T> for SAPBroadPhase<N, T>
+where
+    N: RealField,
+    T: Any + Send + Sync + Clone,
+{
+    fn update(&mut self, handler: &mut dyn BroadPhaseInterferenceHandler<T>) {
+        let mut num_attached = 0;
+        let mut some_proxies_updated = false;
+
+        for (handle, aabb) in self.proxies_to_update.drain(..) {
+            if let Some(proxy) = self.proxies.get_mut(handle.uid()) {
+                proxy.aabb = aabb;
+                some_proxies_updated = true;
+
+                if proxy.status == ProxyStatus::Detached {
+                    for (axis, endpoints) in self.endpoints.iter_mut().enumerate() {
+                        endpoints.push(SAPEndpoint {
+                            value: proxy.aabb.mins[axis],
+                            proxy: handle.uid(),
+                            is_max: false,
+                        });
+                        endpoints.push(SAPEndpoint {
+                            value: proxy.aabb.maxs[axis],
+                            proxy: handle.uid(),
+                            is_max: true,
+                        });
+                    }
+
+                    proxy.status = ProxyStatus::Attached;
+                    num_attached += 1;
+                }
+            }
+        }
+
+        if some_proxies_updated {
+            self.refresh_endpoint_values();
+        }
+
+        if self.purge_all || num_attached * 2 > self.proxies.len() {
+            self.sort_and_sweep(handler);
+        } else if some_proxies_updated {
+            self.sort_incrementally(handler);
+        }
+
+        for i in 0..self.proxies_to_recompute.len() {
+            let handle = self.proxies_to_recompute[i];
+            self.recompute_proximities_with(handle, handler);
+        }
+
+        self.proxies_to_recompute.clear();
+        self.purge_all = false;
+    }
+
+    /// Retrieves the bounding volume and data associated to the given proxy.
+    fn proxy(&self, handle: BroadPhaseProxyHandle) -> Option<(&AABB<N>, &T)> {
+        let proxy = self.proxies.get(handle.uid())?;
+
+        if proxy.status == ProxyStatus::Attached {
+            Some((&proxy.aabb, &proxy.data))
+        } else {
+            None
+        }
+    }
+
+    fn create_proxy(&mut self, aabb: AABB<N>, data: T) -> BroadPhaseProxyHandle {
+        let proxy = SAPBroadPhaseProxy {
+            aabb,
+            data,
+            status: ProxyStatus::Detached,
+        };
+        let handle = BroadPhaseProxyHandle(self.proxies.insert(proxy));
+        self.proxies_to_update.push((handle, aabb));
+        handle
+    }
+
+    fn remove(&mut self, handles: &[BroadPhaseProxyHandle], handler: &mut dyn FnMut(&T, &T)) {
+        for handle in handles {
+            if let Some(proxy) = self.proxies.get_mut(handle.uid()) {
+                proxy.status = ProxyStatus::Deleted;
+            } else {
+                panic!("Attempting to remove an object that does not exist.");
+            }
+        }
+
+        {
+            let proxies = &self.proxies;
+            self.pairs.retain(|pair| {
+                let proxy1 = &proxies[pair.0.uid()];
+                let proxy2 = &proxies[pair.1.uid()];
+
+                if proxy1.status == ProxyStatus::Deleted || proxy2.status == ProxyStatus::Deleted {
+                    handler(&proxy1.data, &proxy2.data);
+                    false
+                } else {
+                    true
+                }
+            });
+
+            for endpoints in self.endpoints.iter_mut() {
+                endpoints.retain(|e| proxies[e.proxy].status != ProxyStatus::Deleted);
+            }
+
+            self.proxies_to_update
+                .retain(|(h, _)| proxies[h.uid()].status != ProxyStatus::Deleted);
+            self.proxies_to_recompute
+                .retain(|h| proxies[h.uid()].status != ProxyStatus::Deleted);
+        }
+
+        for handle in handles {
+            let _ = self.proxies.remove(handle.uid());
+        }
+    }
+
+    fn deferred_set_bounding_volume(&mut self, handle: BroadPhaseProxyHandle, aabb: AABB<N>) {
+        if let Some(proxy) = self.proxies.get(handle.uid()) {
+            let needs_update = match proxy.status {
+                ProxyStatus::Attached => !proxy.aabb.contains(&aabb),
+                ProxyStatus::Detached => true,
+                ProxyStatus::Deleted => panic!("SAP broad phase: internal error, proxy not found."),
+            };
+
+            if needs_update {
+                let new_aabb = aabb.loosened(self.margin);
+                self.proxies_to_update.push((handle, new_aabb));
+            }
+        } else {
+            panic!("Attempting to set the bounding volume of an object that does not exist.");
+        }
+    }
+
+    fn deferred_recompute_all_proximities_with(&mut self, handle: BroadPhaseProxyHandle) {
+        if self.proxies.contains(handle.uid()) {
+            self.proxies_to_recompute.push(handle);
+        }
+    }
+
+    fn deferred_recompute_all_proximities(&mut self) {
+        self.purge_all = true;
+    }
+
+    fn interferences_with_bounding_volume<'a>(&'a self, aabb: &AABB<N>, out: &mut Vec<&'a T>) {
+        for endpoint in self.endpoints_below(aabb.maxs[0]) {
+            if !endpoint.is_max {
+                let proxy = &self.proxies[endpoint.proxy];
+
+                if proxy.aabb.intersects(aabb) {
+                    out.push(&proxy.data)
+                }
+            }
+        }
+    }
+
+    fn interferences_with_ray<'a>(&'a self, ray: &Ray<N>, max_toi: N, out: &mut Vec<&'a T>) {
+        for endpoint in self.endpoints_below(ray_max_along_first_axis(ray, max_toi)) {
+            if !endpoint.is_max {
+                let proxy = &self.proxies[endpoint.proxy];
+
+                if proxy
+                    .aabb
+                    .intersects_ray(&Isometry::identity(), ray, max_toi)
+                {
+                    out.push(&proxy.data)
+                }
+            }
+        }
+    }
+
+    fn interferences_with_point<'a>(&'a self, point: &Point<N>, out: &mut Vec<&'a T>) {
+        for endpoint in self.endpoints_below(point[0]) {
+            if !endpoint.is_max {
+                let proxy = &self.proxies[endpoint.proxy];
+
+                if proxy.aabb.contains_local_point(point) {
+                    out.push(&proxy.data)
+                }
+            }
+        }
+    }
+
+    /// Returns the first object that interferes with a ray.
+    fn first_interference_with_ray<'a, 'b>(
+        &'a self,
+        ray: &'b Ray<N>,
+        max_toi: N,
+        cost_fn: &'a dyn Fn(T, &'b Ray<N>, N) -> Option<(T, RayIntersection<N>)>,
+    ) -> Option<(T, RayIntersection<N>)> {
+        let mut candidates = Vec::new();
+
+        for endpoint in self.endpoints_below(ray_max_along_first_axis(ray, max_toi)) {
+            if !endpoint.is_max {
+                let aabb = &self.proxies[endpoint.proxy].aabb;
+
+                if let Some(toi) = aabb.toi_with_ray(&Isometry::identity(), ray, max_toi, true) {
+                    candidates.push((toi, endpoint.proxy));
+                }
+            }
+        }
+
+        candidates.sort_by(|a, b| a.0.partial_cmp(&b.0).unwrap_or(Ordering::Equal));
+
+        let mut best: Option<(T, RayIntersection<N>)> = None;
+
+        for (rough_toi, id) in candidates {
+            // The remaining AABBs are all further than the best hit found so far.
+            if let Some(best) = &best {
+                if rough_toi >= best.1.toi {
+                    break;
+                }
+            }
+
+            if let Some(result) = cost_fn(self.proxies[id].data.clone(), ray, max_toi) {
+                if best
+                    .as_ref()
+                    .map(|b| result.1.toi < b.1.toi)
+                    .unwrap_or(true)
+                {
+                    best = Some(result);
+                }
+            }
+        }
+
+        best
+    }
+}
+
+// The largest coordinate along the first axis of the points of the ray with a time of impact
+// in `[0, max_toi]`.
+fn ray_max_along_first_axis<N: RealField>(ray: &Ray<N>, max_toi: N) -> N {
+    if ray.dir[0] > N::zero() {
+        ray.origin[0] + ray.dir[0] * max_toi
+    } else {
+        ray.origin[0]
+    }
+}
@@ -1,9 +1,13 @@
 //! Glue code between each part of the collision-detection pipeline.
 
+#[cfg(feature = "parallel")]
+pub use self::query::par_cast_rays;
 pub use self::query::{
-    first_interference_with_ray, interferences_with_aabb, interferences_with_point,
-    interferences_with_ray, FirstInterferenceWithRay, InterferencesWithAABB,
-    InterferencesWithPoint, InterferencesWithRay,
+    cast_rays, contacts_with_shape, first_interference_with_ray, first_nonlinear_shape_cast,
+    first_shape_cast, interferences_with_aabb, interferences_with_point, interferences_with_ray,
+    interferences_with_shape, nonlinear_shape_cast_all, shape_cast_all, ContactsWithShape,
+    FirstInterferenceWithRay, InterferencesWithAABB, InterferencesWithPoint, InterferencesWithRay,
+    InterferencesWithShape,
 };
 pub use setup::{
     create_proxies, default_broad_phase, default_interaction_graph, default_narrow_phase,
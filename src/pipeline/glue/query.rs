@@ -1,11 +1,16 @@
 use na::RealField;
+use std::cmp::Ordering;
 use std::vec::IntoIter;
 
-use crate::bounding_volume::AABB;
-use crate::math::Point;
+use crate::bounding_volume::{BoundingVolume, AABB};
+use crate::interpolation::{ConstantVelocityRigidMotion, RigidMotion};
+use crate::math::{Isometry, Point, Vector};
 use crate::pipeline::broad_phase::BroadPhase;
 use crate::pipeline::object::{CollisionGroups, CollisionObjectRef, CollisionObjectSet};
-use crate::query::{PointQuery, Ray, RayCast, RayIntersection};
+use crate::query::{
+    self, Contact, PointQuery, Proximity, Ray, RayCast, RayIntersection, TOIDispatcher, TOI,
+};
+use crate::shape::Shape;
 
 /// Returns an iterator yielding all the collision objects intersecting with the given ray.
 ///
@@ -180,6 +185,150 @@ impl<'a, 'b, N: RealField, Objects: CollisionObjectSet<N>> Iterator
     }
 }
 
+/// Returns an iterator yielding all the collision objects intersecting with the given shape.
+///
+/// The result will only include collision objects in a group that can interact with the given `groups`.
+/// Objects with a shape not supported by `query::proximity_opt` when paired with `shape` are ignored.
+pub fn interferences_with_shape<'a, 'b, N, Objects>(
+    objects: &'a Objects,
+    broad_phase: &'a (impl BroadPhase<N, AABB<N>, Objects::CollisionObjectHandle> + ?Sized),
+    shape: &'b dyn Shape<N>,
+    pos: &'b Isometry<N>,
+    groups: &'b CollisionGroups,
+) -> InterferencesWithShape<'a, 'b, N, Objects>
+where
+    N: RealField,
+    Objects: CollisionObjectSet<N>,
+{
+    let mut handles = Vec::new();
+    broad_phase.interferences_with_bounding_volume(&shape.aabb(pos), &mut handles);
+
+    InterferencesWithShape {
+        shape,
+        pos,
+        groups,
+        objects,
+        handles: handles.into_iter(),
+    }
+}
+
+/// Iterator through all the objects on the world that intersect a specific shape.
+pub struct InterferencesWithShape<'a, 'b, N: RealField, Objects: CollisionObjectSet<N>> {
+    shape: &'b dyn Shape<N>,
+    pos: &'b Isometry<N>,
+    objects: &'a Objects,
+    groups: &'b CollisionGroups,
+    handles: IntoIter<&'a Objects::CollisionObjectHandle>,
+}
+
+impl<'a, 'b, N: RealField, Objects> Iterator for InterferencesWithShape<'a, 'b, N, Objects>
+where
+    N: RealField,
+    Objects: CollisionObjectSet<N>,
+{
+    type Item = (Objects::CollisionObjectHandle, &'a Objects::CollisionObject);
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        while let Some(handle) = self.handles.next() {
+            if let Some(co) = self.objects.collision_object(*handle) {
+                if co.collision_groups().can_interact_with_groups(self.groups) {
+                    let prox = query::proximity_opt(
+                        self.pos,
+                        self.shape,
+                        co.position(),
+                        co.shape(),
+                        N::zero(),
+                    );
+
+                    if prox == Some(Proximity::Intersecting) {
+                        return Some((*handle, co));
+                    }
+                }
+            }
+        }
+
+        None
+    }
+}
+
+/// Returns an iterator yielding all the collision objects closer than `prediction` to the given
+/// shape, with the corresponding contact.
+///
+/// The first shape of each contact is the given `shape`. The result will only include collision
+/// objects in a group that can interact with the given `groups`. Objects with a shape not
+/// supported by `query::contact_opt` when paired with `shape` are ignored.
+pub fn contacts_with_shape<'a, 'b, N, Objects>(
+    objects: &'a Objects,
+    broad_phase: &'a (impl BroadPhase<N, AABB<N>, Objects::CollisionObjectHandle> + ?Sized),
+    shape: &'b dyn Shape<N>,
+    pos: &'b Isometry<N>,
+    prediction: N,
+    groups: &'b CollisionGroups,
+) -> ContactsWithShape<'a, 'b, N, Objects>
+where
+    N: RealField,
+    Objects: CollisionObjectSet<N>,
+{
+    let mut handles = Vec::new();
+    let aabb = shape.aabb(pos).loosened(prediction);
+    broad_phase.interferences_with_bounding_volume(&aabb, &mut handles);
+
+    ContactsWithShape {
+        shape,
+        pos,
+        prediction,
+        groups,
+        objects,
+        handles: handles.into_iter(),
+    }
+}
+
+/// Iterator through all the objects on the world in contact with a specific shape.
+pub struct ContactsWithShape<'a, 'b, N: RealField, Objects: CollisionObjectSet<N>> {
+    shape: &'b dyn Shape<N>,
+    pos: &'b Isometry<N>,
+    prediction: N,
+    objects: &'a Objects,
+    groups: &'b CollisionGroups,
+    handles: IntoIter<&'a Objects::CollisionObjectHandle>,
+}
+
+impl<'a, 'b, N: RealField, Objects> Iterator for ContactsWithShape<'a, 'b, N, Objects>
+where
+    N: RealField,
+    Objects: CollisionObjectSet<N>,
+{
+    type Item = (
+        Objects::CollisionObjectHandle,
+        &'a Objects::CollisionObject,
+        Contact<N>,
+    );
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        while let Some(handle) = self.handles.next() {
+            if let Some(co) = self.objects.collision_object(*handle) {
+                if co.collision_groups().can_interact_with_groups(self.groups) {
+                    let contact = query::contact_opt(
+                        self.pos,
+                        self.shape,
+                        co.position(),
+                        co.shape(),
+                        self.prediction,
+                    );
+
+                    if let Some(Some(contact)) = contact {
+                        return Some((*handle, co, contact));
+                    }
+                }
+            }
+        }
+
+        None
+    }
+}
+
 /// Return structure for `first_interference_with_ray`
 ///
 /// Contains the handle of the closest object along the ray along with its
@@ -222,3 +371,293 @@ pub fn first_interference_with_ray<'a, 'b, N: RealField, Objects: CollisionObjec
     let co = objects.collision_object(handle)?;
     Some(FirstInterferenceWithRay { handle, co, inter })
 }
+
+/// Pushes to `out` the closest collision object intersecting with each ray of `rays`.
+///
+/// The i-th element pushed to `out` is the first hit of `rays[i]`, if any. The result will only
+/// include collision objects in a group that can interact with the given `groups`.
+pub fn cast_rays<N: RealField, Objects: CollisionObjectSet<N>>(
+    objects: &Objects,
+    broad_phase: &(impl BroadPhase<N, AABB<N>, Objects::CollisionObjectHandle> + ?Sized),
+    rays: &[Ray<N>],
+    max_toi: N,
+    groups: &CollisionGroups,
+    out: &mut Vec<Option<(Objects::CollisionObjectHandle, RayIntersection<N>)>>,
+) {
+    // Narrow phase
+    let narrow_phase = |handle: Objects::CollisionObjectHandle, ray: &Ray<N>, max_toi: N| {
+        let co = objects.collision_object(handle)?;
+        if co.collision_groups().can_interact_with_groups(groups) {
+            let inter = co
+                .shape()
+                .toi_and_normal_with_ray(&co.position(), ray, max_toi, true);
+
+            inter.map(|inter| (handle, inter))
+        } else {
+            None
+        }
+    };
+
+    broad_phase.first_interferences_with_rays(rays, max_toi, &narrow_phase, out)
+}
+
+/// Same as `cast_rays` but distributes the rays among the threads of the rayon thread pool.
+#[cfg(feature = "parallel")]
+pub fn par_cast_rays<N: RealField, Objects: CollisionObjectSet<N> + Sync>(
+    objects: &Objects,
+    broad_phase: &(impl BroadPhase<N, AABB<N>, Objects::CollisionObjectHandle> + ?Sized),
+    rays: &[Ray<N>],
+    max_toi: N,
+    groups: &CollisionGroups,
+    out: &mut Vec<Option<(Objects::CollisionObjectHandle, RayIntersection<N>)>>,
+) {
+    use rayon::prelude::*;
+
+    // Large enough for each chunk to be split into several ray packets.
+    const CHUNK_SIZE: usize = 1024;
+
+    out.par_extend(rays.par_chunks(CHUNK_SIZE).flat_map_iter(|chunk| {
+        let mut hits = Vec::with_capacity(chunk.len());
+        cast_rays(objects, broad_phase, chunk, max_toi, groups, &mut hits);
+        hits
+    }))
+}
+
+/// Returns all the collision objects hit by `shape` moving from `shape_pos` at the constant
+/// velocity `shape_vel`, sorted by increasing time of impact.
+///
+/// A collision object is hit when its distance to `shape` becomes smaller than `target_distance`
+/// before the time `max_toi`. The result will only include collision objects in a group that can
+/// interact with the given `groups`. Pairs of shapes not supported by `dispatcher` are ignored.
+pub fn shape_cast_all<N: RealField, Objects: CollisionObjectSet<N>>(
+    objects: &Objects,
+    broad_phase: &(impl BroadPhase<N, AABB<N>, Objects::CollisionObjectHandle> + ?Sized),
+    dispatcher: &dyn TOIDispatcher<N>,
+    shape_pos: &Isometry<N>,
+    shape_vel: &Vector<N>,
+    shape: &dyn Shape<N>,
+    max_toi: N,
+    target_distance: N,
+    groups: &CollisionGroups,
+) -> Vec<(Objects::CollisionObjectHandle, TOI<N>)> {
+    let aabb = linear_swept_aabb(shape_pos, shape_vel, shape, max_toi, target_distance);
+
+    shape_cast(
+        objects,
+        broad_phase,
+        &aabb,
+        max_toi,
+        groups,
+        false,
+        |co, max_toi| {
+            dispatcher
+                .time_of_impact(
+                    dispatcher,
+                    shape_pos,
+                    shape_vel,
+                    shape,
+                    co.position(),
+                    &Vector::zeros(),
+                    co.shape(),
+                    max_toi,
+                    target_distance,
+                )
+                .unwrap_or(None)
+        },
+    )
+}
+
+/// Returns the first collision object hit by `shape` moving from `shape_pos` at the constant
+/// velocity `shape_vel`.
+///
+/// See `shape_cast_all` for details.
+pub fn first_shape_cast<N: RealField, Objects: CollisionObjectSet<N>>(
+    objects: &Objects,
+    broad_phase: &(impl BroadPhase<N, AABB<N>, Objects::CollisionObjectHandle> + ?Sized),
+    dispatcher: &dyn TOIDispatcher<N>,
+    shape_pos: &Isometry<N>,
+    shape_vel: &Vector<N>,
+    shape: &dyn Shape<N>,
+    max_toi: N,
+    target_distance: N,
+    groups: &CollisionGroups,
+) -> Option<(Objects::CollisionObjectHandle, TOI<N>)> {
+    let aabb = linear_swept_aabb(shape_pos, shape_vel, shape, max_toi, target_distance);
+
+    shape_cast(
+        objects,
+        broad_phase,
+        &aabb,
+        max_toi,
+        groups,
+        true,
+        |co, max_toi| {
+            dispatcher
+                .time_of_impact(
+                    dispatcher,
+                    shape_pos,
+                    shape_vel,
+                    shape,
+                    co.position(),
+                    &Vector::zeros(),
+                    co.shape(),
+                    max_toi,
+                    target_distance,
+                )
+                .unwrap_or(None)
+        },
+    )
+    .pop()
+}
+
+/// Returns all the collision objects hit by `shape` following the translational and rotational
+/// `motion`, sorted by increasing time of impact.
+///
+/// See `shape_cast_all` for details.
+pub fn nonlinear_shape_cast_all<N: RealField, Objects: CollisionObjectSet<N>>(
+    objects: &Objects,
+    broad_phase: &(impl BroadPhase<N, AABB<N>, Objects::CollisionObjectHandle> + ?Sized),
+    dispatcher: &dyn TOIDispatcher<N>,
+    motion: &ConstantVelocityRigidMotion<N>,
+    shape: &dyn Shape<N>,
+    max_toi: N,
+    target_distance: N,
+    groups: &CollisionGroups,
+) -> Vec<(Objects::CollisionObjectHandle, TOI<N>)> {
+    let aabb = nonlinear_swept_aabb(motion, shape, max_toi, target_distance);
+
+    shape_cast(
+        objects,
+        broad_phase,
+        &aabb,
+        max_toi,
+        groups,
+        false,
+        |co, max_toi| {
+            dispatcher
+                .nonlinear_time_of_impact(
+                    dispatcher,
+                    motion,
+                    shape,
+                    co.position(),
+                    co.shape(),
+                    max_toi,
+                    target_distance,
+                )
+                .unwrap_or(None)
+        },
+    )
+}
+
+/// Returns the first collision object hit by `shape` following the translational and rotational
+/// `motion`.
+///
+/// See `shape_cast_all` for details.
+pub fn first_nonlinear_shape_cast<N: RealField, Objects: CollisionObjectSet<N>>(
+    objects: &Objects,
+    broad_phase: &(impl BroadPhase<N, AABB<N>, Objects::CollisionObjectHandle> + ?Sized),
+    dispatcher: &dyn TOIDispatcher<N>,
+    motion: &ConstantVelocityRigidMotion<N>,
+    shape: &dyn Shape<N>,
+    max_toi: N,
+    target_distance: N,
+    groups: &CollisionGroups,
+) -> Option<(Objects::CollisionObjectHandle, TOI<N>)> {
+    let aabb = nonlinear_swept_aabb(motion, shape, max_toi, target_distance);
+
+    shape_cast(
+        objects,
+        broad_phase,
+        &aabb,
+        max_toi,
+        groups,
+        true,
+        |co, max_toi| {
+            dispatcher
+                .nonlinear_time_of_impact(
+                    dispatcher,
+                    motion,
+                    shape,
+                    co.position(),
+                    co.shape(),
+                    max_toi,
+                    target_distance,
+                )
+                .unwrap_or(None)
+        },
+    )
+    .pop()
+}
+
+// Computes the hits of every collision object with an AABB intersecting `aabb`, sorted by
+// time of impact.
+//
+// If `first_only` is set, the time of impact limit is lowered to the best hit found so far and
+// only this best hit is returned.
+fn shape_cast<N: RealField, Objects: CollisionObjectSet<N>>(
+    objects: &Objects,
+    broad_phase: &(impl BroadPhase<N, AABB<N>, Objects::CollisionObjectHandle> + ?Sized),
+    aabb: &AABB<N>,
+    max_toi: N,
+    groups: &CollisionGroups,
+    first_only: bool,
+    toi: impl Fn(&Objects::CollisionObject, N) -> Option<TOI<N>>,
+) -> Vec<(Objects::CollisionObjectHandle, TOI<N>)> {
+    let mut handles = Vec::new();
+    broad_phase.interferences_with_bounding_volume(aabb, &mut handles);
+
+    let mut hits = Vec::new();
+    let mut limit = max_toi;
+
+    for handle in handles {
+        if let Some(co) = objects.collision_object(*handle) {
+            if co.collision_groups().can_interact_with_groups(groups) {
+                if let Some(hit) = toi(co, limit) {
+                    if first_only {
+                        limit = hit.toi;
+                        hits.clear();
+                    }
+
+                    hits.push((*handle, hit));
+                }
+            }
+        }
+    }
+
+    hits.sort_by(|a, b| a.1.toi.partial_cmp(&b.1.toi).unwrap_or(Ordering::Equal));
+    hits
+}
+
+// The AABB enclosing `shape` during its translation.
+fn linear_swept_aabb<N: RealField>(
+    shape_pos: &Isometry<N>,
+    shape_vel: &Vector<N>,
+    shape: &dyn Shape<N>,
+    max_toi: N,
+    target_distance: N,
+) -> AABB<N> {
+    let start = shape.aabb(shape_pos);
+    let shift = shape_vel * max_toi;
+    let end = AABB::new(start.mins + shift, start.maxs + shift);
+    start.merged(&end).loosened(target_distance)
+}
+
+// The AABB enclosing `shape` during its motion.
+//
+// The rotation center follows a straight line, so the shape stays inside of the sphere centered
+// at the rotation center and enclosing the bounding sphere of the shape.
+fn nonlinear_swept_aabb<N: RealField>(
+    motion: &ConstantVelocityRigidMotion<N>,
+    shape: &dyn Shape<N>,
+    max_toi: N,
+    target_distance: N,
+) -> AABB<N> {
+    let bsphere = shape.local_bounding_sphere();
+    let radius =
+        na::distance(bsphere.center(), &motion.local_center) + bsphere.radius() + target_distance;
+    let half_extents = Vector::repeat(radius);
+    let start = motion.position_at_time(N::zero()) * motion.local_center;
+    let end = start + motion.linvel * max_toi;
+
+    AABB::from_half_extents(start, half_extents).merged(&AABB::from_half_extents(end, half_extents))
+}
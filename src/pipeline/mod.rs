@@ -4,10 +4,14 @@ pub use self::broad_phase::*;
 pub use self::glue::*;
 pub use self::narrow_phase::*;
 pub use self::object::*;
+#[cfg(feature = "serde-serialize")]
+pub use self::snapshot::{CollisionWorldSnapshot, CollisionWorldSnapshotError};
 pub use self::world::*;
 
 pub mod broad_phase;
 pub mod glue;
 pub mod narrow_phase;
 pub mod object;
+#[cfg(feature = "serde-serialize")]
+mod snapshot;
 pub mod world;
@@ -0,0 +1,386 @@
+use crate::math::{Isometry, Point, Vector};
+use crate::pipeline::narrow_phase::{ContactDispatcher, ContactManifoldGenerator};
+use crate::query::algorithms::gjk::GJKResult;
+use crate::query::algorithms::VoronoiSimplex;
+use crate::query::{
+    self, Contact, ContactKinematic, ContactManifold, ContactPrediction, ContactPreprocessor,
+    NeighborhoodGeometry,
+};
+use crate::shape::{
+    Ball, ClippingCache, Cone, ConvexPolygonalFeature, Cylinder, FeatureId, Plane, Shape,
+    SupportMap,
+};
+use na::{self, RealField, Unit};
+use std::mem;
+
+/// A cylinder or a cone.
+#[derive(Copy, Clone)]
+enum FlatCapped<'a, N: RealField> {
+    Cylinder(&'a Cylinder<N>),
+    Cone(&'a Cone<N>),
+}
+
+impl<'a, N: RealField> FlatCapped<'a, N> {
+    fn from_shape(shape: &'a dyn Shape<N>) -> Option<Self> {
+        if let Some(cylinder) = shape.as_shape::<Cylinder<N>>() {
+            Some(FlatCapped::Cylinder(cylinder))
+        } else {
+            shape.as_shape::<Cone<N>>().map(FlatCapped::Cone)
+        }
+    }
+
+    fn as_support_map(self) -> &'a dyn SupportMap<N> {
+        match self {
+            FlatCapped::Cylinder(cylinder) => cylinder,
+            FlatCapped::Cone(cone) => cone,
+        }
+    }
+
+    fn support_face_toward(
+        self,
+        m: &Isometry<N>,
+        dir: &Unit<Vector<N>>,
+        out: &mut ConvexPolygonalFeature<N>,
+    ) {
+        match self {
+            FlatCapped::Cylinder(cylinder) => cylinder.support_face_toward(m, dir, out),
+            FlatCapped::Cone(cone) => cone.support_face_toward(m, dir, out),
+        }
+    }
+
+    fn support_feature_toward(
+        self,
+        m: &Isometry<N>,
+        dir: &Unit<Vector<N>>,
+        eps: N,
+        out: &mut ConvexPolygonalFeature<N>,
+    ) {
+        match self {
+            FlatCapped::Cylinder(cylinder) => cylinder.support_feature_toward(m, dir, eps, out),
+            FlatCapped::Cone(cone) => cone.support_feature_toward(m, dir, eps, out),
+        }
+    }
+
+    // The local approximation of the given feature at the point `local` of its surface.
+    fn neighborhood_geometry(
+        self,
+        feature: FeatureId,
+        local: &Point<N>,
+    ) -> NeighborhoodGeometry<N> {
+        let radial = Vector::new(local.x, N::zero(), local.z);
+        let rim_tangent = |radial: &Vector<N>| {
+            Unit::try_new(Vector::y().cross(radial), N::default_epsilon())
+                .map(NeighborhoodGeometry::Line)
+                .unwrap_or(NeighborhoodGeometry::Point)
+        };
+
+        match (self, feature) {
+            (FlatCapped::Cylinder(_), FeatureId::Face(0)) => {
+                NeighborhoodGeometry::Plane(Vector::y_axis())
+            }
+            (FlatCapped::Cylinder(_), FeatureId::Face(1))
+            | (FlatCapped::Cone(_), FeatureId::Face(0)) => {
+                NeighborhoodGeometry::Plane(-Vector::y_axis())
+            }
+            (FlatCapped::Cylinder(_), FeatureId::Face(2)) => {
+                Unit::try_new(radial, N::default_epsilon())
+                    .map(NeighborhoodGeometry::Plane)
+                    .unwrap_or(NeighborhoodGeometry::Point)
+            }
+            (FlatCapped::Cone(cone), FeatureId::Face(1)) => cone
+                .lateral_normal(&radial)
+                .map(NeighborhoodGeometry::Plane)
+                .unwrap_or(NeighborhoodGeometry::Point),
+            (FlatCapped::Cone(_), FeatureId::Vertex(0)) => NeighborhoodGeometry::Point,
+            // The other vertices are samples of the rims.
+            (_, FeatureId::Edge(_)) | (_, FeatureId::Vertex(_)) => rim_tangent(&radial),
+            _ => NeighborhoodGeometry::Point,
+        }
+    }
+}
+
+/// Collision detector between a cylinder or a cone, and a plane or a support-mapped shape.
+///
+/// This generates multiple contacts when a flat cap lies on a plane or on a face of a
+/// convex polyhedron.
+#[derive(Clone)]
+pub struct CylinderConeShapeManifoldGenerator<N: RealField> {
+    flip: bool,
+    simplex: VoronoiSimplex<N>,
+    last_gjk_dir: Option<Unit<Vector<N>>>,
+    clip_cache: ClippingCache<N>,
+    new_contacts: Vec<(Contact<N>, FeatureId, FeatureId)>,
+    manifold1: ConvexPolygonalFeature<N>,
+    manifold2: ConvexPolygonalFeature<N>,
+}
+
+impl<N: RealField> CylinderConeShapeManifoldGenerator<N> {
+    /// Creates a new contact generator between a cylinder or a cone, and another shape.
+    ///
+    /// If `flip` is `true`, the cylinder or the cone is expected to be the second shape.
+    pub fn new(flip: bool) -> Self {
+        CylinderConeShapeManifoldGenerator {
+            flip,
+            simplex: VoronoiSimplex::new(),
+            last_gjk_dir: None,
+            clip_cache: ClippingCache::new(),
+            new_contacts: Vec::new(),
+            manifold1: ConvexPolygonalFeature::new(),
+            manifold2: ConvexPolygonalFeature::new(),
+        }
+    }
+
+    fn generate_plane_contacts(
+        &mut self,
+        ma: &Isometry<N>,
+        a: FlatCapped<N>,
+        mb: &Isometry<N>,
+        plane: &Plane<N>,
+        prediction: &ContactPrediction<N>,
+    ) {
+        let plane_normal = mb * plane.normal;
+        let plane_center = Point::from(mb.translation.vector);
+
+        a.support_face_toward(ma, &-plane_normal, &mut self.manifold1);
+
+        for (world1, f1) in self
+            .manifold1
+            .vertices
+            .iter()
+            .zip(self.manifold1.vertices_id.iter())
+        {
+            let dist = (*world1 - plane_center).dot(&plane_normal);
+
+            if dist <= prediction.linear() {
+                let world2 = *world1 + (-*plane_normal * dist);
+                let contact = Contact::new(*world1, world2, -plane_normal, -dist);
+                self.new_contacts.push((contact, *f1, FeatureId::Face(0)));
+            }
+        }
+    }
+
+    fn generate_support_map_contacts(
+        &mut self,
+        ma: &Isometry<N>,
+        a: FlatCapped<N>,
+        mb: &Isometry<N>,
+        b: &dyn Shape<N>,
+        prediction: &ContactPrediction<N>,
+    ) {
+        let sm2 = match b.as_support_map() {
+            Some(sm2) => sm2,
+            None => return,
+        };
+
+        let contact = query::contact_support_map_support_map_with_params(
+            ma,
+            a.as_support_map(),
+            mb,
+            sm2,
+            prediction.linear(),
+            &mut self.simplex,
+            self.last_gjk_dir,
+        );
+
+        match contact {
+            GJKResult::ClosestPoints(world1, world2, dir) => {
+                self.last_gjk_dir = Some(dir);
+                let contact = Contact::new_wo_depth(world1, world2, dir);
+
+                let penetrating = contact.depth > na::zero();
+
+                if penetrating {
+                    a.support_face_toward(ma, &contact.normal, &mut self.manifold1);
+                } else {
+                    a.support_feature_toward(
+                        ma,
+                        &contact.normal,
+                        prediction.angular1(),
+                        &mut self.manifold1,
+                    );
+                }
+
+                if let Some(cp2) = b.as_convex_polyhedron() {
+                    if penetrating {
+                        cp2.support_face_toward(mb, &-contact.normal, &mut self.manifold2);
+                    } else {
+                        cp2.support_feature_toward(
+                            mb,
+                            &-contact.normal,
+                            prediction.angular2(),
+                            &mut self.manifold2,
+                        );
+                    }
+                } else if let Some(b) = FlatCapped::from_shape(b) {
+                    if penetrating {
+                        b.support_face_toward(mb, &-contact.normal, &mut self.manifold2);
+                    } else {
+                        b.support_feature_toward(
+                            mb,
+                            &-contact.normal,
+                            prediction.angular2(),
+                            &mut self.manifold2,
+                        );
+                    }
+                } else {
+                    self.manifold2.clear();
+                }
+
+                if !self.manifold2.vertices.is_empty() {
+                    self.manifold1.clip(
+                        &self.manifold2,
+                        &contact.normal,
+                        prediction,
+                        &mut self.clip_cache,
+                        &mut self.new_contacts,
+                    );
+                }
+
+                if self.new_contacts.is_empty() {
+                    let f2 = if self.manifold2.vertices.is_empty() {
+                        FeatureId::Face(0)
+                    } else {
+                        self.manifold2.feature_id
+                    };
+
+                    self.new_contacts
+                        .push((contact, self.manifold1.feature_id, f2));
+                }
+            }
+            GJKResult::NoIntersection(dir) => self.last_gjk_dir = Some(dir),
+            _ => {}
+        }
+    }
+
+    // The local approximation of the second shape at the point `local`.
+    fn neighborhood_geometry2(
+        &self,
+        m: &Isometry<N>,
+        b: &dyn Shape<N>,
+        feature: FeatureId,
+        local: &Point<N>,
+    ) -> Option<NeighborhoodGeometry<N>> {
+        if let Some(plane) = b.as_shape::<Plane<N>>() {
+            return Some(NeighborhoodGeometry::Plane(plane.normal));
+        }
+
+        if let Some(b) = FlatCapped::from_shape(b) {
+            return Some(b.neighborhood_geometry(feature, local));
+        }
+
+        if b.as_convex_polyhedron().is_none() {
+            return Some(NeighborhoodGeometry::Point);
+        }
+
+        match feature {
+            FeatureId::Face(..) => self
+                .manifold2
+                .normal
+                .map(|n| NeighborhoodGeometry::Plane(m.inverse_transform_unit_vector(&n))),
+            FeatureId::Edge(..) => self
+                .manifold2
+                .edge(feature)
+                .and_then(|e| e.direction())
+                .map(|dir| NeighborhoodGeometry::Line(m.inverse_transform_unit_vector(&dir))),
+            FeatureId::Vertex(..) => Some(NeighborhoodGeometry::Point),
+            FeatureId::Unknown => None,
+        }
+    }
+
+    fn do_generate(
+        &mut self,
+        ma: &Isometry<N>,
+        a: &dyn Shape<N>,
+        proca: Option<&dyn ContactPreprocessor<N>>,
+        mb: &Isometry<N>,
+        b: &dyn Shape<N>,
+        procb: Option<&dyn ContactPreprocessor<N>>,
+        prediction: &ContactPrediction<N>,
+        manifold: &mut ContactManifold<N>,
+    ) -> bool {
+        let a = match FlatCapped::from_shape(a) {
+            Some(a) => a,
+            None => return false,
+        };
+
+        self.new_contacts.clear();
+        self.manifold1.clear();
+        self.manifold2.clear();
+
+        if let Some(plane) = b.as_shape::<Plane<N>>() {
+            self.generate_plane_contacts(ma, a, mb, plane, prediction);
+        } else if b.is_support_map() {
+            self.generate_support_map_contacts(ma, a, mb, b, prediction);
+        } else {
+            return false;
+        }
+
+        let ball_radius = b.as_shape::<Ball<N>>().map(|ball| ball.radius);
+
+        let mut new_contacts = mem::replace(&mut self.new_contacts, Vec::new());
+
+        for (mut contact, f1, f2) in new_contacts.drain(..) {
+            let local1 = ma.inverse_transform_point(&contact.world1);
+            let mut local2 = mb.inverse_transform_point(&contact.world2);
+            let approx1 = a.neighborhood_geometry(f1, &local1);
+            let approx2 = if ball_radius.is_some() {
+                // Balls are approximated by their center dilated by their radius.
+                local2 = Point::origin();
+                Some(NeighborhoodGeometry::Point)
+            } else {
+                self.neighborhood_geometry2(mb, b, f2, &local2)
+            };
+            let approx2 = match approx2 {
+                Some(approx2) => approx2,
+                None => continue,
+            };
+
+            let mut kinematic = ContactKinematic::new();
+
+            if !self.flip {
+                kinematic.set_approx1(f1, local1, approx1);
+                kinematic.set_approx2(f2, local2, approx2);
+
+                if let Some(radius) = ball_radius {
+                    kinematic.set_dilation2(radius);
+                }
+
+                let _ = manifold.push(contact, kinematic, local1, proca, procb);
+            } else {
+                contact.flip();
+                kinematic.set_approx1(f2, local2, approx2);
+                kinematic.set_approx2(f1, local1, approx1);
+
+                if let Some(radius) = ball_radius {
+                    kinematic.set_dilation1(radius);
+                }
+
+                let _ = manifold.push(contact, kinematic, local1, procb, proca);
+            }
+        }
+
+        self.new_contacts = new_contacts;
+        true
+    }
+}
+
+impl<N: RealField> ContactManifoldGenerator<N> for CylinderConeShapeManifoldGenerator<N> {
+    fn generate_contacts(
+        &mut self,
+        _: &dyn ContactDispatcher<N>,
+        m1: &Isometry<N>,
+        g1: &dyn Shape<N>,
+        proc1: Option<&dyn ContactPreprocessor<N>>,
+        m2: &Isometry<N>,
+        g2: &dyn Shape<N>,
+        proc2: Option<&dyn ContactPreprocessor<N>>,
+        prediction: &ContactPrediction<N>,
+        manifold: &mut ContactManifold<N>,
+    ) -> bool {
+        if !self.flip {
+            self.do_generate(m1, g1, proc1, m2, g2, proc2, prediction, manifold)
+        } else {
+            self.do_generate(m2, g2, proc2, m1, g1, proc1, prediction, manifold)
+        }
+    }
+}
@@ -1,16 +1,20 @@
 #[cfg(feature = "dim3")]
-use crate::pipeline::narrow_phase::TriMeshTriMeshManifoldGenerator;
+use crate::pipeline::narrow_phase::{
+    CylinderConeShapeManifoldGenerator, TorusShapeManifoldGenerator,
+    TriMeshTriMeshManifoldGenerator,
+};
 use crate::pipeline::{
     BallBallManifoldGenerator, BallConvexPolyhedronManifoldGenerator,
     CapsuleCapsuleManifoldGenerator, CapsuleShapeManifoldGenerator,
     CompositeShapeCompositeShapeManifoldGenerator, CompositeShapeShapeManifoldGenerator,
     ContactAlgorithm, ContactDispatcher, ConvexPolyhedronConvexPolyhedronManifoldGenerator,
     HeightFieldShapeManifoldGenerator, PlaneBallManifoldGenerator,
-    PlaneConvexPolyhedronManifoldGenerator,
+    PlaneConvexPolyhedronManifoldGenerator, RoundShapeShapeManifoldGenerator,
+    VoxelsShapeManifoldGenerator,
 };
+use crate::shape::{Ball, Capsule, HeightField, Plane, Shape, Voxels};
 #[cfg(feature = "dim3")]
-use crate::shape::TriMesh;
-use crate::shape::{Ball, Capsule, HeightField, Plane, Shape};
+use crate::shape::{Cone, Cylinder, Torus, TriMesh};
 use na::RealField;
 
 /// Collision dispatcher for shapes defined by `ncollide_entities`.
@@ -35,8 +39,12 @@ impl<N: RealField> ContactDispatcher<N> for DefaultContactDispatcher {
         let b_is_plane = b.is_shape::<Plane<N>>();
         let a_is_capsule = a.is_shape::<Capsule<N>>();
         let b_is_capsule = b.is_shape::<Capsule<N>>();
+        let a_is_round = a.is_round_shape();
+        let b_is_round = b.is_round_shape();
         let a_is_heightfield = a.is_shape::<HeightField<N>>();
         let b_is_heightfield = b.is_shape::<HeightField<N>>();
+        let a_is_voxels = a.is_shape::<Voxels<N>>();
+        let b_is_voxels = b.is_shape::<Voxels<N>>();
 
         #[cfg(feature = "dim3")]
         {
@@ -46,12 +54,49 @@ impl<N: RealField> ContactDispatcher<N> for DefaultContactDispatcher {
             if a_is_trimesh && b_is_trimesh {
                 return Some(Box::new(TriMeshTriMeshManifoldGenerator::<N>::new()));
             }
+
+            let a_is_torus = a.is_shape::<Torus<N>>();
+            let b_is_torus = b.is_shape::<Torus<N>>();
+
+            if a_is_torus && (b_is_plane || b.is_support_map()) {
+                return Some(Box::new(TorusShapeManifoldGenerator::<N>::new(false)));
+            } else if b_is_torus && (a_is_plane || a.is_support_map()) {
+                return Some(Box::new(TorusShapeManifoldGenerator::<N>::new(true)));
+            }
+
+            let a_is_flat_capped = a.is_shape::<Cylinder<N>>() || a.is_shape::<Cone<N>>();
+            let b_is_flat_capped = b.is_shape::<Cylinder<N>>() || b.is_shape::<Cone<N>>();
+
+            // Capsules and round shapes are handled by their specific contact generators.
+            if a_is_flat_capped
+                && !b_is_capsule
+                && !b_is_round
+                && (b_is_plane || b.is_support_map())
+            {
+                return Some(Box::new(CylinderConeShapeManifoldGenerator::<N>::new(
+                    false,
+                )));
+            } else if b_is_flat_capped
+                && !a_is_capsule
+                && !a_is_round
+                && (a_is_plane || a.is_support_map())
+            {
+                return Some(Box::new(CylinderConeShapeManifoldGenerator::<N>::new(true)));
+            }
         }
 
         if a_is_heightfield || b_is_heightfield {
             return Some(Box::new(HeightFieldShapeManifoldGenerator::<N>::new(
                 b_is_heightfield,
             )));
+        } else if a_is_voxels || b_is_voxels {
+            Some(Box::new(VoxelsShapeManifoldGenerator::<N>::new(
+                b_is_voxels,
+            )))
+        } else if a_is_round {
+            Some(Box::new(RoundShapeShapeManifoldGenerator::<N>::new(false)))
+        } else if b_is_round {
+            Some(Box::new(RoundShapeShapeManifoldGenerator::<N>::new(true)))
         } else if a_is_capsule && b_is_capsule {
             Some(Box::new(CapsuleCapsuleManifoldGenerator::<N>::new()))
         } else if a_is_capsule || b_is_capsule {
@@ -10,12 +10,18 @@ pub use self::contact_manifold_generator::{
     ContactAlgorithm, ContactDispatcher, ContactManifoldGenerator,
 };
 pub use self::convex_polyhedron_convex_polyhedron_manifold_generator::ConvexPolyhedronConvexPolyhedronManifoldGenerator;
+#[cfg(feature = "dim3")]
+pub use self::cylinder_cone_shape_manifold_generator::CylinderConeShapeManifoldGenerator;
 pub use self::default_contact_dispatcher::DefaultContactDispatcher;
 pub use self::heightfield_shape_manifold_generator::HeightFieldShapeManifoldGenerator;
 pub use self::plane_ball_manifold_generator::PlaneBallManifoldGenerator;
 pub use self::plane_convex_polyhedron_manifold_generator::PlaneConvexPolyhedronManifoldGenerator;
+pub use self::round_shape_shape_manifold_generator::RoundShapeShapeManifoldGenerator;
+#[cfg(feature = "dim3")]
+pub use self::torus_shape_manifold_generator::TorusShapeManifoldGenerator;
 #[cfg(feature = "dim3")]
 pub use self::trimesh_trimesh_manifold_generator::TriMeshTriMeshManifoldGenerator;
+pub use self::voxels_shape_manifold_generator::VoxelsShapeManifoldGenerator;
 
 // // FIXME: un-hide this and move everything to a folder.
 mod ball_ball_manifold_generator;
@@ -27,9 +33,15 @@ mod composite_shape_shape_manifold_generator;
 #[doc(hidden)]
 pub mod contact_manifold_generator;
 mod convex_polyhedron_convex_polyhedron_manifold_generator;
+#[cfg(feature = "dim3")]
+mod cylinder_cone_shape_manifold_generator;
 mod default_contact_dispatcher;
 mod heightfield_shape_manifold_generator;
 mod plane_ball_manifold_generator;
 mod plane_convex_polyhedron_manifold_generator;
+mod round_shape_shape_manifold_generator;
+#[cfg(feature = "dim3")]
+mod torus_shape_manifold_generator;
 #[cfg(feature = "dim3")]
 mod trimesh_trimesh_manifold_generator;
+mod voxels_shape_manifold_generator;
@@ -0,0 +1,110 @@
+use crate::math::Isometry;
+use crate::pipeline::{ContactAlgorithm, ContactDispatcher, ContactManifoldGenerator};
+use crate::query::{ContactManifold, ContactPrediction, ContactPreprocessor};
+use crate::shape::round_shape::RoundShapeContactPreprocessor;
+use crate::shape::Shape;
+use na::RealField;
+
+/// Collision detector between a round shape and another shape.
+///
+/// The contacts are computed with the inner shape of the round shape, and offset by its
+/// border radius. Thus, the contact manifolds are as stable as the ones of the inner shape.
+pub struct RoundShapeShapeManifoldGenerator<N: RealField> {
+    sub_detector: Option<ContactAlgorithm<N>>,
+    flip: bool,
+}
+
+impl<N: RealField> RoundShapeShapeManifoldGenerator<N> {
+    /// Creates a new collision detector between a round shape and another shape.
+    ///
+    /// If `flip` is `true`, the round shape is expected to be the second shape.
+    pub fn new(flip: bool) -> RoundShapeShapeManifoldGenerator<N> {
+        RoundShapeShapeManifoldGenerator {
+            sub_detector: None,
+            flip,
+        }
+    }
+
+    fn do_update(
+        &mut self,
+        dispatcher: &dyn ContactDispatcher<N>,
+        m1: &Isometry<N>,
+        (inner1, border_radius1): (&dyn Shape<N>, N),
+        proc1: Option<&dyn ContactPreprocessor<N>>,
+        m2: &Isometry<N>,
+        g2: &dyn Shape<N>,
+        proc2: Option<&dyn ContactPreprocessor<N>>,
+        prediction: &ContactPrediction<N>,
+        manifold: &mut ContactManifold<N>,
+        flip: bool,
+    ) -> bool {
+        let mut prediction = prediction.clone();
+        let new_linear_prediction = prediction.linear() + border_radius1;
+        prediction.set_linear(new_linear_prediction);
+
+        if self.sub_detector.is_none() {
+            self.sub_detector = if flip {
+                dispatcher.get_contact_algorithm(g2, inner1)
+            } else {
+                dispatcher.get_contact_algorithm(inner1, g2)
+            }
+        }
+
+        let sub_detector = match self.sub_detector.as_mut() {
+            Some(sub_detector) => sub_detector,
+            None => return false,
+        };
+        let preprocessor = RoundShapeContactPreprocessor::new(border_radius1);
+
+        if flip {
+            sub_detector.generate_contacts(
+                dispatcher,
+                m2,
+                g2,
+                proc2,
+                m1,
+                inner1,
+                Some(&(proc1, &preprocessor)),
+                &prediction,
+                manifold,
+            )
+        } else {
+            sub_detector.generate_contacts(
+                dispatcher,
+                m1,
+                inner1,
+                Some(&(proc1, &preprocessor)),
+                m2,
+                g2,
+                proc2,
+                &prediction,
+                manifold,
+            )
+        }
+    }
+}
+
+impl<N: RealField> ContactManifoldGenerator<N> for RoundShapeShapeManifoldGenerator<N> {
+    fn generate_contacts(
+        &mut self,
+        d: &dyn ContactDispatcher<N>,
+        ma: &Isometry<N>,
+        a: &dyn Shape<N>,
+        proc1: Option<&dyn ContactPreprocessor<N>>,
+        mb: &Isometry<N>,
+        b: &dyn Shape<N>,
+        proc2: Option<&dyn ContactPreprocessor<N>>,
+        prediction: &ContactPrediction<N>,
+        manifold: &mut ContactManifold<N>,
+    ) -> bool {
+        if !self.flip {
+            if let Some(rs) = a.as_round_shape() {
+                return self.do_update(d, ma, rs, proc1, mb, b, proc2, prediction, manifold, false);
+            }
+        } else if let Some(rs) = b.as_round_shape() {
+            return self.do_update(d, mb, rs, proc2, ma, a, proc1, prediction, manifold, true);
+        }
+
+        false
+    }
+}
@@ -0,0 +1,138 @@
+use crate::math::{Isometry, Translation};
+use crate::pipeline::{ContactAlgorithm, ContactDispatcher, ContactManifoldGenerator};
+use crate::query::{ContactManifold, ContactPrediction, ContactPreprocessor, ContactTrackingMode};
+use crate::shape::torus::{self, TorusContactPreprocessor};
+use crate::shape::{Ball, Shape, Torus};
+use na::{self, RealField};
+
+/// Collision detector between a torus and a support-mapped shape or a plane.
+///
+/// A torus is the set of balls of radius `minor_radius` centered on the circle at the center of
+/// its tube. The contacts are generated for the balls locally deepest into the other shape, by
+/// sampling this circle at the angles of the slices of the torus.
+pub struct TorusShapeManifoldGenerator<N: RealField> {
+    sub_detector: Option<ContactAlgorithm<N>>,
+    flip: bool,
+}
+
+impl<N: RealField> TorusShapeManifoldGenerator<N> {
+    /// Creates a new collision detector between a torus and another shape.
+    ///
+    /// If `flip` is `true`, the torus is expected to be the second shape.
+    pub fn new(flip: bool) -> TorusShapeManifoldGenerator<N> {
+        TorusShapeManifoldGenerator {
+            sub_detector: None,
+            flip,
+        }
+    }
+
+    fn do_update(
+        &mut self,
+        dispatcher: &dyn ContactDispatcher<N>,
+        m1: &Isometry<N>,
+        g1: &Torus<N>,
+        proc1: Option<&dyn ContactPreprocessor<N>>,
+        m2: &Isometry<N>,
+        g2: &dyn Shape<N>,
+        proc2: Option<&dyn ContactPreprocessor<N>>,
+        prediction: &ContactPrediction<N>,
+        manifold: &mut ContactManifold<N>,
+        flip: bool,
+    ) -> bool {
+        let ball = Ball::new(g1.minor_radius);
+
+        if self.sub_detector.is_none() {
+            self.sub_detector = if flip {
+                dispatcher.get_contact_algorithm(g2, &ball)
+            } else {
+                dispatcher.get_contact_algorithm(&ball, g2)
+            }
+        }
+
+        let sub_detector = match self.sub_detector.as_mut() {
+            Some(sub_detector) => sub_detector,
+            None => return false,
+        };
+
+        let mut result = true;
+
+        let angles = match torus::deepest_core_angles(m1, g1, m2, g2, prediction.linear()) {
+            Some(angles) => angles,
+            None => return false,
+        };
+
+        for angle in angles {
+            let core = g1.core_point(angle);
+            let ball_pos = Isometry::from(Translation::from(core.coords));
+            let preprocessor = TorusContactPreprocessor::new(ball_pos, g1.slice_feature_id(&core));
+            let world_ball_pos = m1 * ball_pos;
+
+            let generated = if flip {
+                sub_detector.generate_contacts(
+                    dispatcher,
+                    m2,
+                    g2,
+                    proc2,
+                    &world_ball_pos,
+                    &ball,
+                    Some(&(proc1, &preprocessor)),
+                    prediction,
+                    manifold,
+                )
+            } else {
+                sub_detector.generate_contacts(
+                    dispatcher,
+                    &world_ball_pos,
+                    &ball,
+                    Some(&(proc1, &preprocessor)),
+                    m2,
+                    g2,
+                    proc2,
+                    prediction,
+                    manifold,
+                )
+            };
+
+            result = result && generated;
+        }
+
+        result
+    }
+}
+
+impl<N: RealField> ContactManifoldGenerator<N> for TorusShapeManifoldGenerator<N> {
+    fn generate_contacts(
+        &mut self,
+        d: &dyn ContactDispatcher<N>,
+        ma: &Isometry<N>,
+        a: &dyn Shape<N>,
+        proc1: Option<&dyn ContactPreprocessor<N>>,
+        mb: &Isometry<N>,
+        b: &dyn Shape<N>,
+        proc2: Option<&dyn ContactPreprocessor<N>>,
+        prediction: &ContactPrediction<N>,
+        manifold: &mut ContactManifold<N>,
+    ) -> bool {
+        if !self.flip {
+            if let Some(torus) = a.as_shape::<Torus<N>>() {
+                return self.do_update(
+                    d, ma, torus, proc1, mb, b, proc2, prediction, manifold, false,
+                );
+            }
+        } else if let Some(torus) = b.as_shape::<Torus<N>>() {
+            return self.do_update(
+                d, mb, torus, proc2, ma, a, proc1, prediction, manifold, true,
+            );
+        }
+
+        false
+    }
+
+    fn init_manifold(&self) -> ContactManifold<N> {
+        // All the balls have the same local frame so the contacts are told apart by the slices
+        // of the torus they are attached to.
+        let mut res = ContactManifold::new();
+        res.set_tracking_mode(ContactTrackingMode::FeatureBased);
+        res
+    }
+}
@@ -0,0 +1,39 @@
+use crate::math::{Isometry, Point};
+use crate::pipeline::narrow_phase::{DistanceDetector, DistanceDispatcher};
+use crate::query::{self, ClosestPoints};
+use crate::shape::{Ball, Shape};
+use na::RealField;
+
+/// Distance detector between two balls.
+#[derive(Clone)]
+pub struct BallBallDistanceDetector {}
+
+impl BallBallDistanceDetector {
+    /// Creates a new persistent distance detector between two balls.
+    #[inline]
+    pub fn new() -> BallBallDistanceDetector {
+        BallBallDistanceDetector {}
+    }
+}
+
+impl<N: RealField> DistanceDetector<N> for BallBallDistanceDetector {
+    fn update(
+        &mut self,
+        _: &dyn DistanceDispatcher<N>,
+        ma: &Isometry<N>,
+        a: &dyn Shape<N>,
+        mb: &Isometry<N>,
+        b: &dyn Shape<N>,
+        max_dist: N,
+    ) -> Option<ClosestPoints<N>> {
+        let a = a.as_shape::<Ball<N>>()?;
+        let b = b.as_shape::<Ball<N>>()?;
+        Some(query::closest_points_ball_ball(
+            &Point::from(ma.translation.vector),
+            a,
+            &Point::from(mb.translation.vector),
+            b,
+            max_dist,
+        ))
+    }
+}
@@ -0,0 +1,47 @@
+use crate::math::Isometry;
+use crate::pipeline::narrow_phase::{DistanceDetector, DistanceDispatcher};
+use crate::query::{self, ClosestPoints};
+use crate::shape::Shape;
+use na::RealField;
+
+/// Distance detector between a composite shape and another shape.
+///
+/// The closest points are recomputed from scratch at each update using the bounding volume
+/// hierarchy of the composite shape.
+#[derive(Clone)]
+pub struct CompositeShapeShapeDistanceDetector {
+    flip: bool,
+}
+
+impl CompositeShapeShapeDistanceDetector {
+    /// Creates a new distance detector between a composite shape and another shape.
+    ///
+    /// If `flip` is `true`, the composite shape is expected to be the second shape given to `update`.
+    pub fn new(flip: bool) -> CompositeShapeShapeDistanceDetector {
+        CompositeShapeShapeDistanceDetector { flip }
+    }
+}
+
+impl<N: RealField> DistanceDetector<N> for CompositeShapeShapeDistanceDetector {
+    fn update(
+        &mut self,
+        _: &dyn DistanceDispatcher<N>,
+        ma: &Isometry<N>,
+        a: &dyn Shape<N>,
+        mb: &Isometry<N>,
+        b: &dyn Shape<N>,
+        max_dist: N,
+    ) -> Option<ClosestPoints<N>> {
+        if !self.flip {
+            let cs = a.as_composite_shape()?;
+            Some(query::closest_points_composite_shape_shape(
+                ma, cs, mb, b, max_dist,
+            ))
+        } else {
+            let cs = b.as_composite_shape()?;
+            Some(query::closest_points_shape_composite_shape(
+                ma, a, mb, cs, max_dist,
+            ))
+        }
+    }
+}
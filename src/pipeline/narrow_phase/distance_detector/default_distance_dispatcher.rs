@@ -0,0 +1,44 @@
+use crate::pipeline::narrow_phase::distance_detector::{
+    BallBallDistanceDetector, CompositeShapeShapeDistanceDetector, DistanceAlgorithm,
+    DistanceDispatcher, PlaneSupportMapDistanceDetector, SupportMapPlaneDistanceDetector,
+    SupportMapSupportMapDistanceDetector,
+};
+use crate::shape::{Ball, Plane, Shape};
+use na::RealField;
+
+/// Distance dispatcher for shapes defined by `ncollide_entities`.
+pub struct DefaultDistanceDispatcher {}
+
+impl DefaultDistanceDispatcher {
+    /// Creates a new basic distance dispatcher.
+    pub fn new() -> DefaultDistanceDispatcher {
+        DefaultDistanceDispatcher {}
+    }
+}
+
+impl<N: RealField> DistanceDispatcher<N> for DefaultDistanceDispatcher {
+    fn get_distance_algorithm(
+        &self,
+        a: &dyn Shape<N>,
+        b: &dyn Shape<N>,
+    ) -> Option<DistanceAlgorithm<N>> {
+        let a_is_ball = a.is_shape::<Ball<N>>();
+        let b_is_ball = b.is_shape::<Ball<N>>();
+
+        if a_is_ball && b_is_ball {
+            Some(Box::new(BallBallDistanceDetector::new()))
+        } else if a.is_shape::<Plane<N>>() && b.is_support_map() {
+            Some(Box::new(PlaneSupportMapDistanceDetector::new()))
+        } else if b.is_shape::<Plane<N>>() && a.is_support_map() {
+            Some(Box::new(SupportMapPlaneDistanceDetector::new()))
+        } else if a.is_support_map() && b.is_support_map() {
+            Some(Box::new(SupportMapSupportMapDistanceDetector::new()))
+        } else if a.is_composite_shape() {
+            Some(Box::new(CompositeShapeShapeDistanceDetector::new(false)))
+        } else if b.is_composite_shape() {
+            Some(Box::new(CompositeShapeShapeDistanceDetector::new(true)))
+        } else {
+            None
+        }
+    }
+}
@@ -0,0 +1,34 @@
+use crate::math::Isometry;
+use crate::query::ClosestPoints;
+use crate::shape::Shape;
+use na::RealField;
+use std::any::Any;
+
+/// Trait implemented by algorithms that compute the closest points between two objects.
+pub trait DistanceDetector<N: RealField>: Any + Send + Sync {
+    /// Runs the closest points computation on two objects. It is assumed that the same distance
+    /// detector (the same instance) is always used with the same pair of object.
+    ///
+    /// The closest points are computed only if the objects are separated by a distance smaller
+    /// than `max_dist`.
+    fn update(
+        &mut self,
+        dispatcher: &dyn DistanceDispatcher<N>,
+        ma: &Isometry<N>,
+        a: &dyn Shape<N>,
+        mb: &Isometry<N>,
+        b: &dyn Shape<N>,
+        max_dist: N,
+    ) -> Option<ClosestPoints<N>>;
+}
+
+pub type DistanceAlgorithm<N> = Box<dyn DistanceDetector<N>>;
+
+pub trait DistanceDispatcher<N>: Any + Send + Sync {
+    /// Allocate a distance algorithm corresponding to the given pair of shapes.
+    fn get_distance_algorithm(
+        &self,
+        a: &dyn Shape<N>,
+        b: &dyn Shape<N>,
+    ) -> Option<DistanceAlgorithm<N>>;
+}
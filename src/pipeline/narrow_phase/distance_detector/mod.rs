@@ -0,0 +1,18 @@
+//! Persistant distance computation algorithms.
+
+pub use self::ball_ball_distance_detector::BallBallDistanceDetector;
+pub use self::composite_shape_shape_distance_detector::CompositeShapeShapeDistanceDetector;
+pub use self::default_distance_dispatcher::DefaultDistanceDispatcher;
+pub use self::distance_detector::{DistanceAlgorithm, DistanceDetector, DistanceDispatcher};
+pub use self::plane_support_map_distance_detector::{
+    PlaneSupportMapDistanceDetector, SupportMapPlaneDistanceDetector,
+};
+pub use self::support_map_support_map_distance_detector::SupportMapSupportMapDistanceDetector;
+
+mod ball_ball_distance_detector;
+mod composite_shape_shape_distance_detector;
+mod default_distance_dispatcher;
+#[doc(hidden)]
+pub mod distance_detector;
+mod plane_support_map_distance_detector;
+mod support_map_support_map_distance_detector;
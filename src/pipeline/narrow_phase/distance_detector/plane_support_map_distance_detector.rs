@@ -0,0 +1,71 @@
+use crate::math::Isometry;
+use crate::pipeline::narrow_phase::{DistanceDetector, DistanceDispatcher};
+use crate::query::{self, ClosestPoints};
+use crate::shape::{Plane, Shape};
+use na::RealField;
+
+/// Distance detector between a plane and a shape implementing the `SupportMap` trait.
+#[derive(Clone)]
+pub struct PlaneSupportMapDistanceDetector {}
+
+impl PlaneSupportMapDistanceDetector {
+    /// Creates a new persistent distance detector between a plane and a shape with a support
+    /// mapping function.
+    #[inline]
+    pub fn new() -> PlaneSupportMapDistanceDetector {
+        PlaneSupportMapDistanceDetector {}
+    }
+}
+
+/// Distance detector between a plane and a shape implementing the `SupportMap` trait.
+#[derive(Clone)]
+pub struct SupportMapPlaneDistanceDetector {
+    subdetector: PlaneSupportMapDistanceDetector,
+}
+
+impl SupportMapPlaneDistanceDetector {
+    /// Creates a new persistent distance detector between a plane and a shape with a support
+    /// mapping function.
+    #[inline]
+    pub fn new() -> SupportMapPlaneDistanceDetector {
+        SupportMapPlaneDistanceDetector {
+            subdetector: PlaneSupportMapDistanceDetector::new(),
+        }
+    }
+}
+
+impl<N: RealField> DistanceDetector<N> for PlaneSupportMapDistanceDetector {
+    #[inline]
+    fn update(
+        &mut self,
+        _: &dyn DistanceDispatcher<N>,
+        ma: &Isometry<N>,
+        plane: &dyn Shape<N>,
+        mb: &Isometry<N>,
+        b: &dyn Shape<N>,
+        max_dist: N,
+    ) -> Option<ClosestPoints<N>> {
+        let p = plane.as_shape::<Plane<N>>()?;
+        let sm = b.as_support_map()?;
+        Some(query::closest_points_plane_support_map(
+            ma, p, mb, sm, max_dist,
+        ))
+    }
+}
+
+impl<N: RealField> DistanceDetector<N> for SupportMapPlaneDistanceDetector {
+    #[inline]
+    fn update(
+        &mut self,
+        disp: &dyn DistanceDispatcher<N>,
+        ma: &Isometry<N>,
+        a: &dyn Shape<N>,
+        mb: &Isometry<N>,
+        b: &dyn Shape<N>,
+        max_dist: N,
+    ) -> Option<ClosestPoints<N>> {
+        let mut res = self.subdetector.update(disp, mb, b, ma, a, max_dist)?;
+        res.flip();
+        Some(res)
+    }
+}
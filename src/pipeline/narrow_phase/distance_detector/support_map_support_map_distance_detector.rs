@@ -0,0 +1,69 @@
+use crate::math::{Isometry, Vector};
+use crate::pipeline::narrow_phase::{DistanceDetector, DistanceDispatcher};
+use crate::query::algorithms::{gjk::GJKResult, VoronoiSimplex};
+use crate::query::{self, ClosestPoints};
+use crate::shape::Shape;
+use na::{RealField, Unit};
+
+/// Persistent distance detector between two shapes having a support mapping function.
+///
+/// It is based on the GJK algorithm, warm-started with the separating axis found at the
+/// previous update.
+#[derive(Clone)]
+pub struct SupportMapSupportMapDistanceDetector<N: RealField> {
+    simplex: VoronoiSimplex<N>,
+    sep_axis: Option<Unit<Vector<N>>>,
+}
+
+impl<N: RealField> SupportMapSupportMapDistanceDetector<N> {
+    /// Creates a new persistant distance detector between two shapes with support mapping
+    /// functions.
+    pub fn new() -> SupportMapSupportMapDistanceDetector<N> {
+        SupportMapSupportMapDistanceDetector {
+            simplex: VoronoiSimplex::new(),
+            sep_axis: None,
+        }
+    }
+}
+
+impl<N: RealField> DistanceDetector<N> for SupportMapSupportMapDistanceDetector<N> {
+    #[inline]
+    fn update(
+        &mut self,
+        _: &dyn DistanceDispatcher<N>,
+        ma: &Isometry<N>,
+        a: &dyn Shape<N>,
+        mb: &Isometry<N>,
+        b: &dyn Shape<N>,
+        max_dist: N,
+    ) -> Option<ClosestPoints<N>> {
+        let sma = a.as_support_map()?;
+        let smb = b.as_support_map()?;
+
+        let res = query::closest_points_support_map_support_map_with_params(
+            ma,
+            sma,
+            mb,
+            smb,
+            max_dist,
+            &mut self.simplex,
+            self.sep_axis.map(|axis| axis.into_inner()),
+        );
+
+        match res {
+            GJKResult::ClosestPoints(pt1, pt2, axis) => {
+                self.sep_axis = Some(axis);
+                Some(ClosestPoints::WithinMargin(pt1, pt2))
+            }
+            GJKResult::NoIntersection(axis) => {
+                self.sep_axis = Some(axis);
+                Some(ClosestPoints::Disjoint)
+            }
+            GJKResult::Intersection => {
+                self.sep_axis = None;
+                Some(ClosestPoints::Intersecting)
+            }
+            GJKResult::Proximity(_) => unreachable!(),
+        }
+    }
+}
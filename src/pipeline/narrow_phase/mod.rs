@@ -1,7 +1,5 @@
 //! Persistent collision detection algorithms to compute contact points.
 
-#[cfg(feature = "dim3")]
-pub use self::contact_generator::TriMeshTriMeshManifoldGenerator;
 #[doc(inline)]
 pub use self::contact_generator::{
     BallBallManifoldGenerator, BallConvexPolyhedronManifoldGenerator,
@@ -10,7 +8,19 @@ pub use self::contact_generator::{
     ContactAlgorithm, ContactDispatcher, ContactManifoldGenerator,
     ConvexPolyhedronConvexPolyhedronManifoldGenerator, DefaultContactDispatcher,
     HeightFieldShapeManifoldGenerator, PlaneBallManifoldGenerator,
-    PlaneConvexPolyhedronManifoldGenerator,
+    PlaneConvexPolyhedronManifoldGenerator, RoundShapeShapeManifoldGenerator,
+    VoxelsShapeManifoldGenerator,
+};
+#[cfg(feature = "dim3")]
+pub use self::contact_generator::{
+    CylinderConeShapeManifoldGenerator, TorusShapeManifoldGenerator,
+    TriMeshTriMeshManifoldGenerator,
+};
+#[doc(inline)]
+pub use self::distance_detector::{
+    BallBallDistanceDetector, CompositeShapeShapeDistanceDetector, DefaultDistanceDispatcher,
+    DistanceAlgorithm, DistanceDetector, DistanceDispatcher, PlaneSupportMapDistanceDetector,
+    SupportMapPlaneDistanceDetector, SupportMapSupportMapDistanceDetector,
 };
 pub use self::events::{ContactEvent, ContactEvents, EventPool, ProximityEvent, ProximityEvents};
 pub use self::interaction_graph::{
@@ -26,6 +36,8 @@ pub use self::proximity_detector::{
 
 #[doc(hidden)]
 pub mod contact_generator;
+#[doc(hidden)]
+pub mod distance_detector;
 mod events;
 mod interaction_graph;
 mod narrow_phase;
@@ -1,26 +1,35 @@
 use na::RealField;
 use slotmap::{Key, SlotMap};
 
+use crate::math::Isometry;
 use crate::pipeline::narrow_phase::{
-    ContactDispatcher, ContactEvent, ContactEvents, ContactManifoldGenerator, Interaction,
-    InteractionGraph, ProximityDetector, ProximityDispatcher, ProximityEvent, ProximityEvents,
+    ContactDispatcher, ContactEvent, ContactEvents, ContactManifoldGenerator,
+    DefaultDistanceDispatcher, DistanceDetector, DistanceDispatcher, Interaction, InteractionGraph,
+    ProximityDetector, ProximityDispatcher, ProximityEvent, ProximityEvents,
 };
 use crate::pipeline::object::{
     CollisionObjectHandle, CollisionObjectRef, CollisionObjectSet, GeometricQueryType,
 };
-use crate::query::{ContactId, ContactManifold, Proximity};
+use crate::query::{ClosestPoints, ContactId, ContactManifold, Proximity};
+use crate::shape::Shape;
 
 /// Collision detector dispatcher for collision objects.
 pub struct NarrowPhase<N: RealField, Handle: CollisionObjectHandle> {
     contact_dispatcher: Box<dyn ContactDispatcher<N>>,
     proximity_dispatcher: Box<dyn ProximityDispatcher<N>>,
+    distance_dispatcher: Box<dyn DistanceDispatcher<N>>,
     contact_events: ContactEvents<Handle>,
     proximity_events: ProximityEvents<Handle>,
     id_allocator: SlotMap<ContactId, bool>,
+    #[cfg(feature = "parallel")]
+    parallel: bool,
 }
 
 impl<N: RealField, Handle: CollisionObjectHandle> NarrowPhase<N, Handle> {
     /// Creates a new `NarrowPhase`.
+    ///
+    /// Closest points are computed with the `DefaultDistanceDispatcher`. Use
+    /// `.set_distance_dispatcher(...)` to customize it.
     pub fn new(
         contact_dispatcher: Box<dyn ContactDispatcher<N>>,
         proximity_dispatcher: Box<dyn ProximityDispatcher<N>>,
@@ -28,12 +37,40 @@ impl<N: RealField, Handle: CollisionObjectHandle> NarrowPhase<N, Handle> {
         NarrowPhase {
             contact_dispatcher,
             proximity_dispatcher,
+            distance_dispatcher: Box::new(DefaultDistanceDispatcher::new()),
             contact_events: ContactEvents::new(),
             proximity_events: ProximityEvents::new(),
             id_allocator: SlotMap::with_key(),
+            #[cfg(feature = "parallel")]
+            parallel: true,
         }
     }
 
+    /// Sets whether `.update(...)` computes the interactions in parallel, which is the default.
+    ///
+    /// Both ways emit the same events in the same order.
+    #[cfg(feature = "parallel")]
+    pub fn set_parallel(&mut self, parallel: bool) {
+        self.parallel = parallel;
+    }
+
+    /// Sets the dispatcher used to select the distance algorithm of new distance interactions.
+    pub fn set_distance_dispatcher(&mut self, distance_dispatcher: Box<dyn DistanceDispatcher<N>>) {
+        self.distance_dispatcher = distance_dispatcher;
+    }
+
+    /// The contact identifiers currently allocated by this narrow-phase.
+    #[cfg(feature = "serde-serialize")]
+    pub(crate) fn contact_ids(&self) -> &SlotMap<ContactId, bool> {
+        &self.id_allocator
+    }
+
+    /// Replaces the contact identifiers allocated by this narrow-phase.
+    #[cfg(feature = "serde-serialize")]
+    pub(crate) fn set_contact_ids(&mut self, ids: SlotMap<ContactId, bool>) {
+        self.id_allocator = ids
+    }
+
     fn garbage_collect_ids(&mut self, interactions: &mut InteractionGraph<N, Handle>) {
         for interaction in interactions.0.edge_weights_mut() {
             match interaction {
@@ -44,7 +81,7 @@ impl<N: RealField, Handle: CollisionObjectHandle> NarrowPhase<N, Handle> {
                         }
                     }
                 }
-                Interaction::Proximity(..) => {}
+                Interaction::Proximity(..) | Interaction::Distance(..) => {}
             }
         }
 
@@ -62,35 +99,39 @@ impl<N: RealField, Handle: CollisionObjectHandle> NarrowPhase<N, Handle> {
         detector: &mut dyn ContactManifoldGenerator<N>,
         manifold: &mut ContactManifold<N>,
     ) {
-        let had_contacts = manifold.len() != 0;
-
-        if let Some(prediction) = co1
-            .query_type()
-            .contact_queries_to_prediction(co2.query_type())
-        {
-            manifold.save_cache_and_clear();
-            let _ = detector.generate_contacts(
-                &*self.contact_dispatcher,
-                &co1.position(),
-                co1.shape(),
-                None,
-                &co2.position(),
-                co2.shape(),
-                None,
-                &prediction,
-                manifold,
-            );
+        let had_contacts = PairGeometry::new(co1, co2).compute_contacts(
+            &*self.contact_dispatcher,
+            detector,
+            manifold,
+        );
+        self.finish_contact_update(handle1, handle2, had_contacts, manifold)
+    }
 
-            for contact in manifold.contacts_mut() {
-                if contact.id.is_null() {
-                    contact.id = self.id_allocator.insert(false)
-                }
+    // Allocates the ids of the new contacts of `manifold` and emits the contact event.
+    fn finish_contact_update(
+        &mut self,
+        handle1: Handle,
+        handle2: Handle,
+        had_contacts: bool,
+        manifold: &mut ContactManifold<N>,
+    ) {
+        for contact in manifold.contacts_mut() {
+            if contact.id.is_null() {
+                contact.id = self.id_allocator.insert(false)
             }
-        } else {
-            panic!("Unable to compute contact between collision objects with query types different from `GeometricQueryType::Contacts(..)`.")
         }
 
-        if manifold.len() == 0 {
+        self.emit_contact_event(handle1, handle2, had_contacts, manifold.len() != 0)
+    }
+
+    fn emit_contact_event(
+        &mut self,
+        handle1: Handle,
+        handle2: Handle,
+        had_contacts: bool,
+        has_contacts: bool,
+    ) {
+        if !has_contacts {
             if had_contacts {
                 self.contact_events
                     .push(ContactEvent::Stopped(handle1, handle2));
@@ -129,19 +170,39 @@ impl<N: RealField, Handle: CollisionObjectHandle> NarrowPhase<N, Handle> {
         detector: &mut dyn ProximityDetector<N>,
         curr_proximity: &mut Proximity,
     ) {
-        if let Some(new_proximity) = detector.update(
-            &*self.proximity_dispatcher,
-            &co1.position(),
-            co1.shape(),
-            &co2.position(),
-            co2.shape(),
-            co1.query_type().query_limit() + co2.query_type().query_limit(),
-        ) {
+        let new_proximity =
+            PairGeometry::new(co1, co2).compute_proximity(&*self.proximity_dispatcher, detector);
+        self.finish_proximity_update(handle1, handle2, new_proximity, curr_proximity)
+    }
+
+    fn finish_proximity_update(
+        &mut self,
+        handle1: Handle,
+        handle2: Handle,
+        new_proximity: Option<Proximity>,
+        curr_proximity: &mut Proximity,
+    ) {
+        if let Some(new_proximity) = new_proximity {
             self.emit_proximity_event(handle1, handle2, *curr_proximity, new_proximity);
             *curr_proximity = new_proximity;
         }
     }
 
+    /// Update the specified closest points between two collision objects.
+    pub fn update_distance(
+        &mut self,
+        co1: &impl CollisionObjectRef<N>,
+        co2: &impl CollisionObjectRef<N>,
+        detector: &mut dyn DistanceDetector<N>,
+        curr_closest_points: &mut ClosestPoints<N>,
+    ) {
+        PairGeometry::new(co1, co2).compute_distance(
+            &*self.distance_dispatcher,
+            detector,
+            curr_closest_points,
+        )
+    }
+
     /// Update the specified interaction between two collision objects.
     pub fn update_interaction(
         &mut self,
@@ -158,6 +219,9 @@ impl<N: RealField, Handle: CollisionObjectHandle> NarrowPhase<N, Handle> {
             Interaction::Proximity(detector, prox) => {
                 self.update_proximity(co1, co2, handle1, handle2, &mut **detector, prox)
             }
+            Interaction::Distance(detector, pts) => {
+                self.update_distance(co1, co2, &mut **detector, pts)
+            }
         }
     }
 
@@ -171,6 +235,28 @@ impl<N: RealField, Handle: CollisionObjectHandle> NarrowPhase<N, Handle> {
         objects: &Objects,
     ) where
         Objects: CollisionObjectSet<N, CollisionObjectHandle = Handle>,
+    {
+        #[cfg(feature = "parallel")]
+        {
+            if self.parallel {
+                self.par_update_interactions(interactions, objects);
+            } else {
+                self.update_interactions(interactions, objects);
+            }
+        }
+        #[cfg(not(feature = "parallel"))]
+        self.update_interactions(interactions, objects);
+
+        // FIXME: don't do this at each update?
+        self.garbage_collect_ids(interactions)
+    }
+
+    fn update_interactions<Objects>(
+        &mut self,
+        interactions: &mut InteractionGraph<N, Objects::CollisionObjectHandle>,
+        objects: &Objects,
+    ) where
+        Objects: CollisionObjectSet<N, CollisionObjectHandle = Handle>,
     {
         for eid in interactions.0.edge_indices() {
             let (id1, id2) = interactions.0.edge_endpoints(eid).unwrap();
@@ -191,9 +277,90 @@ impl<N: RealField, Handle: CollisionObjectHandle> NarrowPhase<N, Handle> {
                 )
             }
         }
+    }
 
-        // FIXME: don't do this at each update?
-        self.garbage_collect_ids(interactions)
+    /// Computes the interactions in parallel, then allocates contact ids and emits the events
+    /// sequentially, in edge order, so that the result does not depend on the thread scheduling.
+    #[cfg(feature = "parallel")]
+    fn par_update_interactions<Objects>(
+        &mut self,
+        interactions: &mut InteractionGraph<N, Objects::CollisionObjectHandle>,
+        objects: &Objects,
+    ) where
+        Objects: CollisionObjectSet<N, CollisionObjectHandle = Handle>,
+    {
+        use rayon::prelude::*;
+
+        let graph = &mut interactions.0;
+        let endpoints: Vec<_> = graph
+            .raw_edges()
+            .iter()
+            .map(|e| (graph[e.source()], graph[e.target()]))
+            .collect();
+
+        let mut updates: Vec<_> = graph
+            .edge_weights_mut()
+            .zip(endpoints)
+            .filter_map(|(interaction, (handle1, handle2))| {
+                let co1 = objects.collision_object(handle1).unwrap();
+                let co2 = objects.collision_object(handle2).unwrap();
+                let flags1 = co1.update_flags();
+                let flags2 = co2.update_flags();
+
+                if flags1.needs_narrow_phase_update() || flags2.needs_narrow_phase_update() {
+                    Some(InteractionUpdate {
+                        handle1,
+                        handle2,
+                        geometry: PairGeometry::new(co1, co2),
+                        interaction,
+                        had_contacts: false,
+                        new_proximity: None,
+                    })
+                } else {
+                    None
+                }
+            })
+            .collect();
+
+        let contact_dispatcher = &*self.contact_dispatcher;
+        let proximity_dispatcher = &*self.proximity_dispatcher;
+        let distance_dispatcher = &*self.distance_dispatcher;
+
+        updates.par_iter_mut().for_each(|update| {
+            let geometry = &update.geometry;
+
+            match &mut *update.interaction {
+                Interaction::Contact(detector, manifold) => {
+                    update.had_contacts =
+                        geometry.compute_contacts(contact_dispatcher, &mut **detector, manifold)
+                }
+                Interaction::Proximity(detector, _) => {
+                    update.new_proximity =
+                        geometry.compute_proximity(proximity_dispatcher, &mut **detector)
+                }
+                Interaction::Distance(detector, pts) => {
+                    geometry.compute_distance(distance_dispatcher, &mut **detector, pts)
+                }
+            }
+        });
+
+        for update in updates {
+            match update.interaction {
+                Interaction::Contact(_, manifold) => self.finish_contact_update(
+                    update.handle1,
+                    update.handle2,
+                    update.had_contacts,
+                    manifold,
+                ),
+                Interaction::Proximity(_, prox) => self.finish_proximity_update(
+                    update.handle1,
+                    update.handle2,
+                    update.new_proximity,
+                    prox,
+                ),
+                Interaction::Distance(..) => {}
+            }
+        }
     }
 
     /// Handles a pair of collision objects detected as either started or stopped interacting.
@@ -243,6 +410,19 @@ impl<N: RealField, Handle: CollisionObjectHandle> NarrowPhase<N, Handle> {
                             );
                         }
                     }
+                    _ => {
+                        let dispatcher = &self.distance_dispatcher;
+
+                        if let Some(detector) =
+                            dispatcher.get_distance_algorithm(co1.shape(), co2.shape())
+                        {
+                            let _ = interactions.0.add_edge(
+                                id1,
+                                id2,
+                                Interaction::Distance(detector, ClosestPoints::Disjoint),
+                            );
+                        }
+                    }
                 }
             }
         } else {
@@ -271,6 +451,7 @@ impl<N: RealField, Handle: CollisionObjectHandle> NarrowPhase<N, Handle> {
                                 Proximity::Disjoint,
                             );
                         }
+                        Interaction::Distance(..) => {}
                     }
                 }
             }
@@ -293,3 +474,107 @@ impl<N: RealField, Handle: CollisionObjectHandle> NarrowPhase<N, Handle> {
         self.proximity_events.clear();
     }
 }
+
+/// The data of two collision objects needed to update their interaction.
+struct PairGeometry<'a, N: RealField> {
+    position1: &'a Isometry<N>,
+    position2: &'a Isometry<N>,
+    shape1: &'a dyn Shape<N>,
+    shape2: &'a dyn Shape<N>,
+    query_type1: GeometricQueryType<N>,
+    query_type2: GeometricQueryType<N>,
+}
+
+impl<'a, N: RealField> PairGeometry<'a, N> {
+    fn new(co1: &'a impl CollisionObjectRef<N>, co2: &'a impl CollisionObjectRef<N>) -> Self {
+        PairGeometry {
+            position1: co1.position(),
+            position2: co2.position(),
+            shape1: co1.shape(),
+            shape2: co2.shape(),
+            query_type1: co1.query_type(),
+            query_type2: co2.query_type(),
+        }
+    }
+
+    fn max_dist(&self) -> N {
+        self.query_type1.query_limit() + self.query_type2.query_limit()
+    }
+
+    // Recomputes the contacts of `manifold` and returns whether it had contacts before.
+    fn compute_contacts(
+        &self,
+        dispatcher: &dyn ContactDispatcher<N>,
+        detector: &mut dyn ContactManifoldGenerator<N>,
+        manifold: &mut ContactManifold<N>,
+    ) -> bool {
+        let had_contacts = manifold.len() != 0;
+
+        if let Some(prediction) = self
+            .query_type1
+            .contact_queries_to_prediction(self.query_type2)
+        {
+            manifold.save_cache_and_clear();
+            let _ = detector.generate_contacts(
+                dispatcher,
+                self.position1,
+                self.shape1,
+                None,
+                self.position2,
+                self.shape2,
+                None,
+                &prediction,
+                manifold,
+            );
+        } else {
+            panic!("Unable to compute contact between collision objects with query types different from `GeometricQueryType::Contacts(..)`.")
+        }
+
+        had_contacts
+    }
+
+    fn compute_proximity(
+        &self,
+        dispatcher: &dyn ProximityDispatcher<N>,
+        detector: &mut dyn ProximityDetector<N>,
+    ) -> Option<Proximity> {
+        detector.update(
+            dispatcher,
+            self.position1,
+            self.shape1,
+            self.position2,
+            self.shape2,
+            self.max_dist(),
+        )
+    }
+
+    fn compute_distance(
+        &self,
+        dispatcher: &dyn DistanceDispatcher<N>,
+        detector: &mut dyn DistanceDetector<N>,
+        curr_closest_points: &mut ClosestPoints<N>,
+    ) {
+        if let Some(new_closest_points) = detector.update(
+            dispatcher,
+            self.position1,
+            self.shape1,
+            self.position2,
+            self.shape2,
+            self.max_dist(),
+        ) {
+            *curr_closest_points = new_closest_points;
+        }
+    }
+}
+
+/// An interaction to be updated by the parallel narrow-phase, with the data it needs from its
+/// collision objects.
+#[cfg(feature = "parallel")]
+struct InteractionUpdate<'a, N: RealField, Handle> {
+    handle1: Handle,
+    handle2: Handle,
+    geometry: PairGeometry<'a, N>,
+    interaction: &'a mut Interaction<N>,
+    had_contacts: bool,
+    new_proximity: Option<Proximity>,
+}
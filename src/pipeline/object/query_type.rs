@@ -9,13 +9,21 @@ use na::RealField;
 /// * Contacts + Contacts = exact contact point coputation.
 /// * Contacts + Proximity = proximity test only.
 /// * Proximity + Proximity = proximity test only.
+/// * Distance + Contacts = closest points computation only.
+/// * Distance + Distance = closest points computation only.
+/// * Distance + Proximity = proximity test only.
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 #[derive(Debug, PartialEq, Clone, Copy)]
 pub enum GeometricQueryType<N: RealField> {
     /// This objects can respond to both contact point computation and proximity queries.
     Contacts(N, N),
     /// This object can respond to proximity tests only.
     Proximity(N),
-    // FIXME: not yet implemented: Distance
+    /// This object can respond to closest points computation only.
+    ///
+    /// Closest points are computed if the objects are separated by a distance smaller than
+    /// the sum of their respective limits.
+    Distance(N),
 }
 
 impl<N: RealField> GeometricQueryType<N> {
@@ -30,6 +38,7 @@ impl<N: RealField> GeometricQueryType<N> {
         match *self {
             GeometricQueryType::Contacts(ref val, _) => *val,
             GeometricQueryType::Proximity(ref val) => *val,
+            GeometricQueryType::Distance(ref val) => *val,
         }
     }
 
@@ -69,4 +78,14 @@ impl<N: RealField> GeometricQueryType<N> {
             false
         }
     }
+
+    /// Returns `true` if this is a distance query type.
+    #[inline]
+    pub fn is_distance_query(&self) -> bool {
+        if let GeometricQueryType::Distance(_) = *self {
+            true
+        } else {
+            false
+        }
+    }
 }
@@ -0,0 +1,433 @@
+use crate::math::Isometry;
+use crate::pipeline::broad_phase::BroadPhasePairFilter;
+use crate::pipeline::glue;
+use crate::pipeline::narrow_phase::Interaction;
+use crate::pipeline::object::{
+    CollisionGroups, CollisionObject, CollisionObjectRef, CollisionObjectSlab,
+    CollisionObjectSlabHandle, CollisionObjectUpdateFlags, GeometricQueryType,
+};
+use crate::pipeline::world::CollisionWorld;
+use crate::query::{ClosestPoints, ContactId, ContactManifold, Proximity};
+use crate::shape::{ShapeHandle, ShapeRegistry, ShapeSeed};
+use na::RealField;
+use serde::de::{self, DeserializeOwned, DeserializeSeed, SeqAccess, Visitor};
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+use slotmap::SlotMap;
+use std::collections::{HashMap, HashSet};
+use std::error::Error;
+use std::fmt;
+use std::marker::PhantomData;
+use std::sync::Arc;
+
+/// A copy of the state of a `CollisionWorld` that can be serialized and restored.
+///
+/// The snapshot contains the collision objects, the state of all their interactions (including
+/// the contact manifolds and their contact identifiers), and the update flags of the objects.
+/// Shapes shared by several collision objects are stored only once.
+///
+/// The dispatchers, the broad-phase pair filter, and the internal caches of the contact
+/// algorithms are not part of the snapshot: they must be set again on the restored world if
+/// they were customized.
+#[derive(Clone)]
+pub struct CollisionWorldSnapshot<N: RealField, T> {
+    margin: N,
+    shapes: Vec<ShapeHandle<N>>,
+    objects: Vec<CollisionObjectSnapshot<N, T>>,
+    interactions: Vec<InteractionSnapshot<N>>,
+    contact_ids: SlotMap<ContactId, bool>,
+}
+
+/// The reason why a snapshot cannot be restored.
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub enum CollisionWorldSnapshotError {
+    /// The margin of the world is negative or not finite.
+    InvalidMargin,
+    /// The collision object with this handle refers to a shape missing from the snapshot.
+    MissingShape(CollisionObjectSlabHandle),
+    /// Several collision objects have this handle.
+    DuplicateHandle(CollisionObjectSlabHandle),
+}
+
+impl fmt::Display for CollisionWorldSnapshotError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            CollisionWorldSnapshotError::InvalidMargin => {
+                f.pad("the margin of the world is negative or not finite")
+            }
+            CollisionWorldSnapshotError::MissingShape(handle) => write!(
+                f,
+                "the collision object {} refers to a shape missing from the snapshot",
+                handle.0
+            ),
+            CollisionWorldSnapshotError::DuplicateHandle(handle) => {
+                write!(f, "several collision objects have the handle {}", handle.0)
+            }
+        }
+    }
+}
+
+impl Error for CollisionWorldSnapshotError {}
+
+#[derive(Clone, Serialize, Deserialize)]
+struct CollisionObjectSnapshot<N: RealField, T> {
+    handle: CollisionObjectSlabHandle,
+    position: Isometry<N>,
+    predicted_position: Option<Isometry<N>>,
+    shape: usize,
+    collision_groups: CollisionGroups,
+    query_type: GeometricQueryType<N>,
+    update_flags: u8,
+    data: T,
+}
+
+#[derive(Clone, Serialize, Deserialize)]
+enum InteractionSnapshot<N: RealField> {
+    Contact(
+        CollisionObjectSlabHandle,
+        CollisionObjectSlabHandle,
+        ContactManifold<N>,
+    ),
+    Proximity(
+        CollisionObjectSlabHandle,
+        CollisionObjectSlabHandle,
+        Proximity,
+    ),
+    Distance(
+        CollisionObjectSlabHandle,
+        CollisionObjectSlabHandle,
+        ClosestPoints<N>,
+    ),
+}
+
+impl<N: RealField, T> CollisionWorldSnapshot<N, T> {
+    /// The margin of the world this snapshot was taken from.
+    #[inline]
+    pub fn margin(&self) -> N {
+        self.margin
+    }
+
+    /// Checks that this snapshot can be restored.
+    fn validate(&self) -> Result<(), CollisionWorldSnapshotError> {
+        if !self.margin.is_finite() || self.margin < N::zero() {
+            return Err(CollisionWorldSnapshotError::InvalidMargin);
+        }
+
+        let mut handles = HashSet::with_capacity(self.objects.len());
+
+        for object in &self.objects {
+            if object.shape >= self.shapes.len() {
+                return Err(CollisionWorldSnapshotError::MissingShape(object.handle));
+            }
+
+            if !handles.insert(object.handle) {
+                return Err(CollisionWorldSnapshotError::DuplicateHandle(object.handle));
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Serializes this snapshot, using `registry` to serialize its shapes.
+    ///
+    /// Fails if the type of a shape is not registered in `registry`.
+    pub fn serialize<S: Serializer>(
+        &self,
+        registry: &ShapeRegistry<N>,
+        serializer: S,
+    ) -> Result<S::Ok, S::Error>
+    where
+        N: Serialize + DeserializeOwned,
+        T: Serialize,
+    {
+        let shapes: Vec<_> = self
+            .shapes
+            .iter()
+            .map(|shape| registry.serializable(shape.as_ref()))
+            .collect();
+
+        (
+            &self.margin,
+            &shapes,
+            &self.objects,
+            &self.interactions,
+            &self.contact_ids,
+        )
+            .serialize(serializer)
+    }
+
+    /// Deserializes a snapshot serialized with `.serialize(...)`, using `registry` to
+    /// deserialize its shapes.
+    pub fn deserialize<'de, D: Deserializer<'de>>(
+        registry: &ShapeRegistry<N>,
+        deserializer: D,
+    ) -> Result<Self, D::Error>
+    where
+        N: Serialize + DeserializeOwned,
+        T: Deserialize<'de>,
+    {
+        deserializer.deserialize_tuple(
+            5,
+            SnapshotVisitor {
+                shapes: registry.seed(),
+                _phantom: PhantomData,
+            },
+        )
+    }
+}
+
+struct SnapshotVisitor<'a, N: RealField, T> {
+    shapes: ShapeSeed<'a, N>,
+    _phantom: PhantomData<T>,
+}
+
+impl<'a, 'de, N, T> Visitor<'de> for SnapshotVisitor<'a, N, T>
+where
+    N: RealField + Serialize + DeserializeOwned,
+    T: Deserialize<'de>,
+{
+    type Value = CollisionWorldSnapshot<N, T>;
+
+    fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str("a collision world snapshot")
+    }
+
+    fn visit_seq<A: SeqAccess<'de>>(self, mut seq: A) -> Result<Self::Value, A::Error> {
+        let margin = seq
+            .next_element()?
+            .ok_or_else(|| de::Error::invalid_length(0, &self))?;
+        let shapes = seq
+            .next_element_seed(ShapesSeed(self.shapes))?
+            .ok_or_else(|| de::Error::invalid_length(1, &self))?;
+        let objects = seq
+            .next_element()?
+            .ok_or_else(|| de::Error::invalid_length(2, &self))?;
+        let interactions = seq
+            .next_element()?
+            .ok_or_else(|| de::Error::invalid_length(3, &self))?;
+        let contact_ids = seq
+            .next_element()?
+            .ok_or_else(|| de::Error::invalid_length(4, &self))?;
+
+        let snapshot = CollisionWorldSnapshot {
+            margin,
+            shapes,
+            objects,
+            interactions,
+            contact_ids,
+        };
+        snapshot.validate().map_err(de::Error::custom)?;
+
+        Ok(snapshot)
+    }
+}
+
+struct ShapesSeed<'a, N: RealField>(ShapeSeed<'a, N>);
+
+impl<'a, 'de, N: RealField + Serialize + DeserializeOwned> DeserializeSeed<'de>
+    for ShapesSeed<'a, N>
+{
+    type Value = Vec<ShapeHandle<N>>;
+
+    fn deserialize<D: Deserializer<'de>>(self, deserializer: D) -> Result<Self::Value, D::Error> {
+        deserializer.deserialize_seq(self)
+    }
+}
+
+impl<'a, 'de, N: RealField + Serialize + DeserializeOwned> Visitor<'de> for ShapesSeed<'a, N> {
+    type Value = Vec<ShapeHandle<N>>;
+
+    fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str("a sequence of shapes")
+    }
+
+    fn visit_seq<A: SeqAccess<'de>>(self, mut seq: A) -> Result<Self::Value, A::Error> {
+        let mut shapes = Vec::new();
+
+        while let Some(shape) = seq.next_element_seed(self.0)? {
+            shapes.push(shape)
+        }
+
+        Ok(shapes)
+    }
+}
+
+impl<N: RealField, T> CollisionWorld<N, T> {
+    /// Takes a snapshot of the state of this collision world.
+    pub fn snapshot(&self) -> CollisionWorldSnapshot<N, T>
+    where
+        T: Clone,
+    {
+        let mut shapes = Vec::new();
+        let mut shape_ids = HashMap::new();
+        let mut objects = Vec::new();
+
+        for (handle, co) in self.objects.iter() {
+            let ptr = Arc::as_ptr(co.shape().as_arc()) as *const () as usize;
+            let shape = *shape_ids.entry(ptr).or_insert_with(|| {
+                shapes.push(co.shape().clone());
+                shapes.len() - 1
+            });
+
+            objects.push(CollisionObjectSnapshot {
+                handle,
+                position: *co.position(),
+                predicted_position: co.predicted_position().cloned(),
+                shape,
+                collision_groups: *co.collision_groups(),
+                query_type: co.query_type(),
+                update_flags: co.update_flags().bits(),
+                data: co.data().clone(),
+            })
+        }
+
+        let interactions = self
+            .interactions
+            .interaction_pairs(false)
+            .map(|(h1, h2, interaction)| match interaction {
+                Interaction::Contact(_, manifold) => {
+                    InteractionSnapshot::Contact(h1, h2, manifold.clone())
+                }
+                Interaction::Proximity(_, prox) => InteractionSnapshot::Proximity(h1, h2, *prox),
+                Interaction::Distance(_, pts) => InteractionSnapshot::Distance(h1, h2, *pts),
+            })
+            .collect();
+
+        CollisionWorldSnapshot {
+            margin: self.margin(),
+            shapes,
+            objects,
+            interactions,
+            contact_ids: self.narrow_phase.contact_ids().clone(),
+        }
+    }
+
+    /// Creates a collision world from a snapshot.
+    ///
+    /// The collision objects keep their handles, and the interactions keep their state so that
+    /// the next update of the world does not generate any spurious event. The restored world
+    /// has the margin of the world the snapshot was taken from, and uses the default broad
+    /// phase and dispatchers, and no broad-phase pair filter.
+    pub fn from_snapshot(
+        snapshot: CollisionWorldSnapshot<N, T>,
+    ) -> Result<Self, CollisionWorldSnapshotError> {
+        snapshot.validate()?;
+
+        let CollisionWorldSnapshot {
+            margin,
+            shapes,
+            objects,
+            interactions,
+            contact_ids,
+        } = snapshot;
+        let mut world = CollisionWorld::new(margin);
+        let mut entries = Vec::with_capacity(objects.len());
+        let mut flags = Vec::with_capacity(objects.len());
+
+        for object in objects {
+            let shape = shapes[object.shape].clone();
+            let (proxy_handle, graph_index) = glue::create_proxies(
+                object.handle,
+                &mut *world.broad_phase,
+                &mut world.interactions,
+                &object.position,
+                shape.as_ref(),
+                object.query_type,
+            );
+
+            let mut co = CollisionObject::new(
+                Some(proxy_handle),
+                Some(graph_index),
+                object.position,
+                shape,
+                object.collision_groups,
+                object.query_type,
+                object.data,
+            );
+            co.set_predicted_position(object.predicted_position);
+
+            flags.push((object.handle, object.update_flags));
+            entries.push((object.handle.0, co));
+        }
+
+        world.objects = CollisionObjectSlab {
+            objects: entries.into_iter().collect(),
+        };
+
+        // Let the broad-phase find the interacting pairs and create their algorithms.
+        glue::perform_broad_phase(
+            &world.objects,
+            &mut *world.broad_phase,
+            &mut world.narrow_phase,
+            &mut world.interactions,
+            None::<&dyn BroadPhasePairFilter<N, CollisionObjectSlab<N, T>>>,
+        );
+
+        // Restore the state of the interactions. The interactions are re-inserted in the order
+        // they had in the saved world so that the narrow-phase processes them in the same order.
+        let graph = &mut world.interactions.0;
+        let mut fresh = Vec::with_capacity(graph.edge_count());
+
+        while let Some(eid) = graph.edge_indices().next_back() {
+            let (id1, id2) = graph.edge_endpoints(eid).unwrap();
+            fresh.push(Some((id1, id2, graph.remove_edge(eid).unwrap())));
+        }
+
+        fresh.reverse();
+        let fresh_ids: HashMap<_, _> = fresh
+            .iter()
+            .flatten()
+            .enumerate()
+            .map(|(i, (id1, id2, _))| ((*id1.min(id2), *id1.max(id2)), i))
+            .collect();
+
+        for saved in interactions {
+            let (h1, h2) = match &saved {
+                InteractionSnapshot::Contact(h1, h2, _)
+                | InteractionSnapshot::Proximity(h1, h2, _)
+                | InteractionSnapshot::Distance(h1, h2, _) => (*h1, *h2),
+            };
+
+            let ids = match (world.objects.get(h1), world.objects.get(h2)) {
+                (Some(co1), Some(co2)) => co1.graph_index().zip(co2.graph_index()),
+                _ => None,
+            };
+            let entry = ids
+                .and_then(|(id1, id2)| fresh_ids.get(&(id1.min(id2), id1.max(id2))))
+                .and_then(|i| fresh[*i].take());
+
+            if let Some((id1, id2, mut interaction)) = entry {
+                match (&mut interaction, saved) {
+                    (
+                        Interaction::Contact(_, manifold),
+                        InteractionSnapshot::Contact(_, _, saved),
+                    ) => *manifold = saved,
+                    (
+                        Interaction::Proximity(_, prox),
+                        InteractionSnapshot::Proximity(_, _, saved),
+                    ) => *prox = saved,
+                    (Interaction::Distance(_, pts), InteractionSnapshot::Distance(_, _, saved)) => {
+                        *pts = saved
+                    }
+                    _ => {}
+                }
+
+                let _ = graph.add_edge(id1, id2, interaction);
+            }
+        }
+
+        // Interactions missing from the snapshot go last.
+        for (id1, id2, interaction) in fresh.into_iter().flatten() {
+            let _ = graph.add_edge(id1, id2, interaction);
+        }
+
+        world.narrow_phase.set_contact_ids(contact_ids);
+        world.narrow_phase.clear_events();
+
+        for (handle, flags) in flags {
+            let co = world.objects.get_mut(handle).unwrap();
+            *co.update_flags_mut() = CollisionObjectUpdateFlags::from_bits_truncate(flags);
+        }
+
+        Ok(world)
+    }
+}
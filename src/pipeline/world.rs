@@ -3,22 +3,26 @@
 use na::{RealField, Unit};
 
 use crate::bounding_volume::{BoundingVolume, AABB};
+use crate::interpolation::ConstantVelocityRigidMotion;
 use crate::math::{Isometry, Point, Rotation, Translation, Vector};
 use crate::pipeline::broad_phase::{BroadPhase, BroadPhasePairFilter, DBVTBroadPhase};
 use crate::pipeline::glue::{
-    self, FirstInterferenceWithRay, InterferencesWithAABB, InterferencesWithPoint,
-    InterferencesWithRay,
+    self, ContactsWithShape, FirstInterferenceWithRay, InterferencesWithAABB,
+    InterferencesWithPoint, InterferencesWithRay, InterferencesWithShape,
 };
 use crate::pipeline::narrow_phase::{
     ContactAlgorithm, ContactEvents, DefaultContactDispatcher, DefaultProximityDispatcher,
-    Interaction, InteractionGraph, NarrowPhase, ProximityDetector, ProximityEvents,
-    TemporaryInteractionIndex,
+    DistanceDetector, Interaction, InteractionGraph, NarrowPhase, ProximityDetector,
+    ProximityEvents, TemporaryInteractionIndex,
 };
 use crate::pipeline::object::{
     CollisionGroups, CollisionObject, CollisionObjectSet, CollisionObjectSlab,
     CollisionObjectSlabHandle, CollisionObjects, GeometricQueryType,
 };
-use crate::query::{ContactManifold, DefaultTOIDispatcher, Proximity, Ray, TOIDispatcher, TOI};
+use crate::query::{
+    ClosestPoints, ContactManifold, DefaultTOIDispatcher, Proximity, Ray, RayIntersection,
+    TOIDispatcher, TOI,
+};
 use crate::shape::{Shape, ShapeHandle};
 
 /// Type of the broad phase trait-object used by the collision world.
@@ -38,6 +42,7 @@ pub struct CollisionWorld<N: RealField, T> {
     pub interactions: InteractionGraph<N, CollisionObjectSlabHandle>,
     /// A user-defined broad-phase pair filter.
     pub pair_filters: Option<Box<dyn BroadPhasePairFilter<N, CollisionObjectSlab<N, T>>>>,
+    margin: N,
 }
 
 impl<N: RealField, T> CollisionWorld<N, T> {
@@ -59,9 +64,17 @@ impl<N: RealField, T> CollisionWorld<N, T> {
             narrow_phase,
             toi_dispatcher,
             pair_filters: None,
+            margin,
         }
     }
 
+    /// The margin given to `CollisionWorld::new`, by which the bounding volumes of the default
+    /// broad phase are enlarged.
+    #[inline]
+    pub fn margin(&self) -> N {
+        self.margin
+    }
+
     /// Adds a collision object to the world.
     pub fn add(
         &mut self,
@@ -337,6 +350,101 @@ impl<N: RealField, T> CollisionWorld<N, T> {
         })
     }
 
+    /// Returns all the collision objects hit by `shape` moving from `shape_pos` at the constant
+    /// velocity `shape_vel`, sorted by increasing time of impact.
+    ///
+    /// A collision object is hit when its distance to `shape` becomes smaller than
+    /// `target_distance` before the time `max_toi`.
+    pub fn shape_cast_all(
+        &self,
+        shape_pos: &Isometry<N>,
+        shape_vel: &Vector<N>,
+        shape: &dyn Shape<N>,
+        max_toi: N,
+        target_distance: N,
+        groups: &CollisionGroups,
+    ) -> Vec<(CollisionObjectSlabHandle, TOI<N>)> {
+        glue::shape_cast_all(
+            &self.objects,
+            &*self.broad_phase,
+            &*self.toi_dispatcher,
+            shape_pos,
+            shape_vel,
+            shape,
+            max_toi,
+            target_distance,
+            groups,
+        )
+    }
+
+    /// Returns the first collision object hit by `shape` moving from `shape_pos` at the constant
+    /// velocity `shape_vel`.
+    pub fn first_shape_cast(
+        &self,
+        shape_pos: &Isometry<N>,
+        shape_vel: &Vector<N>,
+        shape: &dyn Shape<N>,
+        max_toi: N,
+        target_distance: N,
+        groups: &CollisionGroups,
+    ) -> Option<(CollisionObjectSlabHandle, TOI<N>)> {
+        glue::first_shape_cast(
+            &self.objects,
+            &*self.broad_phase,
+            &*self.toi_dispatcher,
+            shape_pos,
+            shape_vel,
+            shape,
+            max_toi,
+            target_distance,
+            groups,
+        )
+    }
+
+    /// Returns all the collision objects hit by `shape` following the translational and
+    /// rotational `motion`, sorted by increasing time of impact.
+    pub fn nonlinear_shape_cast_all(
+        &self,
+        motion: &ConstantVelocityRigidMotion<N>,
+        shape: &dyn Shape<N>,
+        max_toi: N,
+        target_distance: N,
+        groups: &CollisionGroups,
+    ) -> Vec<(CollisionObjectSlabHandle, TOI<N>)> {
+        glue::nonlinear_shape_cast_all(
+            &self.objects,
+            &*self.broad_phase,
+            &*self.toi_dispatcher,
+            motion,
+            shape,
+            max_toi,
+            target_distance,
+            groups,
+        )
+    }
+
+    /// Returns the first collision object hit by `shape` following the translational and
+    /// rotational `motion`.
+    pub fn first_nonlinear_shape_cast(
+        &self,
+        motion: &ConstantVelocityRigidMotion<N>,
+        shape: &dyn Shape<N>,
+        max_toi: N,
+        target_distance: N,
+        groups: &CollisionGroups,
+    ) -> Option<(CollisionObjectSlabHandle, TOI<N>)> {
+        glue::first_nonlinear_shape_cast(
+            &self.objects,
+            &*self.broad_phase,
+            &*self.toi_dispatcher,
+            motion,
+            shape,
+            max_toi,
+            target_distance,
+            groups,
+        )
+    }
+
     /// Computes the interferences between every rigid bodies on this world and a ray.
     #[inline]
     pub fn interferences_with_ray<'a, 'b>(
@@ -359,6 +467,41 @@ impl<N: RealField, T> CollisionWorld<N, T> {
         glue::first_interference_with_ray(&self.objects, &*self.broad_phase, ray, max_toi, groups)
     }
 
+    /// Computes the first interference of each ray of `rays`.
+    ///
+    /// The i-th element of the result is the closest hit of `rays[i]`, if any.
+    pub fn cast_rays(
+        &self,
+        rays: &[Ray<N>],
+        max_toi: N,
+        groups: &CollisionGroups,
+    ) -> Vec<Option<(CollisionObjectSlabHandle, RayIntersection<N>)>> {
+        let mut out = Vec::with_capacity(rays.len());
+        self.cast_rays_into(rays, max_toi, groups, &mut out);
+        out
+    }
+
+    /// Same as `cast_rays` but writes the hits into `out`, which is cleared first.
+    ///
+    /// This allows the same buffer to be reused for each batch of rays.
+    pub fn cast_rays_into(
+        &self,
+        rays: &[Ray<N>],
+        max_toi: N,
+        groups: &CollisionGroups,
+        out: &mut Vec<Option<(CollisionObjectSlabHandle, RayIntersection<N>)>>,
+    ) {
+        out.clear();
+        glue::cast_rays(
+            &self.objects,
+            &*self.broad_phase,
+            rays,
+            max_toi,
+            groups,
+            out,
+        )
+    }
+
     /// Computes the interferences between every rigid bodies of a given broad phase, and a point.
     #[inline]
     pub fn interferences_with_point<'a, 'b>(
@@ -379,6 +522,42 @@ impl<N: RealField, T> CollisionWorld<N, T> {
         glue::interferences_with_aabb(&self.objects, &*self.broad_phase, aabb, groups)
     }
 
+    /// Computes the interferences between every rigid bodies of a given broad phase, and a shape.
+    ///
+    /// Objects with a shape not supported by `query::proximity_opt` when paired with `shape` are
+    /// ignored.
+    #[inline]
+    pub fn interferences_with_shape<'a, 'b>(
+        &'a self,
+        shape: &'b dyn Shape<N>,
+        pos: &'b Isometry<N>,
+        groups: &'b CollisionGroups,
+    ) -> InterferencesWithShape<'a, 'b, N, CollisionObjectSlab<N, T>> {
+        glue::interferences_with_shape(&self.objects, &*self.broad_phase, shape, pos, groups)
+    }
+
+    /// Computes the contacts between every rigid bodies of a given broad phase, and a shape.
+    ///
+    /// Only contacts with a distance smaller than `prediction` are reported. Objects with a shape
+    /// not supported by `query::contact_opt` when paired with `shape` are ignored.
+    #[inline]
+    pub fn contacts_with_shape<'a, 'b>(
+        &'a self,
+        shape: &'b dyn Shape<N>,
+        pos: &'b Isometry<N>,
+        prediction: N,
+        groups: &'b CollisionGroups,
+    ) -> ContactsWithShape<'a, 'b, N, CollisionObjectSlab<N, T>> {
+        glue::contacts_with_shape(
+            &self.objects,
+            &*self.broad_phase,
+            shape,
+            pos,
+            prediction,
+            groups,
+        )
+    }
+
     /// Customize the selection of narrowphase collision detection algorithms
     pub fn set_narrow_phase(&mut self, narrow_phase: NarrowPhase<N, CollisionObjectSlabHandle>) {
         self.narrow_phase = narrow_phase;
@@ -444,6 +623,24 @@ impl<N: RealField, T> CollisionWorld<N, T> {
         self.interactions.proximity_pairs(effective_only)
     }
 
+    /// All the potential distance pairs.
+    ///
+    /// Refer to the official [user guide](https://nphysics.org/interaction_handling_and_sensors/#interaction-iterators)
+    /// for details.
+    pub fn distance_pairs(
+        &self,
+        effective_only: bool,
+    ) -> impl Iterator<
+        Item = (
+            CollisionObjectSlabHandle,
+            CollisionObjectSlabHandle,
+            &dyn DistanceDetector<N>,
+            ClosestPoints<N>,
+        ),
+    > {
+        self.interactions.distance_pairs(effective_only)
+    }
+
     /// The potential interaction pair between the two specified collision objects.
     ///
     /// Refer to the official [user guide](https://nphysics.org/interaction_handling_and_sensors/#interaction-iterators)
@@ -509,6 +706,28 @@ impl<N: RealField, T> CollisionWorld<N, T> {
         self.interactions.proximity_pair(id1, id2, effective_only)
     }
 
+    /// The potential distance pair between the two specified collision objects.
+    ///
+    /// Refer to the official [user guide](https://nphysics.org/interaction_handling_and_sensors/#interaction-iterators)
+    /// for details.
+    pub fn distance_pair(
+        &self,
+        handle1: CollisionObjectSlabHandle,
+        handle2: CollisionObjectSlabHandle,
+        effective_only: bool,
+    ) -> Option<(
+        CollisionObjectSlabHandle,
+        CollisionObjectSlabHandle,
+        &dyn DistanceDetector<N>,
+        ClosestPoints<N>,
+    )> {
+        let co1 = self.objects.collision_object(handle1)?;
+        let co2 = self.objects.collision_object(handle2)?;
+        let id1 = co1.graph_index().expect(crate::NOT_REGISTERED_ERROR);
+        let id2 = co2.graph_index().expect(crate::NOT_REGISTERED_ERROR);
+        self.interactions.distance_pair(id1, id2, effective_only)
+    }
+
     /// All the interaction pairs involving the specified collision object.
     ///
     /// Refer to the official [user guide](https://nphysics.org/interaction_handling_and_sensors/#interaction-iterators)
@@ -580,6 +799,29 @@ impl<N: RealField, T> CollisionWorld<N, T> {
         Some(self.interactions.proximities_with(id, effective_only))
     }
 
+    /// All the distance pairs involving the specified collision object.
+    ///
+    /// Refer to the official [user guide](https://nphysics.org/interaction_handling_and_sensors/#interaction-iterators)
+    /// for details.
+    pub fn distances_with(
+        &self,
+        handle: CollisionObjectSlabHandle,
+        effective_only: bool,
+    ) -> Option<
+        impl Iterator<
+            Item = (
+                CollisionObjectSlabHandle,
+                CollisionObjectSlabHandle,
+                &dyn DistanceDetector<N>,
+                ClosestPoints<N>,
+            ),
+        >,
+    > {
+        let co = self.objects.collision_object(handle)?;
+        let id = co.graph_index().expect(crate::NOT_REGISTERED_ERROR);
+        Some(self.interactions.distances_with(id, effective_only))
+    }
+
     /// All the contact pairs involving the specified collision object.
     ///
     /// Refer to the official [user guide](https://nphysics.org/interaction_handling_and_sensors/#interaction-iterators)
@@ -659,3 +901,37 @@ impl<N: RealField, T> CollisionWorld<N, T> {
         self.narrow_phase.proximity_events()
     }
 }
+
+#[cfg(feature = "parallel")]
+impl<N: RealField, T: Sync> CollisionWorld<N, T> {
+    /// Same as `cast_rays` but distributes the rays among the threads of the rayon thread pool.
+    pub fn par_cast_rays(
+        &self,
+        rays: &[Ray<N>],
+        max_toi: N,
+        groups: &CollisionGroups,
+    ) -> Vec<Option<(CollisionObjectSlabHandle, RayIntersection<N>)>> {
+        let mut out = Vec::with_capacity(rays.len());
+        self.par_cast_rays_into(rays, max_toi, groups, &mut out);
+        out
+    }
+
+    /// Same as `cast_rays_into` but distributes the rays among the threads of the rayon thread pool.
+    pub fn par_cast_rays_into(
+        &self,
+        rays: &[Ray<N>],
+        max_toi: N,
+        groups: &CollisionGroups,
+        out: &mut Vec<Option<(CollisionObjectSlabHandle, RayIntersection<N>)>>,
+    ) {
+        out.clear();
+        glue::par_cast_rays(
+            &self.objects,
+            &*self.broad_phase,
+            rays,
+            max_toi,
+            groups,
+            out,
+        )
+    }
+}
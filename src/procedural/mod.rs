@@ -22,6 +22,8 @@ pub use self::sphere::{circle, unit_circle};
 #[cfg(feature = "dim3")]
 pub use self::sphere::{sphere, unit_hemisphere, unit_sphere};
 #[cfg(feature = "dim3")]
+pub use self::torus::torus;
+#[cfg(feature = "dim3")]
 pub use self::trimesh::{IndexBuffer, TriMesh};
 
 #[cfg(feature = "dim3")]
@@ -43,3 +45,5 @@ mod cylinder;
 #[cfg(feature = "dim3")]
 mod quad;
 mod sphere;
+#[cfg(feature = "dim3")]
+mod torus;
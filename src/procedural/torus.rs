@@ -0,0 +1,63 @@
+use super::{IndexBuffer, TriMesh};
+use na::{self, Point2, Point3, Vector3};
+use simba::scalar::RealField;
+
+/// Generates a torus with its axis of revolution aligned with the `y` axis.
+///
+/// # Arguments:
+/// * `major_radius` - the radius of the circle at the center of the tube of the torus.
+/// * `minor_radius` - the radius of the tube of the torus.
+/// * `nmajor_subdiv` - the number of subdivisions along the circle at the center of the tube.
+/// * `nminor_subdiv` - the number of subdivisions around the tube.
+pub fn torus<N: RealField>(
+    major_radius: N,
+    minor_radius: N,
+    nmajor_subdiv: u32,
+    nminor_subdiv: u32,
+) -> TriMesh<N> {
+    assert!(nmajor_subdiv >= 3 && nminor_subdiv >= 3);
+
+    let dtheta = N::two_pi() / na::convert(nmajor_subdiv as f64);
+    let dphi = N::two_pi() / na::convert(nminor_subdiv as f64);
+    let mut coords = Vec::new();
+    let mut normals = Vec::new();
+    let mut uvs = Vec::new();
+    let mut indices = Vec::new();
+
+    // The first and last rings are duplicated so that the texture coordinates wrap properly.
+    for i in 0..=nmajor_subdiv {
+        let (stheta, ctheta) = (dtheta * na::convert(i as f64)).sin_cos();
+
+        for j in 0..=nminor_subdiv {
+            let (sphi, cphi) = (dphi * na::convert(j as f64)).sin_cos();
+            let normal = Vector3::new(cphi * ctheta, sphi, cphi * stheta);
+            let core = Vector3::new(ctheta, N::zero(), stheta) * major_radius;
+
+            coords.push(Point3::from(core + normal * minor_radius));
+            normals.push(normal);
+            uvs.push(Point2::new(
+                na::convert::<f64, N>(i as f64) / na::convert(nmajor_subdiv as f64),
+                na::convert::<f64, N>(j as f64) / na::convert(nminor_subdiv as f64),
+            ));
+        }
+    }
+
+    let ring_len = nminor_subdiv + 1;
+
+    for i in 0..nmajor_subdiv {
+        for j in 0..nminor_subdiv {
+            let a = i * ring_len + j;
+            let b = a + ring_len;
+
+            indices.push(Point3::new(a, a + 1, b));
+            indices.push(Point3::new(b, a + 1, b + 1));
+        }
+    }
+
+    TriMesh::new(
+        coords,
+        Some(normals),
+        Some(uvs),
+        Some(IndexBuffer::Unified(indices)),
+    )
+}
@@ -61,6 +61,7 @@ impl<N: RealField> Contact<N> {
 /// match contact points found at successive frames. Two contact points are said to "match" if
 /// they can be seen as the same contact point that moved in-between frames. Two matching
 /// contact points are given the same `id` here.
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 #[derive(Copy, Clone, Debug)]
 pub struct TrackedContact<N: RealField> {
     /// The geometric contact information.
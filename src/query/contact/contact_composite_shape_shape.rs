@@ -13,6 +13,22 @@ pub fn contact_composite_shape_shape<N: RealField, G1: ?Sized>(
     g2: &dyn Shape<N>,
     prediction: N,
 ) -> Option<Contact<N>>
+where
+    G1: CompositeShape<N>,
+{
+    contact_composite_shape_shape_opt(m1, g1, m2, g2, prediction)
+        .expect("No algorithm known to compute a contact point between the given pair of shapes.")
+}
+
+// Same as `contact_composite_shape_shape` but returns `None` instead of panicking if a part of
+// the composite shape close to `g2` is not supported by `query::contact_opt`.
+pub(crate) fn contact_composite_shape_shape_opt<N: RealField, G1: ?Sized>(
+    m1: &Isometry<N>,
+    g1: &G1,
+    m2: &Isometry<N>,
+    g2: &dyn Shape<N>,
+    prediction: N,
+) -> Option<Option<Contact<N>>>
 where
     G1: CompositeShape<N>,
 {
@@ -28,20 +44,31 @@ where
     }
 
     let mut res = None::<Contact<N>>;
+    let mut supported = true;
 
     for i in interferences.into_iter() {
-        g1.map_part_at(i, m1, &mut |m, part| {
-            if let Some(c) = query::contact(m, part, m2, g2, prediction) {
-                let replace = res.map_or(true, |cbest| c.depth > cbest.depth);
+        g1.map_part_at(
+            i,
+            m1,
+            &mut |m, part| match query::contact_opt(m, part, m2, g2, prediction) {
+                Some(Some(c)) => {
+                    let replace = res.map_or(true, |cbest| c.depth > cbest.depth);
 
-                if replace {
-                    res = Some(c)
+                    if replace {
+                        res = Some(c)
+                    }
                 }
-            }
-        });
+                Some(None) => {}
+                None => supported = false,
+            },
+        );
+
+        if !supported {
+            return None;
+        }
     }
 
-    res
+    Some(res)
 }
 
 /// Best contact between a shape and a composite (`Mesh`, `Compound`) shape.
@@ -4,6 +4,7 @@ use crate::shape::{FeatureId, Shape};
 use na::{self, RealField, Unit};
 
 /// A shape geometry type at the neighborhood of a point.
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 #[derive(Copy, Clone, Debug, PartialEq, Eq)]
 pub enum NeighborhoodGeometry<N: RealField> {
     /// A punctual approximation.
@@ -15,6 +16,7 @@ pub enum NeighborhoodGeometry<N: RealField> {
 }
 
 /// The approximation of a shape on the neighborhood of a point.
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 #[derive(Copy, Clone, Debug)]
 pub struct LocalShapeApproximation<N: RealField> {
     // XXX: currently, there is no explicit representation
@@ -53,6 +55,7 @@ impl<N: RealField> LocalShapeApproximation<N> {
 /// from one frame to another. To achieve this, the local shape of the solids
 /// around the given points are approximated by either dilated lines (unbounded
 /// cylinders), planes, dilated points (spheres).
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 #[derive(Copy, Clone, Debug)]
 pub struct ContactKinematic<N: RealField> {
     approx1: LocalShapeApproximation<N>,
@@ -245,14 +248,19 @@ impl<N: RealField> ContactKinematic<N> {
             }
             (NeighborhoodGeometry::Point, NeighborhoodGeometry::Point) => {
                 if let Some((n, d)) = Unit::try_new_and_get(world2 - world1, N::default_epsilon()) {
-                    if s1.tangent_cone_contains_dir(self.approx1.feature, m1, deformations1, &n)
-                        || s2.tangent_cone_contains_dir(
-                            self.approx2.feature,
-                            m2,
-                            deformations2,
-                            &-n,
-                        )
-                    {
+                    if s1.tangent_cone_at_point_contains_dir(
+                        self.approx1.feature,
+                        m1,
+                        deformations1,
+                        &self.approx1.point,
+                        &n,
+                    ) || s2.tangent_cone_at_point_contains_dir(
+                        self.approx2.feature,
+                        m2,
+                        deformations2,
+                        &self.approx2.point,
+                        &-n,
+                    ) {
                         depth = d;
                         normal = -n;
                     } else {
@@ -273,14 +281,19 @@ impl<N: RealField> ContactKinematic<N> {
                 if let Some((n, d)) = Unit::try_new_and_get(shift, na::zero()) {
                     world1 = world2 + (-shift);
 
-                    if s1.tangent_cone_contains_dir(self.approx1.feature, m1, deformations1, &n)
-                        || s2.tangent_cone_contains_dir(
-                            self.approx2.feature,
-                            m2,
-                            deformations2,
-                            &-n,
-                        )
-                    {
+                    if s1.tangent_cone_at_point_contains_dir(
+                        self.approx1.feature,
+                        m1,
+                        deformations1,
+                        &self.approx1.point,
+                        &n,
+                    ) || s2.tangent_cone_at_point_contains_dir(
+                        self.approx2.feature,
+                        m2,
+                        deformations2,
+                        &self.approx2.point,
+                        &-n,
+                    ) {
                         depth = d;
                         normal = -n;
                     } else {
@@ -304,14 +317,19 @@ impl<N: RealField> ContactKinematic<N> {
                 if let Some((n, d)) = Unit::try_new_and_get(shift, na::zero()) {
                     world2 = world1 + shift;
 
-                    if s1.tangent_cone_contains_dir(self.approx1.feature, m1, deformations1, &n)
-                        || s2.tangent_cone_contains_dir(
-                            self.approx2.feature,
-                            m2,
-                            deformations2,
-                            &-n,
-                        )
-                    {
+                    if s1.tangent_cone_at_point_contains_dir(
+                        self.approx1.feature,
+                        m1,
+                        deformations1,
+                        &self.approx1.point,
+                        &n,
+                    ) || s2.tangent_cone_at_point_contains_dir(
+                        self.approx2.feature,
+                        m2,
+                        deformations2,
+                        &self.approx2.point,
+                        &-n,
+                    ) {
                         depth = d;
                         normal = -n;
                     } else {
@@ -333,14 +351,19 @@ impl<N: RealField> ContactKinematic<N> {
                 world2 = pt2;
 
                 if let Some((n, d)) = Unit::try_new_and_get(world2 - world1, na::zero()) {
-                    if s1.tangent_cone_contains_dir(self.approx1.feature, m1, deformations1, &n)
-                        || s2.tangent_cone_contains_dir(
-                            self.approx2.feature,
-                            m2,
-                            deformations2,
-                            &-n,
-                        )
-                    {
+                    if s1.tangent_cone_at_point_contains_dir(
+                        self.approx1.feature,
+                        m1,
+                        deformations1,
+                        &self.approx1.point,
+                        &n,
+                    ) || s2.tangent_cone_at_point_contains_dir(
+                        self.approx2.feature,
+                        m2,
+                        deformations2,
+                        &self.approx2.point,
+                        &-n,
+                    ) {
                         depth = d;
                         normal = -n;
                     } else {
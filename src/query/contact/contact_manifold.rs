@@ -17,18 +17,46 @@ pub enum ContactTrackingMode<N: RealField> {
     DistanceBased(N),
 }
 
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 #[derive(Clone, Debug)]
 enum ContactCache<N: RealField> {
-    FeatureBased(HashMap<(FeatureId, FeatureId), usize>),
+    FeatureBased(
+        #[cfg_attr(feature = "serde", serde(with = "feature_cache_serde"))]
+        HashMap<(FeatureId, FeatureId), usize>,
+    ),
     DistanceBased(Vec<(Point<N>, usize)>, N),
 }
 
+// Tuple keys are not supported by all serialization formats so the feature-based cache is
+// serialized as a sequence of entries.
+#[cfg(feature = "serde")]
+mod feature_cache_serde {
+    use crate::shape::FeatureId;
+    use serde::{Deserialize, Deserializer, Serializer};
+    use std::collections::HashMap;
+
+    pub fn serialize<S: Serializer>(
+        cache: &HashMap<(FeatureId, FeatureId), usize>,
+        serializer: S,
+    ) -> Result<S::Ok, S::Error> {
+        serializer.collect_seq(cache.iter())
+    }
+
+    pub fn deserialize<'de, D: Deserializer<'de>>(
+        deserializer: D,
+    ) -> Result<HashMap<(FeatureId, FeatureId), usize>, D::Error> {
+        let entries = Vec::<((FeatureId, FeatureId), usize)>::deserialize(deserializer)?;
+        Ok(entries.into_iter().collect())
+    }
+}
+
 /// A contact manifold.
 ///
 /// A contact manifold is a set of contacts between two shapes.
 /// If the shapes are convex, then the convex hull of those contacts are often interpreted as surface.
 /// This structure is responsible for matching new contacts with old ones in order to perform an
 /// approximate tracking of the contact points.
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 #[derive(Clone, Debug)]
 pub struct ContactManifold<N: RealField> {
     ncontacts: usize,
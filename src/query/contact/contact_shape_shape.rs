@@ -1,12 +1,19 @@
 use na::RealField;
 
 use crate::math::{Isometry, Point};
+use crate::query::contact::contact_composite_shape_shape;
+#[cfg(feature = "dim3")]
+use crate::query::contact::contact_torus_shape;
 use crate::query::{self, Contact};
+#[cfg(feature = "dim3")]
+use crate::shape::Torus;
 use crate::shape::{Ball, Plane, Shape};
 
 /// Computes one contact point between two shapes.
 ///
 /// Returns `None` if the objects are separated by a distance greater than `prediction`.
+///
+/// Panics if no algorithm is known to compute a contact point between the given pair of shapes.
 pub fn contact<N: RealField>(
     m1: &Isometry<N>,
     g1: &dyn Shape<N>,
@@ -14,10 +21,39 @@ pub fn contact<N: RealField>(
     g2: &dyn Shape<N>,
     prediction: N,
 ) -> Option<Contact<N>> {
+    contact_opt(m1, g1, m2, g2, prediction)
+        .expect("No algorithm known to compute a contact point between the given pair of shapes.")
+}
+
+/// Computes one contact point between two shapes, if an algorithm is known for this pair of shapes.
+///
+/// Returns `None` if no algorithm is known to compute a contact point between the given pair of
+/// shapes, and `Some(None)` if the objects are separated by a distance greater than `prediction`.
+/// Only the parts of a composite shape close to the other shape are required to be supported.
+pub fn contact_opt<N: RealField>(
+    m1: &Isometry<N>,
+    g1: &dyn Shape<N>,
+    m2: &Isometry<N>,
+    g2: &dyn Shape<N>,
+    prediction: N,
+) -> Option<Option<Contact<N>>> {
+    #[cfg(feature = "dim3")]
+    {
+        if let Some(t1) = g1.as_shape::<Torus<N>>() {
+            return contact_torus_shape::contact_torus_shape_opt(m1, t1, m2, g2, prediction);
+        } else if let Some(t2) = g2.as_shape::<Torus<N>>() {
+            let res = contact_torus_shape::contact_torus_shape_opt(m2, t2, m1, g1, prediction)?;
+            return Some(res.map(|mut c| {
+                c.flip();
+                c
+            }));
+        }
+    }
+
     let ball1 = g1.as_shape::<Ball<N>>();
     let ball2 = g2.as_shape::<Ball<N>>();
 
-    if let (Some(b1), Some(b2)) = (ball1, ball2) {
+    let res = if let (Some(b1), Some(b2)) = (ball1, ball2) {
         let p1 = Point::from(m1.translation.vector);
         let p2 = Point::from(m2.translation.vector);
 
@@ -39,10 +75,20 @@ pub fn contact<N: RealField>(
     } else if let (Some(s1), Some(s2)) = (g1.as_support_map(), g2.as_support_map()) {
         query::contact_support_map_support_map(m1, s1, m2, s2, prediction)
     } else if let Some(c1) = g1.as_composite_shape() {
-        query::contact_composite_shape_shape(m1, c1, m2, g2, prediction)
+        contact_composite_shape_shape::contact_composite_shape_shape_opt(
+            m1, c1, m2, g2, prediction,
+        )?
     } else if let Some(c2) = g2.as_composite_shape() {
-        query::contact_shape_composite_shape(m1, g1, m2, c2, prediction)
+        let res = contact_composite_shape_shape::contact_composite_shape_shape_opt(
+            m2, c2, m1, g1, prediction,
+        )?;
+        res.map(|mut c| {
+            c.flip();
+            c
+        })
     } else {
-        panic!("No algorithm known to compute a contact point between the given pair of shapes.")
-    }
+        return None;
+    };
+
+    Some(res)
 }
@@ -0,0 +1,55 @@
+use crate::math::{Isometry, Translation};
+use crate::query::{self, Contact};
+use crate::shape::{torus, Ball, Shape, Torus};
+use na::RealField;
+
+/// Contact between a torus and any shape supported by `query::contact` when paired with a ball.
+///
+/// The torus is seen as the set of balls of radius `minor_radius` centered on the circle at the
+/// center of its tube, and the contact with the deepest of those balls is returned.
+pub fn contact_torus_shape<N: RealField>(
+    mtorus: &Isometry<N>,
+    torus: &Torus<N>,
+    mother: &Isometry<N>,
+    other: &dyn Shape<N>,
+    prediction: N,
+) -> Option<Contact<N>> {
+    contact_torus_shape_opt(mtorus, torus, mother, other, prediction)
+        .expect("No algorithm known to compute a contact point between the given pair of shapes.")
+}
+
+// Same as `contact_torus_shape` but returns `None` instead of panicking if the other shape is not
+// supported by `query::contact_opt` when paired with a ball.
+pub(crate) fn contact_torus_shape_opt<N: RealField>(
+    mtorus: &Isometry<N>,
+    torus: &Torus<N>,
+    mother: &Isometry<N>,
+    other: &dyn Shape<N>,
+    prediction: N,
+) -> Option<Option<Contact<N>>> {
+    let ball = Ball::new(torus.minor_radius);
+    let angles = torus::deepest_core_angles(mtorus, torus, mother, other, prediction)?;
+    let contact = angles
+        .into_iter()
+        .filter_map(|angle| {
+            let ball_pos = mtorus * Translation::from(torus.core_point(angle).coords);
+            query::contact(&ball_pos, &ball, mother, other, prediction)
+        })
+        .max_by(|c1, c2| c1.depth.partial_cmp(&c2.depth).unwrap());
+
+    Some(contact)
+}
+
+/// Contact between any shape supported by `query::contact` when paired with a ball, and a torus.
+pub fn contact_shape_torus<N: RealField>(
+    mother: &Isometry<N>,
+    other: &dyn Shape<N>,
+    mtorus: &Isometry<N>,
+    torus: &Torus<N>,
+    prediction: N,
+) -> Option<Contact<N>> {
+    contact_torus_shape(mtorus, torus, mother, other, prediction).map(|mut c| {
+        c.flip();
+        c
+    })
+}
@@ -15,9 +15,13 @@ pub use self::contact_composite_shape_shape::{
     contact_composite_shape_shape, contact_shape_composite_shape,
 };
 pub use self::contact_plane_support_map::{contact_plane_support_map, contact_support_map_plane};
-pub use self::contact_shape_shape::contact;
+pub use self::contact_shape_shape::{contact, contact_opt};
 pub use self::contact_support_map_support_map::contact_support_map_support_map;
 pub use self::contact_support_map_support_map::contact_support_map_support_map_with_params;
+#[cfg(feature = "dim3")]
+pub(crate) use self::contact_torus_shape::contact_torus_shape_opt;
+#[cfg(feature = "dim3")]
+pub use self::contact_torus_shape::{contact_shape_torus, contact_torus_shape};
 
 mod contact;
 mod contact_ball_ball;
@@ -29,3 +33,5 @@ mod contact_plane_support_map;
 mod contact_preprocessor;
 mod contact_shape_shape;
 mod contact_support_map_support_map;
+#[cfg(feature = "dim3")]
+mod contact_torus_shape;
@@ -0,0 +1,136 @@
+use na::{Isometry3, Matrix4, Perspective3, RealField, Unit, Vector3};
+
+use crate::bounding_volume::AABB;
+use crate::math::{Isometry, Point};
+use crate::query::{self, Proximity};
+use crate::shape::{ConvexHull, Plane, Shape};
+use crate::transformation::{HalfSpace, HalfSpaceIntersectionError};
+
+/// A view frustum, i.e., the volume seen by a camera, for visibility culling.
+///
+/// It is the intersection of the six half-spaces bounded by its left, right, bottom, top, near,
+/// and far planes.
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Clone, Debug)]
+pub struct Frustum<N: RealField> {
+    half_spaces: [HalfSpace<N>; 6],
+    hull: ConvexHull<N>,
+}
+
+impl<N: RealField> Frustum<N> {
+    /// Creates a frustum from its left, right, bottom, top, near, and far half-spaces.
+    pub fn from_half_spaces(
+        half_spaces: [HalfSpace<N>; 6],
+    ) -> Result<Self, HalfSpaceIntersectionError> {
+        let hull = ConvexHull::try_from_half_spaces(&half_spaces)?;
+        Ok(Frustum { half_spaces, hull })
+    }
+
+    /// Extracts the frustum from a view-projection matrix.
+    ///
+    /// The matrix maps the world-space frustum to the OpenGL clip-space where the visible points
+    /// have their normalized coordinates in `[-1, 1]`, e.g., the product of the matrix of a
+    /// `Perspective3` and the view matrix of the camera.
+    pub fn from_matrix(view_projection: &Matrix4<N>) -> Result<Self, HalfSpaceIntersectionError> {
+        let m = view_projection;
+        let r3 = m.row(3);
+        let clip_planes = [
+            r3 + m.row(0),
+            r3 - m.row(0),
+            r3 + m.row(1),
+            r3 - m.row(1),
+            r3 + m.row(2),
+            r3 - m.row(2),
+        ];
+
+        // A point is visible if `plane.dot(&[x, y, z, 1]) >= 0` for all the clip planes.
+        let mut half_spaces = [HalfSpace::new(Vector3::x_axis(), N::zero()); 6];
+
+        for (half_space, plane) in half_spaces.iter_mut().zip(clip_planes.iter()) {
+            let (normal, norm) = Unit::try_new_and_get(
+                -Vector3::new(plane[0], plane[1], plane[2]),
+                N::default_epsilon(),
+            )
+            .ok_or(HalfSpaceIntersectionError::Degenerate)?;
+            *half_space = HalfSpace::new(normal, plane[3] / norm);
+        }
+
+        Self::from_half_spaces(half_spaces)
+    }
+
+    /// Creates the frustum of a perspective camera.
+    ///
+    /// The camera is located at `pos` and looks toward the `-z` axis of its local frame, with the
+    /// `y` axis pointing upward. The vertical field of view `fovy` is in radians.
+    pub fn perspective(
+        pos: &Isometry3<N>,
+        fovy: N,
+        aspect: N,
+        znear: N,
+        zfar: N,
+    ) -> Result<Self, HalfSpaceIntersectionError> {
+        let projection = Perspective3::new(aspect, fovy, znear, zfar);
+        Self::from_matrix(&(projection.as_matrix() * pos.inverse().to_homogeneous()))
+    }
+
+    /// The left, right, bottom, top, near, and far half-spaces of this frustum.
+    #[inline]
+    pub fn half_spaces(&self) -> &[HalfSpace<N>; 6] {
+        &self.half_spaces
+    }
+
+    /// The convex polyhedron corresponding to this frustum.
+    #[inline]
+    pub fn convex_hull(&self) -> &ConvexHull<N> {
+        &self.hull
+    }
+
+    /// Whether the given point lies inside of this frustum.
+    #[inline]
+    pub fn contains_point(&self, pt: &Point<N>) -> bool {
+        self.half_spaces.iter().all(|h| h.contains_point(pt))
+    }
+
+    /// Whether the given AABB lies entirely inside of this frustum.
+    #[inline]
+    pub fn contains_aabb(&self, aabb: &AABB<N>) -> bool {
+        let center = aabb.center();
+        let half_extents = aabb.half_extents();
+
+        self.half_spaces.iter().all(|h| {
+            h.signed_distance_to_point(&center) + h.normal.abs().dot(&half_extents) <= N::zero()
+        })
+    }
+
+    /// Conservatively tests whether the given AABB intersects this frustum.
+    ///
+    /// This returns `false` only if the AABB lies entirely outside of one of the planes of this
+    /// frustum, so some AABBs close to the edges of this frustum may be reported as intersecting
+    /// while they are not.
+    #[inline]
+    pub fn intersects_aabb(&self, aabb: &AABB<N>) -> bool {
+        let center = aabb.center();
+        let half_extents = aabb.half_extents();
+
+        self.half_spaces.iter().all(|h| {
+            h.signed_distance_to_point(&center) - h.normal.abs().dot(&half_extents) <= N::zero()
+        })
+    }
+
+    /// Tests whether the given shape intersects this frustum.
+    ///
+    /// The test is exact for shapes supported by `query::proximity` and falls back to the test of
+    /// the shape AABB for the other ones.
+    pub fn intersects_shape(&self, m: &Isometry<N>, shape: &dyn Shape<N>) -> bool {
+        if !self.intersects_aabb(&shape.aabb(m)) {
+            return false;
+        }
+
+        if shape.is_support_map() || shape.is_composite_shape() || shape.is_shape::<Plane<N>>() {
+            query::proximity(&Isometry::identity(), &self.hull, m, shape, N::zero())
+                != Proximity::Disjoint
+        } else {
+            true
+        }
+    }
+}
@@ -29,6 +29,8 @@ pub use self::closest_points::*;
 pub use self::contact::*;
 pub use self::distance::*;
 pub use self::error::*;
+#[cfg(feature = "dim3")]
+pub use self::frustum::Frustum;
 pub use self::nonlinear_time_of_impact::*;
 pub use self::point::*;
 pub use self::proximity::*;
@@ -41,6 +43,8 @@ mod closest_points;
 mod contact;
 mod distance;
 mod error;
+#[cfg(feature = "dim3")]
+mod frustum;
 mod nonlinear_time_of_impact;
 mod point;
 mod proximity;
@@ -1,5 +1,6 @@
 //! Point inclusion and projection.
 
+pub use self::point_ellipsoid::local_ellipsoid_boundary_projection;
 #[doc(inline)]
 pub use self::point_query::{PointProjection, PointQuery, PointQueryWithLocation};
 pub use self::point_support_map::point_projection_on_support_map;
@@ -10,16 +11,23 @@ mod point_bounding_sphere;
 mod point_capsule;
 mod point_compound;
 mod point_cuboid;
+mod point_ellipsoid;
 mod point_heightfield;
+mod point_obb;
 mod point_plane;
 mod point_polyline;
 #[doc(hidden)]
 pub mod point_query;
+mod point_round_shape;
+mod point_scaled;
 mod point_segment;
 mod point_shape;
 mod point_support_map;
 #[cfg(feature = "dim3")]
 mod point_tetrahedron;
+#[cfg(feature = "dim3")]
+mod point_torus;
 mod point_triangle;
 #[cfg(feature = "dim3")]
 mod point_trimesh;
+mod point_voxels;
@@ -0,0 +1,108 @@
+use na::{self, RealField};
+
+use crate::math::{Isometry, Point, Vector, DIM};
+use crate::query::{PointProjection, PointQuery};
+use crate::shape::{Ellipsoid, FeatureId};
+
+// Upper bound of the number of bisection steps. Bisection stops earlier once the floating-point
+// precision is reached.
+const MAX_BISECTION_STEPS: usize = 256;
+
+/// Projects a point, expressed in the local-space of an ellipsoid, on the boundary of this ellipsoid.
+///
+/// The projection `x` of `pt` satisfies `x_i = r_i² * pt_i / (t + r_i²)` where `t` is the unique
+/// root, greater than `-min(r_i²)`, of `F(t) = Σ (r_i * pt_i / (t + r_i²))² - 1`. This root is
+/// found by bisection, which is robust to points close to the center or to the principal planes.
+pub fn local_ellipsoid_boundary_projection<N: RealField>(
+    ellipsoid: &Ellipsoid<N>,
+    pt: &Point<N>,
+) -> Point<N> {
+    let radii = &ellipsoid.radii;
+    let radii2 = radii.component_mul(radii);
+    let abs_pt = pt.coords.abs();
+    let (imin, min_radius2) = radii2.argmin();
+
+    // If the point lies on all the principal planes orthogonal to the smallest radii, the root of
+    // `F` may be missing. The projection then has a non-zero component along one of these axes.
+    if (0..DIM).all(|i| radii2[i] != min_radius2 || abs_pt[i].is_zero()) {
+        let mut proj = Vector::zeros();
+        let mut sum = N::zero();
+
+        for i in 0..DIM {
+            if radii2[i] != min_radius2 {
+                proj[i] = radii2[i] * abs_pt[i] / (radii2[i] - min_radius2);
+                sum += (proj[i] / radii[i]) * (proj[i] / radii[i]);
+            }
+        }
+
+        if sum < N::one() {
+            proj[imin] = radii[imin] * (N::one() - sum).sqrt();
+            return Point::from(proj.zip_map(&pt.coords, |p, s| p.copysign(s)));
+        }
+    }
+
+    // `F` is decreasing on `]-min(r_i²), +inf[`, positive close to `-min(r_i²)`, and
+    // non-positive at `t = |R * pt| - min(r_i²)`.
+    let f = |t: N| {
+        let mut res = -N::one();
+
+        for i in 0..DIM {
+            let ratio = radii[i] * abs_pt[i] / (t + radii2[i]);
+            res += ratio * ratio;
+        }
+
+        res
+    };
+
+    let mut lo = -min_radius2;
+    let mut hi = radii.component_mul(&abs_pt).norm() - min_radius2;
+    let _0_5: N = na::convert(0.5f64);
+
+    for _ in 0..MAX_BISECTION_STEPS {
+        let mid = (lo + hi) * _0_5;
+
+        if mid == lo || mid == hi {
+            break;
+        }
+
+        if f(mid) > N::zero() {
+            lo = mid;
+        } else {
+            hi = mid;
+        }
+    }
+
+    let t = (lo + hi) * _0_5;
+    let proj = Vector::from_fn(|i, _| radii2[i] * abs_pt[i] / (t + radii2[i]));
+
+    Point::from(proj.zip_map(&pt.coords, |p, s| p.copysign(s)))
+}
+
+impl<N: RealField> PointQuery<N> for Ellipsoid<N> {
+    #[inline]
+    fn project_point(&self, m: &Isometry<N>, pt: &Point<N>, solid: bool) -> PointProjection<N> {
+        let ls_pt = m.inverse_transform_point(pt);
+        let inside = self.contains_local_point(&ls_pt);
+
+        if inside && solid {
+            PointProjection::new(true, *pt)
+        } else {
+            let ls_proj = local_ellipsoid_boundary_projection(self, &ls_pt);
+            PointProjection::new(inside, m * ls_proj)
+        }
+    }
+
+    #[inline]
+    fn project_point_with_feature(
+        &self,
+        m: &Isometry<N>,
+        pt: &Point<N>,
+    ) -> (PointProjection<N>, FeatureId) {
+        (self.project_point(m, pt, false), FeatureId::Face(0))
+    }
+
+    #[inline]
+    fn contains_point(&self, m: &Isometry<N>, pt: &Point<N>) -> bool {
+        self.contains_local_point(&m.inverse_transform_point(pt))
+    }
+}
@@ -0,0 +1,81 @@
+use na::{RealField, Unit};
+
+use crate::math::{Isometry, Point};
+use crate::query::algorithms::VoronoiSimplex;
+use crate::query::{self, PointProjection, PointQuery};
+use crate::shape::{FeatureId, RoundShape, SupportMap};
+
+impl<N: RealField, S: PointQuery<N> + SupportMap<N>> RoundShape<N, S> {
+    // Projects a local point on the boundary of this round shape.
+    fn local_project_point_on_boundary(&self, pt: &Point<N>) -> (PointProjection<N>, FeatureId) {
+        let (inner_proj, feature) = self
+            .inner_shape
+            .project_point_with_feature(&Isometry::identity(), pt);
+        let dpt = pt - inner_proj.point;
+
+        match Unit::try_new_and_get(dpt, N::default_epsilon()) {
+            Some((dir, dist)) => {
+                // The outward normal of the inner shape at the projection, and the signed
+                // distance from the inner shape.
+                let (normal, dist) = if inner_proj.is_inside {
+                    (-dir, -dist)
+                } else {
+                    (dir, dist)
+                };
+                let proj = inner_proj.point + *normal * self.border_radius;
+
+                (
+                    PointProjection::new(dist <= self.border_radius, proj),
+                    feature,
+                )
+            }
+            None => {
+                // The point is on the boundary of the inner shape so the projection direction
+                // is unknown.
+                let proj = query::point_projection_on_support_map(
+                    &Isometry::identity(),
+                    self,
+                    &mut VoronoiSimplex::new(),
+                    pt,
+                    false,
+                );
+
+                (PointProjection::new(true, proj.point), feature)
+            }
+        }
+    }
+}
+
+impl<N: RealField, S: PointQuery<N> + SupportMap<N>> PointQuery<N> for RoundShape<N, S> {
+    #[inline]
+    fn project_point(&self, m: &Isometry<N>, pt: &Point<N>, solid: bool) -> PointProjection<N> {
+        let ls_pt = m.inverse_transform_point(pt);
+        let (proj, _) = self.local_project_point_on_boundary(&ls_pt);
+
+        if proj.is_inside && solid {
+            PointProjection::new(true, *pt)
+        } else {
+            PointProjection::new(proj.is_inside, m * proj.point)
+        }
+    }
+
+    #[inline]
+    fn project_point_with_feature(
+        &self,
+        m: &Isometry<N>,
+        pt: &Point<N>,
+    ) -> (PointProjection<N>, FeatureId) {
+        let ls_pt = m.inverse_transform_point(pt);
+        let (proj, feature) = self.local_project_point_on_boundary(&ls_pt);
+
+        (
+            PointProjection::new(proj.is_inside, m * proj.point),
+            feature,
+        )
+    }
+
+    #[inline]
+    fn contains_point(&self, m: &Isometry<N>, pt: &Point<N>) -> bool {
+        self.inner_shape.distance_to_point(m, pt, true) <= self.border_radius
+    }
+}
@@ -0,0 +1,82 @@
+use na::RealField;
+
+use crate::math::{Isometry, Point};
+use crate::query::algorithms::VoronoiSimplex;
+use crate::query::{self, PointProjection, PointQuery};
+use crate::shape::{FeatureId, Scaled, Shape};
+
+/// Point queries on a scaled shape.
+///
+/// If the scaled shape is a support map, the projection of a point is computed exactly with the
+/// GJK algorithm on the support map of the scaled shape. Otherwise, it is computed on the unscaled
+/// shape, and then scaled: this is the exact projection only if the scaling is uniform. With a
+/// non-uniform scaling, the projected point lies on the boundary of the scaled shape but is not
+/// necessarily the closest to the input point. Point inclusion tests are always exact.
+///
+/// Panics if the scaled shape does not support point queries.
+impl<N: RealField, S: Shape<N>> PointQuery<N> for Scaled<N, S> {
+    #[inline]
+    fn project_point(&self, m: &Isometry<N>, pt: &Point<N>, solid: bool) -> PointProjection<N> {
+        if self.shape().is_support_map() {
+            return query::point_projection_on_support_map(
+                m,
+                self,
+                &mut VoronoiSimplex::new(),
+                pt,
+                solid,
+            );
+        }
+
+        let unscaled_pt = self.unscale_point(&m.inverse_transform_point(pt));
+        let proj =
+            self.inner_point_query()
+                .project_point(&Isometry::identity(), &unscaled_pt, solid);
+
+        if proj.is_inside && solid {
+            PointProjection::new(true, *pt)
+        } else {
+            PointProjection::new(proj.is_inside, m * self.scale_point(&proj.point))
+        }
+    }
+
+    #[inline]
+    fn project_point_with_feature(
+        &self,
+        m: &Isometry<N>,
+        pt: &Point<N>,
+    ) -> (PointProjection<N>, FeatureId) {
+        if self.shape().is_support_map() {
+            // The feature is the one of the unscaled shape containing the exact projection.
+            let proj = self.project_point(m, pt, false);
+            let unscaled_proj = self.unscale_point(&m.inverse_transform_point(&proj.point));
+            let (_, feature) = self
+                .inner_point_query()
+                .project_point_with_feature(&Isometry::identity(), &unscaled_proj);
+
+            return (proj, feature);
+        }
+
+        let unscaled_pt = self.unscale_point(&m.inverse_transform_point(pt));
+        let (proj, feature) = self
+            .inner_point_query()
+            .project_point_with_feature(&Isometry::identity(), &unscaled_pt);
+        let proj = PointProjection::new(proj.is_inside, m * self.scale_point(&proj.point));
+
+        (proj, feature)
+    }
+
+    #[inline]
+    fn contains_point(&self, m: &Isometry<N>, pt: &Point<N>) -> bool {
+        let unscaled_pt = self.unscale_point(&m.inverse_transform_point(pt));
+        self.inner_point_query()
+            .contains_point(&Isometry::identity(), &unscaled_pt)
+    }
+}
+
+impl<N: RealField, S: Shape<N>> Scaled<N, S> {
+    fn inner_point_query(&self) -> &dyn PointQuery<N> {
+        self.shape()
+            .as_point_query()
+            .expect("The scaled shape does not support point queries.")
+    }
+}
@@ -1,4 +1,6 @@
-use na::{RealField, Unit};
+use na::{self, RealField, Unit};
+#[cfg(feature = "dim3")]
+use std::f64;
 
 use crate::math::{Isometry, Point, Translation, Vector};
 use crate::query::algorithms::{
@@ -9,7 +11,7 @@ use crate::query::{PointProjection, PointQuery};
 use crate::shape::ConvexPolygon;
 #[cfg(feature = "dim3")]
 use crate::shape::{Cone, ConvexHull, Cylinder};
-use crate::shape::{ConvexPolyhedron, FeatureId, SupportMap};
+use crate::shape::{ConvexHullOf, ConvexPolyhedron, FeatureId, MinkowskiSum, SupportMap};
 
 /// Projects a point on a shape using the GJK algorithm.
 pub fn point_projection_on_support_map<N, G>(
@@ -65,7 +67,20 @@ impl<N: RealField> PointQuery<N> for Cylinder<N> {
         m: &Isometry<N>,
         point: &Point<N>,
     ) -> (PointProjection<N>, FeatureId) {
-        (self.project_point(m, point, false), FeatureId::Unknown)
+        let proj = self.project_point(m, point, false);
+        let dpt = *point - proj.point;
+        let local_dir = if proj.is_inside {
+            m.inverse_transform_vector(&-dpt)
+        } else {
+            m.inverse_transform_vector(&dpt)
+        };
+
+        if let Some(local_dir) = Unit::try_new(local_dir, N::default_epsilon()) {
+            let eps = na::convert(f64::consts::PI / 180.0);
+            (proj, self.support_feature_id_toward(&local_dir, eps))
+        } else {
+            (proj, FeatureId::Unknown)
+        }
     }
 }
 
@@ -82,7 +97,20 @@ impl<N: RealField> PointQuery<N> for Cone<N> {
         m: &Isometry<N>,
         point: &Point<N>,
     ) -> (PointProjection<N>, FeatureId) {
-        (self.project_point(m, point, false), FeatureId::Unknown)
+        let proj = self.project_point(m, point, false);
+        let dpt = *point - proj.point;
+        let local_dir = if proj.is_inside {
+            m.inverse_transform_vector(&-dpt)
+        } else {
+            m.inverse_transform_vector(&dpt)
+        };
+
+        if let Some(local_dir) = Unit::try_new(local_dir, N::default_epsilon()) {
+            let eps = na::convert(f64::consts::PI / 180.0);
+            (proj, self.support_feature_id_toward(&local_dir, eps))
+        } else {
+            (proj, FeatureId::Unknown)
+        }
     }
 }
 
@@ -145,3 +173,31 @@ impl<N: RealField> PointQuery<N> for ConvexPolygon<N> {
         }
     }
 }
+
+macro_rules! impl_point_query_for_pair(
+    ($shape: ident) => {
+        impl<N: RealField, A: SupportMap<N>, B: SupportMap<N>> PointQuery<N> for $shape<N, A, B> {
+            #[inline]
+            fn project_point(
+                &self,
+                m: &Isometry<N>,
+                point: &Point<N>,
+                solid: bool,
+            ) -> PointProjection<N> {
+                point_projection_on_support_map(m, self, &mut VoronoiSimplex::new(), point, solid)
+            }
+
+            #[inline]
+            fn project_point_with_feature(
+                &self,
+                m: &Isometry<N>,
+                point: &Point<N>,
+            ) -> (PointProjection<N>, FeatureId) {
+                (self.project_point(m, point, false), FeatureId::Unknown)
+            }
+        }
+    }
+);
+
+impl_point_query_for_pair!(MinkowskiSum);
+impl_point_query_for_pair!(ConvexHullOf);
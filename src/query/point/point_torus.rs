@@ -0,0 +1,54 @@
+use na::RealField;
+
+use crate::math::{Isometry, Point};
+use crate::query::{PointProjection, PointQuery};
+use crate::shape::{FeatureId, Torus};
+
+impl<N: RealField> PointQuery<N> for Torus<N> {
+    #[inline]
+    fn project_point(&self, m: &Isometry<N>, pt: &Point<N>, solid: bool) -> PointProjection<N> {
+        let ls_pt = m.inverse_transform_point(pt);
+        let core = self.closest_core_point(&ls_pt);
+        let dpt = ls_pt - core;
+        let inside = dpt.norm_squared() <= self.minor_radius * self.minor_radius;
+
+        if inside && solid {
+            return PointProjection::new(true, *pt);
+        }
+
+        // Points on the circle at the center of the tube are projected on its outer equator.
+        let dir = dpt
+            .try_normalize(N::default_epsilon())
+            .unwrap_or_else(|| core.coords / self.major_radius);
+        let ls_proj = core + dir * self.minor_radius;
+
+        PointProjection::new(inside, m * ls_proj)
+    }
+
+    #[inline]
+    fn project_point_with_feature(
+        &self,
+        m: &Isometry<N>,
+        pt: &Point<N>,
+    ) -> (PointProjection<N>, FeatureId) {
+        let proj = self.project_point(m, pt, false);
+        let feature = self.slice_feature_id(&m.inverse_transform_point(&proj.point));
+        (proj, feature)
+    }
+
+    #[inline]
+    fn distance_to_point(&self, m: &Isometry<N>, pt: &Point<N>, solid: bool) -> N {
+        let dist = self.distance_to_core(&m.inverse_transform_point(pt)) - self.minor_radius;
+
+        if solid && dist < N::zero() {
+            N::zero()
+        } else {
+            dist
+        }
+    }
+
+    #[inline]
+    fn contains_point(&self, m: &Isometry<N>, pt: &Point<N>) -> bool {
+        self.contains_local_point(&m.inverse_transform_point(pt))
+    }
+}
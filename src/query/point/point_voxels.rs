@@ -0,0 +1,132 @@
+use crate::bounding_volume::AABB;
+use crate::math::{Isometry, Point, DIM};
+use crate::partitioning::{BestFirstVisitStatus, BestFirstVisitor, BVH};
+use crate::query::{PointProjection, PointQuery};
+use crate::shape::{FeatureId, Voxels};
+use na::{self, RealField};
+
+impl<N: RealField> PointQuery<N> for Voxels<N> {
+    #[inline]
+    fn project_point(&self, m: &Isometry<N>, point: &Point<N>, solid: bool) -> PointProjection<N> {
+        self.project_point_with_feature_and_solid(m, point, solid).0
+    }
+
+    #[inline]
+    fn project_point_with_feature(
+        &self,
+        m: &Isometry<N>,
+        point: &Point<N>,
+    ) -> (PointProjection<N>, FeatureId) {
+        self.project_point_with_feature_and_solid(m, point, false)
+    }
+
+    #[inline]
+    fn contains_point(&self, m: &Isometry<N>, point: &Point<N>) -> bool {
+        let ls_pt = m.inverse_transform_point(point);
+        self.is_filled(&self.voxel_key_at_point(&ls_pt))
+    }
+}
+
+impl<N: RealField> Voxels<N> {
+    fn project_point_with_feature_and_solid(
+        &self,
+        m: &Isometry<N>,
+        point: &Point<N>,
+        solid: bool,
+    ) -> (PointProjection<N>, FeatureId) {
+        let ls_pt = m.inverse_transform_point(point);
+        let is_inside = self.is_filled(&self.voxel_key_at_point(&ls_pt));
+
+        if is_inside && solid {
+            return (PointProjection::new(true, *point), FeatureId::Unknown);
+        }
+
+        // The closest point of the boundary lies on a voxel face that is not shared with a
+        // filled neighbor.
+        let mut visitor = VoxelsPointProjVisitor {
+            voxels: self,
+            point: &ls_pt,
+        };
+
+        let (ls_proj, feature) = self
+            .bvt()
+            .best_first_search(&mut visitor)
+            .expect("A voxel shape always has at least one exposed face.")
+            .1;
+
+        (PointProjection::new(is_inside, m * ls_proj), feature)
+    }
+}
+
+/*
+ * Visitors
+ */
+struct VoxelsPointProjVisitor<'a, N: 'a + RealField> {
+    voxels: &'a Voxels<N>,
+    point: &'a Point<N>,
+}
+
+impl<'a, N: RealField> BestFirstVisitor<N, usize, AABB<N>> for VoxelsPointProjVisitor<'a, N> {
+    type Result = (Point<N>, FeatureId);
+
+    #[inline]
+    fn visit(
+        &mut self,
+        best: N,
+        aabb: &AABB<N>,
+        data: Option<&usize>,
+    ) -> BestFirstVisitStatus<N, Self::Result> {
+        let dist = aabb.distance_to_point(&Isometry::identity(), self.point, true);
+
+        let mut res = BestFirstVisitStatus::Continue {
+            cost: dist,
+            result: None,
+        };
+
+        if let Some(i) = data {
+            if dist < best {
+                let key = self.voxels.voxels()[*i];
+                let voxel_aabb = self.voxels.voxel_aabb(&key);
+                let mut best_face = None;
+                let mut best_dist = best;
+
+                for a in 0..DIM {
+                    for &positive in &[true, false] {
+                        let mut neighbor = key;
+                        neighbor[a] += if positive { 1 } else { -1 };
+
+                        if self.voxels.is_filled(&neighbor) {
+                            continue;
+                        }
+
+                        // Flatten the voxel AABB into its face orthogonal to the axis `a`.
+                        let mut face = voxel_aabb;
+                        if positive {
+                            face.mins[a] = face.maxs[a];
+                        } else {
+                            face.maxs[a] = face.mins[a];
+                        }
+
+                        let proj = face.project_point(&Isometry::identity(), self.point, true);
+                        let dist = na::distance(self.point, &proj.point);
+
+                        if dist < best_dist {
+                            best_dist = dist;
+                            best_face =
+                                Some((proj.point, self.voxels.voxel_face_id(*i, a, positive)));
+                        }
+                    }
+                }
+
+                if let Some(result) = best_face {
+                    res = BestFirstVisitStatus::Continue {
+                        cost: best_dist,
+                        result: Some(result),
+                    };
+                }
+            }
+        }
+
+        res
+    }
+}
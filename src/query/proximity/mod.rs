@@ -8,9 +8,11 @@ pub use self::proximity_composite_shape_shape::{
 pub use self::proximity_plane_support_map::{
     proximity_plane_support_map, proximity_support_map_plane,
 };
-pub use self::proximity_shape_shape::proximity;
+pub use self::proximity_shape_shape::{proximity, proximity_opt};
 pub use self::proximity_support_map_support_map::proximity_support_map_support_map;
 pub use self::proximity_support_map_support_map::proximity_support_map_support_map_with_params;
+#[cfg(feature = "dim3")]
+pub use self::proximity_torus_shape::{proximity_shape_torus, proximity_torus_shape};
 
 mod proximity;
 mod proximity_ball_ball;
@@ -18,3 +20,5 @@ mod proximity_composite_shape_shape;
 mod proximity_plane_support_map;
 mod proximity_shape_shape;
 mod proximity_support_map_support_map;
+#[cfg(feature = "dim3")]
+mod proximity_torus_shape;
@@ -13,6 +13,22 @@ pub fn proximity_composite_shape_shape<N: RealField, G1: ?Sized>(
     g2: &dyn Shape<N>,
     margin: N,
 ) -> Proximity
+where
+    G1: CompositeShape<N>,
+{
+    proximity_composite_shape_shape_opt(m1, g1, m2, g2, margin)
+        .expect("No algorithm known to compute proximity between the given pair of shapes.")
+}
+
+// Same as `proximity_composite_shape_shape` but returns `None` instead of panicking if a part of
+// the composite shape visited is not supported by `query::proximity_opt`.
+pub(crate) fn proximity_composite_shape_shape_opt<N: RealField, G1: ?Sized>(
+    m1: &Isometry<N>,
+    g1: &G1,
+    m2: &Isometry<N>,
+    g2: &dyn Shape<N>,
+    margin: N,
+) -> Option<Proximity>
 where
     G1: CompositeShape<N>,
 {
@@ -24,7 +40,7 @@ where
     let mut visitor = CompositeShapeAgainstAnyInterfVisitor::new(m1, g1, m2, g2, margin);
 
     match g1.bvh().best_first_search(&mut visitor) {
-        None => Proximity::Disjoint,
+        None => Some(Proximity::Disjoint),
         Some(prox) => prox.1,
     }
 }
@@ -85,7 +101,8 @@ impl<'a, N: RealField, G1: ?Sized> BestFirstVisitor<N, usize, AABB<N>>
 where
     G1: CompositeShape<N>,
 {
-    type Result = Proximity;
+    // `None` if a part of the composite shape is not supported.
+    type Result = Option<Proximity>;
 
     fn visit(
         &mut self,
@@ -107,25 +124,28 @@ where
         };
 
         if let Some(b) = data {
-            if dist < best {
+            // The parts farther than the margin are disjoint from `g2` so they are not checked.
+            if dist < best && dist <= self.margin {
                 self.g1
-                    .map_part_at(*b, self.m1, &mut |m1, g1| match query::proximity(
+                    .map_part_at(*b, self.m1, &mut |m1, g1| match query::proximity_opt(
                         m1,
                         g1,
                         self.m2,
                         self.g2,
                         self.margin,
                     ) {
-                        Proximity::WithinMargin => {
+                        Some(Proximity::WithinMargin) => {
                             res = BestFirstVisitStatus::Continue {
                                 cost: self.margin,
-                                result: Some(Proximity::WithinMargin),
+                                result: Some(Some(Proximity::WithinMargin)),
                             }
                         }
-                        Proximity::Intersecting => {
-                            res = BestFirstVisitStatus::ExitEarly(Some(Proximity::Intersecting))
+                        Some(Proximity::Intersecting) => {
+                            res =
+                                BestFirstVisitStatus::ExitEarly(Some(Some(Proximity::Intersecting)))
                         }
-                        Proximity::Disjoint => {}
+                        Some(Proximity::Disjoint) => {}
+                        None => res = BestFirstVisitStatus::ExitEarly(Some(None)),
                     });
             }
         }
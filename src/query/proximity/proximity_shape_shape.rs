@@ -1,10 +1,17 @@
 use na::RealField;
 
 use crate::math::{Isometry, Point};
+use crate::query::proximity::proximity_composite_shape_shape;
+#[cfg(feature = "dim3")]
+use crate::query::proximity::proximity_torus_shape;
 use crate::query::{self, Proximity};
+#[cfg(feature = "dim3")]
+use crate::shape::Torus;
 use crate::shape::{Ball, Plane, Shape};
 
 /// Tests whether two shapes are in intersecting or separated by a distance smaller than `margin`.
+///
+/// Panics if no algorithm is known to compute proximity between the given pair of shapes.
 pub fn proximity<N: RealField>(
     m1: &Isometry<N>,
     g1: &dyn Shape<N>,
@@ -12,7 +19,32 @@ pub fn proximity<N: RealField>(
     g2: &dyn Shape<N>,
     margin: N,
 ) -> Proximity {
-    if let (Some(b1), Some(b2)) = (g1.as_shape::<Ball<N>>(), g2.as_shape::<Ball<N>>()) {
+    proximity_opt(m1, g1, m2, g2, margin)
+        .expect("No algorithm known to compute proximity between the given pair of shapes.")
+}
+
+/// Tests whether two shapes are in intersecting or separated by a distance smaller than `margin`,
+/// if an algorithm is known for this pair of shapes.
+///
+/// Returns `None` if no algorithm is known to compute proximity between the given pair of shapes.
+/// Only the parts of a composite shape close to the other shape are required to be supported.
+pub fn proximity_opt<N: RealField>(
+    m1: &Isometry<N>,
+    g1: &dyn Shape<N>,
+    m2: &Isometry<N>,
+    g2: &dyn Shape<N>,
+    margin: N,
+) -> Option<Proximity> {
+    #[cfg(feature = "dim3")]
+    {
+        if let Some(t1) = g1.as_shape::<Torus<N>>() {
+            return proximity_torus_shape::proximity_torus_shape_opt(m1, t1, m2, g2, margin);
+        } else if let Some(t2) = g2.as_shape::<Torus<N>>() {
+            return proximity_torus_shape::proximity_torus_shape_opt(m2, t2, m1, g1, margin);
+        }
+    }
+
+    let res = if let (Some(b1), Some(b2)) = (g1.as_shape::<Ball<N>>(), g2.as_shape::<Ball<N>>()) {
         let p1 = Point::from(m1.translation.vector);
         let p2 = Point::from(m2.translation.vector);
 
@@ -24,10 +56,16 @@ pub fn proximity<N: RealField>(
     } else if let (Some(s1), Some(s2)) = (g1.as_support_map(), g2.as_support_map()) {
         query::proximity_support_map_support_map(m1, s1, m2, s2, margin)
     } else if let Some(c1) = g1.as_composite_shape() {
-        query::proximity_composite_shape_shape(m1, c1, m2, g2, margin)
+        proximity_composite_shape_shape::proximity_composite_shape_shape_opt(
+            m1, c1, m2, g2, margin,
+        )?
     } else if let Some(c2) = g2.as_composite_shape() {
-        query::proximity_shape_composite_shape(m1, g1, m2, c2, margin)
+        proximity_composite_shape_shape::proximity_composite_shape_shape_opt(
+            m2, c2, m1, g1, margin,
+        )?
     } else {
-        panic!("No algorithm known to compute proximity between the given pair of shapes.")
-    }
+        return None;
+    };
+
+    Some(res)
 }
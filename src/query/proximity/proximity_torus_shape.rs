@@ -0,0 +1,46 @@
+use crate::math::Isometry;
+use crate::query::contact::contact_torus_shape_opt;
+use crate::query::Proximity;
+use crate::shape::{Shape, Torus};
+use na::RealField;
+
+/// Proximity between a torus and any shape supported by `query::contact` when paired with a ball.
+pub fn proximity_torus_shape<N: RealField>(
+    mtorus: &Isometry<N>,
+    torus: &Torus<N>,
+    mother: &Isometry<N>,
+    other: &dyn Shape<N>,
+    margin: N,
+) -> Proximity {
+    proximity_torus_shape_opt(mtorus, torus, mother, other, margin)
+        .expect("No algorithm known to compute proximity between the given pair of shapes.")
+}
+
+// Same as `proximity_torus_shape` but returns `None` instead of panicking if the other shape is
+// not supported by `query::contact_opt` when paired with a ball.
+pub(crate) fn proximity_torus_shape_opt<N: RealField>(
+    mtorus: &Isometry<N>,
+    torus: &Torus<N>,
+    mother: &Isometry<N>,
+    other: &dyn Shape<N>,
+    margin: N,
+) -> Option<Proximity> {
+    let prox = match contact_torus_shape_opt(mtorus, torus, mother, other, margin)? {
+        Some(c) if c.depth >= N::zero() => Proximity::Intersecting,
+        Some(_) => Proximity::WithinMargin,
+        None => Proximity::Disjoint,
+    };
+
+    Some(prox)
+}
+
+/// Proximity between any shape supported by `query::contact` when paired with a ball, and a torus.
+pub fn proximity_shape_torus<N: RealField>(
+    mother: &Isometry<N>,
+    other: &dyn Shape<N>,
+    mtorus: &Isometry<N>,
+    torus: &Torus<N>,
+    margin: N,
+) -> Proximity {
+    proximity_torus_shape(mtorus, torus, mother, other, margin)
+}
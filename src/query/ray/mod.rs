@@ -13,14 +13,28 @@ pub mod ray;
 mod ray_aabb;
 mod ray_ball;
 mod ray_bounding_sphere;
+mod ray_capsule;
 mod ray_compound;
+#[cfg(feature = "dim3")]
+mod ray_cone;
 mod ray_cuboid;
+#[cfg(feature = "dim3")]
+mod ray_cylinder;
+mod ray_ellipsoid;
 mod ray_heightfield;
+mod ray_kdop;
+mod ray_obb;
 mod ray_plane;
 mod ray_polyline;
+mod ray_quadric;
+mod ray_round_shape;
+mod ray_scaled;
 mod ray_shape;
 mod ray_support_map;
 #[cfg(feature = "dim3")]
+mod ray_torus;
+#[cfg(feature = "dim3")]
 mod ray_triangle;
 #[cfg(feature = "dim3")]
 mod ray_trimesh;
+mod ray_voxels;
@@ -11,7 +11,7 @@ use crate::shape::{Ball, FeatureId};
 
 #[cfg(feature = "dim3")]
 #[inline]
-fn ball_uv<N: RealField>(normal: &Vector<N>) -> Point2<N> {
+pub(crate) fn ball_uv<N: RealField>(normal: &Vector<N>) -> Point2<N> {
     let two_pi: N = RealField::two_pi();
     let pi: N = RealField::pi();
     let _0_5: N = na::convert(0.5f64);
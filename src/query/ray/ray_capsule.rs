@@ -0,0 +1,133 @@
+use na::RealField;
+#[cfg(feature = "dim3")]
+use na::{self, Point2};
+
+use crate::math::{Isometry, Point, Vector};
+use crate::query::ray::ray_quadric::{self, LineClip};
+use crate::query::{Ray, RayCast, RayIntersection};
+use crate::shape::{Capsule, FeatureId};
+
+impl<N: RealField> RayCast<N> for Capsule<N> {
+    #[inline]
+    fn toi_and_normal_with_ray(
+        &self,
+        m: &Isometry<N>,
+        ray: &Ray<N>,
+        max_toi: N,
+        solid: bool,
+    ) -> Option<RayIntersection<N>> {
+        local_ray_intersection(self, &ray.inverse_transform_by(m), max_toi, solid).map(|mut res| {
+            res.normal = m * res.normal;
+            res
+        })
+    }
+
+    /// The texture coordinates are `(u, v)` where `u` is the angle around the `y` axis and `v`
+    /// the curvilinear abscissa along the profile of the capsule, from its top to its bottom.
+    /// Both are normalized to `[0, 1]`.
+    #[cfg(feature = "dim3")]
+    #[inline]
+    fn toi_and_normal_and_uv_with_ray(
+        &self,
+        m: &Isometry<N>,
+        ray: &Ray<N>,
+        max_toi: N,
+        solid: bool,
+    ) -> Option<RayIntersection<N>> {
+        let ls_ray = ray.inverse_transform_by(m);
+
+        local_ray_intersection(self, &ls_ray, max_toi, solid).map(|mut res| {
+            if res.normal != Vector::zeros() {
+                // The abscissa is measured from the top along the top half-circle, the lateral
+                // segment, and the bottom half-circle of the profile.
+                let pt = ls_ray.point_at(res.toi);
+                let height = self.half_height * na::convert(2.0);
+                let center_y = pt.y.max(-self.half_height).min(self.half_height);
+                let polar = res.normal.y.max(-N::one()).min(N::one()).acos();
+                let abscissa = self.radius * polar + self.half_height - center_y;
+
+                res.uvs = Some(Point2::new(
+                    ray_quadric::angular_uv(&res.normal),
+                    abscissa / (self.radius * N::pi() + height),
+                ));
+            }
+
+            res.normal = m * res.normal;
+            res
+        })
+    }
+}
+
+/// Computes the part of a line, expressed in the local-space of a capsule, inside of this capsule.
+fn clip_line<N: RealField>(
+    capsule: &Capsule<N>,
+    origin: &Point<N>,
+    dir: &Vector<N>,
+) -> Option<LineClip<N>> {
+    let segment = capsule.segment();
+    let caps = [
+        (segment.a, FeatureId::Face(0)),
+        (segment.b, FeatureId::Face(1)),
+    ];
+    let mut res: Option<LineClip<N>> = None;
+
+    // The capsule is convex so it is crossed by the line along the smallest interval containing
+    // the intersections of the line with its two balls and its cylindrical part.
+    for (center, feature) in caps.iter() {
+        if let Some((t0, t1)) =
+            ray_quadric::clip_line_with_ball(center, capsule.radius, origin, dir)
+        {
+            let clip = LineClip::new().clip((t0, *feature), (t1, *feature));
+            res = match (res, clip) {
+                (Some(res), Some(clip)) => Some(res.union(&clip)),
+                (res, clip) => res.or(clip),
+            };
+        }
+    }
+
+    // The cylindrical part is checked last so the balls take precedence at the rims.
+    let lateral = FeatureId::Face(2);
+    let cylinder = ray_quadric::clip_line_with_infinite_cylinder(capsule.radius, origin, dir)
+        .and_then(|(t0, t1)| LineClip::new().clip((t0, lateral), (t1, lateral)))
+        .and_then(|clip| clip.clip_below(origin, dir, capsule.half_height, lateral))
+        .and_then(|clip| clip.clip_above(origin, dir, -capsule.half_height, lateral));
+
+    match (res, cylinder) {
+        (Some(res), Some(cylinder)) => Some(res.union(&cylinder)),
+        (res, cylinder) => res.or(cylinder),
+    }
+}
+
+fn local_ray_intersection<N: RealField>(
+    capsule: &Capsule<N>,
+    ls_ray: &Ray<N>,
+    max_toi: N,
+    solid: bool,
+) -> Option<RayIntersection<N>> {
+    let (toi, feature, inside) =
+        clip_line(capsule, &ls_ray.origin, &ls_ray.dir)?.ray_intersection(max_toi, solid)?;
+
+    if !toi.is_finite() {
+        return ray_quadric::local_ray_intersection_with_gjk(capsule, ls_ray, max_toi, solid);
+    }
+
+    let pt = ls_ray.point_at(toi);
+    let normal = if inside {
+        Vector::zeros()
+    } else {
+        let mut center = Point::origin();
+        center.y = pt.y.max(-capsule.half_height).min(capsule.half_height);
+        let dpt = pt - center;
+        dpt.try_normalize(N::default_epsilon()).unwrap_or(dpt)
+    };
+
+    // In 2D, the two sides of the cylindrical part are distinct faces.
+    #[cfg(feature = "dim2")]
+    let feature = if feature == FeatureId::Face(2) && pt.x < N::zero() {
+        FeatureId::Face(3)
+    } else {
+        feature
+    };
+
+    Some(RayIntersection::new(toi, normal, feature))
+}
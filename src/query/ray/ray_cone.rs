@@ -0,0 +1,154 @@
+use na::{self, Point2, RealField};
+
+use crate::math::{Isometry, Point, Vector};
+use crate::query::ray::ray_quadric::{self, LineClip};
+use crate::query::{Ray, RayCast, RayIntersection};
+use crate::shape::{Cone, FeatureId};
+
+impl<N: RealField> RayCast<N> for Cone<N> {
+    #[inline]
+    fn toi_and_normal_with_ray(
+        &self,
+        m: &Isometry<N>,
+        ray: &Ray<N>,
+        max_toi: N,
+        solid: bool,
+    ) -> Option<RayIntersection<N>> {
+        local_ray_intersection(self, &ray.inverse_transform_by(m), max_toi, solid, false).map(
+            |mut res| {
+                res.normal = m * res.normal;
+                res
+            },
+        )
+    }
+
+    /// The texture coordinates are `(u, v)` where `u` is the angle around the `y` axis and `v`
+    /// the curvilinear abscissa along the profile of the cone, from its apex to the center of
+    /// its base. Both are normalized to `[0, 1]`.
+    #[inline]
+    fn toi_and_normal_and_uv_with_ray(
+        &self,
+        m: &Isometry<N>,
+        ray: &Ray<N>,
+        max_toi: N,
+        solid: bool,
+    ) -> Option<RayIntersection<N>> {
+        local_ray_intersection(self, &ray.inverse_transform_by(m), max_toi, solid, true).map(
+            |mut res| {
+                res.normal = m * res.normal;
+                res
+            },
+        )
+    }
+}
+
+/// Computes the part of a line, expressed in the local-space of a cone, inside of this cone.
+fn clip_line<N: RealField>(
+    cone: &Cone<N>,
+    origin: &Point<N>,
+    dir: &Vector<N>,
+) -> Option<LineClip<N>> {
+    let lateral = FeatureId::Face(1);
+    let infinity = N::max_value();
+
+    // The infinite double cone with the same apex and axis is the set of points such that
+    // `|radial|² <= k² * (half_height - y)²`. Along the line, this reads
+    // `a * t² + 2 * b * t + c <= 0`.
+    let k = cone.radius / (cone.half_height * na::convert(2.0));
+    let k2 = k * k;
+    let w0 = cone.half_height - origin.y;
+    let radial_origin = Vector::new(origin.x, N::zero(), origin.z);
+    let radial_dir = Vector::new(dir.x, N::zero(), dir.z);
+
+    let a = radial_dir.norm_squared() - k2 * dir.y * dir.y;
+    let b = radial_origin.dot(&radial_dir) + k2 * w0 * dir.y;
+    let c = radial_origin.norm_squared() - k2 * w0 * w0;
+
+    let (near, far) = if a.is_zero() {
+        // The line is parallel to a generatrix.
+        if b.is_zero() {
+            if c > N::zero() {
+                return None;
+            }
+
+            (
+                (-infinity, FeatureId::Unknown),
+                (infinity, FeatureId::Unknown),
+            )
+        } else {
+            let t = -c / (b * na::convert(2.0));
+
+            if b > N::zero() {
+                ((-infinity, FeatureId::Unknown), (t, lateral))
+            } else {
+                ((t, lateral), (infinity, FeatureId::Unknown))
+            }
+        }
+    } else {
+        let (t0, t1) = ray_quadric::solve_quadratic(a, b, c)?;
+
+        if a > N::zero() {
+            ((t0, lateral), (t1, lateral))
+        } else if dir.y > N::zero() {
+            // The line crosses both nappes of the double cone. Only the part below
+            // the apex is kept.
+            ((-infinity, FeatureId::Unknown), (t0, lateral))
+        } else {
+            ((t1, lateral), (infinity, FeatureId::Unknown))
+        }
+    };
+
+    // Clipping by the plane of the apex removes the upper nappe of the double cone.
+    LineClip::new()
+        .clip(near, far)?
+        .clip_below(origin, dir, cone.half_height, FeatureId::Vertex(0))?
+        .clip_above(origin, dir, -cone.half_height, FeatureId::Face(0))
+}
+
+fn local_ray_intersection<N: RealField>(
+    cone: &Cone<N>,
+    ls_ray: &Ray<N>,
+    max_toi: N,
+    solid: bool,
+    with_uvs: bool,
+) -> Option<RayIntersection<N>> {
+    let (toi, feature, inside) =
+        clip_line(cone, &ls_ray.origin, &ls_ray.dir)?.ray_intersection(max_toi, solid)?;
+
+    if !toi.is_finite() {
+        return ray_quadric::local_ray_intersection_with_gjk(cone, ls_ray, max_toi, solid);
+    }
+
+    let pt = ls_ray.point_at(toi);
+    let normal = if inside {
+        Vector::zeros()
+    } else {
+        match feature {
+            FeatureId::Face(0) => -Vector::y(),
+            FeatureId::Face(1) => cone
+                .lateral_normal(&pt.coords)
+                .map(|n| n.into_inner())
+                .unwrap_or_else(Vector::y),
+            _ => Vector::y(),
+        }
+    };
+
+    let uvs = if with_uvs {
+        let height = cone.half_height * na::convert(2.0);
+        let slant = (height * height + cone.radius * cone.radius).sqrt();
+        let abscissa = match feature {
+            FeatureId::Face(0) => slant + cone.radius - Vector::new(pt.x, N::zero(), pt.z).norm(),
+            FeatureId::Face(1) => slant * (cone.half_height - pt.y) / height,
+            _ => N::zero(),
+        };
+
+        Some(Point2::new(
+            ray_quadric::angular_uv(&pt.coords),
+            abscissa / (slant + cone.radius),
+        ))
+    } else {
+        None
+    };
+
+    Some(RayIntersection::new_with_uvs(toi, normal, feature, uvs))
+}
@@ -0,0 +1,104 @@
+use na::{self, Point2, RealField};
+
+use crate::math::{Isometry, Point, Vector};
+use crate::query::ray::ray_quadric::{self, LineClip};
+use crate::query::{Ray, RayCast, RayIntersection};
+use crate::shape::{Cylinder, FeatureId};
+
+impl<N: RealField> RayCast<N> for Cylinder<N> {
+    #[inline]
+    fn toi_and_normal_with_ray(
+        &self,
+        m: &Isometry<N>,
+        ray: &Ray<N>,
+        max_toi: N,
+        solid: bool,
+    ) -> Option<RayIntersection<N>> {
+        local_ray_intersection(self, &ray.inverse_transform_by(m), max_toi, solid, false).map(
+            |mut res| {
+                res.normal = m * res.normal;
+                res
+            },
+        )
+    }
+
+    /// The texture coordinates are `(u, v)` where `u` is the angle around the `y` axis and `v`
+    /// the curvilinear abscissa along the profile of the cylinder, from the center of its top
+    /// cap to the center of its bottom cap. Both are normalized to `[0, 1]`.
+    #[inline]
+    fn toi_and_normal_and_uv_with_ray(
+        &self,
+        m: &Isometry<N>,
+        ray: &Ray<N>,
+        max_toi: N,
+        solid: bool,
+    ) -> Option<RayIntersection<N>> {
+        local_ray_intersection(self, &ray.inverse_transform_by(m), max_toi, solid, true).map(
+            |mut res| {
+                res.normal = m * res.normal;
+                res
+            },
+        )
+    }
+}
+
+/// Computes the part of a line, expressed in the local-space of a cylinder, inside of this cylinder.
+fn clip_line<N: RealField>(
+    cylinder: &Cylinder<N>,
+    origin: &Point<N>,
+    dir: &Vector<N>,
+) -> Option<LineClip<N>> {
+    let lateral = FeatureId::Face(2);
+    let (t0, t1) = ray_quadric::clip_line_with_infinite_cylinder(cylinder.radius, origin, dir)?;
+
+    LineClip::new()
+        .clip((t0, lateral), (t1, lateral))?
+        .clip_below(origin, dir, cylinder.half_height, FeatureId::Face(0))?
+        .clip_above(origin, dir, -cylinder.half_height, FeatureId::Face(1))
+}
+
+fn local_ray_intersection<N: RealField>(
+    cylinder: &Cylinder<N>,
+    ls_ray: &Ray<N>,
+    max_toi: N,
+    solid: bool,
+    with_uvs: bool,
+) -> Option<RayIntersection<N>> {
+    let (toi, feature, inside) =
+        clip_line(cylinder, &ls_ray.origin, &ls_ray.dir)?.ray_intersection(max_toi, solid)?;
+
+    if !toi.is_finite() {
+        return ray_quadric::local_ray_intersection_with_gjk(cylinder, ls_ray, max_toi, solid);
+    }
+
+    let pt = ls_ray.point_at(toi);
+    let radial = Vector::new(pt.x, N::zero(), pt.z);
+    let normal = if inside {
+        Vector::zeros()
+    } else {
+        match feature {
+            FeatureId::Face(0) => Vector::y(),
+            FeatureId::Face(1) => -Vector::y(),
+            _ => radial.try_normalize(N::default_epsilon()).unwrap_or(radial),
+        }
+    };
+
+    let uvs = if with_uvs {
+        let radius = cylinder.radius;
+        let height = cylinder.half_height * na::convert(2.0);
+        let abscissa = match feature {
+            FeatureId::Face(0) => radial.norm(),
+            FeatureId::Face(1) => radius + height + radius - radial.norm(),
+            _ => radius + cylinder.half_height - pt.y,
+        };
+
+        Some(Point2::new(
+            ray_quadric::angular_uv(&pt.coords),
+            abscissa / (radius + height + radius),
+        ))
+    } else {
+        None
+    };
+
+    Some(RayIntersection::new_with_uvs(toi, normal, feature, uvs))
+}
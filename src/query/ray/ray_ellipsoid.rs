@@ -0,0 +1,79 @@
+use na::RealField;
+
+use crate::math::{Isometry, Point};
+#[cfg(feature = "dim3")]
+use crate::query::ray::ray_ball;
+use crate::query::{self, Ray, RayCast, RayIntersection};
+use crate::shape::{Ellipsoid, FeatureId};
+
+impl<N: RealField> RayCast<N> for Ellipsoid<N> {
+    #[inline]
+    fn toi_and_normal_with_ray(
+        &self,
+        m: &Isometry<N>,
+        ray: &Ray<N>,
+        max_toi: N,
+        solid: bool,
+    ) -> Option<RayIntersection<N>> {
+        local_ray_intersection(self, &ray.inverse_transform_by(m), max_toi, solid).map(
+            |(mut res, _)| {
+                res.normal = m * res.normal;
+                res
+            },
+        )
+    }
+
+    /// The texture coordinates are the ones of the ball obtained by scaling this ellipsoid to
+    /// a unit ball.
+    #[cfg(feature = "dim3")]
+    #[inline]
+    fn toi_and_normal_and_uv_with_ray(
+        &self,
+        m: &Isometry<N>,
+        ray: &Ray<N>,
+        max_toi: N,
+        solid: bool,
+    ) -> Option<RayIntersection<N>> {
+        local_ray_intersection(self, &ray.inverse_transform_by(m), max_toi, solid).map(
+            |(mut res, unit_pt)| {
+                res.uvs = Some(ray_ball::ball_uv(&unit_pt.coords));
+                res.normal = m * res.normal;
+                res
+            },
+        )
+    }
+}
+
+// Casts a ray expressed in the local-space of the ellipsoid. Also returns the intersection point
+// on the unit ball obtained by scaling the ellipsoid.
+fn local_ray_intersection<N: RealField>(
+    ellipsoid: &Ellipsoid<N>,
+    ls_ray: &Ray<N>,
+    max_toi: N,
+    solid: bool,
+) -> Option<(RayIntersection<N>, Point<N>)> {
+    // The ellipsoid is the image of the unit ball by a scaling, which preserves times of impact.
+    let unit_ray = Ray::new(
+        Point::from(ls_ray.origin.coords.component_div(&ellipsoid.radii)),
+        ls_ray.dir.component_div(&ellipsoid.radii),
+    );
+    let (inside, toi) = query::ray_toi_with_ball(&Point::origin(), N::one(), &unit_ray, solid);
+    let toi = toi.filter(|toi| *toi <= max_toi)?;
+
+    let unit_pt = unit_ray.point_at(toi);
+    let normal = ellipsoid
+        .local_normal_at(&ls_ray.point_at(toi))
+        .map(|n| {
+            if inside {
+                -n.into_inner()
+            } else {
+                n.into_inner()
+            }
+        })
+        .unwrap_or_else(na::zero);
+
+    Some((
+        RayIntersection::new(toi, normal, FeatureId::Face(0)),
+        unit_pt,
+    ))
+}
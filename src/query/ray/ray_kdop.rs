@@ -0,0 +1,107 @@
+use crate::bounding_volume::KDOP;
+use crate::math::{Isometry, Vector};
+use crate::query::{Ray, RayCast, RayIntersection};
+use crate::shape::FeatureId;
+use na::{self, RealField};
+
+impl<N: RealField, const K: usize> RayCast<N> for KDOP<N, K> {
+    #[inline]
+    fn toi_and_normal_with_ray(
+        &self,
+        m: &Isometry<N>,
+        ray: &Ray<N>,
+        max_toi: N,
+        solid: bool,
+    ) -> Option<RayIntersection<N>> {
+        let ls_ray = ray.inverse_transform_by(m);
+
+        ray_kdop(self, &ls_ray, max_toi, solid)
+            .map(|(t, n, i)| RayIntersection::new(t, m * n, FeatureId::Face(i)))
+    }
+}
+
+impl<N: RealField, const K: usize> KDOP<N, K> {
+    /// Computes the parameters of the two intersection points between a ray and this k-DOP.
+    ///
+    /// The parameters are such that the point are given by `ray.orig + ray.dir * parameter`.
+    /// Returns `None` if there is no intersection.
+    #[inline]
+    pub fn clip_ray_parameters(&self, ray: &Ray<N>) -> Option<(N, N)> {
+        clip_line(self, ray).and_then(|(near, far)| {
+            if far.0 < N::zero() {
+                None
+            } else {
+                Some((near.0.max(N::zero()), far.0))
+            }
+        })
+    }
+}
+
+// Clips the line supporting `ray` with each half-space of the k-DOP. Each returned
+// intersection contains its parameter and the index of the face hit.
+fn clip_line<N: RealField, const K: usize>(
+    kdop: &KDOP<N, K>,
+    ray: &Ray<N>,
+) -> Option<((N, usize), (N, usize))> {
+    let mut tmax = N::max_value();
+    let mut tmin = -tmax;
+    let mut near_face = 0;
+    let mut far_face = 0;
+
+    for (i, support) in kdop.support_values().iter().enumerate() {
+        let dir = KDOP::<N, K>::direction(i);
+        let denom = dir.dot(&ray.dir);
+        let dist = *support - dir.dot(&ray.origin.coords);
+
+        if denom.is_zero() {
+            if dist < N::zero() {
+                return None;
+            }
+        } else {
+            let t = dist / denom;
+
+            if denom < N::zero() {
+                if t > tmin {
+                    tmin = t;
+                    near_face = i;
+                }
+            } else if t < tmax {
+                tmax = t;
+                far_face = i;
+            }
+
+            if tmin > tmax {
+                return None;
+            }
+        }
+    }
+
+    Some(((tmin, near_face), (tmax, far_face)))
+}
+
+fn ray_kdop<N: RealField, const K: usize>(
+    kdop: &KDOP<N, K>,
+    ray: &Ray<N>,
+    max_toi: N,
+    solid: bool,
+) -> Option<(N, Vector<N>, usize)> {
+    let normal = |i| KDOP::<N, K>::direction(i).normalize();
+
+    clip_line(kdop, ray).and_then(|(near, far)| {
+        if far.0 < N::zero() {
+            None
+        } else if near.0 < N::zero() {
+            if solid {
+                Some((na::zero(), na::zero(), far.1))
+            } else if far.0 <= max_toi {
+                Some((far.0, normal(far.1), far.1))
+            } else {
+                None
+            }
+        } else if near.0 <= max_toi {
+            Some((near.0, normal(near.1), near.1))
+        } else {
+            None
+        }
+    })
+}
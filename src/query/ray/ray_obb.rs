@@ -0,0 +1,43 @@
+use crate::bounding_volume::OBB;
+use crate::math::Isometry;
+use crate::query::{Ray, RayCast, RayIntersection};
+use na::RealField;
+
+impl<N: RealField> RayCast<N> for OBB<N> {
+    #[inline]
+    fn toi_with_ray(&self, m: &Isometry<N>, ray: &Ray<N>, max_toi: N, solid: bool) -> Option<N> {
+        self.cuboid()
+            .toi_with_ray(&(m * self.position()), ray, max_toi, solid)
+    }
+
+    #[inline]
+    fn toi_and_normal_with_ray(
+        &self,
+        m: &Isometry<N>,
+        ray: &Ray<N>,
+        max_toi: N,
+        solid: bool,
+    ) -> Option<RayIntersection<N>> {
+        self.cuboid()
+            .toi_and_normal_with_ray(&(m * self.position()), ray, max_toi, solid)
+    }
+
+    #[cfg(feature = "dim3")]
+    #[inline]
+    fn toi_and_normal_and_uv_with_ray(
+        &self,
+        m: &Isometry<N>,
+        ray: &Ray<N>,
+        max_toi: N,
+        solid: bool,
+    ) -> Option<RayIntersection<N>> {
+        self.cuboid()
+            .toi_and_normal_and_uv_with_ray(&(m * self.position()), ray, max_toi, solid)
+    }
+
+    #[inline]
+    fn intersects_ray(&self, m: &Isometry<N>, ray: &Ray<N>, max_toi: N) -> bool {
+        self.cuboid()
+            .intersects_ray(&(m * self.position()), ray, max_toi)
+    }
+}
@@ -0,0 +1,227 @@
+//! Clipping of lines by the quadrics and half-spaces bounding capsules, cylinders, and cones.
+
+use na::{self, RealField};
+
+use crate::math::{Isometry, Point, Vector};
+use crate::query::algorithms::VoronoiSimplex;
+use crate::query::{self, Ray, RayIntersection};
+use crate::shape::{FeatureId, SupportMap};
+
+/// The parameter of a point of a line, and the feature of the shape the line crosses at this point.
+pub(crate) type ClipPoint<N> = (N, FeatureId);
+
+/// The part of a line inside of a convex shape.
+///
+/// The line enters the shape at `near` and exits it at `far`.
+#[derive(Copy, Clone, Debug)]
+pub(crate) struct LineClip<N: RealField> {
+    pub near: ClipPoint<N>,
+    pub far: ClipPoint<N>,
+}
+
+impl<N: RealField> LineClip<N> {
+    /// A clip containing the whole line.
+    pub fn new() -> Self {
+        LineClip {
+            near: (-N::max_value(), FeatureId::Unknown),
+            far: (N::max_value(), FeatureId::Unknown),
+        }
+    }
+
+    /// Intersects this clip with the part of the line between `near` and `far`.
+    ///
+    /// Returns `None` if the intersection is empty.
+    pub fn clip(mut self, near: ClipPoint<N>, far: ClipPoint<N>) -> Option<Self> {
+        if near.0 > self.near.0 {
+            self.near = near;
+        }
+
+        if far.0 < self.far.0 {
+            self.far = far;
+        }
+
+        if self.near.0 <= self.far.0 {
+            Some(self)
+        } else {
+            None
+        }
+    }
+
+    /// Intersects this clip with the half-space `y <= top` of the line `origin + t * dir`.
+    ///
+    /// The boundary of the half-space is identified by `feature`.
+    pub fn clip_below(
+        self,
+        origin: &Point<N>,
+        dir: &Vector<N>,
+        top: N,
+        feature: FeatureId,
+    ) -> Option<Self> {
+        clip_below(self, origin.y, dir.y, top, feature)
+    }
+
+    /// Intersects this clip with the half-space `y >= bottom` of the line `origin + t * dir`.
+    ///
+    /// The boundary of the half-space is identified by `feature`.
+    pub fn clip_above(
+        self,
+        origin: &Point<N>,
+        dir: &Vector<N>,
+        bottom: N,
+        feature: FeatureId,
+    ) -> Option<Self> {
+        clip_below(self, -origin.y, -dir.y, -bottom, feature)
+    }
+
+    /// The smallest part of the line containing both `self` and `other`.
+    ///
+    /// On ties, the features of `self` are kept.
+    pub fn union(mut self, other: &Self) -> Self {
+        if other.near.0 < self.near.0 {
+            self.near = other.near;
+        }
+
+        if other.far.0 > self.far.0 {
+            self.far = other.far;
+        }
+
+        self
+    }
+
+    /// The first point of a ray, clipped by this, with a time of impact smaller than `max_toi`.
+    ///
+    /// If the ray starts inside of the shape and `solid` is `true`, the time of impact is zero
+    /// and the last element of the result is `true`.
+    pub fn ray_intersection(&self, max_toi: N, solid: bool) -> Option<(N, FeatureId, bool)> {
+        if self.far.0 < N::zero() {
+            None
+        } else if self.near.0 < N::zero() {
+            if solid {
+                Some((N::zero(), self.far.1, true))
+            } else if self.far.0 <= max_toi {
+                Some((self.far.0, self.far.1, false))
+            } else {
+                None
+            }
+        } else if self.near.0 <= max_toi {
+            Some((self.near.0, self.near.1, false))
+        } else {
+            None
+        }
+    }
+}
+
+fn clip_below<N: RealField>(
+    clip: LineClip<N>,
+    origin: N,
+    dir: N,
+    top: N,
+    feature: FeatureId,
+) -> Option<LineClip<N>> {
+    if dir.is_zero() {
+        if origin > top {
+            None
+        } else {
+            Some(clip)
+        }
+    } else {
+        let t = (top - origin) / dir;
+
+        if dir > N::zero() {
+            clip.clip((-N::max_value(), FeatureId::Unknown), (t, feature))
+        } else {
+            clip.clip((t, feature), (N::max_value(), FeatureId::Unknown))
+        }
+    }
+}
+
+/// Computes the parameters of the points where the line `origin + t * dir` enters and exits
+/// the ball with the given `center` and `radius`.
+pub(crate) fn clip_line_with_ball<N: RealField>(
+    center: &Point<N>,
+    radius: N,
+    origin: &Point<N>,
+    dir: &Vector<N>,
+) -> Option<(N, N)> {
+    let dcenter = origin - center;
+    let a = dir.norm_squared();
+    let b = dcenter.dot(dir);
+    let c = dcenter.norm_squared() - radius * radius;
+
+    if a.is_zero() {
+        return if c > N::zero() {
+            None
+        } else {
+            Some((-N::max_value(), N::max_value()))
+        };
+    }
+
+    solve_quadratic(a, b, c)
+}
+
+/// Computes the real roots, in increasing order, of `a * t² + 2 * b * t + c` with `a != 0`.
+pub(crate) fn solve_quadratic<N: RealField>(a: N, b: N, c: N) -> Option<(N, N)> {
+    let delta = b * b - a * c;
+
+    if delta < N::zero() {
+        return None;
+    }
+
+    // Avoid the cancellation of `-b + sqrt(delta)` when `a * c` is small.
+    let q = if b > N::zero() {
+        -b - delta.sqrt()
+    } else {
+        -b + delta.sqrt()
+    };
+
+    if q.is_zero() {
+        // b = c = 0
+        return Some((N::zero(), N::zero()));
+    }
+
+    let t0 = q / a;
+    let t1 = c / q;
+
+    Some((t0.min(t1), t0.max(t1)))
+}
+
+/// Computes the parameters of the points where the line `origin + t * dir` enters and exits
+/// the infinite cylinder with the given `radius` around the `y` axis.
+pub(crate) fn clip_line_with_infinite_cylinder<N: RealField>(
+    radius: N,
+    origin: &Point<N>,
+    dir: &Vector<N>,
+) -> Option<(N, N)> {
+    let mut radial_origin = *origin;
+    let mut radial_dir = *dir;
+    radial_origin.y = N::zero();
+    radial_dir.y = N::zero();
+
+    clip_line_with_ball(&Point::origin(), radius, &radial_origin, &radial_dir)
+}
+
+/// Casts a ray, expressed in the local-space of `shape`, with the GJK algorithm.
+///
+/// This is used whenever the analytic intersection is not finite, e.g., for degenerate shapes.
+pub(crate) fn local_ray_intersection_with_gjk<N: RealField, G: SupportMap<N>>(
+    shape: &G,
+    ls_ray: &Ray<N>,
+    max_toi: N,
+    solid: bool,
+) -> Option<RayIntersection<N>> {
+    query::ray_intersection_with_support_map_with_params(
+        &Isometry::identity(),
+        shape,
+        &mut VoronoiSimplex::new(),
+        ls_ray,
+        max_toi,
+        solid,
+    )
+}
+
+/// The texture coordinate around the `y` axis of the point with the given local coordinates.
+#[cfg(feature = "dim3")]
+pub(crate) fn angular_uv<N: RealField>(local: &Vector<N>) -> N {
+    let _0_5: N = na::convert(0.5f64);
+    _0_5 + local.z.atan2(local.x) / N::two_pi()
+}
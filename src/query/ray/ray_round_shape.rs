@@ -0,0 +1,31 @@
+use na::RealField;
+
+use crate::math::Isometry;
+use crate::query::algorithms::VoronoiSimplex;
+use crate::query::{self, Ray, RayCast, RayIntersection};
+use crate::shape::{RoundShape, SupportMap};
+
+impl<N: RealField, S: SupportMap<N>> RayCast<N> for RoundShape<N, S> {
+    fn toi_and_normal_with_ray(
+        &self,
+        m: &Isometry<N>,
+        ray: &Ray<N>,
+        max_toi: N,
+        solid: bool,
+    ) -> Option<RayIntersection<N>> {
+        let ls_ray = ray.inverse_transform_by(m);
+
+        query::ray_intersection_with_support_map_with_params(
+            &Isometry::identity(),
+            self,
+            &mut VoronoiSimplex::new(),
+            &ls_ray,
+            max_toi,
+            solid,
+        )
+        .map(|mut res| {
+            res.normal = m * res.normal;
+            res
+        })
+    }
+}
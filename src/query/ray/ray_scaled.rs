@@ -0,0 +1,69 @@
+use na::RealField;
+
+use crate::math::Isometry;
+use crate::query::{Ray, RayCast, RayIntersection};
+use crate::shape::{Scaled, Shape};
+
+impl<N: RealField, S: Shape<N>> RayCast<N> for Scaled<N, S> {
+    #[inline]
+    fn toi_and_normal_with_ray(
+        &self,
+        m: &Isometry<N>,
+        ray: &Ray<N>,
+        max_toi: N,
+        solid: bool,
+    ) -> Option<RayIntersection<N>> {
+        let ray_cast = self.shape().as_ray_cast()?;
+        let unscaled_ray = self.unscale_ray(&ray.inverse_transform_by(m));
+        let res = ray_cast.toi_and_normal_with_ray(
+            &Isometry::identity(),
+            &unscaled_ray,
+            max_toi,
+            solid,
+        )?;
+
+        Some(self.scale_intersection(m, res))
+    }
+
+    #[cfg(feature = "dim3")]
+    #[inline]
+    fn toi_and_normal_and_uv_with_ray(
+        &self,
+        m: &Isometry<N>,
+        ray: &Ray<N>,
+        max_toi: N,
+        solid: bool,
+    ) -> Option<RayIntersection<N>> {
+        let ray_cast = self.shape().as_ray_cast()?;
+        let unscaled_ray = self.unscale_ray(&ray.inverse_transform_by(m));
+        let res = ray_cast.toi_and_normal_and_uv_with_ray(
+            &Isometry::identity(),
+            &unscaled_ray,
+            max_toi,
+            solid,
+        )?;
+
+        Some(self.scale_intersection(m, res))
+    }
+}
+
+impl<N: RealField, S> Scaled<N, S> {
+    // The scaling is an affine map so it preserves the times of impact of the rays.
+    fn unscale_ray(&self, ls_ray: &Ray<N>) -> Ray<N> {
+        Ray::new(
+            self.unscale_point(&ls_ray.origin),
+            ls_ray.dir.component_div(self.scale()),
+        )
+    }
+
+    // Normals are transformed by the inverse transpose of the scaling.
+    fn scale_intersection(
+        &self,
+        m: &Isometry<N>,
+        mut res: RayIntersection<N>,
+    ) -> RayIntersection<N> {
+        let normal = res.normal.component_div(self.scale());
+        res.normal = m * normal.try_normalize(N::zero()).unwrap_or(normal);
+        res
+    }
+}
@@ -1,4 +1,8 @@
+#[cfg(feature = "dim3")]
+use na::Unit;
 use na::{self, RealField};
+#[cfg(feature = "dim3")]
+use std::f64;
 
 use crate::math::Isometry;
 #[cfg(feature = "dim2")]
@@ -7,9 +11,9 @@ use crate::query::algorithms::{gjk, CSOPoint, VoronoiSimplex};
 use crate::query::{Ray, RayCast, RayIntersection};
 #[cfg(feature = "dim2")]
 use crate::shape::ConvexPolygon;
-use crate::shape::{Capsule, FeatureId, Segment, SupportMap};
 #[cfg(feature = "dim3")]
-use crate::shape::{Cone, ConvexHull, Cylinder};
+use crate::shape::{ConvexHull, Tetrahedron};
+use crate::shape::{ConvexHullOf, FeatureId, MinkowskiSum, Segment, SupportMap};
 
 /// Cast a ray on a shape using the GJK algorithm.
 pub fn ray_intersection_with_support_map_with_params<N, G: ?Sized>(
@@ -60,7 +64,7 @@ where
 }
 
 #[cfg(feature = "dim3")]
-impl<N: RealField> RayCast<N> for Cylinder<N> {
+impl<N: RealField> RayCast<N> for Tetrahedron<N> {
     fn toi_and_normal_with_ray(
         &self,
         m: &Isometry<N>,
@@ -79,57 +83,11 @@ impl<N: RealField> RayCast<N> for Cylinder<N> {
             solid,
         )
         .map(|mut res| {
-            res.normal = m * res.normal;
-            res
-        })
-    }
-}
-
-#[cfg(feature = "dim3")]
-impl<N: RealField> RayCast<N> for Cone<N> {
-    fn toi_and_normal_with_ray(
-        &self,
-        m: &Isometry<N>,
-        ray: &Ray<N>,
-        max_toi: N,
-        solid: bool,
-    ) -> Option<RayIntersection<N>> {
-        let ls_ray = ray.inverse_transform_by(m);
-
-        ray_intersection_with_support_map_with_params(
-            &Isometry::identity(),
-            self,
-            &mut VoronoiSimplex::new(),
-            &ls_ray,
-            max_toi,
-            solid,
-        )
-        .map(|mut res| {
-            res.normal = m * res.normal;
-            res
-        })
-    }
-}
-
-impl<N: RealField> RayCast<N> for Capsule<N> {
-    fn toi_and_normal_with_ray(
-        &self,
-        m: &Isometry<N>,
-        ray: &Ray<N>,
-        max_toi: N,
-        solid: bool,
-    ) -> Option<RayIntersection<N>> {
-        let ls_ray = ray.inverse_transform_by(m);
+            if let Some(normal) = Unit::try_new(res.normal, N::default_epsilon()) {
+                let eps = na::convert(f64::consts::PI / 180.0);
+                res.feature = self.support_feature_id_toward(&normal, eps);
+            }
 
-        ray_intersection_with_support_map_with_params(
-            &Isometry::identity(),
-            self,
-            &mut VoronoiSimplex::new(),
-            &ls_ray,
-            max_toi,
-            solid,
-        )
-        .map(|mut res| {
             res.normal = m * res.normal;
             res
         })
@@ -311,3 +269,35 @@ impl<N: RealField> RayCast<N> for Segment<N> {
         }
     }
 }
+
+macro_rules! impl_ray_cast_for_pair(
+    ($shape: ident) => {
+        impl<N: RealField, A: SupportMap<N>, B: SupportMap<N>> RayCast<N> for $shape<N, A, B> {
+            fn toi_and_normal_with_ray(
+                &self,
+                m: &Isometry<N>,
+                ray: &Ray<N>,
+                max_toi: N,
+                solid: bool,
+            ) -> Option<RayIntersection<N>> {
+                let ls_ray = ray.inverse_transform_by(m);
+
+                ray_intersection_with_support_map_with_params(
+                    &Isometry::identity(),
+                    self,
+                    &mut VoronoiSimplex::new(),
+                    &ls_ray,
+                    max_toi,
+                    solid,
+                )
+                .map(|mut res| {
+                    res.normal = m * res.normal;
+                    res
+                })
+            }
+        }
+    }
+);
+
+impl_ray_cast_for_pair!(MinkowskiSum);
+impl_ray_cast_for_pair!(ConvexHullOf);
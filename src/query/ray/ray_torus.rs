@@ -0,0 +1,175 @@
+use na::{self, Matrix4, RealField};
+
+use crate::math::Isometry;
+use crate::query::{Ray, RayCast, RayIntersection};
+use crate::shape::Torus;
+
+// Number of Newton iterations used to refine the roots computed from the companion matrix.
+const NEWTON_ITERATIONS: usize = 4;
+
+impl<N: RealField> RayCast<N> for Torus<N> {
+    #[inline]
+    fn toi_and_normal_with_ray(
+        &self,
+        m: &Isometry<N>,
+        ray: &Ray<N>,
+        max_toi: N,
+        solid: bool,
+    ) -> Option<RayIntersection<N>> {
+        let ls_ray = ray.inverse_transform_by(m);
+
+        self.local_toi_and_normal_with_ray(&ls_ray, max_toi, solid)
+            .map(|mut res| {
+                res.normal = m * res.normal;
+                res
+            })
+    }
+}
+
+impl<N: RealField> Torus<N> {
+    fn local_toi_and_normal_with_ray(
+        &self,
+        ray: &Ray<N>,
+        max_toi: N,
+        solid: bool,
+    ) -> Option<RayIntersection<N>> {
+        let inside = self.contains_local_point(&ray.origin);
+
+        if inside && solid {
+            return Some(RayIntersection::new(
+                N::zero(),
+                na::zero(),
+                self.slice_feature_id(&ray.origin),
+            ));
+        }
+
+        let dir_norm = ray.dir.norm();
+
+        if dir_norm.is_zero() {
+            return None;
+        }
+
+        let dir = ray.dir / dir_norm;
+
+        // Start the search from the closest point of the bounding sphere of the torus. This
+        // keeps the coefficients of the quartic small when the ray origin is far away.
+        let radius = self.major_radius + self.minor_radius;
+        let shift = (-ray.origin.coords.dot(&dir) - radius).max(N::zero());
+        let origin = ray.origin + dir * shift;
+
+        // Substituting `origin + s * dir` into the implicit equation of the torus
+        // `(|p|² + R² - r²)² = 4R² (px² + pz²)` yields a quartic in `s`.
+        let r2 = self.major_radius * self.major_radius;
+        let g = origin.coords.norm_squared() + r2 - self.minor_radius * self.minor_radius;
+        let h = origin.coords.dot(&dir);
+        let a = dir.x * dir.x + dir.z * dir.z;
+        let b = origin.x * dir.x + origin.z * dir.z;
+        let c = origin.x * origin.x + origin.z * origin.z;
+        let two: N = na::convert(2.0);
+        let four: N = na::convert(4.0);
+        let eight: N = na::convert(8.0);
+
+        let coeffs = [
+            g * g - four * r2 * c,
+            four * h * g - eight * r2 * b,
+            four * h * h + two * g - four * r2 * a,
+            four * h,
+        ];
+
+        // The candidates are rejected if they are too far from the boundary of the torus, e.g.,
+        // because they are actually complex.
+        let tolerance = N::default_epsilon().sqrt() * radius;
+        let mut best = None;
+
+        for s in quartic_root_candidates(&coeffs) {
+            let pt = origin + dir * s;
+
+            if (self.distance_to_core(&pt) - self.minor_radius).abs() > tolerance {
+                continue;
+            }
+
+            let toi = (shift + s) / dir_norm;
+
+            // Grazing roots at the ray origin are ignored if the ray starts inside of the torus.
+            if toi < N::zero() || toi > max_toi || (inside && s + shift <= tolerance) {
+                continue;
+            }
+
+            match best {
+                Some((best_toi, _)) if best_toi <= toi => {}
+                _ => best = Some((toi, pt)),
+            }
+        }
+
+        let (toi, pt) = best?;
+        let normal = (pt - self.closest_core_point(&pt)) / self.minor_radius;
+        let normal = if inside { -normal } else { normal };
+
+        Some(RayIntersection::new(
+            toi,
+            normal,
+            self.slice_feature_id(&pt),
+        ))
+    }
+}
+
+/// Candidates for the real roots of the monic quartic `s⁴ + c[3] s³ + c[2] s² + c[1] s + c[0]`.
+///
+/// The roots are computed as the eigenvalues of the companion matrix of the polynomial, and then
+/// polished with a few Newton iterations. The real parts of the complex roots are returned too so
+/// that nearly double roots are not missed: the caller is responsible for filtering them.
+fn quartic_root_candidates<N: RealField>(c: &[N; 4]) -> Vec<N> {
+    let companion = Matrix4::new(
+        N::zero(),
+        N::zero(),
+        N::zero(),
+        -c[0],
+        N::one(),
+        N::zero(),
+        N::zero(),
+        -c[1],
+        N::zero(),
+        N::one(),
+        N::zero(),
+        -c[2],
+        N::zero(),
+        N::zero(),
+        N::one(),
+        -c[3],
+    );
+
+    let eval = |s: N| (((s + c[3]) * s + c[2]) * s + c[1]) * s + c[0];
+    let eval_derivative = |s: N| {
+        let two: N = na::convert(2.0);
+        let three: N = na::convert(3.0);
+        let four: N = na::convert(4.0);
+        ((four * s + three * c[3]) * s + two * c[2]) * s + c[1]
+    };
+
+    companion
+        .complex_eigenvalues()
+        .iter()
+        .map(|root| {
+            let mut s = root.re;
+
+            for _ in 0..NEWTON_ITERATIONS {
+                let derivative = eval_derivative(s);
+
+                if derivative.is_zero() {
+                    break;
+                }
+
+                let next = s - eval(s) / derivative;
+
+                // Newton iterations are unstable close to double roots, e.g., for grazing rays.
+                if !next.is_finite() || eval(next).abs() >= eval(s).abs() {
+                    break;
+                }
+
+                s = next;
+            }
+
+            s
+        })
+        .collect()
+}
@@ -0,0 +1,163 @@
+use na::{self, RealField};
+
+use crate::math::{Isometry, Vector, DIM};
+use crate::query::{Ray, RayCast, RayIntersection};
+use crate::shape::Voxels;
+
+impl<N: RealField> RayCast<N> for Voxels<N> {
+    #[inline]
+    fn toi_and_normal_with_ray(
+        &self,
+        m: &Isometry<N>,
+        ray: &Ray<N>,
+        max_toi: N,
+        solid: bool,
+    ) -> Option<RayIntersection<N>> {
+        let ls_ray = ray.inverse_transform_by(m);
+
+        self.local_toi_and_normal_with_ray(&ls_ray, max_toi, solid)
+            .map(|mut res| {
+                res.normal = m * res.normal;
+                res
+            })
+    }
+}
+
+impl<N: RealField> Voxels<N> {
+    /// Traverses the voxels crossed by a local ray, in order, until a filled voxel is hit.
+    ///
+    /// If the ray starts inside of a filled voxel and `solid` is `false`, the traversal continues
+    /// until the ray exits the filled voxels.
+    fn local_toi_and_normal_with_ray(
+        &self,
+        ray: &Ray<N>,
+        max_toi: N,
+        solid: bool,
+    ) -> Option<RayIntersection<N>> {
+        let aabb = self.aabb();
+
+        // Clip the ray with the grid bounds, keeping track of the axis the ray enters through.
+        let mut tmin = -N::max_value();
+        let mut tmax = N::max_value();
+        let mut entry_axis = 0;
+
+        for a in 0..DIM {
+            if ray.dir[a].is_zero() {
+                if ray.origin[a] < aabb.mins[a] || ray.origin[a] > aabb.maxs[a] {
+                    return None;
+                }
+            } else {
+                let denom = N::one() / ray.dir[a];
+                let mut t1 = (aabb.mins[a] - ray.origin[a]) * denom;
+                let mut t2 = (aabb.maxs[a] - ray.origin[a]) * denom;
+
+                if t1 > t2 {
+                    std::mem::swap(&mut t1, &mut t2);
+                }
+
+                if t1 > tmin {
+                    tmin = t1;
+                    entry_axis = a;
+                }
+
+                tmax = tmax.min(t2);
+            }
+        }
+
+        if tmin > tmax || tmax < N::zero() || tmin > max_toi {
+            return None;
+        }
+
+        let mut step = Vector::<i32>::zeros();
+        let mut t_next = Vector::repeat(N::max_value());
+        let mut t_delta = Vector::repeat(N::max_value());
+        let mut last_axis = None;
+        let mut toi = N::zero();
+
+        if tmin > N::zero() {
+            toi = tmin;
+            last_axis = Some(entry_axis);
+        }
+
+        let start = ray.point_at(toi);
+        let mut key = self.voxel_key_at_point(&start);
+
+        for a in 0..DIM {
+            key[a] = na::clamp(key[a], self.mins_key()[a], self.maxs_key()[a]);
+
+            if ray.dir[a] > N::zero() {
+                step[a] = 1;
+            } else if ray.dir[a] < N::zero() {
+                step[a] = -1;
+            } else {
+                continue;
+            }
+
+            if last_axis == Some(a) {
+                // The ray enters the grid through this axis, so the first voxel is known exactly.
+                key[a] = if step[a] > 0 {
+                    self.mins_key()[a]
+                } else {
+                    self.maxs_key()[a]
+                };
+            }
+
+            let boundary = if step[a] > 0 { key[a] + 1 } else { key[a] };
+            let boundary = na::convert::<f64, N>(boundary as f64) * self.voxel_size()[a];
+            t_next[a] = (boundary - ray.origin[a]) / ray.dir[a];
+            t_delta[a] = self.voxel_size()[a] / ray.dir[a].abs();
+        }
+
+        // Whether we are looking for the exit of the filled voxels containing the ray origin.
+        let inside = last_axis.is_none() && self.is_filled(&key);
+
+        if inside && solid {
+            return Some(RayIntersection::new(
+                N::zero(),
+                na::zero(),
+                self.voxel_face_id(self.voxel_index(&key)?, 0, true),
+            ));
+        }
+
+        loop {
+            let filled = self.is_filled(&key);
+
+            if filled != inside {
+                let axis = last_axis?;
+                let (voxel, positive) = if inside {
+                    // The exited voxel is the previous one.
+                    let mut prev = key;
+                    prev[axis] -= step[axis];
+                    (self.voxel_index(&prev)?, step[axis] > 0)
+                } else {
+                    (self.voxel_index(&key)?, step[axis] < 0)
+                };
+
+                let mut normal = Vector::zeros();
+                normal[axis] = na::convert(-step[axis] as f64);
+
+                return Some(RayIntersection::new(
+                    toi,
+                    normal,
+                    self.voxel_face_id(voxel, axis, positive),
+                ));
+            }
+
+            let axis = t_next.imin();
+            toi = t_next[axis];
+
+            if toi > max_toi || toi == N::max_value() {
+                return None;
+            }
+
+            key[axis] += step[axis];
+            t_next[axis] += t_delta[axis];
+            last_axis = Some(axis);
+
+            // Leaving the grid means leaving the filled voxels too.
+            if !inside && (key[axis] < self.mins_key()[axis] || key[axis] > self.maxs_key()[axis]) {
+                return None;
+            }
+        }
+    }
+}
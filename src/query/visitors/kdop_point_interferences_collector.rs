@@ -0,0 +1,44 @@
+use crate::bounding_volume::KDOP;
+use crate::math::Point;
+use crate::partitioning::{VisitStatus, Visitor};
+use na::RealField;
+
+/// Spatial partitioning structure visitor collecting nodes with a k-DOP that may contain a given point.
+pub struct KDOPPointInterferencesCollector<'a, N: 'a + RealField, T: 'a> {
+    /// Point to be tested.
+    pub point: &'a Point<N>,
+    /// The data contained by the nodes which bounding volume contain `self.point`.
+    pub collector: &'a mut Vec<T>,
+}
+
+impl<'a, N: RealField, T> KDOPPointInterferencesCollector<'a, N, T> {
+    /// Creates a new `KDOPPointInterferencesCollector`.
+    #[inline]
+    pub fn new(
+        point: &'a Point<N>,
+        buffer: &'a mut Vec<T>,
+    ) -> KDOPPointInterferencesCollector<'a, N, T> {
+        KDOPPointInterferencesCollector {
+            point,
+            collector: buffer,
+        }
+    }
+}
+
+impl<'a, N, T, const K: usize> Visitor<T, KDOP<N, K>> for KDOPPointInterferencesCollector<'a, N, T>
+where
+    N: RealField,
+    T: Clone,
+{
+    #[inline]
+    fn visit(&mut self, bv: &KDOP<N, K>, t: Option<&T>) -> VisitStatus {
+        if bv.contains_local_point(self.point) {
+            if let Some(t) = t {
+                self.collector.push(t.clone());
+            }
+            VisitStatus::Continue
+        } else {
+            VisitStatus::Stop
+        }
+    }
+}
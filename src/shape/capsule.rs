@@ -117,14 +117,16 @@ impl<N: RealField> ContactPreprocessor<N> for CapsuleContactPreprocessor<N> {
             FeatureId::Unknown => return false,
         };
 
+        // The preprocessors of the enclosing shapes, e.g., of a `RoundShape`, are applied first
+        // so the radius is added to the dilation they set.
         if is_first {
             kinematic.set_feature1(actual_feature);
-            kinematic.set_dilation1(self.radius);
+            kinematic.set_dilation1(kinematic.dilation1() + self.radius);
             c.world1 += *c.normal * self.radius;
             c.depth += self.radius;
         } else {
             kinematic.set_feature2(actual_feature);
-            kinematic.set_dilation2(self.radius);
+            kinematic.set_dilation2(kinematic.dilation2() + self.radius);
             c.world2 -= *c.normal * self.radius;
             c.depth += self.radius;
         }
@@ -1,10 +1,18 @@
 //! Support mapping based Cone shape.
 
-use crate::math::{Point, Vector};
-use crate::shape::SupportMap;
-use na::{self, RealField};
+use crate::math::{Isometry, Point, Vector};
+use crate::shape::cylinder::{disk_polygonal_approximation, NCAP_VERTICES};
+use crate::shape::{ConvexPolygonalFeature, FeatureId, SupportMap};
+use na::{self, RealField, Unit};
 
-/// SupportMap description of a cylinder shape with its principal axis aligned with the `y` axis.
+/// SupportMap description of a cone shape with its principal axis aligned with the `y` axis.
+///
+/// The apex of the cone is at `(0, half_height, 0)` and the center of its base at
+/// `(0, -half_height, 0)`. The features of a cone are identified as follows:
+/// * `FeatureId::Face(0)` is the base and `FeatureId::Face(1)` is the lateral surface.
+/// * `FeatureId::Edge(0)` is the rim of the base.
+/// * `FeatureId::Vertex(0)` is the apex and `FeatureId::Vertex(i)` with `i > 0` are the points
+///   of the rim sampled by the polygonal approximation of the base.
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 #[derive(PartialEq, Debug, Copy, Clone)]
 pub struct Cone<N> {
@@ -40,6 +48,179 @@ impl<N: RealField> Cone<N> {
     pub fn radius(&self) -> N {
         self.radius
     }
+
+    /// The sine and cosine of the angle between the `xz` plane and the normals of the lateral surface.
+    #[inline]
+    fn lateral_normal_elevation(&self) -> (N, N) {
+        let height = self.half_height * na::convert(2.0);
+        let slant = (height * height + self.radius * self.radius).sqrt();
+
+        (self.radius / slant, height / slant)
+    }
+
+    /// The outward normal of the lateral surface on the half-plane containing the `y` axis and `local_dir`.
+    #[inline]
+    pub(crate) fn lateral_normal(&self, local_dir: &Vector<N>) -> Option<Unit<Vector<N>>> {
+        let (sin, cos) = self.lateral_normal_elevation();
+        let radial = Vector::new(local_dir.x, N::zero(), local_dir.z);
+
+        radial
+            .try_normalize(N::default_epsilon())
+            .map(|radial| Unit::new_unchecked(radial * cos + Vector::y() * sin))
+    }
+
+    /// Checks that the given direction in world-space is on the tangent cone of the given `feature`
+    /// at the local point `pt`.
+    ///
+    /// The tangent cones of the lateral surface and of the rim depend on the radial direction of
+    /// `pt`. If it is undefined, i.e., if `pt` lies on the `y` axis, the lateral surface is assumed
+    /// not to contain `dir` and only the base is taken into account for the rim.
+    pub fn tangent_cone_contains_dir(
+        &self,
+        feature: FeatureId,
+        m: &Isometry<N>,
+        pt: &Point<N>,
+        dir: &Unit<Vector<N>>,
+    ) -> bool {
+        let ls_dir = m.inverse_transform_vector(dir);
+        let lateral = || {
+            self.lateral_normal(&pt.coords)
+                .map(|n| n.dot(&ls_dir) <= N::zero())
+        };
+
+        match feature {
+            FeatureId::Face(0) => ls_dir.y >= N::zero(),
+            FeatureId::Face(1) => lateral().unwrap_or(false),
+            FeatureId::Edge(0) => ls_dir.y >= N::zero() && lateral().unwrap_or(true),
+            FeatureId::Vertex(i) if i > 0 && i <= NCAP_VERTICES => {
+                ls_dir.y >= N::zero() && lateral().unwrap_or(true)
+            }
+            FeatureId::Vertex(0) => {
+                // The angle between `ls_dir` and `-y` must not exceed the half-angle of the apex.
+                let (_, cos) = self.lateral_normal_elevation();
+                -ls_dir.y >= cos
+            }
+            _ => panic!("Invalid feature ID {:?}.", feature),
+        }
+    }
+
+    /// Retrieves the identifier of the feature which normal cone contains `local_dir`.
+    ///
+    /// The base and the lateral surface are returned if `local_dir` is at most `eps` radians
+    /// away from one of their normals.
+    pub fn support_feature_id_toward(&self, local_dir: &Unit<Vector<N>>, eps: N) -> FeatureId {
+        let ceps = eps.cos();
+
+        if local_dir.y <= -ceps {
+            return FeatureId::Face(0);
+        }
+
+        match self.lateral_normal(local_dir) {
+            Some(normal) => {
+                if normal.dot(local_dir) >= ceps {
+                    FeatureId::Face(1)
+                } else if local_dir.y > normal.y {
+                    FeatureId::Vertex(0)
+                } else {
+                    FeatureId::Edge(0)
+                }
+            }
+            None => FeatureId::Vertex(0),
+        }
+    }
+
+    /// Retrieves the face (in world-space) with a normal that maximizes the scalar product with `dir`.
+    ///
+    /// The base is approximated by a regular polygon and the lateral surface by one of its
+    /// segments.
+    pub fn support_face_toward(
+        &self,
+        m: &Isometry<N>,
+        dir: &Unit<Vector<N>>,
+        out: &mut ConvexPolygonalFeature<N>,
+    ) {
+        let mut local_dir = m.inverse_transform_unit_vector(dir).into_inner();
+
+        if local_dir.x.is_zero() && local_dir.z.is_zero() {
+            // Any segment of the lateral surface is as good as the others.
+            local_dir.x = N::default_epsilon();
+        }
+
+        let lateral = self
+            .lateral_normal(&local_dir)
+            .map(|n| n.dot(&local_dir))
+            .unwrap_or_else(N::zero);
+
+        let fid = if -local_dir.y >= lateral {
+            FeatureId::Face(0)
+        } else {
+            FeatureId::Face(1)
+        };
+
+        self.polygonal_feature(fid, &local_dir, out);
+        out.transform_by(m);
+    }
+
+    /// Retrieves the feature (in world-space) which normal cone contains `dir`.
+    ///
+    /// The base is approximated by a regular polygon and the lateral surface by one of its
+    /// segments.
+    pub fn support_feature_toward(
+        &self,
+        m: &Isometry<N>,
+        dir: &Unit<Vector<N>>,
+        eps: N,
+        out: &mut ConvexPolygonalFeature<N>,
+    ) {
+        let local_dir = m.inverse_transform_unit_vector(dir);
+        let fid = self.support_feature_id_toward(&local_dir, eps);
+
+        self.polygonal_feature(fid, &local_dir, out);
+        out.transform_by(m);
+    }
+
+    // The feature `fid` (in local-space) on the side of the cone pointed by `local_dir`.
+    fn polygonal_feature(
+        &self,
+        fid: FeatureId,
+        local_dir: &Vector<N>,
+        out: &mut ConvexPolygonalFeature<N>,
+    ) {
+        out.clear();
+        let mut apex = Point::origin();
+        apex.y = self.half_height;
+
+        match fid {
+            FeatureId::Face(0) => disk_polygonal_approximation(
+                -self.half_height,
+                self.radius,
+                false,
+                fid,
+                FeatureId::Edge(0),
+                1,
+                out,
+            ),
+            FeatureId::Face(1) => {
+                let mut radial = Vector::new(local_dir.x, N::zero(), local_dir.z);
+                let _ = radial.normalize_mut();
+                let mut rim = Point::from(radial * self.radius);
+                rim.y = -self.half_height;
+
+                out.push(rim, FeatureId::Edge(0));
+                out.push(apex, FeatureId::Vertex(0));
+                out.push_edge_feature_id(fid);
+                out.set_feature_id(fid);
+            }
+            FeatureId::Vertex(0) => {
+                out.push(apex, fid);
+                out.set_feature_id(fid);
+            }
+            _ => {
+                out.push(self.local_support_point(local_dir), fid);
+                out.set_feature_id(fid);
+            }
+        }
+    }
 }
 
 impl<N: RealField> SupportMap<N> for Cone<N> {
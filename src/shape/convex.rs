@@ -1,6 +1,6 @@
 use crate::math::{Isometry, Point, Vector};
 use crate::shape::{ConvexPolygonalFeature, ConvexPolyhedron, FeatureId, SupportMap};
-use crate::transformation;
+use crate::transformation::{self, HalfSpace, HalfSpaceIntersectionError};
 use crate::utils::{self, SortedPair};
 use na::{self, Point2, Point3, RealField, Unit};
 use std::collections::hash_map::Entry;
@@ -95,6 +95,17 @@ impl<N: RealField> ConvexHull<N> {
 
         Self::try_new(hull.coords, &indices)
     }
+
+    /// Creates a new convex polyhedron from the intersection of a set of half-spaces.
+    ///
+    /// Returns an error if this intersection is empty, unbounded, or flat.
+    pub fn try_from_half_spaces(
+        half_spaces: &[HalfSpace<N>],
+    ) -> Result<ConvexHull<N>, HalfSpaceIntersectionError> {
+        let vertices = transformation::half_space_intersection(half_spaces)?;
+        Self::try_from_points(&vertices).ok_or(HalfSpaceIntersectionError::Degenerate)
+    }
+
     /// Attempts to create a new solid assumed to be convex from the set of points and indices.
     ///
     /// The given points and index information are assumed to describe a convex polyhedron.
@@ -352,6 +363,15 @@ impl<N: RealField> ConvexHull<N> {
         &self.points[..]
     }
 
+    /// The faces of this convex polyhedron split into triangles, as indices of `self.points()`.
+    pub(crate) fn triangulated_faces(&self) -> impl Iterator<Item = Point3<usize>> + '_ {
+        self.faces.iter().flat_map(move |face| {
+            let first = face.first_vertex_or_edge;
+            let ids = &self.vertices_adj_to_face[first..first + face.num_vertices_or_edges];
+            (1..ids.len() - 1).map(move |i| Point3::new(ids[0], ids[i], ids[i + 1]))
+        })
+    }
+
     /// Checks that the given direction in world-space is on the tangent cone of the given `feature`.
     pub fn tangent_cone_contains_dir(
         &self,
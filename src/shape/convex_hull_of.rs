@@ -0,0 +1,65 @@
+//! Convex hull of two support-mapped shapes.
+
+use na::{RealField, Unit};
+
+use crate::math::{Isometry, Point, Vector};
+use crate::shape::SupportMap;
+
+/// The convex hull of two support-mapped shapes, each placed at a given position.
+///
+/// For example, the convex hull of the same shape at two different positions encloses the volume
+/// swept by this shape moving from one position to the other without rotating.
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(PartialEq, Debug, Clone)]
+pub struct ConvexHullOf<N: RealField, A, B> {
+    /// The position of the first shape.
+    pub pos1: Isometry<N>,
+    /// The first shape of the convex hull.
+    pub shape1: A,
+    /// The position of the second shape.
+    pub pos2: Isometry<N>,
+    /// The second shape of the convex hull.
+    pub shape2: B,
+}
+
+impl<N: RealField, A, B> ConvexHullOf<N, A, B> {
+    /// Creates the convex hull of `shape1` transformed by `pos1` and `shape2` transformed by `pos2`.
+    #[inline]
+    pub fn new(pos1: Isometry<N>, shape1: A, pos2: Isometry<N>, shape2: B) -> Self {
+        ConvexHullOf {
+            pos1,
+            shape1,
+            pos2,
+            shape2,
+        }
+    }
+
+    #[inline]
+    fn farthest(dir: &Vector<N>, pt1: Point<N>, pt2: Point<N>) -> Point<N> {
+        if pt1.coords.dot(dir) >= pt2.coords.dot(dir) {
+            pt1
+        } else {
+            pt2
+        }
+    }
+}
+
+impl<N: RealField, A: SupportMap<N>, B: SupportMap<N>> SupportMap<N> for ConvexHullOf<N, A, B> {
+    #[inline]
+    fn local_support_point(&self, dir: &Vector<N>) -> Point<N> {
+        Self::farthest(
+            dir,
+            self.shape1.support_point(&self.pos1, dir),
+            self.shape2.support_point(&self.pos2, dir),
+        )
+    }
+
+    #[inline]
+    fn local_support_point_toward(&self, dir: &Unit<Vector<N>>) -> Point<N> {
+        Self::farthest(
+            dir,
+            self.shape1.support_point_toward(&self.pos1, dir),
+            self.shape2.support_point_toward(&self.pos2, dir),
+        )
+    }
+}
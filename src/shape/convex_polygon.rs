@@ -1,6 +1,6 @@
 use crate::math::{Isometry, Point, Vector};
 use crate::shape::{ConvexPolygonalFeature, ConvexPolyhedron, FeatureId, SupportMap};
-use crate::transformation;
+use crate::transformation::{self, HalfSpace, HalfSpaceIntersectionError};
 use crate::utils;
 use na::{self, RealField, Unit};
 use std::f64;
@@ -26,6 +26,16 @@ impl<N: RealField> ConvexPolygon<N> {
         Self::try_new(vertices)
     }
 
+    /// Creates a new 2D convex polygon from the intersection of a set of half-planes.
+    ///
+    /// Returns an error if this intersection is empty, unbounded, or flat.
+    pub fn try_from_half_spaces(
+        half_spaces: &[HalfSpace<N>],
+    ) -> Result<Self, HalfSpaceIntersectionError> {
+        let vertices = transformation::half_space_intersection(half_spaces)?;
+        Self::try_from_points(&vertices).ok_or(HalfSpaceIntersectionError::Degenerate)
+    }
+
     /// Creates a new 2D convex polygon from a set of points assumed to describe a counter-clockwise convex polyline.
     ///
     /// Convexity of the input polyline is not checked.
@@ -1,10 +1,53 @@
 //! Support mapping based Cylinder shape.
 
-use crate::math::{Point, Vector};
-use crate::shape::SupportMap;
-use na::{self, RealField};
+use crate::math::{Isometry, Point, Vector};
+use crate::shape::{ConvexPolygonalFeature, FeatureId, SupportMap};
+use na::{self, RealField, Unit};
+
+// Number of vertices of the polygonal approximation of the flat caps used for contact generation.
+pub(crate) const NCAP_VERTICES: usize = 16;
+
+/// Fills `out` with a polygonal approximation of a disk orthogonal to the `y` axis.
+///
+/// The disk is centered at `(0, y, 0)` and its outward normal is `+y` if `upward` is `true`
+/// and `-y` otherwise. The vertices are identified by `FeatureId::Vertex(first_vertex + i)`
+/// and all the edges by `rim`.
+pub(crate) fn disk_polygonal_approximation<N: RealField>(
+    y: N,
+    radius: N,
+    upward: bool,
+    face: FeatureId,
+    rim: FeatureId,
+    first_vertex: usize,
+    out: &mut ConvexPolygonalFeature<N>,
+) {
+    let dangle: N = N::two_pi() / na::convert(NCAP_VERTICES as f64);
+    let dangle = if upward { -dangle } else { dangle };
+    let mut normal = Vector::zeros();
+    normal[1] = if upward { N::one() } else { -N::one() };
+
+    for i in 0..NCAP_VERTICES {
+        let (sin, cos) = (dangle * na::convert(i as f64)).sin_cos();
+        out.push(
+            Point::new(cos * radius, y, sin * radius),
+            FeatureId::Vertex(first_vertex + i),
+        );
+        out.push_edge_feature_id(rim);
+    }
+
+    out.set_normal(Unit::new_unchecked(normal));
+    out.set_feature_id(face);
+    out.recompute_edge_normals();
+}
 
 /// SupportMap description of a cylinder shape with its principal axis aligned with the `y` axis.
+///
+/// The features of a cylinder are identified as follows:
+/// * `FeatureId::Face(0)` is the top cap and `FeatureId::Face(1)` is the bottom cap.
+/// * `FeatureId::Face(2)` is the lateral surface.
+/// * `FeatureId::Edge(0)` is the top rim and `FeatureId::Edge(1)` is the bottom rim.
+/// * `FeatureId::Vertex(i)` are the points of the rims sampled by the polygonal approximations
+///   of the caps. The first half of them is on the top rim.
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 #[derive(PartialEq, Debug, Copy, Clone)]
 pub struct Cylinder<N> {
@@ -42,6 +85,148 @@ impl<N: RealField> Cylinder<N> {
     pub fn radius(&self) -> N {
         self.radius
     }
+
+    /// Checks that the given direction in world-space is on the tangent cone of the given `feature`
+    /// at the local point `pt`.
+    ///
+    /// The tangent cones of the lateral surface and of the rims depend on the radial direction of
+    /// `pt`. If it is undefined, i.e., if `pt` lies on the `y` axis, the lateral surface is assumed
+    /// not to contain `dir` and only the caps are taken into account for the rims.
+    pub fn tangent_cone_contains_dir(
+        &self,
+        feature: FeatureId,
+        m: &Isometry<N>,
+        pt: &Point<N>,
+        dir: &Unit<Vector<N>>,
+    ) -> bool {
+        let ls_dir = m.inverse_transform_vector(dir);
+        let radial = Vector::new(pt.x, N::zero(), pt.z).try_normalize(N::default_epsilon());
+        let lateral = || radial.map(|r| r.dot(&ls_dir) <= N::zero());
+
+        match feature {
+            FeatureId::Face(0) => ls_dir.y <= N::zero(),
+            FeatureId::Face(1) => ls_dir.y >= N::zero(),
+            FeatureId::Face(2) => lateral().unwrap_or(false),
+            FeatureId::Edge(0) => ls_dir.y <= N::zero() && lateral().unwrap_or(true),
+            FeatureId::Edge(1) => ls_dir.y >= N::zero() && lateral().unwrap_or(true),
+            FeatureId::Vertex(i) if i < NCAP_VERTICES => {
+                ls_dir.y <= N::zero() && lateral().unwrap_or(true)
+            }
+            FeatureId::Vertex(i) if i < 2 * NCAP_VERTICES => {
+                ls_dir.y >= N::zero() && lateral().unwrap_or(true)
+            }
+            _ => panic!("Invalid feature ID {:?}.", feature),
+        }
+    }
+
+    /// Retrieves the identifier of the feature which normal cone contains `local_dir`.
+    ///
+    /// The flat caps and the lateral surface are returned if `local_dir` is at most `eps` radians
+    /// away from one of their normals.
+    pub fn support_feature_id_toward(&self, local_dir: &Unit<Vector<N>>, eps: N) -> FeatureId {
+        let (seps, ceps) = eps.sin_cos();
+
+        if local_dir.y >= ceps {
+            FeatureId::Face(0)
+        } else if local_dir.y <= -ceps {
+            FeatureId::Face(1)
+        } else if local_dir.y.abs() <= seps {
+            FeatureId::Face(2)
+        } else if local_dir.y > N::zero() {
+            FeatureId::Edge(0)
+        } else {
+            FeatureId::Edge(1)
+        }
+    }
+
+    /// Retrieves the face (in world-space) with a normal that maximizes the scalar product with `dir`.
+    ///
+    /// The flat caps are approximated by regular polygons and the lateral surface by one of
+    /// its segments.
+    pub fn support_face_toward(
+        &self,
+        m: &Isometry<N>,
+        dir: &Unit<Vector<N>>,
+        out: &mut ConvexPolygonalFeature<N>,
+    ) {
+        let local_dir = m.inverse_transform_unit_vector(dir);
+        let lateral = Vector::new(local_dir.x, N::zero(), local_dir.z).norm();
+
+        let fid = if lateral > local_dir.y.abs() {
+            FeatureId::Face(2)
+        } else if local_dir.y >= N::zero() {
+            FeatureId::Face(0)
+        } else {
+            FeatureId::Face(1)
+        };
+
+        self.polygonal_feature(fid, &local_dir, out);
+        out.transform_by(m);
+    }
+
+    /// Retrieves the feature (in world-space) which normal cone contains `dir`.
+    ///
+    /// The flat caps are approximated by regular polygons and the lateral surface by one of
+    /// its segments.
+    pub fn support_feature_toward(
+        &self,
+        m: &Isometry<N>,
+        dir: &Unit<Vector<N>>,
+        eps: N,
+        out: &mut ConvexPolygonalFeature<N>,
+    ) {
+        let local_dir = m.inverse_transform_unit_vector(dir);
+        let fid = self.support_feature_id_toward(&local_dir, eps);
+
+        self.polygonal_feature(fid, &local_dir, out);
+        out.transform_by(m);
+    }
+
+    // The feature `fid` (in local-space) on the side of the cylinder pointed by `local_dir`.
+    fn polygonal_feature(
+        &self,
+        fid: FeatureId,
+        local_dir: &Vector<N>,
+        out: &mut ConvexPolygonalFeature<N>,
+    ) {
+        out.clear();
+
+        match fid {
+            FeatureId::Face(0) => disk_polygonal_approximation(
+                self.half_height,
+                self.radius,
+                true,
+                fid,
+                FeatureId::Edge(0),
+                0,
+                out,
+            ),
+            FeatureId::Face(1) => disk_polygonal_approximation(
+                -self.half_height,
+                self.radius,
+                false,
+                fid,
+                FeatureId::Edge(1),
+                NCAP_VERTICES,
+                out,
+            ),
+            FeatureId::Face(2) => {
+                let mut top = self.local_support_point(local_dir);
+                top.y = self.half_height;
+                let mut bottom = top;
+                bottom.y = -self.half_height;
+
+                out.push(top, FeatureId::Edge(0));
+                out.push(bottom, FeatureId::Edge(1));
+                out.push_edge_feature_id(fid);
+                out.set_feature_id(fid);
+            }
+            _ => {
+                out.push(self.local_support_point(local_dir), fid);
+                out.set_feature_id(fid);
+            }
+        }
+    }
 }
 
 impl<N: RealField> SupportMap<N> for Cylinder<N> {
@@ -0,0 +1,60 @@
+//! Support mapping based Ellipsoid shape.
+
+use na::{RealField, Unit};
+
+use crate::math::{Point, Vector};
+use crate::shape::SupportMap;
+
+/// An ellipsoid centered at the origin, with its principal axes aligned with the coordinate axes.
+///
+/// In 2D, this is an ellipse.
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(PartialEq, Debug, Copy, Clone)]
+pub struct Ellipsoid<N: RealField> {
+    /// The radii of the ellipsoid along each coordinate axis.
+    pub radii: Vector<N>,
+}
+
+impl<N: RealField> Ellipsoid<N> {
+    /// Creates a new ellipsoid from its radii along each coordinate axis.
+    ///
+    /// All the radii must be strictly positive.
+    #[inline]
+    pub fn new(radii: Vector<N>) -> Ellipsoid<N> {
+        assert!(
+            radii.iter().all(|r| r.is_positive()),
+            "The radii of an ellipsoid must be strictly positive."
+        );
+
+        Ellipsoid { radii }
+    }
+
+    /// Checks if the given point, expressed in the local-space of this ellipsoid, is inside of it.
+    #[inline]
+    pub fn contains_local_point(&self, pt: &Point<N>) -> bool {
+        pt.coords.component_div(&self.radii).norm_squared() <= N::one()
+    }
+
+    /// The outward normal of the level set of this ellipsoid containing the local point `pt`.
+    ///
+    /// Returns `None` if `pt` is the center of the ellipsoid.
+    #[inline]
+    pub fn local_normal_at(&self, pt: &Point<N>) -> Option<Unit<Vector<N>>> {
+        let radii2 = self.radii.component_mul(&self.radii);
+        Unit::try_new(pt.coords.component_div(&radii2), N::default_epsilon())
+    }
+}
+
+impl<N: RealField> SupportMap<N> for Ellipsoid<N> {
+    #[inline]
+    fn local_support_point(&self, dir: &Vector<N>) -> Point<N> {
+        // The support point is `R² * dir / |R * dir|` where `R` is the diagonal matrix of the radii.
+        match dir
+            .component_mul(&self.radii)
+            .try_normalize(N::default_epsilon())
+        {
+            Some(scaled_dir) => Point::from(scaled_dir.component_mul(&self.radii)),
+            None => Point::origin(),
+        }
+    }
+}
@@ -0,0 +1,51 @@
+//! Minkowski sum of two support-mapped shapes.
+
+use na::{RealField, Unit};
+
+use crate::math::{Isometry, Point, Vector};
+use crate::shape::SupportMap;
+
+/// The Minkowski sum of two support-mapped shapes, each placed at a given position.
+///
+/// This is the set of all the sums `a + b` of a point `a` of the first shape and a point `b` of
+/// the second shape. For example, the volume swept by a shape translating along a segment is
+/// the Minkowski sum of this shape and of this segment.
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(PartialEq, Debug, Clone)]
+pub struct MinkowskiSum<N: RealField, A, B> {
+    /// The position of the first shape.
+    pub pos1: Isometry<N>,
+    /// The first shape of the sum.
+    pub shape1: A,
+    /// The position of the second shape.
+    pub pos2: Isometry<N>,
+    /// The second shape of the sum.
+    pub shape2: B,
+}
+
+impl<N: RealField, A, B> MinkowskiSum<N, A, B> {
+    /// Creates the Minkowski sum of `shape1` transformed by `pos1` and `shape2` transformed by `pos2`.
+    #[inline]
+    pub fn new(pos1: Isometry<N>, shape1: A, pos2: Isometry<N>, shape2: B) -> Self {
+        MinkowskiSum {
+            pos1,
+            shape1,
+            pos2,
+            shape2,
+        }
+    }
+}
+
+impl<N: RealField, A: SupportMap<N>, B: SupportMap<N>> SupportMap<N> for MinkowskiSum<N, A, B> {
+    #[inline]
+    fn local_support_point(&self, dir: &Vector<N>) -> Point<N> {
+        self.shape1.support_point(&self.pos1, dir)
+            + self.shape2.support_point(&self.pos2, dir).coords
+    }
+
+    #[inline]
+    fn local_support_point_toward(&self, dir: &Unit<Vector<N>>) -> Point<N> {
+        self.shape1.support_point_toward(&self.pos1, dir)
+            + self.shape2.support_point_toward(&self.pos2, dir).coords
+    }
+}
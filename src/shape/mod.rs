@@ -9,6 +9,7 @@ pub use self::compound::Compound;
 pub use self::cone::Cone;
 #[cfg(feature = "dim3")]
 pub use self::convex::ConvexHull;
+pub use self::convex_hull_of::ConvexHullOf;
 #[cfg(feature = "dim2")]
 pub use self::convex_polygon::ConvexPolygon;
 #[cfg(feature = "dim2")]
@@ -20,24 +21,33 @@ pub use self::cuboid::Cuboid;
 #[cfg(feature = "dim3")]
 pub use self::cylinder::Cylinder;
 pub use self::deformable_shape::{DeformableShape, DeformationsType};
+pub use self::ellipsoid::Ellipsoid;
 //#[cfg(feature = "dim3")]
 //pub use self::deformable_trimesh::DeformableTriMesh;
 #[cfg(feature = "dim2")]
 pub use self::heightfield2::HeightField;
 #[cfg(feature = "dim3")]
 pub use self::heightfield3::{HeightField, HeightFieldCellStatus};
+pub use self::minkowski_sum::MinkowskiSum;
 pub use self::plane::Plane;
 pub use self::polyline::Polyline;
+pub use self::round_shape::RoundShape;
+pub use self::scaled::Scaled;
 pub use self::segment::{Segment, SegmentPointLocation};
 #[doc(inline)]
 pub use self::shape::{Shape, ShapeHandle};
+#[cfg(feature = "serde-serialize")]
+pub use self::shape_registry::{SerializableShape, ShapeRegistry, ShapeSeed};
 #[doc(inline)]
 pub use self::support_map::SupportMap;
 #[cfg(feature = "dim3")]
 pub use self::tetrahedron::{Tetrahedron, TetrahedronPointLocation};
+#[cfg(feature = "dim3")]
+pub use self::torus::Torus;
 pub use self::triangle::{Triangle, TrianglePointLocation};
 #[cfg(feature = "dim3")]
 pub use self::trimesh::{FaceAdjacentToEdge, TriMesh, TriMeshEdge, TriMeshFace, TriMeshVertex};
+pub use self::voxels::Voxels;
 
 mod ball;
 mod capsule;
@@ -48,6 +58,7 @@ mod compound;
 mod cone;
 #[cfg(feature = "dim3")]
 mod convex;
+mod convex_hull_of;
 #[cfg(feature = "dim2")]
 mod convex_polygon;
 #[cfg(feature = "dim2")]
@@ -59,20 +70,29 @@ mod cuboid;
 #[cfg(feature = "dim3")]
 mod cylinder;
 mod deformable_shape;
+mod ellipsoid;
 #[cfg(feature = "dim2")]
 mod heightfield2;
 #[cfg(feature = "dim3")]
 mod heightfield3;
+mod minkowski_sum;
 mod plane;
 mod polyline;
+pub(crate) mod round_shape;
+mod scaled;
 mod segment;
 #[doc(hidden)]
 pub mod shape;
 mod shape_impl;
+#[cfg(feature = "serde-serialize")]
+mod shape_registry;
 #[doc(hidden)]
 pub mod support_map;
 #[cfg(feature = "dim3")]
 mod tetrahedron;
+#[cfg(feature = "dim3")]
+pub(crate) mod torus;
 mod triangle;
 #[cfg(feature = "dim3")]
 mod trimesh;
+mod voxels;
@@ -0,0 +1,101 @@
+//! Shapes dilated by a ball.
+
+use na::{self, RealField, Unit};
+
+use crate::math::{Point, Vector};
+use crate::query::{Contact, ContactKinematic, ContactPreprocessor};
+use crate::shape::{FeatureId, SupportMap};
+
+/// A shape dilated by a ball, i.e., the Minkowski sum of a shape and a ball.
+///
+/// This is useful to represent rounded cuboids, rounded cylinders, rounded convex hulls, etc.
+/// The features of a round shape are the features of its inner shape.
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(PartialEq, Debug, Clone)]
+pub struct RoundShape<N: RealField, S> {
+    /// The shape being dilated.
+    pub inner_shape: S,
+    /// The radius of the ball the inner shape is dilated by.
+    pub border_radius: N,
+}
+
+impl<N: RealField, S> RoundShape<N, S> {
+    /// Creates a new shape by dilating `inner_shape` by a ball with the radius `border_radius`.
+    #[inline]
+    pub fn new(inner_shape: S, border_radius: N) -> RoundShape<N, S> {
+        assert!(
+            border_radius >= N::zero(),
+            "The border radius of a round shape must be positive."
+        );
+
+        RoundShape {
+            inner_shape,
+            border_radius,
+        }
+    }
+
+    /// The contact preprocessor to be used for contact determination with this round shape.
+    ///
+    /// It offsets the contacts computed with the inner shape by the border radius.
+    #[inline]
+    pub fn contact_preprocessor(&self) -> impl ContactPreprocessor<N> {
+        RoundShapeContactPreprocessor::new(self.border_radius)
+    }
+}
+
+impl<N: RealField, S: SupportMap<N>> SupportMap<N> for RoundShape<N, S> {
+    #[inline]
+    fn local_support_point(&self, dir: &Vector<N>) -> Point<N> {
+        match Unit::try_new(*dir, N::default_epsilon()) {
+            Some(dir) => self.local_support_point_toward(&dir),
+            None => self.inner_shape.local_support_point(dir),
+        }
+    }
+
+    #[inline]
+    fn local_support_point_toward(&self, dir: &Unit<Vector<N>>) -> Point<N> {
+        self.inner_shape.local_support_point_toward(dir) + **dir * self.border_radius
+    }
+}
+
+/// Offsets the contacts computed with the inner shape of a round shape by its border radius.
+pub(crate) struct RoundShapeContactPreprocessor<N: RealField> {
+    border_radius: N,
+}
+
+impl<N: RealField> RoundShapeContactPreprocessor<N> {
+    pub fn new(border_radius: N) -> Self {
+        RoundShapeContactPreprocessor { border_radius }
+    }
+}
+
+impl<N: RealField> ContactPreprocessor<N> for RoundShapeContactPreprocessor<N> {
+    fn process_contact(
+        &self,
+        c: &mut Contact<N>,
+        kinematic: &mut ContactKinematic<N>,
+        is_first: bool,
+    ) -> bool {
+        let feature = if is_first {
+            kinematic.feature1()
+        } else {
+            kinematic.feature2()
+        };
+
+        if feature == FeatureId::Unknown {
+            return false;
+        }
+
+        if is_first {
+            kinematic.set_dilation1(kinematic.dilation1() + self.border_radius);
+            c.world1 += *c.normal * self.border_radius;
+        } else {
+            kinematic.set_dilation2(kinematic.dilation2() + self.border_radius);
+            c.world2 -= *c.normal * self.border_radius;
+        }
+
+        c.depth += self.border_radius;
+
+        true
+    }
+}
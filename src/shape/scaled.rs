@@ -0,0 +1,117 @@
+//! Shape scaled along each coordinate axis.
+
+use std::sync::Arc;
+
+use na::RealField;
+
+use crate::math::{Point, Vector};
+use crate::shape::{Shape, SupportMap};
+
+/// A shape scaled along each coordinate axis.
+///
+/// The scaling is applied at query time so the scaled shape is stored behind an `Arc` and shared
+/// by all the clones of this `Scaled`, as well as by the instances created with `.with_scale(...)`.
+/// This is useful to place several differently-scaled instances of the same triangle mesh or
+/// heightfield.
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Debug)]
+pub struct Scaled<N: RealField, S> {
+    shape: Arc<S>,
+    scale: Vector<N>,
+}
+
+impl<N: RealField, S> Scaled<N, S> {
+    /// Creates a new shape by scaling `shape` by `scale` along each coordinate axis.
+    ///
+    /// All the components of `scale` must be non-zero. Negative components mirror the shape.
+    #[inline]
+    pub fn new(shape: S, scale: Vector<N>) -> Self {
+        Self::from_arc(Arc::new(shape), scale)
+    }
+
+    /// Creates a new shape by scaling a shared `shape` by `scale` along each coordinate axis.
+    ///
+    /// All the components of `scale` must be non-zero. Negative components mirror the shape.
+    #[inline]
+    pub fn from_arc(shape: Arc<S>, scale: Vector<N>) -> Self {
+        assert!(
+            scale.iter().all(|s| !s.is_zero()),
+            "The scaling factors of a scaled shape must be non-zero."
+        );
+
+        Scaled { shape, scale }
+    }
+
+    /// Creates a new instance of the same shape with a different scale.
+    ///
+    /// The shape itself is shared, not copied.
+    #[inline]
+    pub fn with_scale(&self, scale: Vector<N>) -> Self {
+        Self::from_arc(self.shape.clone(), scale)
+    }
+
+    /// The shape being scaled.
+    #[inline]
+    pub fn shape(&self) -> &S {
+        &self.shape
+    }
+
+    /// The shared handle to the shape being scaled.
+    #[inline]
+    pub fn shape_arc(&self) -> &Arc<S> {
+        &self.shape
+    }
+
+    /// The scaling factors along each coordinate axis.
+    #[inline]
+    pub fn scale(&self) -> &Vector<N> {
+        &self.scale
+    }
+
+    /// Whether the scaling factors are the same along all the coordinate axes, up to their sign.
+    #[inline]
+    pub fn is_uniform(&self) -> bool {
+        let s0 = self.scale[0].abs();
+        self.scale.iter().all(|s| s.abs() == s0)
+    }
+
+    /// Maps a point of the scaled shape to a point of the unscaled shape.
+    #[inline]
+    pub fn unscale_point(&self, pt: &Point<N>) -> Point<N> {
+        Point::from(pt.coords.component_div(&self.scale))
+    }
+
+    /// Maps a point of the unscaled shape to a point of the scaled shape.
+    #[inline]
+    pub fn scale_point(&self, pt: &Point<N>) -> Point<N> {
+        Point::from(pt.coords.component_mul(&self.scale))
+    }
+}
+
+impl<N: RealField, S> Clone for Scaled<N, S> {
+    #[inline]
+    fn clone(&self) -> Self {
+        Scaled {
+            shape: self.shape.clone(),
+            scale: self.scale,
+        }
+    }
+}
+
+/// The support mapping of a scaled support-mapped shape.
+///
+/// Panics if the scaled shape is not a support-mapped shape, i.e., if `self.shape().as_support_map()`
+/// is `None`.
+impl<N: RealField, S: Shape<N>> SupportMap<N> for Scaled<N, S> {
+    #[inline]
+    fn local_support_point(&self, dir: &Vector<N>) -> Point<N> {
+        let support_map = self
+            .shape
+            .as_support_map()
+            .expect("The scaled shape is not a support map.");
+
+        // The support function of the image of a shape by a linear map `A` is
+        // `A * support(A^T * dir)`.
+        self.scale_point(&support_map.local_support_point(&dir.component_mul(&self.scale)))
+    }
+}
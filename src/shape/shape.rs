@@ -1,6 +1,7 @@
 // Queries.
-use crate::bounding_volume::{BoundingSphere, AABB};
-use crate::math::{Isometry, Vector};
+use crate::bounding_volume::{BoundingSphere, AABB, OBB};
+use crate::mass_properties::MassProperties;
+use crate::math::{Isometry, Point, Vector};
 use crate::query::{PointQuery, RayCast};
 use crate::shape::{CompositeShape, ConvexPolyhedron, DeformableShape, FeatureId, SupportMap};
 use downcast_rs::Downcast;
@@ -46,6 +47,27 @@ pub trait Shape<N: RealField>: Send + Sync + Downcast + ShapeClone<N> {
         BoundingSphere::new(aabb.center(), aabb.half_extents().norm())
     }
 
+    /// The OBB of `self` transformed by `m`.
+    #[inline]
+    fn obb(&self, m: &Isometry<N>) -> OBB<N> {
+        self.local_obb().transform_by(m)
+    }
+
+    /// The OBB of `self`.
+    #[inline]
+    fn local_obb(&self) -> OBB<N> {
+        OBB::from_aabb(&self.local_aabb())
+    }
+
+    /// The mass properties of `self` assuming it has the uniform density `density`.
+    ///
+    /// Returns `None` if `self` does not enclose any volume (or any area in 2D) or if its mass
+    /// properties cannot be computed.
+    #[inline]
+    fn mass_properties(&self, _density: N) -> Option<MassProperties<N>> {
+        None
+    }
+
     /// Check if if the feature `_feature` of the `i-th` subshape of `self` transformed by `m` has a tangent
     /// cone that contains `dir` at the point `pt`.
     // NOTE: for the moment, we assume the tangent cone is the same for the whole feature.
@@ -57,6 +79,23 @@ pub trait Shape<N: RealField>: Send + Sync + Downcast + ShapeClone<N> {
         _dir: &Unit<Vector<N>>,
     ) -> bool;
 
+    /// Check if the feature `feature` of `self` transformed by `m` has a tangent cone at the local
+    /// point `pt` that contains `dir`.
+    ///
+    /// This must be implemented by the shapes with curved features, which tangent cones depend on
+    /// the point of the feature. It defaults to `self.tangent_cone_contains_dir(...)`.
+    #[inline]
+    fn tangent_cone_at_point_contains_dir(
+        &self,
+        feature: FeatureId,
+        m: &Isometry<N>,
+        deformations: Option<&[N]>,
+        _pt: &Point<N>,
+        dir: &Unit<Vector<N>>,
+    ) -> bool {
+        self.tangent_cone_contains_dir(feature, m, deformations, dir)
+    }
+
     /// Returns the id of the subshape containing the specified feature.
     ///
     /// If several subshape contains the same feature, any one is returned.
@@ -88,6 +127,12 @@ pub trait Shape<N: RealField>: Send + Sync + Downcast + ShapeClone<N> {
         None
     }
 
+    /// The inner shape and the border radius of `self` if it is a shape dilated by a ball.
+    #[inline]
+    fn as_round_shape(&self) -> Option<(&dyn Shape<N>, N)> {
+        None
+    }
+
     /// The composite shape representation of `self` if applicable.
     #[inline]
     fn as_composite_shape(&self) -> Option<&dyn CompositeShape<N>> {
@@ -118,6 +163,12 @@ pub trait Shape<N: RealField>: Send + Sync + Downcast + ShapeClone<N> {
         self.as_support_map().is_some()
     }
 
+    /// Whether `self` is a shape dilated by a ball.
+    #[inline]
+    fn is_round_shape(&self) -> bool {
+        self.as_round_shape().is_some()
+    }
+
     /// Whether `self` uses a composite shape-based representation.
     #[inline]
     fn is_composite_shape(&self) -> bool {
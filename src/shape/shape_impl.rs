@@ -1,14 +1,16 @@
-use crate::bounding_volume::{self, BoundingSphere, AABB};
-use crate::math::{Isometry, Vector};
+use crate::bounding_volume::{self, BoundingSphere, AABB, OBB};
+use crate::mass_properties::MassProperties;
+use crate::math::{Isometry, Point, Vector};
 use crate::query::{PointQuery, RayCast};
 #[cfg(feature = "dim2")]
 use crate::shape::ConvexPolygon;
 use crate::shape::{
-    Ball, Capsule, CompositeShape, Compound, ConvexPolyhedron, Cuboid, DeformableShape, FeatureId,
-    HeightField, Plane, Polyline, Segment, Shape, SupportMap,
+    Ball, Capsule, CompositeShape, Compound, ConvexHullOf, ConvexPolyhedron, Cuboid,
+    DeformableShape, Ellipsoid, FeatureId, HeightField, MinkowskiSum, Plane, Polyline, RoundShape,
+    Scaled, Segment, Shape, SupportMap, Voxels,
 };
 #[cfg(feature = "dim3")]
-use crate::shape::{ConvexHull, TriMesh, Triangle};
+use crate::shape::{Cone, ConvexHull, Cylinder, Tetrahedron, Torus, TriMesh, Triangle};
 use na::{RealField, Unit};
 
 macro_rules! impl_as_convex_polyhedron (
@@ -94,6 +96,16 @@ macro_rules! impl_shape_common (
             bounding_volume::bounding_sphere(self, m)
         }
 
+        #[inline]
+        fn obb(&self, m: &Isometry<N>) -> OBB<N> {
+            bounding_volume::obb(self, m)
+        }
+
+        #[inline]
+        fn local_obb(&self) -> OBB<N> {
+            bounding_volume::local_obb(self)
+        }
+
         #[inline]
         fn as_ray_cast(&self) -> Option<&dyn RayCast<N>> {
             Some(self)
@@ -123,6 +135,29 @@ impl<N: RealField> Shape<N> for Ball<N> {
     impl_shape_common!();
     impl_as_support_map!();
 
+    #[inline]
+    fn mass_properties(&self, density: N) -> Option<MassProperties<N>> {
+        Some(MassProperties::from_ball(density, self.radius))
+    }
+
+    // FIXME: this is wrong in theory but keep it this
+    // way for now because of the way the ContactKinematic
+    // currently works.
+    fn tangent_cone_contains_dir(
+        &self,
+        _: FeatureId,
+        _: &Isometry<N>,
+        _: Option<&[N]>,
+        _: &Unit<Vector<N>>,
+    ) -> bool {
+        false
+    }
+}
+
+impl<N: RealField> Shape<N> for Ellipsoid<N> {
+    impl_shape_common!();
+    impl_as_support_map!();
+
     // FIXME: this is wrong in theory but keep it this
     // way for now because of the way the ContactKinematic
     // currently works.
@@ -137,16 +172,138 @@ impl<N: RealField> Shape<N> for Ball<N> {
     }
 }
 
+impl<N, S> Shape<N> for RoundShape<N, S>
+where
+    N: RealField,
+    S: Shape<N> + SupportMap<N> + PointQuery<N> + Clone,
+{
+    impl_shape_common!();
+    impl_as_support_map!();
+
+    #[inline]
+    fn as_round_shape(&self) -> Option<(&dyn Shape<N>, N)> {
+        Some((&self.inner_shape, self.border_radius))
+    }
+
+    // The features of a round shape are the ones of its inner shape.
+    fn tangent_cone_contains_dir(
+        &self,
+        feature: FeatureId,
+        m: &Isometry<N>,
+        deformations: Option<&[N]>,
+        dir: &Unit<Vector<N>>,
+    ) -> bool {
+        self.inner_shape
+            .tangent_cone_contains_dir(feature, m, deformations, dir)
+    }
+
+    fn tangent_cone_at_point_contains_dir(
+        &self,
+        feature: FeatureId,
+        m: &Isometry<N>,
+        deformations: Option<&[N]>,
+        pt: &Point<N>,
+        dir: &Unit<Vector<N>>,
+    ) -> bool {
+        self.inner_shape
+            .tangent_cone_at_point_contains_dir(feature, m, deformations, pt, dir)
+    }
+
+    #[inline]
+    fn subshape_containing_feature(&self, feature: FeatureId) -> usize {
+        self.inner_shape.subshape_containing_feature(feature)
+    }
+}
+
+impl<N, A, B> Shape<N> for MinkowskiSum<N, A, B>
+where
+    N: RealField,
+    A: Shape<N> + SupportMap<N> + Clone,
+    B: Shape<N> + SupportMap<N> + Clone,
+{
+    impl_shape_common!();
+    impl_as_support_map!();
+
+    fn tangent_cone_contains_dir(
+        &self,
+        _: FeatureId,
+        _: &Isometry<N>,
+        _: Option<&[N]>,
+        _: &Unit<Vector<N>>,
+    ) -> bool {
+        false
+    }
+}
+
+impl<N, A, B> Shape<N> for ConvexHullOf<N, A, B>
+where
+    N: RealField,
+    A: Shape<N> + SupportMap<N> + Clone,
+    B: Shape<N> + SupportMap<N> + Clone,
+{
+    impl_shape_common!();
+    impl_as_support_map!();
+
+    fn tangent_cone_contains_dir(
+        &self,
+        _: FeatureId,
+        _: &Isometry<N>,
+        _: Option<&[N]>,
+        _: &Unit<Vector<N>>,
+    ) -> bool {
+        false
+    }
+}
+
+impl<N: RealField, S: Shape<N>> Shape<N> for Scaled<N, S> {
+    impl_shape_common!();
+
+    #[inline]
+    fn as_support_map(&self) -> Option<&dyn SupportMap<N>> {
+        if self.shape().is_support_map() {
+            Some(self)
+        } else {
+            None
+        }
+    }
+
+    // FIXME: the tangent cones of the scaled shape are not tracked, so
+    // the contact kinematics must not rely on them.
+    fn tangent_cone_contains_dir(
+        &self,
+        _: FeatureId,
+        _: &Isometry<N>,
+        _: Option<&[N]>,
+        _: &Unit<Vector<N>>,
+    ) -> bool {
+        false
+    }
+}
+
 impl<N: RealField> Shape<N> for Cuboid<N> {
     impl_shape_common!();
     impl_as_support_map!();
     impl_as_convex_polyhedron!();
+
+    #[inline]
+    fn mass_properties(&self, density: N) -> Option<MassProperties<N>> {
+        Some(MassProperties::from_cuboid(density, &self.half_extents))
+    }
 }
 
 impl<N: RealField> Shape<N> for Capsule<N> {
     impl_shape_common!();
     impl_as_support_map!();
 
+    #[inline]
+    fn mass_properties(&self, density: N) -> Option<MassProperties<N>> {
+        Some(MassProperties::from_capsule(
+            density,
+            self.half_height,
+            self.radius,
+        ))
+    }
+
     // FIXME: this is wrong in theory but keep it this
     // way for now because of the way the ContactKinematic
     // currently works.
@@ -166,6 +323,111 @@ impl<N: RealField> Shape<N> for ConvexHull<N> {
     impl_shape_common!();
     impl_as_support_map!();
     impl_as_convex_polyhedron!();
+
+    #[inline]
+    fn mass_properties(&self, density: N) -> Option<MassProperties<N>> {
+        Some(MassProperties::from_convex_hull(density, self))
+    }
+}
+
+#[cfg(feature = "dim3")]
+impl<N: RealField> Shape<N> for Tetrahedron<N> {
+    impl_shape_common!();
+    impl_as_support_map!();
+    impl_as_convex_polyhedron!();
+}
+
+#[cfg(feature = "dim3")]
+impl<N: RealField> Shape<N> for Cylinder<N> {
+    impl_shape_common!();
+    impl_as_support_map!();
+
+    #[inline]
+    fn mass_properties(&self, density: N) -> Option<MassProperties<N>> {
+        Some(MassProperties::from_cylinder(
+            density,
+            self.half_height,
+            self.radius,
+        ))
+    }
+
+    // Without a point, the tangent cones are evaluated at the axis, where the lateral surface
+    // is ignored.
+    fn tangent_cone_contains_dir(
+        &self,
+        feature: FeatureId,
+        m: &Isometry<N>,
+        _: Option<&[N]>,
+        dir: &Unit<Vector<N>>,
+    ) -> bool {
+        self.tangent_cone_contains_dir(feature, m, &Point::origin(), dir)
+    }
+
+    fn tangent_cone_at_point_contains_dir(
+        &self,
+        feature: FeatureId,
+        m: &Isometry<N>,
+        _: Option<&[N]>,
+        pt: &Point<N>,
+        dir: &Unit<Vector<N>>,
+    ) -> bool {
+        self.tangent_cone_contains_dir(feature, m, pt, dir)
+    }
+}
+
+#[cfg(feature = "dim3")]
+impl<N: RealField> Shape<N> for Cone<N> {
+    impl_shape_common!();
+    impl_as_support_map!();
+
+    #[inline]
+    fn mass_properties(&self, density: N) -> Option<MassProperties<N>> {
+        Some(MassProperties::from_cone(
+            density,
+            self.half_height,
+            self.radius,
+        ))
+    }
+
+    // Without a point, the tangent cones are evaluated at the axis, where the lateral surface
+    // is ignored.
+    fn tangent_cone_contains_dir(
+        &self,
+        feature: FeatureId,
+        m: &Isometry<N>,
+        _: Option<&[N]>,
+        dir: &Unit<Vector<N>>,
+    ) -> bool {
+        self.tangent_cone_contains_dir(feature, m, &Point::origin(), dir)
+    }
+
+    fn tangent_cone_at_point_contains_dir(
+        &self,
+        feature: FeatureId,
+        m: &Isometry<N>,
+        _: Option<&[N]>,
+        pt: &Point<N>,
+        dir: &Unit<Vector<N>>,
+    ) -> bool {
+        self.tangent_cone_contains_dir(feature, m, pt, dir)
+    }
+}
+
+#[cfg(feature = "dim3")]
+impl<N: RealField> Shape<N> for Torus<N> {
+    impl_shape_common!();
+
+    // The boundary of a torus is smooth so its tangent cone at a point is a half-space, but
+    // this point is not known here.
+    fn tangent_cone_contains_dir(
+        &self,
+        _: FeatureId,
+        _: &Isometry<N>,
+        _: Option<&[N]>,
+        _: &Unit<Vector<N>>,
+    ) -> bool {
+        false
+    }
 }
 
 #[cfg(feature = "dim2")]
@@ -173,12 +435,22 @@ impl<N: RealField> Shape<N> for ConvexPolygon<N> {
     impl_shape_common!();
     impl_as_support_map!();
     impl_as_convex_polyhedron!();
+
+    #[inline]
+    fn mass_properties(&self, density: N) -> Option<MassProperties<N>> {
+        Some(MassProperties::from_convex_polygon(density, self.points()))
+    }
 }
 
 impl<N: RealField> Shape<N> for Compound<N> {
     impl_shape_common!();
     impl_as_composite_shape!();
 
+    #[inline]
+    fn mass_properties(&self, density: N) -> Option<MassProperties<N>> {
+        MassProperties::from_compound(density, self.shapes())
+    }
+
     fn tangent_cone_contains_dir(
         &self,
         feature: FeatureId,
@@ -194,6 +466,23 @@ impl<N: RealField> Shape<N> for Compound<N> {
             .tangent_cone_contains_dir(fid, &shape.0, None, &ls_dir)
     }
 
+    fn tangent_cone_at_point_contains_dir(
+        &self,
+        feature: FeatureId,
+        m: &Isometry<N>,
+        _: Option<&[N]>,
+        pt: &Point<N>,
+        dir: &Unit<Vector<N>>,
+    ) -> bool {
+        let (i, fid) = self.subshape_feature_id(feature);
+        let shape = &self.shapes()[i];
+        let ls_dir = m.inverse_transform_unit_vector(dir);
+        let ls_pt = shape.0.inverse_transform_point(pt);
+        shape
+            .1
+            .tangent_cone_at_point_contains_dir(fid, &shape.0, None, &ls_pt, &ls_dir)
+    }
+
     fn subshape_containing_feature(&self, feature: FeatureId) -> usize {
         self.subshape_feature_id(feature).0
     }
@@ -205,6 +494,11 @@ impl<N: RealField> Shape<N> for TriMesh<N> {
     impl_as_composite_shape!();
     impl_as_deformable_shape!();
 
+    #[inline]
+    fn mass_properties(&self, density: N) -> Option<MassProperties<N>> {
+        MassProperties::from_trimesh(density, self)
+    }
+
     fn tangent_cone_contains_dir(
         &self,
         fid: FeatureId,
@@ -268,6 +562,28 @@ impl<N: RealField> Shape<N> for HeightField<N> {
     }
 }
 
+impl<N: RealField> Shape<N> for Voxels<N> {
+    impl_shape_common!();
+    impl_as_composite_shape!();
+
+    fn tangent_cone_contains_dir(
+        &self,
+        feature: FeatureId,
+        m: &Isometry<N>,
+        _: Option<&[N]>,
+        dir: &Unit<Vector<N>>,
+    ) -> bool {
+        let (i, fid) = self.subshape_feature_id(feature);
+        let ls_dir = m.inverse_transform_unit_vector(dir);
+        self.voxel_cuboid()
+            .tangent_cone_contains_dir(fid, &self.voxel_position(i), &ls_dir)
+    }
+
+    fn subshape_containing_feature(&self, feature: FeatureId) -> usize {
+        self.subshape_feature_id(feature).0
+    }
+}
+
 impl<N: RealField> Shape<N> for Plane<N> {
     impl_shape_common!();
 
@@ -0,0 +1,319 @@
+use crate::math::Isometry;
+#[cfg(feature = "dim2")]
+use crate::shape::ConvexPolygon;
+use crate::shape::{
+    Ball, Capsule, Compound, Cuboid, Ellipsoid, HeightField, Plane, Polyline, RoundShape, Scaled,
+    Segment, Shape, ShapeHandle, Voxels,
+};
+#[cfg(feature = "dim3")]
+use crate::shape::{Cone, ConvexHull, Cylinder, Tetrahedron, Torus, TriMesh, Triangle};
+use na::RealField;
+use serde::de::{self, DeserializeOwned, DeserializeSeed, SeqAccess, Visitor};
+use serde::ser::{self, SerializeTuple};
+use serde::{Deserializer, Serialize, Serializer};
+use std::any::TypeId;
+use std::collections::HashMap;
+use std::fmt;
+use std::marker::PhantomData;
+
+type SerializeFn<N> = fn(&dyn Shape<N>) -> &dyn erased_serde::Serialize;
+type DeserializeFn<N> = for<'de> fn(
+    &mut dyn erased_serde::Deserializer<'de>,
+) -> Result<ShapeHandle<N>, erased_serde::Error>;
+
+// Compound shapes contain shape handles so they are serialized by the registry itself.
+const COMPOUND_NAME: &str = "Compound";
+
+/// A registry of shape types, used to serialize and deserialize shape trait-objects.
+///
+/// A `ShapeHandle` does not know the concrete type of its shape. The registry associates a unique
+/// name to each registered shape type so that a shape can be serialized along with its name, and
+/// deserialized back with the right type. `ShapeRegistry::new()` registers all the serializable
+/// shapes of ncollide, as well as compound shapes made of registered shapes.
+pub struct ShapeRegistry<N: RealField> {
+    names: HashMap<TypeId, (String, SerializeFn<N>)>,
+    deserializers: HashMap<String, DeserializeFn<N>>,
+}
+
+impl<N: RealField + Serialize + DeserializeOwned> ShapeRegistry<N> {
+    /// Creates a registry containing only compound shapes.
+    pub fn empty() -> Self {
+        ShapeRegistry {
+            names: HashMap::new(),
+            deserializers: HashMap::new(),
+        }
+    }
+
+    /// Creates a registry containing all the serializable shapes of ncollide.
+    pub fn new() -> Self {
+        let mut res = Self::empty();
+        res.register::<Ball<N>>("Ball");
+        res.register::<Capsule<N>>("Capsule");
+        res.register::<Cuboid<N>>("Cuboid");
+        res.register::<Ellipsoid<N>>("Ellipsoid");
+        res.register::<HeightField<N>>("HeightField");
+        res.register::<Plane<N>>("Plane");
+        res.register::<Polyline<N>>("Polyline");
+        res.register::<Segment<N>>("Segment");
+        res.register::<Voxels<N>>("Voxels");
+        res.register::<RoundShape<N, Cuboid<N>>>("RoundCuboid");
+        res.register::<Scaled<N, Ball<N>>>("ScaledBall");
+        res.register::<Scaled<N, Cuboid<N>>>("ScaledCuboid");
+        res.register::<Scaled<N, HeightField<N>>>("ScaledHeightField");
+        res.register::<Scaled<N, Polyline<N>>>("ScaledPolyline");
+        #[cfg(feature = "dim2")]
+        res.register::<ConvexPolygon<N>>("ConvexPolygon");
+        #[cfg(feature = "dim2")]
+        res.register::<RoundShape<N, ConvexPolygon<N>>>("RoundConvexPolygon");
+        #[cfg(feature = "dim3")]
+        res.register::<Triangle<N>>("Triangle");
+        #[cfg(feature = "dim3")]
+        res.register::<ConvexHull<N>>("ConvexHull");
+        #[cfg(feature = "dim3")]
+        res.register::<TriMesh<N>>("TriMesh");
+        #[cfg(feature = "dim3")]
+        res.register::<Cone<N>>("Cone");
+        #[cfg(feature = "dim3")]
+        res.register::<Cylinder<N>>("Cylinder");
+        #[cfg(feature = "dim3")]
+        res.register::<Tetrahedron<N>>("Tetrahedron");
+        #[cfg(feature = "dim3")]
+        res.register::<Torus<N>>("Torus");
+        #[cfg(feature = "dim3")]
+        res.register::<RoundShape<N, ConvexHull<N>>>("RoundConvexHull");
+        #[cfg(feature = "dim3")]
+        res.register::<Scaled<N, TriMesh<N>>>("ScaledTriMesh");
+        #[cfg(feature = "dim3")]
+        res.register::<RoundShape<N, Cylinder<N>>>("RoundCylinder");
+        #[cfg(feature = "dim3")]
+        res.register::<RoundShape<N, Cone<N>>>("RoundCone");
+        res
+    }
+
+    /// Registers the shape type `S` with the given name.
+    ///
+    /// Panics if `name` is already used by another shape type.
+    pub fn register<S: Shape<N> + Serialize + DeserializeOwned>(&mut self, name: &str) {
+        assert!(
+            name != COMPOUND_NAME && !self.deserializers.contains_key(name),
+            "The shape name `{}` is already registered.",
+            name
+        );
+
+        let _ = self.names.insert(
+            TypeId::of::<S>(),
+            (name.to_string(), serialize_shape::<N, S>),
+        );
+        let _ = self
+            .deserializers
+            .insert(name.to_string(), deserialize_shape::<N, S>);
+    }
+
+    /// Checks if the type of the given shape has been registered.
+    pub fn is_registered(&self, shape: &dyn Shape<N>) -> bool {
+        if let Some(compound) = shape.as_shape::<Compound<N>>() {
+            compound
+                .shapes()
+                .iter()
+                .all(|part| self.is_registered(part.1.as_ref()))
+        } else {
+            self.names.contains_key(&shape.as_any().type_id())
+        }
+    }
+
+    /// Wraps `shape` into a value serializable with this registry.
+    ///
+    /// Serializing a shape with a type missing from this registry fails.
+    pub fn serializable<'a>(&'a self, shape: &'a dyn Shape<N>) -> SerializableShape<'a, N> {
+        SerializableShape {
+            registry: self,
+            shape,
+        }
+    }
+
+    /// A seed deserializing the shapes serialized with `self.serializable(...)`.
+    pub fn seed(&self) -> ShapeSeed<'_, N> {
+        ShapeSeed { registry: self }
+    }
+}
+
+impl<N: RealField + Serialize + DeserializeOwned> Default for ShapeRegistry<N> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+fn serialize_shape<N: RealField, S: Shape<N> + Serialize>(
+    shape: &dyn Shape<N>,
+) -> &dyn erased_serde::Serialize {
+    shape
+        .as_shape::<S>()
+        .expect("Shape registry: internal error.")
+}
+
+fn deserialize_shape<'de, N: RealField, S: Shape<N> + DeserializeOwned>(
+    deserializer: &mut dyn erased_serde::Deserializer<'de>,
+) -> Result<ShapeHandle<N>, erased_serde::Error> {
+    erased_serde::deserialize::<S>(deserializer).map(ShapeHandle::new)
+}
+
+/// A shape trait-object serializable with the `ShapeRegistry` that created it.
+///
+/// The shape is serialized as a pair made of the name of its type and its data.
+pub struct SerializableShape<'a, N: RealField> {
+    registry: &'a ShapeRegistry<N>,
+    shape: &'a dyn Shape<N>,
+}
+
+impl<'a, N: RealField + Serialize + DeserializeOwned> Serialize for SerializableShape<'a, N> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let mut tuple = serializer.serialize_tuple(2)?;
+
+        if let Some(compound) = self.shape.as_shape::<Compound<N>>() {
+            let parts: Vec<_> = compound
+                .shapes()
+                .iter()
+                .map(|part| (&part.0, self.registry.serializable(part.1.as_ref())))
+                .collect();
+            tuple.serialize_element(COMPOUND_NAME)?;
+            tuple.serialize_element(&parts)?;
+        } else if let Some((name, serialize)) =
+            self.registry.names.get(&self.shape.as_any().type_id())
+        {
+            tuple.serialize_element(name)?;
+            tuple.serialize_element(serialize(self.shape))?;
+        } else {
+            return Err(ser::Error::custom(
+                "the type of this shape is not registered in the shape registry",
+            ));
+        }
+
+        tuple.end()
+    }
+}
+
+/// A seed deserializing a shape serialized with a `SerializableShape`.
+pub struct ShapeSeed<'a, N: RealField> {
+    registry: &'a ShapeRegistry<N>,
+}
+
+impl<'a, N: RealField> Clone for ShapeSeed<'a, N> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<'a, N: RealField> Copy for ShapeSeed<'a, N> {}
+
+impl<'a, 'de, N: RealField + Serialize + DeserializeOwned> DeserializeSeed<'de>
+    for ShapeSeed<'a, N>
+{
+    type Value = ShapeHandle<N>;
+
+    fn deserialize<D: Deserializer<'de>>(self, deserializer: D) -> Result<Self::Value, D::Error> {
+        deserializer.deserialize_tuple(2, self)
+    }
+}
+
+impl<'a, 'de, N: RealField + Serialize + DeserializeOwned> Visitor<'de> for ShapeSeed<'a, N> {
+    type Value = ShapeHandle<N>;
+
+    fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str("a shape type name followed by the shape data")
+    }
+
+    fn visit_seq<A: SeqAccess<'de>>(self, mut seq: A) -> Result<Self::Value, A::Error> {
+        let name: String = seq
+            .next_element()?
+            .ok_or_else(|| de::Error::invalid_length(0, &self))?;
+
+        let shape = if name == COMPOUND_NAME {
+            seq.next_element_seed(CompoundSeed(self))?
+        } else {
+            let deserialize = *self
+                .registry
+                .deserializers
+                .get(&name)
+                .ok_or_else(|| de::Error::custom(format!("unknown shape type `{}`", name)))?;
+            seq.next_element_seed(PayloadSeed(deserialize, PhantomData))?
+        };
+
+        shape.ok_or_else(|| de::Error::invalid_length(1, &self))
+    }
+}
+
+// Deserializes the data of a registered shape type.
+struct PayloadSeed<N: RealField>(DeserializeFn<N>, PhantomData<N>);
+
+impl<'de, N: RealField> DeserializeSeed<'de> for PayloadSeed<N> {
+    type Value = ShapeHandle<N>;
+
+    fn deserialize<D: Deserializer<'de>>(self, deserializer: D) -> Result<Self::Value, D::Error> {
+        let mut erased = <dyn erased_serde::Deserializer>::erase(deserializer);
+        (self.0)(&mut erased).map_err(de::Error::custom)
+    }
+}
+
+// Deserializes the parts of a compound shape.
+struct CompoundSeed<'a, N: RealField>(ShapeSeed<'a, N>);
+
+impl<'a, 'de, N: RealField + Serialize + DeserializeOwned> DeserializeSeed<'de>
+    for CompoundSeed<'a, N>
+{
+    type Value = ShapeHandle<N>;
+
+    fn deserialize<D: Deserializer<'de>>(self, deserializer: D) -> Result<Self::Value, D::Error> {
+        deserializer.deserialize_seq(self)
+    }
+}
+
+impl<'a, 'de, N: RealField + Serialize + DeserializeOwned> Visitor<'de> for CompoundSeed<'a, N> {
+    type Value = ShapeHandle<N>;
+
+    fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str("a sequence of positioned shapes")
+    }
+
+    fn visit_seq<A: SeqAccess<'de>>(self, mut seq: A) -> Result<Self::Value, A::Error> {
+        let mut parts = Vec::new();
+
+        while let Some(part) = seq.next_element_seed(CompoundPartSeed(self.0))? {
+            parts.push(part)
+        }
+
+        Ok(ShapeHandle::new(Compound::new(parts)))
+    }
+}
+
+// Deserializes a positioned part of a compound shape.
+struct CompoundPartSeed<'a, N: RealField>(ShapeSeed<'a, N>);
+
+impl<'a, 'de, N: RealField + Serialize + DeserializeOwned> DeserializeSeed<'de>
+    for CompoundPartSeed<'a, N>
+{
+    type Value = (Isometry<N>, ShapeHandle<N>);
+
+    fn deserialize<D: Deserializer<'de>>(self, deserializer: D) -> Result<Self::Value, D::Error> {
+        deserializer.deserialize_tuple(2, self)
+    }
+}
+
+impl<'a, 'de, N: RealField + Serialize + DeserializeOwned> Visitor<'de>
+    for CompoundPartSeed<'a, N>
+{
+    type Value = (Isometry<N>, ShapeHandle<N>);
+
+    fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str("a position followed by a shape")
+    }
+
+    fn visit_seq<A: SeqAccess<'de>>(self, mut seq: A) -> Result<Self::Value, A::Error> {
+        let pos = seq
+            .next_element()?
+            .ok_or_else(|| de::Error::invalid_length(0, &self))?;
+        let shape = seq
+            .next_element_seed(self.0)?
+            .ok_or_else(|| de::Error::invalid_length(1, &self))?;
+        Ok((pos, shape))
+    }
+}
@@ -1,8 +1,11 @@
 //! Definition of the tetrahedron shape.
 
-use crate::math::{Matrix, Point};
-use crate::shape::{Segment, Triangle};
-use na::RealField;
+use crate::math::{Isometry, Matrix, Point, Vector};
+use crate::shape::{
+    ConvexPolygonalFeature, ConvexPolyhedron, FeatureId, Segment, SupportMap, Triangle,
+};
+use na::{self, RealField, Unit};
+use std::f64;
 use std::mem;
 
 /// A tetrahedron with 4 vertices.
@@ -163,6 +166,22 @@ impl<N: RealField> Tetrahedron<N> {
         }
     }
 
+    /// The outward normal of the i-th face of this tetrahedron.
+    ///
+    /// Returns `None` if this face is degenerate.
+    pub fn face_normal(&self, i: usize) -> Option<Unit<Vector<N>>> {
+        let (i1, i2, i3) = Self::face_ids(i);
+        let pts = self.vertices();
+        let opposite = &pts[6 - i1 - i2 - i3];
+        let mut normal = (pts[i2] - pts[i1]).cross(&(pts[i3] - pts[i1]));
+
+        if normal.dot(&(opposite - pts[i1])) > N::zero() {
+            normal = -normal;
+        }
+
+        Unit::try_new(normal, N::default_epsilon())
+    }
+
     /// Returns the i-th edge of this tetrahedron.
     ///
     /// The 0-st edge is the segment AB.
@@ -203,6 +222,25 @@ impl<N: RealField> Tetrahedron<N> {
         }
     }
 
+    /// Returns the index of the edge joining the `i`-th and the `j`-th vertices of this tetrahedron.
+    pub fn edge_id(i: usize, j: usize) -> usize {
+        match (i.min(j), i.max(j)) {
+            (0, 1) => 0,
+            (0, 2) => 1,
+            (0, 3) => 2,
+            (1, 2) => 3,
+            (1, 3) => 4,
+            (2, 3) => 5,
+            _ => panic!("Tetrahedron edge vertices out of bounds (must be distinct and < 4)."),
+        }
+    }
+
+    /// The vertices of this tetrahedron.
+    #[inline]
+    pub fn vertices(&self) -> &[Point<N>; 4] {
+        unsafe { mem::transmute(self) }
+    }
+
     /// Computes the barycentric coordinates of the given point in the coordinate system of this tetrahedron.
     ///
     /// Returns `None` if this tetrahedron is degenerate.
@@ -222,4 +260,225 @@ impl<N: RealField> Tetrahedron<N> {
             ]
         })
     }
+
+    /// Checks that the given direction in world-space is on the tangent cone of the given `feature`.
+    pub fn tangent_cone_contains_dir(
+        &self,
+        feature: FeatureId,
+        m: &Isometry<N>,
+        dir: &Unit<Vector<N>>,
+    ) -> bool {
+        let ls_dir = m.inverse_transform_vector(dir);
+        let face_contains_dir = |i| {
+            self.face_normal(i)
+                .map(|n| n.dot(&ls_dir) <= N::zero())
+                .unwrap_or(false)
+        };
+
+        match feature {
+            FeatureId::Face(i) => face_contains_dir(i),
+            FeatureId::Edge(i) => {
+                let (v1, v2) = Self::edge_ids(i);
+                (0..4)
+                    .filter(|f| {
+                        Self::face_contains_vertex(*f, v1) && Self::face_contains_vertex(*f, v2)
+                    })
+                    .all(face_contains_dir)
+            }
+            FeatureId::Vertex(i) => (0..4)
+                .filter(|f| Self::face_contains_vertex(*f, i))
+                .all(face_contains_dir),
+            FeatureId::Unknown => panic!("Invalid feature ID {:?}.", feature),
+        }
+    }
+
+    fn face_contains_vertex(face: usize, vertex: usize) -> bool {
+        let (i1, i2, i3) = Self::face_ids(face);
+        i1 == vertex || i2 == vertex || i3 == vertex
+    }
+
+    /// Retrieves the identifier of the feature which normal cone contains `local_dir`.
+    ///
+    /// A face or an edge is returned if `local_dir` is at most `eps` radians away from its normal cone.
+    pub fn support_feature_id_toward(&self, local_dir: &Unit<Vector<N>>, eps: N) -> FeatureId {
+        let (seps, ceps) = eps.sin_cos();
+
+        for i in 0..4 {
+            if let Some(normal) = self.face_normal(i) {
+                if normal.dot(local_dir) >= ceps {
+                    return FeatureId::Face(i);
+                }
+            }
+        }
+
+        let pts = self.vertices();
+        let best = self.support_vertex_id(local_dir);
+
+        for i in (0..4).filter(|i| *i != best) {
+            if let Some(dir) = (pts[i] - pts[best]).try_normalize(N::default_epsilon()) {
+                if dir.dot(local_dir).abs() <= seps {
+                    return FeatureId::Edge(Self::edge_id(best, i));
+                }
+            }
+        }
+
+        FeatureId::Vertex(best)
+    }
+
+    fn support_vertex_id(&self, dir: &Vector<N>) -> usize {
+        let pts = self.vertices();
+        let mut best = 0;
+        let mut best_dot = pts[0].coords.dot(dir);
+
+        for i in 1..4 {
+            let dot = pts[i].coords.dot(dir);
+
+            if dot > best_dot {
+                best = i;
+                best_dot = dot;
+            }
+        }
+
+        best
+    }
+}
+
+impl<N: RealField> SupportMap<N> for Tetrahedron<N> {
+    #[inline]
+    fn local_support_point(&self, dir: &Vector<N>) -> Point<N> {
+        self.vertices()[self.support_vertex_id(dir)]
+    }
+}
+
+impl<N: RealField> ConvexPolyhedron<N> for Tetrahedron<N> {
+    fn vertex(&self, id: FeatureId) -> Point<N> {
+        self.vertices()[id.unwrap_vertex()]
+    }
+
+    fn edge(&self, id: FeatureId) -> (Point<N>, Point<N>, FeatureId, FeatureId) {
+        let (i1, i2) = Self::edge_ids(id.unwrap_edge());
+        let pts = self.vertices();
+
+        (
+            pts[i1],
+            pts[i2],
+            FeatureId::Vertex(i1),
+            FeatureId::Vertex(i2),
+        )
+    }
+
+    fn face(&self, id: FeatureId, face: &mut ConvexPolygonalFeature<N>) {
+        face.clear();
+        let i = id.unwrap_face();
+        let pts = self.vertices();
+        let (i1, mut i2, mut i3) = Self::face_ids(i);
+
+        if let Some(normal) = self.face_normal(i) {
+            // Make the vertices counterclockwise wrt. the outward normal.
+            if (pts[i2] - pts[i1]).cross(&(pts[i3] - pts[i1])).dot(&normal) < N::zero() {
+                mem::swap(&mut i2, &mut i3);
+            }
+
+            face.push(pts[i1], FeatureId::Vertex(i1));
+            face.push(pts[i2], FeatureId::Vertex(i2));
+            face.push(pts[i3], FeatureId::Vertex(i3));
+            face.push_edge_feature_id(FeatureId::Edge(Self::edge_id(i1, i2)));
+            face.push_edge_feature_id(FeatureId::Edge(Self::edge_id(i2, i3)));
+            face.push_edge_feature_id(FeatureId::Edge(Self::edge_id(i3, i1)));
+            face.set_normal(normal);
+            face.set_feature_id(id);
+            face.recompute_edge_normals();
+        } else {
+            face.push(pts[i1], FeatureId::Vertex(i1));
+            face.set_feature_id(FeatureId::Vertex(i1));
+        }
+    }
+
+    fn feature_normal(&self, feature: FeatureId) -> Unit<Vector<N>> {
+        let normal = match feature {
+            FeatureId::Face(i) => self.face_normal(i).map(|n| n.into_inner()),
+            FeatureId::Edge(i) => {
+                let (v1, v2) = Self::edge_ids(i);
+                Some(
+                    (0..4)
+                        .filter(|f| {
+                            Self::face_contains_vertex(*f, v1) && Self::face_contains_vertex(*f, v2)
+                        })
+                        .filter_map(|f| self.face_normal(f))
+                        .fold(Vector::zeros(), |acc, n| acc + n.into_inner()),
+                )
+            }
+            FeatureId::Vertex(i) => Some(
+                (0..4)
+                    .filter(|f| Self::face_contains_vertex(*f, i))
+                    .filter_map(|f| self.face_normal(f))
+                    .fold(Vector::zeros(), |acc, n| acc + n.into_inner()),
+            ),
+            FeatureId::Unknown => panic!("Invalid feature ID {:?}.", feature),
+        };
+
+        normal
+            .and_then(|n| Unit::try_new(n, N::default_epsilon()))
+            .unwrap_or(Vector::y_axis())
+    }
+
+    fn support_face_toward(
+        &self,
+        m: &Isometry<N>,
+        dir: &Unit<Vector<N>>,
+        face: &mut ConvexPolygonalFeature<N>,
+    ) {
+        let ls_dir = m.inverse_transform_vector(dir);
+        let mut best_face = 0;
+        let mut best_dot = -N::max_value();
+
+        for i in 0..4 {
+            if let Some(normal) = self.face_normal(i) {
+                let dot = normal.dot(&ls_dir);
+
+                if dot > best_dot {
+                    best_face = i;
+                    best_dot = dot;
+                }
+            }
+        }
+
+        ConvexPolyhedron::<N>::face(self, FeatureId::Face(best_face), face);
+        face.transform_by(m)
+    }
+
+    fn support_feature_toward(
+        &self,
+        transform: &Isometry<N>,
+        dir: &Unit<Vector<N>>,
+        angle: N,
+        out: &mut ConvexPolygonalFeature<N>,
+    ) {
+        out.clear();
+        let local_dir = transform.inverse_transform_unit_vector(dir);
+        let fid = self.support_feature_id_toward(&local_dir, angle);
+
+        match fid {
+            FeatureId::Vertex(_) => {
+                let v = ConvexPolyhedron::<N>::vertex(self, fid);
+                out.push(v, fid);
+                out.set_feature_id(fid);
+            }
+            FeatureId::Edge(_) => {
+                let edge = ConvexPolyhedron::<N>::edge(self, fid);
+                out.push(edge.0, edge.2);
+                out.push(edge.1, edge.3);
+                out.set_feature_id(fid);
+                out.push_edge_feature_id(fid);
+            }
+            FeatureId::Face(_) => ConvexPolyhedron::<N>::face(self, fid, out),
+            FeatureId::Unknown => unreachable!(),
+        }
+
+        out.transform_by(transform);
+    }
+
+    fn support_feature_id_toward(&self, local_dir: &Unit<Vector<N>>) -> FeatureId {
+        self.support_feature_id_toward(local_dir, na::convert(f64::consts::PI / 180.0))
+    }
 }
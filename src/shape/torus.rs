@@ -0,0 +1,260 @@
+//! A torus shape.
+
+use na::{self, RealField};
+
+use crate::math::{Isometry, Point, Translation, Vector};
+use crate::query::{self, Contact, ContactKinematic, ContactPreprocessor};
+use crate::shape::{Ball, FeatureId, Shape};
+
+/// A torus with its axis of revolution aligned with the `y` axis.
+///
+/// This is the set of points at a distance smaller than `minor_radius` from the circle of radius
+/// `major_radius` lying on the `xz` plane and centered at the origin.
+///
+/// The boundary of a torus is split into `Torus::NSLICES` slices around its axis:
+/// `FeatureId::Face(i)` is the slice of the points at an angle from the `x` axis, around the `y`
+/// axis, closest to `i * 2π / Torus::NSLICES`.
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(PartialEq, Debug, Copy, Clone)]
+pub struct Torus<N> {
+    /// The radius of the circle at the center of the tube of the torus.
+    pub major_radius: N,
+    /// The radius of the tube of the torus.
+    pub minor_radius: N,
+}
+
+impl<N: RealField> Torus<N> {
+    /// The number of slices the boundary of a torus is split into.
+    pub const NSLICES: usize = 32;
+
+    /// Creates a new torus.
+    ///
+    /// The major radius must be greater than the minor radius, which must be strictly positive.
+    pub fn new(major_radius: N, minor_radius: N) -> Torus<N> {
+        assert!(
+            minor_radius.is_positive() && major_radius > minor_radius,
+            "The radii of a torus must satisfy major_radius > minor_radius > 0."
+        );
+
+        Torus {
+            major_radius,
+            minor_radius,
+        }
+    }
+
+    /// The point of the circle at the center of the tube at the angle `theta` from the `x` axis.
+    #[inline]
+    pub fn core_point(&self, theta: N) -> Point<N> {
+        let (sin, cos) = theta.sin_cos();
+        Point::new(cos * self.major_radius, N::zero(), sin * self.major_radius)
+    }
+
+    /// The angle between the slice `i` and the `x` axis.
+    #[inline]
+    pub fn slice_angle(i: usize) -> N {
+        N::two_pi() * na::convert(i as f64) / na::convert(Self::NSLICES as f64)
+    }
+
+    /// The feature of the boundary slice containing the local point `pt`.
+    #[inline]
+    pub fn slice_feature_id(&self, pt: &Point<N>) -> FeatureId {
+        let angle = pt.z.atan2(pt.x);
+        let slice = (angle / Self::slice_angle(1)).round();
+        let slice = na::convert_unchecked::<N, f64>(slice) as isize;
+
+        FeatureId::Face(slice.rem_euclid(Self::NSLICES as isize) as usize)
+    }
+
+    /// The point of the circle at the center of the tube closest to the local point `pt`.
+    ///
+    /// If `pt` lies on the axis of the torus, all the points of this circle are equally close so
+    /// the one on the `x` axis is returned.
+    #[inline]
+    pub fn closest_core_point(&self, pt: &Point<N>) -> Point<N> {
+        let planar = Vector::new(pt.x, N::zero(), pt.z);
+
+        match planar.try_normalize(N::default_epsilon()) {
+            Some(dir) => Point::from(dir * self.major_radius),
+            None => Point::new(self.major_radius, N::zero(), N::zero()),
+        }
+    }
+
+    /// The distance between the local point `pt` and the circle at the center of the tube.
+    #[inline]
+    pub fn distance_to_core(&self, pt: &Point<N>) -> N {
+        let planar = Vector::new(pt.x, N::zero(), pt.z).norm() - self.major_radius;
+        (planar * planar + pt.y * pt.y).sqrt()
+    }
+
+    /// Checks if the given point, expressed in the local-space of this torus, is inside of it.
+    #[inline]
+    pub fn contains_local_point(&self, pt: &Point<N>) -> bool {
+        self.distance_to_core(pt) <= self.minor_radius
+    }
+}
+
+// Number of iterations of the golden-section search refining each deepest ball.
+const NREFINEMENT_STEPS: usize = 16;
+
+/// The angles of the points of the circle at the center of the tube of `torus` where a ball of
+/// radius `minor_radius` is locally deepest into `shape`.
+///
+/// Returns an empty vector if all these balls are farther than `prediction` from `shape`, and
+/// `None` if `query::contact_opt` does not support `shape` paired with a ball.
+pub(crate) fn deepest_core_angles<N: RealField>(
+    m1: &Isometry<N>,
+    torus: &Torus<N>,
+    m2: &Isometry<N>,
+    shape: &dyn Shape<N>,
+    prediction: N,
+) -> Option<Vec<N>> {
+    let ball = Ball::new(torus.minor_radius);
+
+    // If a ball is within the prediction distance of `shape`, then all the other balls are
+    // within this enlarged distance so the depth is known along the whole circle.
+    let search_prediction = prediction + torus.major_radius * na::convert(2.0);
+    let depth_at = |angle: N| {
+        let ball_pos = m1 * Translation::from(torus.core_point(angle).coords);
+        query::contact_opt(&ball_pos, &ball, m2, shape, search_prediction)
+            .map(|c| c.map(|c| c.depth).unwrap_or_else(|| -N::max_value()))
+    };
+
+    // The support of a pair of shapes does not depend on their positions.
+    let depths: Vec<N> = (0..Torus::<N>::NSLICES)
+        .map(|i| depth_at(Torus::slice_angle(i)))
+        .collect::<Option<_>>()?;
+
+    // Depths closer than this tolerance are considered equal.
+    let tolerance = N::default_epsilon().sqrt() * (torus.major_radius + torus.minor_radius);
+
+    Some(deepest_angles(&depths, tolerance, |angle| {
+        depth_at(angle).unwrap_or_else(|| -N::max_value())
+    }))
+}
+
+// The refined angles of the local maxima of the penetration depth sampled at the angles of the
+// slices of a torus.
+fn deepest_angles<N: RealField>(depths: &[N], tolerance: N, depth_at: impl Fn(N) -> N) -> Vec<N> {
+    let n = depths.len();
+    let prev = |i: usize| (i + n - 1) % n;
+    let next = |i: usize| (i + 1) % n;
+    let is_max: Vec<bool> = (0..n)
+        .map(|i| {
+            depths[i] > -N::max_value()
+                && depths[i] + tolerance >= depths[prev(i)]
+                && depths[i] + tolerance >= depths[next(i)]
+        })
+        .collect();
+    let mut result = Vec::new();
+
+    // Runs of maxima come from the other shape touching a whole arc of the torus, e.g., a
+    // plane lying on it, or a ball in its hole. The whole circle is covered by four contacts.
+    let start = match (0..n).find(|&i| is_max[i] && !is_max[prev(i)]) {
+        Some(start) => start,
+        None => {
+            if is_max[0] {
+                for k in 0..4 {
+                    result.push(Torus::slice_angle(k * n / 4));
+                }
+            }
+
+            return result;
+        }
+    };
+
+    let mut i = start;
+
+    loop {
+        if is_max[i] && !is_max[prev(i)] {
+            let mut len = 1;
+
+            while is_max[(i + len) % n] {
+                len += 1;
+            }
+
+            // Short runs are due to the tolerance around a single maximum.
+            let angle = if len <= 2 {
+                let deepest = (i..i + len)
+                    .map(|j| j % n)
+                    .max_by(|a, b| depths[*a].partial_cmp(&depths[*b]).unwrap())
+                    .unwrap();
+                refine_deepest_angle(deepest, &depth_at)
+            } else {
+                Torus::slice_angle((i + len / 2) % n)
+            };
+
+            result.push(angle);
+        }
+
+        i = next(i);
+
+        if i == start {
+            return result;
+        }
+    }
+}
+
+// Golden-section search of the deepest angle between the neighbors of the given slice.
+fn refine_deepest_angle<N: RealField>(slice: usize, depth_at: impl Fn(N) -> N) -> N {
+    let center = Torus::<N>::slice_angle(slice);
+    let dangle = Torus::<N>::slice_angle(1);
+    let inv_phi: N = na::convert(0.618_033_988_749_894_9);
+    let mut lo = center - dangle;
+    let mut hi = center + dangle;
+    let mut a = hi - (hi - lo) * inv_phi;
+    let mut b = lo + (hi - lo) * inv_phi;
+    let mut depth_a = depth_at(a);
+    let mut depth_b = depth_at(b);
+
+    for _ in 0..NREFINEMENT_STEPS {
+        if depth_a >= depth_b {
+            hi = b;
+            b = a;
+            depth_b = depth_a;
+            a = hi - (hi - lo) * inv_phi;
+            depth_a = depth_at(a);
+        } else {
+            lo = a;
+            a = b;
+            depth_a = depth_b;
+            b = lo + (hi - lo) * inv_phi;
+            depth_b = depth_at(b);
+        }
+    }
+
+    (lo + hi) * na::convert(0.5)
+}
+
+/// Maps the contacts computed with one of the balls swept along the circle at the center of the
+/// tube of a torus to the torus itself.
+pub(crate) struct TorusContactPreprocessor<N: RealField> {
+    ball_pos: Isometry<N>,
+    feature: FeatureId,
+}
+
+impl<N: RealField> TorusContactPreprocessor<N> {
+    /// `ball_pos` is the local position of the ball and `feature` the torus feature the contacts
+    /// computed with this ball are attached to.
+    pub fn new(ball_pos: Isometry<N>, feature: FeatureId) -> Self {
+        TorusContactPreprocessor { ball_pos, feature }
+    }
+}
+
+impl<N: RealField> ContactPreprocessor<N> for TorusContactPreprocessor<N> {
+    fn process_contact(
+        &self,
+        _: &mut Contact<N>,
+        kinematic: &mut ContactKinematic<N>,
+        is_first: bool,
+    ) -> bool {
+        if is_first {
+            kinematic.set_feature1(self.feature);
+            kinematic.transform1(&self.ball_pos);
+        } else {
+            kinematic.set_feature2(self.feature);
+            kinematic.transform2(&self.ball_pos);
+        }
+
+        true
+    }
+}
@@ -0,0 +1,415 @@
+//! Shape made of filled cells of a regular grid.
+
+use na::{self, RealField, Unit};
+use std::collections::HashMap;
+
+use crate::bounding_volume::AABB;
+use crate::math::{Isometry, Point, Translation, Vector, DIM};
+use crate::partitioning::{BVHImpl, BVH, BVT};
+use crate::query::{
+    visitors::BoundingVolumeInterferencesCollector, Contact, ContactKinematic, ContactPrediction,
+    ContactPreprocessor,
+};
+use crate::shape::{CompositeShape, Cuboid, FeatureId, Shape};
+
+/// A set of filled cells of a regular grid, e.g., an occupancy map.
+///
+/// The voxel with the integer coordinates `key` covers the box with corners
+/// `key * voxel_size` and `(key + 1) * voxel_size` (component-wise products). Only the filled
+/// voxels are stored, so large sparse grids are cheap. Each voxel is a cuboid part of this
+/// composite shape.
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Clone)]
+pub struct Voxels<N: RealField> {
+    voxel_size: Vector<N>,
+    cuboid: Cuboid<N>,
+    keys: Vec<Point<i32>>,
+    indices: HashMap<Point<i32>, usize>,
+    mins_key: Point<i32>,
+    maxs_key: Point<i32>,
+    bvt: BVT<usize, AABB<N>>,
+    nbits: usize,
+}
+
+impl<N: RealField> Voxels<N> {
+    /// Builds a new voxel shape from the integer coordinates of its filled voxels.
+    ///
+    /// Duplicate keys are ignored. At least one voxel must be filled.
+    pub fn new(voxel_size: Vector<N>, keys: &[Point<i32>]) -> Self {
+        assert!(
+            voxel_size.iter().all(|s| *s > N::zero()),
+            "The voxel size must be positive."
+        );
+
+        let mut unique_keys = Vec::with_capacity(keys.len());
+        let mut indices = HashMap::with_capacity(keys.len());
+
+        for key in keys {
+            if !indices.contains_key(key) {
+                let _ = indices.insert(*key, unique_keys.len());
+                unique_keys.push(*key);
+            }
+        }
+
+        assert!(
+            !unique_keys.is_empty(),
+            "A voxel shape must have at least one filled voxel."
+        );
+
+        let mut mins_key = unique_keys[0];
+        let mut maxs_key = unique_keys[0];
+
+        for key in &unique_keys[1..] {
+            mins_key = Point::from(mins_key.coords.zip_map(&key.coords, |a, b| a.min(b)));
+            maxs_key = Point::from(maxs_key.coords.zip_map(&key.coords, |a, b| a.max(b)));
+        }
+
+        let nbits = (usize::BITS - unique_keys.len().leading_zeros()) as usize;
+        let cuboid = Cuboid::new(voxel_size * na::convert::<_, N>(0.5));
+        let leaves = unique_keys
+            .iter()
+            .enumerate()
+            .map(|(i, key)| (i, Self::key_aabb(&voxel_size, key)))
+            .collect();
+
+        Voxels {
+            voxel_size,
+            cuboid,
+            keys: unique_keys,
+            indices,
+            mins_key,
+            maxs_key,
+            bvt: BVT::new_balanced(leaves),
+            nbits,
+        }
+    }
+
+    /// Builds a new voxel shape by filling the voxels containing the given points.
+    pub fn from_points(voxel_size: Vector<N>, points: &[Point<N>]) -> Self {
+        let keys: Vec<_> = points
+            .iter()
+            .map(|pt| Self::key_at(&voxel_size, pt))
+            .collect();
+        Self::new(voxel_size, &keys)
+    }
+
+    fn key_at(voxel_size: &Vector<N>, pt: &Point<N>) -> Point<i32> {
+        Point::from(
+            pt.coords
+                .component_div(voxel_size)
+                .map(|e| na::convert_unchecked::<N, f64>(e.floor()) as i32),
+        )
+    }
+
+    fn key_aabb(voxel_size: &Vector<N>, key: &Point<i32>) -> AABB<N> {
+        let mins = key
+            .coords
+            .map(|e| na::convert::<f64, N>(e as f64))
+            .component_mul(voxel_size);
+        AABB::new(Point::from(mins), Point::from(mins + voxel_size))
+    }
+
+    /// The size of each voxel along each coordinate axis.
+    #[inline]
+    pub fn voxel_size(&self) -> &Vector<N> {
+        &self.voxel_size
+    }
+
+    /// The integer coordinates of the filled voxels.
+    ///
+    /// The index of a voxel in this slice is its part index as a composite shape.
+    #[inline]
+    pub fn voxels(&self) -> &[Point<i32>] {
+        &self.keys[..]
+    }
+
+    /// The smallest integer coordinates of the filled voxels.
+    #[inline]
+    pub fn mins_key(&self) -> &Point<i32> {
+        &self.mins_key
+    }
+
+    /// The largest integer coordinates of the filled voxels.
+    #[inline]
+    pub fn maxs_key(&self) -> &Point<i32> {
+        &self.maxs_key
+    }
+
+    /// The index of the voxel with the integer coordinates `key`, if it is filled.
+    #[inline]
+    pub fn voxel_index(&self, key: &Point<i32>) -> Option<usize> {
+        self.indices.get(key).cloned()
+    }
+
+    /// Whether the voxel with the integer coordinates `key` is filled.
+    #[inline]
+    pub fn is_filled(&self, key: &Point<i32>) -> bool {
+        self.indices.contains_key(key)
+    }
+
+    /// The integer coordinates of the voxel containing the given local point.
+    ///
+    /// The voxel is not necessarily filled.
+    #[inline]
+    pub fn voxel_key_at_point(&self, pt: &Point<N>) -> Point<i32> {
+        Self::key_at(&self.voxel_size, pt)
+    }
+
+    /// The local AABB of the voxel with the integer coordinates `key`.
+    #[inline]
+    pub fn voxel_aabb(&self, key: &Point<i32>) -> AABB<N> {
+        Self::key_aabb(&self.voxel_size, key)
+    }
+
+    /// The local position of the cuboid representing the `i`-th filled voxel.
+    #[inline]
+    pub fn voxel_position(&self, i: usize) -> Isometry<N> {
+        let center = self.voxel_aabb(&self.keys[i]).center();
+        Isometry::from_parts(Translation::from(center.coords), na::one())
+    }
+
+    /// The cuboid each voxel is made of, centered at the origin.
+    #[inline]
+    pub fn voxel_cuboid(&self) -> &Cuboid<N> {
+        &self.cuboid
+    }
+
+    /// The optimization structure used by this voxel shape.
+    #[inline]
+    pub fn bvt(&self) -> &BVT<usize, AABB<N>> {
+        &self.bvt
+    }
+
+    /// The AABB of this voxel shape in its local-space.
+    #[inline]
+    pub fn aabb(&self) -> AABB<N> {
+        let mut aabb = self.voxel_aabb(&self.mins_key);
+        aabb.maxs = self.voxel_aabb(&self.maxs_key).maxs;
+        aabb
+    }
+
+    /// Transforms a FeatureId of this voxel shape into a pair containing the index of the voxel
+    /// containing this feature, and the corresponding FeatureId on the voxel cuboid.
+    pub fn subshape_feature_id(&self, fid: FeatureId) -> (usize, FeatureId) {
+        let mask = !(usize::MAX << self.nbits);
+
+        match fid {
+            FeatureId::Face(i) => (i & mask, FeatureId::Face(i >> self.nbits)),
+            #[cfg(feature = "dim3")]
+            FeatureId::Edge(i) => (i & mask, FeatureId::Edge(i >> self.nbits)),
+            FeatureId::Vertex(i) => (i & mask, FeatureId::Vertex(i >> self.nbits)),
+            FeatureId::Unknown => (0, FeatureId::Unknown),
+        }
+    }
+
+    /// The FeatureId of the face of the `i`-th voxel orthogonal to the given axis.
+    ///
+    /// The face ids follow the ones of the `Cuboid` shape.
+    pub(crate) fn voxel_face_id(&self, i: usize, axis: usize, positive: bool) -> FeatureId {
+        let face = if positive { axis } else { axis + DIM };
+        FeatureId::Face((face << self.nbits) | i)
+    }
+
+    /// Applies the function `f` to all the voxels intersecting the given local AABB.
+    ///
+    /// The voxels positions given to `f` are expressed relative to `m`, and the contact
+    /// preprocessors assume this voxel shape is located at `m`.
+    pub fn map_elements_in_local_aabb(
+        &self,
+        m: &Isometry<N>,
+        aabb: &AABB<N>,
+        f: &mut impl FnMut(usize, &Isometry<N>, &Cuboid<N>, &dyn ContactPreprocessor<N>),
+    ) {
+        let mut apply = |i: usize| {
+            let part_pos = self.voxel_position(i);
+            let proc = VoxelContactPreprocessor::new(self, m, part_pos, i);
+            f(i, &(m * part_pos), &self.cuboid, &proc)
+        };
+
+        let mins = self.voxel_key_at_point(&aabb.mins).coords;
+        let maxs = self.voxel_key_at_point(&aabb.maxs).coords;
+        let mins = mins.zip_map(&self.mins_key.coords, |a, b| a.max(b));
+        let maxs = maxs.zip_map(&self.maxs_key.coords, |a, b| a.min(b));
+
+        let mut ncells = 1u64;
+        for a in 0..DIM {
+            if mins[a] > maxs[a] {
+                return;
+            }
+
+            ncells = ncells.saturating_mul((maxs[a] - mins[a]) as u64 + 1);
+        }
+
+        if ncells <= self.keys.len() as u64 {
+            // Few cells: lookup each one of them.
+            let mut key = Point::from(mins);
+
+            loop {
+                if let Some(i) = self.voxel_index(&key) {
+                    apply(i)
+                }
+
+                let mut a = 0;
+                loop {
+                    if a == DIM {
+                        return;
+                    }
+
+                    if key[a] < maxs[a] {
+                        key[a] += 1;
+                        break;
+                    }
+
+                    key[a] = mins[a];
+                    a += 1;
+                }
+            }
+        } else {
+            // Many cells: let the BVT find the filled ones.
+            let mut interferences = Vec::new();
+            {
+                let mut visitor =
+                    BoundingVolumeInterferencesCollector::new(aabb, &mut interferences);
+                self.bvt.visit(&mut visitor);
+            }
+
+            for i in interferences {
+                apply(i)
+            }
+        }
+    }
+}
+
+impl<N: RealField> CompositeShape<N> for Voxels<N> {
+    #[inline]
+    fn nparts(&self) -> usize {
+        self.keys.len()
+    }
+
+    #[inline(always)]
+    fn map_part_at(
+        &self,
+        i: usize,
+        m: &Isometry<N>,
+        f: &mut dyn FnMut(&Isometry<N>, &dyn Shape<N>),
+    ) {
+        f(&(m * self.voxel_position(i)), &self.cuboid)
+    }
+
+    fn map_part_and_preprocessor_at(
+        &self,
+        i: usize,
+        m: &Isometry<N>,
+        _prediction: &ContactPrediction<N>,
+        f: &mut dyn FnMut(&Isometry<N>, &dyn Shape<N>, &dyn ContactPreprocessor<N>),
+    ) {
+        let part_pos = self.voxel_position(i);
+        let proc = VoxelContactPreprocessor::new(self, m, part_pos, i);
+
+        f(&(m * part_pos), &self.cuboid, &proc)
+    }
+
+    #[inline]
+    fn aabb_at(&self, i: usize) -> AABB<N> {
+        self.voxel_aabb(&self.keys[i])
+    }
+
+    #[inline]
+    fn bvh(&self) -> BVHImpl<N, usize, AABB<N>> {
+        BVHImpl::BVT(&self.bvt)
+    }
+}
+
+/// Removes the ghost contacts generated by the faces shared by adjacent voxels.
+///
+/// The components of the contact normal pointing toward a filled neighbor of the voxel are
+/// removed. The contact is discarded if nothing remains.
+struct VoxelContactPreprocessor<'a, N: RealField> {
+    voxels: &'a Voxels<N>,
+    pos: &'a Isometry<N>,
+    part_pos: Isometry<N>,
+    part_id: usize,
+}
+
+impl<'a, N: RealField> VoxelContactPreprocessor<'a, N> {
+    pub fn new(
+        voxels: &'a Voxels<N>,
+        pos: &'a Isometry<N>,
+        part_pos: Isometry<N>,
+        part_id: usize,
+    ) -> Self {
+        VoxelContactPreprocessor {
+            voxels,
+            pos,
+            part_pos,
+            part_id,
+        }
+    }
+}
+
+impl<'a, N: RealField> ContactPreprocessor<N> for VoxelContactPreprocessor<'a, N> {
+    fn process_contact(
+        &self,
+        c: &mut Contact<N>,
+        kinematic: &mut ContactKinematic<N>,
+        is_first: bool,
+    ) -> bool {
+        let nbits = self.voxels.nbits;
+        let feature = if is_first {
+            kinematic.feature1()
+        } else {
+            kinematic.feature2()
+        };
+
+        let actual_feature = match feature {
+            FeatureId::Vertex(i) => FeatureId::Vertex((i << nbits) | self.part_id),
+            #[cfg(feature = "dim3")]
+            FeatureId::Edge(i) => FeatureId::Edge((i << nbits) | self.part_id),
+            FeatureId::Face(i) => FeatureId::Face((i << nbits) | self.part_id),
+            FeatureId::Unknown => return false,
+        };
+
+        // The normal pointing outside of the voxel.
+        let world_normal = if is_first { *c.normal } else { -*c.normal };
+        let mut normal = self.pos.inverse_transform_vector(&world_normal);
+        let key = self.voxels.keys[self.part_id];
+        let mut corrected = false;
+
+        for a in 0..DIM {
+            let mut neighbor = key;
+
+            if normal[a] > N::zero() {
+                neighbor[a] += 1;
+            } else if normal[a] < N::zero() {
+                neighbor[a] -= 1;
+            } else {
+                continue;
+            }
+
+            if self.voxels.is_filled(&neighbor) {
+                normal[a] = N::zero();
+                corrected = true;
+            }
+        }
+
+        if corrected {
+            match Unit::try_new(self.pos * normal, N::default_epsilon()) {
+                Some(normal) => {
+                    c.normal = if is_first { normal } else { -normal };
+                    c.depth = c.normal.dot(&(c.world1 - c.world2));
+                }
+                None => return false,
+            }
+        }
+
+        if is_first {
+            kinematic.set_feature1(actual_feature);
+            kinematic.transform1(&self.part_pos);
+        } else {
+            kinematic.set_feature2(actual_feature);
+            kinematic.transform2(&self.part_pos);
+        }
+
+        true
+    }
+}
@@ -0,0 +1,158 @@
+use crate::math::{Isometry, Point};
+use crate::shape::{Compound, ConvexPolygon, Polyline, ShapeHandle};
+use crate::transformation::triangulate_polygon_idx;
+use crate::utils::DeterministicState;
+use na::{self, RealField};
+use std::collections::HashMap;
+
+/// Approximate convex decomposition of a simple polygon.
+///
+/// The polygon is given by its vertices, in clockwise or counterclockwise order. It is first
+/// triangulated, then the diagonals of the triangulation are removed, longest first, as long as
+/// the parts they separate form a convex polygon once merged (Hertel-Mehlhorn algorithm). This
+/// yields at most four times the minimal number of convex parts.
+pub fn convex_decomposition<N: RealField>(polygon: &[Point<N>]) -> Vec<ConvexPolygon<N>> {
+    let (points, triangles) = triangulate_polygon_idx(polygon, &[], &[]);
+    let mut parts: Vec<Option<Vec<usize>>> = triangles
+        .iter()
+        .map(|t| Some(vec![t.x, t.y, t.z]))
+        .collect();
+    // Maps each directed edge to the counterclockwise part it belongs to.
+    let mut edges = HashMap::with_hasher(DeterministicState::new());
+
+    for (i, t) in triangles.iter().enumerate() {
+        for k in 0..3 {
+            let _ = edges.insert((t[k], t[(k + 1) % 3]), i);
+        }
+    }
+
+    let mut diagonals: Vec<(usize, usize)> = edges
+        .keys()
+        .filter(|(a, b)| a < b && edges.contains_key(&(*b, *a)))
+        .cloned()
+        .collect();
+    diagonals.sort_by(|(a1, b1), (a2, b2)| {
+        let l1 = na::distance_squared(&points[*a1], &points[*b1]);
+        let l2 = na::distance_squared(&points[*a2], &points[*b2]);
+        l2.partial_cmp(&l1).unwrap_or(std::cmp::Ordering::Equal)
+    });
+
+    for (a, b) in diagonals {
+        let (p1, p2) = (edges[&(a, b)], edges[&(b, a)]);
+        let merged = merge_parts(
+            parts[p1].as_ref().unwrap(),
+            parts[p2].as_ref().unwrap(),
+            a,
+            b,
+        );
+
+        if is_convex_at(&points, &merged, a) && is_convex_at(&points, &merged, b) {
+            let _ = edges.remove(&(a, b));
+            let _ = edges.remove(&(b, a));
+
+            for k in 0..merged.len() {
+                let _ = edges.insert((merged[k], merged[(k + 1) % merged.len()]), p1);
+            }
+
+            parts[p1] = Some(merged);
+            parts[p2] = None;
+        }
+    }
+
+    parts
+        .iter()
+        .flatten()
+        .filter_map(|part| {
+            let pts: Vec<_> = part.iter().map(|i| points[*i]).collect();
+            ConvexPolygon::try_from_points(&pts)
+        })
+        .collect()
+}
+
+/// Approximate convex decomposition of a simple polygon, returned as a compound shape.
+///
+/// See `convex_decomposition` for details.
+pub fn convex_decomposition_compound<N: RealField>(polygon: &[Point<N>]) -> Compound<N> {
+    let shapes = convex_decomposition(polygon)
+        .into_iter()
+        .map(|part| (Isometry::identity(), ShapeHandle::new(part)))
+        .collect();
+    Compound::new(shapes)
+}
+
+/// Approximate convex decomposition of the simple polygon delimited by a closed polyline.
+///
+/// Returns `None` if the edges of the polyline do not form a single closed loop. The loop may be
+/// closed either by an edge joining its last vertex to its first vertex, or by a last vertex
+/// identical to the first one. See `convex_decomposition` for details.
+pub fn convex_decomposition_polyline<N: RealField>(
+    polyline: &Polyline<N>,
+) -> Option<Vec<ConvexPolygon<N>>> {
+    let points = polyline.points();
+    let edges = polyline.edges();
+    let same = |i: usize, j: usize| i == j || points[i] == points[j];
+    let mut used = vec![false; edges.len()];
+    let start = edges.first()?.indices.x;
+    let mut curr = start;
+    let mut polygon = Vec::with_capacity(edges.len());
+
+    loop {
+        let (e, next) = edges.iter().enumerate().find_map(|(e, edge)| {
+            if used[e] {
+                None
+            } else if same(edge.indices.x, curr) {
+                Some((e, edge.indices.y))
+            } else if same(edge.indices.y, curr) {
+                Some((e, edge.indices.x))
+            } else {
+                None
+            }
+        })?;
+
+        used[e] = true;
+        polygon.push(points[curr]);
+        curr = next;
+
+        if same(curr, start) {
+            break;
+        }
+    }
+
+    if used.iter().all(|u| *u) {
+        Some(convex_decomposition(&polygon))
+    } else {
+        None
+    }
+}
+
+// Merges two counterclockwise polygons sharing the edge `(a, b)`, where `a` is followed by `b` in
+// the first one.
+fn merge_parts(part1: &[usize], part2: &[usize], a: usize, b: usize) -> Vec<usize> {
+    let n1 = part1.len();
+    let n2 = part2.len();
+    let ib = part1.iter().position(|v| *v == b).unwrap();
+    let ia = part2.iter().position(|v| *v == a).unwrap();
+    let mut res = Vec::with_capacity(n1 + n2 - 2);
+
+    // From `b` to `a` along the first part, then from `a` to `b` along the second one, excluding
+    // the endpoints.
+    for k in 0..n1 {
+        res.push(part1[(ib + k) % n1]);
+    }
+
+    for k in 1..n2 - 1 {
+        res.push(part2[(ia + k) % n2]);
+    }
+
+    res
+}
+
+fn is_convex_at<N: RealField>(points: &[Point<N>], polygon: &[usize], vertex: usize) -> bool {
+    let n = polygon.len();
+    let i = polygon.iter().position(|v| *v == vertex).unwrap();
+    let prev = points[polygon[(i + n - 1) % n]];
+    let curr = points[polygon[i]];
+    let next = points[polygon[(i + 1) % n]];
+
+    (curr - prev).perp(&(next - curr)) >= N::zero()
+}
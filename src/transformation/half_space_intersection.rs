@@ -0,0 +1,217 @@
+use std::fmt;
+
+use na::{self, RealField, Unit};
+
+use crate::math::{Matrix, Point, Vector, DIM};
+
+/// The set of points `x` such that `normal.dot(x) <= offset`.
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(PartialEq, Debug, Clone, Copy)]
+pub struct HalfSpace<N: RealField> {
+    /// The outward normal of the boundary of this half-space.
+    pub normal: Unit<Vector<N>>,
+    /// The signed distance from the origin to the boundary of this half-space, along `normal`.
+    pub offset: N,
+}
+
+impl<N: RealField> HalfSpace<N> {
+    /// Creates the half-space of the points `x` such that `normal.dot(x) <= offset`.
+    #[inline]
+    pub fn new(normal: Unit<Vector<N>>, offset: N) -> Self {
+        HalfSpace { normal, offset }
+    }
+
+    /// Creates the half-space with the outward normal `normal` and bounded by a plane passing
+    /// through `point`.
+    #[inline]
+    pub fn from_point(normal: Unit<Vector<N>>, point: &Point<N>) -> Self {
+        HalfSpace::new(normal, normal.dot(&point.coords))
+    }
+
+    /// The signed distance from `point` to the boundary of this half-space.
+    ///
+    /// This is negative for points inside of this half-space.
+    #[inline]
+    pub fn signed_distance_to_point(&self, point: &Point<N>) -> N {
+        self.normal.dot(&point.coords) - self.offset
+    }
+
+    /// Whether `point` lies inside of this half-space.
+    #[inline]
+    pub fn contains_point(&self, point: &Point<N>) -> bool {
+        self.signed_distance_to_point(point) <= N::zero()
+    }
+}
+
+/// The reason why the intersection of a set of half-spaces is not a convex polytope.
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub enum HalfSpaceIntersectionError {
+    /// The intersection is empty.
+    Infeasible,
+    /// The intersection is not bounded.
+    Unbounded,
+    /// The intersection is flat, e.g., a single point.
+    Degenerate,
+}
+
+impl fmt::Display for HalfSpaceIntersectionError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            HalfSpaceIntersectionError::Infeasible => f.pad("the half-spaces do not intersect"),
+            HalfSpaceIntersectionError::Unbounded => {
+                f.pad("the intersection of the half-spaces is unbounded")
+            }
+            HalfSpaceIntersectionError::Degenerate => {
+                f.pad("the intersection of the half-spaces is flat")
+            }
+        }
+    }
+}
+
+impl std::error::Error for HalfSpaceIntersectionError {}
+
+/// Computes the vertices of the convex polytope obtained by intersecting the given half-spaces.
+///
+/// This enumerates the intersections of the boundaries of every `DIM` half-spaces so this is
+/// meant for small sets of half-spaces, e.g., the planes of a view frustum. The returned
+/// vertices are not ordered.
+pub fn half_space_intersection<N: RealField>(
+    half_spaces: &[HalfSpace<N>],
+) -> Result<Vec<Point<N>>, HalfSpaceIntersectionError> {
+    let eps = N::default_epsilon().sqrt();
+    let scale = half_spaces
+        .iter()
+        .fold(N::one(), |scale, h| scale.max(N::one() + h.offset.abs()));
+    let tolerance = eps * scale;
+
+    if !is_bounded(half_spaces, eps) {
+        // Check if the intersection is empty nevertheless by clipping it with a large box.
+        let mut clipped = half_spaces.to_vec();
+        let extent = scale * na::convert(1.0e3);
+
+        for i in 0..DIM {
+            let mut normal = Vector::zeros();
+            normal[i] = N::one();
+            clipped.push(HalfSpace::new(Unit::new_unchecked(normal), extent));
+            clipped.push(HalfSpace::new(Unit::new_unchecked(-normal), extent));
+        }
+
+        return if enumerate_vertices(&clipped, tolerance).is_empty() {
+            Err(HalfSpaceIntersectionError::Infeasible)
+        } else {
+            Err(HalfSpaceIntersectionError::Unbounded)
+        };
+    }
+
+    let vertices = enumerate_vertices(half_spaces, tolerance);
+
+    if vertices.is_empty() {
+        return Err(HalfSpaceIntersectionError::Infeasible);
+    }
+
+    // The vertices must span the whole space.
+    let center = vertices
+        .iter()
+        .fold(Vector::zeros(), |acc: Vector<N>, v| acc + v.coords)
+        / na::convert::<f64, N>(vertices.len() as f64);
+    let mut covariance = Matrix::zeros();
+
+    for v in &vertices {
+        let dv = v.coords - center;
+        covariance += dv * dv.transpose();
+    }
+
+    if covariance.symmetric_eigenvalues().min() <= tolerance * tolerance {
+        return Err(HalfSpaceIntersectionError::Degenerate);
+    }
+
+    Ok(vertices)
+}
+
+// Whether no direction leaves all the half-spaces, i.e., whether their intersection is bounded
+// assuming it is not empty.
+//
+// If such a direction exists, there is one orthogonal to the normals of `DIM - 1` half-spaces,
+// or to all the normals if they are all parallel.
+fn is_bounded<N: RealField>(half_spaces: &[HalfSpace<N>], eps: N) -> bool {
+    let escapes = |dir: &Vector<N>| half_spaces.iter().all(|h| h.normal.dot(dir) <= eps);
+    let mut candidates = Vec::new();
+
+    #[cfg(feature = "dim2")]
+    for h in half_spaces {
+        candidates.push(Vector::new(-h.normal.y, h.normal.x));
+    }
+
+    #[cfg(feature = "dim3")]
+    for (i, h1) in half_spaces.iter().enumerate() {
+        for h2 in &half_spaces[i + 1..] {
+            if let Some(dir) = Unit::try_new(h1.normal.cross(&h2.normal), eps) {
+                candidates.push(dir.into_inner());
+            }
+        }
+    }
+
+    if candidates.is_empty() {
+        // All the normals are parallel.
+        return false;
+    }
+
+    !candidates.iter().any(|dir| escapes(dir) || escapes(&-dir))
+}
+
+// Intersects the boundaries of every `DIM` half-spaces and keeps the points inside of all the
+// half-spaces.
+fn enumerate_vertices<N: RealField>(half_spaces: &[HalfSpace<N>], tolerance: N) -> Vec<Point<N>> {
+    let mut vertices: Vec<Point<N>> = Vec::new();
+    let mut ids = [0; DIM];
+
+    if half_spaces.len() < DIM {
+        return vertices;
+    }
+
+    for (i, id) in ids.iter_mut().enumerate() {
+        *id = i;
+    }
+
+    loop {
+        let mat = Matrix::from_fn(|r, c| half_spaces[ids[r]].normal[c]);
+
+        if mat.determinant().abs() > N::default_epsilon() {
+            let rhs = Vector::from_fn(|r, _| half_spaces[ids[r]].offset);
+
+            if let Some(inv) = mat.try_inverse() {
+                let pt = Point::from(inv * rhs);
+
+                if half_spaces
+                    .iter()
+                    .all(|h| h.signed_distance_to_point(&pt) <= tolerance)
+                    && vertices
+                        .iter()
+                        .all(|v| na::distance_squared(v, &pt) > tolerance * tolerance)
+                {
+                    vertices.push(pt)
+                }
+            }
+        }
+
+        // Next combination of `DIM` indices.
+        let mut k = DIM;
+        loop {
+            if k == 0 {
+                return vertices;
+            }
+
+            k -= 1;
+
+            if ids[k] < half_spaces.len() - DIM + k {
+                ids[k] += 1;
+
+                for j in k + 1..DIM {
+                    ids[j] = ids[j - 1] + 1;
+                }
+
+                break;
+            }
+        }
+    }
+}
@@ -0,0 +1,60 @@
+use std::error::Error;
+use std::fmt;
+use std::io;
+
+/// Error that can occur while loading or saving a mesh file.
+#[derive(Debug)]
+pub enum MeshIoError {
+    /// Reading from or writing to the underlying stream failed.
+    Io(io::Error),
+    /// The content of the file does not follow the expected format.
+    Malformed {
+        /// The line where the error was detected, for text-based content.
+        line: Option<usize>,
+        /// A description of the error.
+        message: String,
+    },
+    /// The file format, or one of the features it uses, is not supported.
+    Unsupported(String),
+}
+
+impl MeshIoError {
+    pub(crate) fn malformed(line: Option<usize>, message: impl Into<String>) -> Self {
+        MeshIoError::Malformed {
+            line,
+            message: message.into(),
+        }
+    }
+}
+
+impl From<io::Error> for MeshIoError {
+    fn from(err: io::Error) -> Self {
+        MeshIoError::Io(err)
+    }
+}
+
+impl fmt::Display for MeshIoError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            MeshIoError::Io(err) => write!(f, "I/O error: {}", err),
+            MeshIoError::Malformed {
+                line: Some(line),
+                message,
+            } => write!(f, "malformed mesh file at line {}: {}", line, message),
+            MeshIoError::Malformed {
+                line: None,
+                message,
+            } => write!(f, "malformed mesh file: {}", message),
+            MeshIoError::Unsupported(what) => write!(f, "unsupported mesh file: {}", what),
+        }
+    }
+}
+
+impl Error for MeshIoError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            MeshIoError::Io(err) => Some(err),
+            _ => None,
+        }
+    }
+}
@@ -0,0 +1,93 @@
+//! Loading and saving of `procedural::TriMesh` from and to common mesh file formats.
+//!
+//! The supported formats are Wavefront OBJ, STL (ASCII and binary) and PLY (ASCII and binary).
+//! Meshes loaded with a split index buffer can be converted to a `shape::TriMesh` after a call
+//! to `procedural::TriMesh::unify_index_buffer`.
+
+pub use self::error::MeshIoError;
+pub use self::obj::{read_obj, write_obj};
+pub use self::ply::{read_ply, write_ply, PlyFormat};
+pub use self::stl::{read_stl, write_stl_ascii, write_stl_binary};
+
+use crate::procedural::TriMesh;
+use na::RealField;
+use std::fs::File;
+use std::io::{BufReader, BufWriter};
+use std::path::Path;
+
+mod error;
+mod obj;
+mod ply;
+mod stl;
+
+/// Loads a mesh from a file, choosing the format from the file extension.
+///
+/// The recognized extensions are `obj`, `stl` and `ply`, regardless of their case.
+pub fn load<N: RealField, P: AsRef<Path>>(path: P) -> Result<TriMesh<N>, MeshIoError> {
+    let path = path.as_ref();
+    let format = MeshFormat::from_path(path)?;
+    let reader = BufReader::new(File::open(path)?);
+
+    match format {
+        MeshFormat::Obj => read_obj(reader),
+        MeshFormat::Stl => read_stl(reader),
+        MeshFormat::Ply => read_ply(reader),
+    }
+}
+
+/// Saves a mesh to a file, choosing the format from the file extension.
+///
+/// The recognized extensions are `obj`, `stl` and `ply`, regardless of their case. STL and PLY
+/// files are written in their binary little-endian variant.
+pub fn save<N: RealField, P: AsRef<Path>>(mesh: &TriMesh<N>, path: P) -> Result<(), MeshIoError> {
+    let path = path.as_ref();
+    let format = MeshFormat::from_path(path)?;
+    let writer = BufWriter::new(File::create(path)?);
+
+    match format {
+        MeshFormat::Obj => write_obj(mesh, writer)?,
+        MeshFormat::Stl => write_stl_binary(mesh, writer)?,
+        MeshFormat::Ply => write_ply(mesh, writer, PlyFormat::BinaryLittleEndian)?,
+    }
+
+    Ok(())
+}
+
+enum MeshFormat {
+    Obj,
+    Stl,
+    Ply,
+}
+
+impl MeshFormat {
+    fn from_path(path: &Path) -> Result<Self, MeshIoError> {
+        let ext = path
+            .extension()
+            .and_then(|ext| ext.to_str())
+            .map(|ext| ext.to_ascii_lowercase());
+
+        match ext.as_ref().map(|ext| &ext[..]) {
+            Some("obj") => Ok(MeshFormat::Obj),
+            Some("stl") => Ok(MeshFormat::Stl),
+            Some("ply") => Ok(MeshFormat::Ply),
+            _ => Err(MeshIoError::Unsupported(format!(
+                "unknown file extension for {}",
+                path.display()
+            ))),
+        }
+    }
+}
+
+/// Parses a floating-point number from a text-based mesh file.
+fn parse_real<N: RealField>(word: Option<&str>, line: usize) -> Result<N, MeshIoError> {
+    let word = word.ok_or_else(|| MeshIoError::malformed(Some(line), "missing number"))?;
+    let val: f64 = word
+        .parse()
+        .map_err(|_| MeshIoError::malformed(Some(line), format!("invalid number `{}`", word)))?;
+    Ok(na::convert(val))
+}
+
+/// Converts a real number to `f64` for binary serialization.
+fn to_f64<N: RealField>(val: N) -> f64 {
+    na::try_convert(val).unwrap_or(f64::NAN)
+}
@@ -0,0 +1,219 @@
+use super::{parse_real, MeshIoError};
+use crate::procedural::{IndexBuffer, TriMesh};
+use na::{Point2, Point3, RealField, Vector3};
+use std::io::{self, BufRead, BufReader, BufWriter, Read, Write};
+
+/// A vertex of a face, given by the indices of its position, texture coordinates, and normal.
+type FaceVertex = (u32, Option<u32>, Option<u32>);
+
+/// Reads a mesh from a Wavefront OBJ file.
+///
+/// Only the vertex positions (`v`), texture coordinates (`vt`), normals (`vn`) and faces (`f`)
+/// are read. Polygonal faces are split into triangle fans, and negative (relative) indices are
+/// supported. The resulting mesh has a split index buffer if its faces reference texture
+/// coordinates or normals, and a unified index buffer otherwise.
+pub fn read_obj<N: RealField, R: Read>(reader: R) -> Result<TriMesh<N>, MeshIoError> {
+    let mut coords = Vec::new();
+    let mut normals = Vec::new();
+    let mut uvs = Vec::new();
+    let mut triangles: Vec<[FaceVertex; 3]> = Vec::new();
+    // Whether the face vertices reference texture coordinates and normals.
+    let mut layout = None;
+
+    for (i, line) in BufReader::new(reader).lines().enumerate() {
+        let line = line?;
+        let line_id = i + 1;
+        let content = line.split('#').next().unwrap_or("");
+        let mut words = content.split_whitespace();
+
+        match words.next() {
+            Some("v") => coords.push(Point3::new(
+                parse_real(words.next(), line_id)?,
+                parse_real(words.next(), line_id)?,
+                parse_real(words.next(), line_id)?,
+            )),
+            Some("vn") => normals.push(Vector3::new(
+                parse_real(words.next(), line_id)?,
+                parse_real(words.next(), line_id)?,
+                parse_real(words.next(), line_id)?,
+            )),
+            Some("vt") => {
+                let u = parse_real(words.next(), line_id)?;
+                let v = match words.next() {
+                    Some(word) => parse_real(Some(word), line_id)?,
+                    None => N::zero(),
+                };
+                uvs.push(Point2::new(u, v));
+            }
+            Some("f") => {
+                let mut face = Vec::new();
+
+                for word in words {
+                    let vertex =
+                        parse_face_vertex(word, coords.len(), uvs.len(), normals.len(), line_id)?;
+                    let vertex_layout = (vertex.1.is_some(), vertex.2.is_some());
+
+                    if *layout.get_or_insert(vertex_layout) != vertex_layout {
+                        return Err(MeshIoError::malformed(
+                            Some(line_id),
+                            "faces must all reference the same kinds of vertex attributes",
+                        ));
+                    }
+
+                    face.push(vertex);
+                }
+
+                if face.len() < 3 {
+                    return Err(MeshIoError::malformed(
+                        Some(line_id),
+                        "a face must have at least three vertices",
+                    ));
+                }
+
+                for k in 1..face.len() - 1 {
+                    triangles.push([face[0], face[k], face[k + 1]]);
+                }
+            }
+            // Groups, materials, smoothing groups, lines, etc. are ignored.
+            _ => {}
+        }
+    }
+
+    let (has_uvs, has_normals) = layout.unwrap_or((false, false));
+
+    let indices = if has_uvs || has_normals {
+        let split = |v: &FaceVertex| Point3::new(v.0, v.2.unwrap_or(0), v.1.unwrap_or(0));
+        IndexBuffer::Split(
+            triangles
+                .iter()
+                .map(|t| Point3::new(split(&t[0]), split(&t[1]), split(&t[2])))
+                .collect(),
+        )
+    } else {
+        IndexBuffer::Unified(
+            triangles
+                .iter()
+                .map(|t| Point3::new(t[0].0, t[1].0, t[2].0))
+                .collect(),
+        )
+    };
+
+    let normals = if has_normals { Some(normals) } else { None };
+    let uvs = if has_uvs { Some(uvs) } else { None };
+
+    Ok(TriMesh::new(coords, normals, uvs, Some(indices)))
+}
+
+fn parse_face_vertex(
+    word: &str,
+    num_coords: usize,
+    num_uvs: usize,
+    num_normals: usize,
+    line: usize,
+) -> Result<FaceVertex, MeshIoError> {
+    let mut parts = word.split('/');
+    let coord = parse_index(parts.next(), num_coords, line)?;
+    let uv = match parts.next() {
+        Some("") | None => None,
+        Some(part) => Some(parse_index(Some(part), num_uvs, line)?),
+    };
+    let normal = match parts.next() {
+        Some("") | None => None,
+        Some(part) => Some(parse_index(Some(part), num_normals, line)?),
+    };
+
+    if parts.next().is_some() {
+        return Err(MeshIoError::malformed(
+            Some(line),
+            format!("invalid face vertex `{}`", word),
+        ));
+    }
+
+    Ok((coord, uv, normal))
+}
+
+// Converts a one-based (or negative relative) index into a zero-based index.
+fn parse_index(word: Option<&str>, len: usize, line: usize) -> Result<u32, MeshIoError> {
+    let word = word.unwrap_or("");
+    let index: i64 = word
+        .parse()
+        .map_err(|_| MeshIoError::malformed(Some(line), format!("invalid index `{}`", word)))?;
+    let resolved = if index < 0 {
+        len as i64 + index
+    } else {
+        index - 1
+    };
+
+    if resolved < 0 || resolved >= len as i64 {
+        return Err(MeshIoError::malformed(
+            Some(line),
+            format!("index {} out of bounds", index),
+        ));
+    }
+
+    Ok(resolved as u32)
+}
+
+/// Writes a mesh to a Wavefront OBJ file.
+///
+/// Its normals and texture coordinates are written too if the mesh has some.
+pub fn write_obj<N: RealField, W: Write>(mesh: &TriMesh<N>, writer: W) -> io::Result<()> {
+    let mut out = BufWriter::new(writer);
+
+    for pt in &mesh.coords {
+        writeln!(out, "v {} {} {}", pt.x, pt.y, pt.z)?;
+    }
+
+    if let Some(normals) = &mesh.normals {
+        for n in normals {
+            writeln!(out, "vn {} {} {}", n.x, n.y, n.z)?;
+        }
+    }
+
+    if let Some(uvs) = &mesh.uvs {
+        for uv in uvs {
+            writeln!(out, "vt {} {}", uv.x, uv.y)?;
+        }
+    }
+
+    let has_normals = mesh.has_normals();
+    let has_uvs = mesh.has_uvs();
+
+    match &mesh.indices {
+        IndexBuffer::Unified(idx) => {
+            for t in idx {
+                write!(out, "f")?;
+                for i in t.iter() {
+                    let normal = if has_normals { Some(*i) } else { None };
+                    let uv = if has_uvs { Some(*i) } else { None };
+                    write_face_vertex(&mut out, (*i, uv, normal))?;
+                }
+                writeln!(out)?;
+            }
+        }
+        IndexBuffer::Split(idx) => {
+            for t in idx {
+                write!(out, "f")?;
+                for i in t.iter() {
+                    let normal = if has_normals { Some(i.y) } else { None };
+                    let uv = if has_uvs { Some(i.z) } else { None };
+                    write_face_vertex(&mut out, (i.x, uv, normal))?;
+                }
+                writeln!(out)?;
+            }
+        }
+    }
+
+    out.flush()
+}
+
+fn write_face_vertex<W: Write>(out: &mut W, vertex: FaceVertex) -> io::Result<()> {
+    match vertex {
+        (coord, None, None) => write!(out, " {}", coord + 1),
+        (coord, Some(uv), None) => write!(out, " {}/{}", coord + 1, uv + 1),
+        (coord, None, Some(normal)) => write!(out, " {}//{}", coord + 1, normal + 1),
+        (coord, Some(uv), Some(normal)) => {
+            write!(out, " {}/{}/{}", coord + 1, uv + 1, normal + 1)
+        }
+    }
+}
@@ -0,0 +1,572 @@
+use super::{to_f64, MeshIoError};
+use crate::procedural::{IndexBuffer, TriMesh};
+use na::{self, Point2, Point3, RealField, Vector3};
+use std::io::{self, BufWriter, Read, Write};
+use std::str;
+
+/// The encoding of the body of a PLY file.
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub enum PlyFormat {
+    /// The values are written as text.
+    Ascii,
+    /// The values are written in binary, least significant byte first.
+    BinaryLittleEndian,
+    /// The values are written in binary, most significant byte first.
+    BinaryBigEndian,
+}
+
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+enum ScalarType {
+    I8,
+    U8,
+    I16,
+    U16,
+    I32,
+    U32,
+    F32,
+    F64,
+}
+
+impl ScalarType {
+    fn parse(name: &str, line: usize) -> Result<Self, MeshIoError> {
+        match name {
+            "char" | "int8" => Ok(ScalarType::I8),
+            "uchar" | "uint8" => Ok(ScalarType::U8),
+            "short" | "int16" => Ok(ScalarType::I16),
+            "ushort" | "uint16" => Ok(ScalarType::U16),
+            "int" | "int32" => Ok(ScalarType::I32),
+            "uint" | "uint32" => Ok(ScalarType::U32),
+            "float" | "float32" => Ok(ScalarType::F32),
+            "double" | "float64" => Ok(ScalarType::F64),
+            _ => Err(MeshIoError::malformed(
+                Some(line),
+                format!("unknown property type `{}`", name),
+            )),
+        }
+    }
+
+    fn size(self) -> usize {
+        match self {
+            ScalarType::I8 | ScalarType::U8 => 1,
+            ScalarType::I16 | ScalarType::U16 => 2,
+            ScalarType::I32 | ScalarType::U32 | ScalarType::F32 => 4,
+            ScalarType::F64 => 8,
+        }
+    }
+}
+
+enum PropertyType {
+    Scalar(ScalarType),
+    List { count: ScalarType, item: ScalarType },
+}
+
+struct Property {
+    name: String,
+    ty: PropertyType,
+}
+
+struct Element {
+    name: String,
+    count: usize,
+    properties: Vec<Property>,
+}
+
+struct Header {
+    format: PlyFormat,
+    elements: Vec<Element>,
+    // The offset of the body in the file, and the number of lines of the header.
+    body_start: usize,
+    num_lines: usize,
+}
+
+fn parse_header(data: &[u8]) -> Result<Header, MeshIoError> {
+    let mut format = None;
+    let mut elements: Vec<Element> = Vec::new();
+    let mut pos = 0;
+    let mut line_id = 0;
+
+    loop {
+        let end = data[pos..]
+            .iter()
+            .position(|b| *b == b'\n')
+            .map(|i| pos + i)
+            .ok_or_else(|| MeshIoError::malformed(None, "missing `end_header`"))?;
+        let line = str::from_utf8(&data[pos..end])
+            .map_err(|_| MeshIoError::malformed(Some(line_id + 1), "invalid UTF-8 in header"))?;
+        pos = end + 1;
+        line_id += 1;
+
+        let mut words = line.split_whitespace();
+        let keyword = words.next();
+
+        if line_id == 1 {
+            if keyword != Some("ply") {
+                return Err(MeshIoError::malformed(Some(1), "not a PLY file"));
+            }
+            continue;
+        }
+
+        match keyword {
+            Some("format") => {
+                format = Some(match words.next() {
+                    Some("ascii") => PlyFormat::Ascii,
+                    Some("binary_little_endian") => PlyFormat::BinaryLittleEndian,
+                    Some("binary_big_endian") => PlyFormat::BinaryBigEndian,
+                    _ => {
+                        return Err(MeshIoError::Unsupported(format!(
+                            "PLY format on line {}",
+                            line_id
+                        )))
+                    }
+                })
+            }
+            Some("element") => {
+                let name = words.next().unwrap_or("").to_string();
+                let count = words
+                    .next()
+                    .and_then(|w| w.parse().ok())
+                    .ok_or_else(|| MeshIoError::malformed(Some(line_id), "invalid element"))?;
+                elements.push(Element {
+                    name,
+                    count,
+                    properties: Vec::new(),
+                });
+            }
+            Some("property") => {
+                let element = elements.last_mut().ok_or_else(|| {
+                    MeshIoError::malformed(Some(line_id), "property outside of an element")
+                })?;
+                let ty = match words.next() {
+                    Some("list") => PropertyType::List {
+                        count: ScalarType::parse(words.next().unwrap_or(""), line_id)?,
+                        item: ScalarType::parse(words.next().unwrap_or(""), line_id)?,
+                    },
+                    Some(ty) => PropertyType::Scalar(ScalarType::parse(ty, line_id)?),
+                    None => return Err(MeshIoError::malformed(Some(line_id), "invalid property")),
+                };
+                let name = words
+                    .next()
+                    .ok_or_else(|| MeshIoError::malformed(Some(line_id), "unnamed property"))?;
+                element.properties.push(Property {
+                    name: name.to_string(),
+                    ty,
+                });
+            }
+            Some("end_header") => break,
+            Some("comment") | Some("obj_info") | None => {}
+            Some(word) => {
+                return Err(MeshIoError::malformed(
+                    Some(line_id),
+                    format!("unexpected keyword `{}`", word),
+                ))
+            }
+        }
+    }
+
+    let format =
+        format.ok_or_else(|| MeshIoError::malformed(None, "missing `format` in header"))?;
+
+    Ok(Header {
+        format,
+        elements,
+        body_start: pos,
+        num_lines: line_id,
+    })
+}
+
+/// Sequential reader of the values of the body of a PLY file.
+enum Body<'a> {
+    Ascii {
+        text: &'a str,
+        pos: usize,
+        line_id: usize,
+    },
+    Binary {
+        data: &'a [u8],
+        pos: usize,
+        big_endian: bool,
+    },
+}
+
+impl<'a> Body<'a> {
+    fn read(&mut self, ty: ScalarType) -> Result<f64, MeshIoError> {
+        match self {
+            Body::Ascii { text, pos, line_id } => {
+                let bytes = text.as_bytes();
+
+                while *pos < bytes.len() && bytes[*pos].is_ascii_whitespace() {
+                    if bytes[*pos] == b'\n' {
+                        *line_id += 1;
+                    }
+                    *pos += 1;
+                }
+
+                let start = *pos;
+
+                while *pos < bytes.len() && !bytes[*pos].is_ascii_whitespace() {
+                    *pos += 1;
+                }
+
+                if start == *pos {
+                    return Err(MeshIoError::malformed(None, "unexpected end of file"));
+                }
+
+                let word = &text[start..*pos];
+                word.parse().map_err(|_| {
+                    MeshIoError::malformed(Some(*line_id), format!("invalid number `{}`", word))
+                })
+            }
+            Body::Binary {
+                data,
+                pos,
+                big_endian,
+            } => {
+                let size = ty.size();
+                let bytes = data
+                    .get(*pos..*pos + size)
+                    .ok_or_else(|| MeshIoError::malformed(None, "unexpected end of file"))?;
+                let mut buf = [0u8; 8];
+                buf[..size].copy_from_slice(bytes);
+                if *big_endian {
+                    buf[..size].reverse();
+                }
+                *pos += size;
+
+                Ok(match ty {
+                    ScalarType::I8 => buf[0] as i8 as f64,
+                    ScalarType::U8 => buf[0] as f64,
+                    ScalarType::I16 => i16::from_le_bytes([buf[0], buf[1]]) as f64,
+                    ScalarType::U16 => u16::from_le_bytes([buf[0], buf[1]]) as f64,
+                    ScalarType::I32 => i32::from_le_bytes([buf[0], buf[1], buf[2], buf[3]]) as f64,
+                    ScalarType::U32 => u32::from_le_bytes([buf[0], buf[1], buf[2], buf[3]]) as f64,
+                    ScalarType::F32 => f32::from_le_bytes([buf[0], buf[1], buf[2], buf[3]]) as f64,
+                    ScalarType::F64 => f64::from_le_bytes(buf),
+                })
+            }
+        }
+    }
+
+    // The line of the last value read, for text-based content.
+    fn line(&self) -> Option<usize> {
+        match self {
+            Body::Ascii { line_id, .. } => Some(*line_id),
+            Body::Binary { .. } => None,
+        }
+    }
+
+    fn remaining_bytes(&self) -> usize {
+        match self {
+            Body::Ascii { text, pos, .. } => text.len() - *pos,
+            Body::Binary { data, pos, .. } => data.len() - *pos,
+        }
+    }
+
+    // Reads the length of a list, which cannot exceed the number of bytes left in the body since
+    // each item takes at least one byte.
+    fn read_len(&mut self, ty: ScalarType) -> Result<usize, MeshIoError> {
+        let len = self.read(ty)?;
+
+        if len.is_finite()
+            && len >= 0.0
+            && len.fract() == 0.0
+            && len <= self.remaining_bytes() as f64
+        {
+            Ok(len as usize)
+        } else {
+            Err(MeshIoError::malformed(
+                self.line(),
+                format!("invalid list length `{}`", len),
+            ))
+        }
+    }
+
+    fn read_index(&mut self, ty: ScalarType) -> Result<u32, MeshIoError> {
+        let index = self.read(ty)?;
+
+        if index.is_finite() && index >= 0.0 && index.fract() == 0.0 && index <= u32::MAX as f64 {
+            Ok(index as u32)
+        } else {
+            Err(MeshIoError::malformed(
+                self.line(),
+                format!("invalid vertex index `{}`", index),
+            ))
+        }
+    }
+}
+
+/// Reads a mesh from an ASCII or binary PLY file.
+///
+/// The vertex positions are read from the `x`, `y`, `z` properties of the `vertex` element, the
+/// normals from `nx`, `ny`, `nz`, and the texture coordinates from `u`, `v` (or `s`, `t`). The
+/// faces are read from the `vertex_indices` (or `vertex_index`) list property of the `face`
+/// element and polygonal faces are split into triangle fans. Other elements and properties are
+/// skipped. The resulting mesh has a unified index buffer.
+pub fn read_ply<N: RealField, R: Read>(mut reader: R) -> Result<TriMesh<N>, MeshIoError> {
+    let mut data = Vec::new();
+    let _ = reader.read_to_end(&mut data)?;
+
+    let header = parse_header(&data)?;
+    let body_data = &data[header.body_start..];
+    let mut body = match header.format {
+        PlyFormat::Ascii => {
+            let text = str::from_utf8(body_data)
+                .map_err(|_| MeshIoError::malformed(None, "ASCII PLY body is not valid UTF-8"))?;
+            Body::Ascii {
+                text,
+                pos: 0,
+                line_id: header.num_lines + 1,
+            }
+        }
+        format => Body::Binary {
+            data: body_data,
+            pos: 0,
+            big_endian: format == PlyFormat::BinaryBigEndian,
+        },
+    };
+
+    let mut coords = Vec::new();
+    let mut normals = Vec::new();
+    let mut uvs = Vec::new();
+    let mut indices = Vec::new();
+    let mut has_normals = false;
+    let mut has_uvs = false;
+
+    for element in &header.elements {
+        let prop_id = |names: &[&str]| {
+            element
+                .properties
+                .iter()
+                .position(|p| names.contains(&&p.name[..]))
+        };
+
+        match &element.name[..] {
+            "vertex" => {
+                let pos_ids = [prop_id(&["x"]), prop_id(&["y"]), prop_id(&["z"])];
+                let normal_ids = [prop_id(&["nx"]), prop_id(&["ny"]), prop_id(&["nz"])];
+                let uv_ids = [
+                    prop_id(&["u", "s", "texture_u"]),
+                    prop_id(&["v", "t", "texture_v"]),
+                ];
+
+                if pos_ids.iter().any(|id| id.is_none()) {
+                    return Err(MeshIoError::malformed(
+                        None,
+                        "the vertex element must have `x`, `y` and `z` properties",
+                    ));
+                }
+
+                has_normals = normal_ids.iter().all(|id| id.is_some());
+                has_uvs = uv_ids.iter().all(|id| id.is_some());
+                let mut values = vec![0.0; element.properties.len()];
+
+                for _ in 0..element.count {
+                    for (i, prop) in element.properties.iter().enumerate() {
+                        match prop.ty {
+                            PropertyType::Scalar(ty) => values[i] = body.read(ty)?,
+                            PropertyType::List { count, item } => {
+                                skip_list(&mut body, count, item)?
+                            }
+                        }
+                    }
+
+                    let get = |id: Option<usize>| -> N { na::convert(values[id.unwrap()]) };
+                    coords.push(Point3::new(
+                        get(pos_ids[0]),
+                        get(pos_ids[1]),
+                        get(pos_ids[2]),
+                    ));
+
+                    if has_normals {
+                        normals.push(Vector3::new(
+                            get(normal_ids[0]),
+                            get(normal_ids[1]),
+                            get(normal_ids[2]),
+                        ));
+                    }
+
+                    if has_uvs {
+                        uvs.push(Point2::new(get(uv_ids[0]), get(uv_ids[1])));
+                    }
+                }
+            }
+            "face" => {
+                let index_id = prop_id(&["vertex_indices", "vertex_index"]).ok_or_else(|| {
+                    MeshIoError::malformed(
+                        None,
+                        "the face element must have a `vertex_indices` property",
+                    )
+                })?;
+
+                for _ in 0..element.count {
+                    for (i, prop) in element.properties.iter().enumerate() {
+                        match prop.ty {
+                            PropertyType::List { count, item } if i == index_id => {
+                                let len = body.read_len(count)?;
+                                let mut face = Vec::with_capacity(len);
+
+                                for _ in 0..len {
+                                    face.push(body.read_index(item)?);
+                                }
+
+                                if len < 3 {
+                                    return Err(MeshIoError::malformed(
+                                        body.line(),
+                                        "a face must have at least three vertices",
+                                    ));
+                                }
+
+                                for k in 1..len - 1 {
+                                    indices.push(Point3::new(face[0], face[k], face[k + 1]));
+                                }
+                            }
+                            PropertyType::List { count, item } => {
+                                skip_list(&mut body, count, item)?
+                            }
+                            PropertyType::Scalar(ty) => {
+                                let _ = body.read(ty)?;
+                            }
+                        }
+                    }
+                }
+            }
+            _ => {
+                for _ in 0..element.count {
+                    for prop in &element.properties {
+                        match prop.ty {
+                            PropertyType::Scalar(ty) => {
+                                let _ = body.read(ty)?;
+                            }
+                            PropertyType::List { count, item } => {
+                                skip_list(&mut body, count, item)?
+                            }
+                        }
+                    }
+                }
+            }
+        }
+    }
+
+    if let Some(t) = indices
+        .iter()
+        .find(|t| t.iter().any(|i| *i as usize >= coords.len()))
+    {
+        return Err(MeshIoError::malformed(
+            None,
+            format!("the face {:?} references a nonexistent vertex", t),
+        ));
+    }
+
+    let normals = if has_normals { Some(normals) } else { None };
+    let uvs = if has_uvs { Some(uvs) } else { None };
+
+    Ok(TriMesh::new(
+        coords,
+        normals,
+        uvs,
+        Some(IndexBuffer::Unified(indices)),
+    ))
+}
+
+fn skip_list(body: &mut Body, count: ScalarType, item: ScalarType) -> Result<(), MeshIoError> {
+    let len = body.read_len(count)?;
+
+    for _ in 0..len {
+        let _ = body.read(item)?;
+    }
+
+    Ok(())
+}
+
+/// Writes a mesh to a PLY file with the given body encoding.
+///
+/// The vertex positions, normals and texture coordinates are written as `double` properties and
+/// the faces as `vertex_indices` lists. A mesh with a split index buffer is unified first.
+pub fn write_ply<N: RealField, W: Write>(
+    mesh: &TriMesh<N>,
+    writer: W,
+    format: PlyFormat,
+) -> io::Result<()> {
+    let unified;
+    let mesh = if let IndexBuffer::Split(_) = mesh.indices {
+        let mut m = mesh.clone();
+        m.unify_index_buffer();
+        unified = m;
+        &unified
+    } else {
+        mesh
+    };
+
+    let mut out = BufWriter::new(writer);
+    let format_name = match format {
+        PlyFormat::Ascii => "ascii",
+        PlyFormat::BinaryLittleEndian => "binary_little_endian",
+        PlyFormat::BinaryBigEndian => "binary_big_endian",
+    };
+
+    writeln!(out, "ply")?;
+    writeln!(out, "format {} 1.0", format_name)?;
+    writeln!(out, "element vertex {}", mesh.coords.len())?;
+    for name in &["x", "y", "z"] {
+        writeln!(out, "property double {}", name)?;
+    }
+    if mesh.has_normals() {
+        for name in &["nx", "ny", "nz"] {
+            writeln!(out, "property double {}", name)?;
+        }
+    }
+    if mesh.has_uvs() {
+        for name in &["u", "v"] {
+            writeln!(out, "property double {}", name)?;
+        }
+    }
+    writeln!(out, "element face {}", mesh.num_triangles())?;
+    writeln!(out, "property list uchar uint vertex_indices")?;
+    writeln!(out, "end_header")?;
+
+    let indices = mesh.flat_indices();
+
+    for i in 0..mesh.coords.len() {
+        let mut values = mesh.coords[i].coords.as_slice().to_vec();
+
+        if let Some(normals) = &mesh.normals {
+            values.extend_from_slice(normals[i].as_slice());
+        }
+
+        if let Some(uvs) = &mesh.uvs {
+            values.extend_from_slice(uvs[i].coords.as_slice());
+        }
+
+        if format == PlyFormat::Ascii {
+            let words: Vec<_> = values.iter().map(|v| v.to_string()).collect();
+            writeln!(out, "{}", words.join(" "))?;
+        } else {
+            for v in values {
+                let v = to_f64(v);
+                let bytes = if format == PlyFormat::BinaryBigEndian {
+                    v.to_be_bytes()
+                } else {
+                    v.to_le_bytes()
+                };
+                out.write_all(&bytes)?;
+            }
+        }
+    }
+
+    for t in indices.chunks(3) {
+        if format == PlyFormat::Ascii {
+            writeln!(out, "3 {} {} {}", t[0], t[1], t[2])?;
+        } else {
+            out.write_all(&[3])?;
+            for i in t {
+                let bytes = if format == PlyFormat::BinaryBigEndian {
+                    i.to_be_bytes()
+                } else {
+                    i.to_le_bytes()
+                };
+                out.write_all(&bytes)?;
+            }
+        }
+    }
+
+    out.flush()
+}
@@ -0,0 +1,267 @@
+use super::{parse_real, to_f64, MeshIoError};
+use crate::procedural::{IndexBuffer, TriMesh};
+use crate::utils::DeterministicState;
+use na::{self, Point3, RealField, Vector3};
+use std::collections::hash_map::Entry;
+use std::collections::HashMap;
+use std::io::{self, BufWriter, Read, Write};
+use std::str;
+
+const HEADER_LEN: usize = 80;
+const TRIANGLE_LEN: usize = 50;
+
+/// A triangle read from a STL file, with its facet normal.
+type Facet<N> = (Vector3<N>, [Point3<N>; 3]);
+
+/// Reads a mesh from an ASCII or binary STL file.
+///
+/// The format is detected automatically. Because STL files store each triangle independently,
+/// the vertices with exactly the same position are merged. The resulting mesh has a split index
+/// buffer referencing one normal per facet. Facets with a zero normal are given the normal
+/// computed from their vertices.
+pub fn read_stl<N: RealField, R: Read>(mut reader: R) -> Result<TriMesh<N>, MeshIoError> {
+    let mut data = Vec::new();
+    let _ = reader.read_to_end(&mut data)?;
+
+    let facets: Vec<Facet<N>> = if is_binary(&data) {
+        read_binary_facets(&data)?
+    } else {
+        read_ascii_facets(&data)?
+    };
+
+    let mut coords = Vec::new();
+    let mut normals = Vec::with_capacity(facets.len());
+    let mut indices = Vec::with_capacity(facets.len());
+    let mut coord_ids = HashMap::with_hasher(DeterministicState::new());
+
+    for (normal, pts) in facets {
+        let normal_id = normals.len() as u32;
+        let mut ids = Point3::new(Point3::origin(), Point3::origin(), Point3::origin());
+
+        for (k, pt) in pts.iter().enumerate() {
+            let key = [
+                to_f64(pt.x).to_bits(),
+                to_f64(pt.y).to_bits(),
+                to_f64(pt.z).to_bits(),
+            ];
+            let coord_id = match coord_ids.entry(key) {
+                Entry::Occupied(e) => *e.get(),
+                Entry::Vacant(e) => {
+                    coords.push(*pt);
+                    *e.insert(coords.len() as u32 - 1)
+                }
+            };
+            ids[k] = Point3::new(coord_id, normal_id, 0);
+        }
+
+        let normal = if normal.norm_squared().is_zero() {
+            facet_normal(&pts)
+        } else {
+            normal
+        };
+
+        normals.push(normal);
+        indices.push(ids);
+    }
+
+    Ok(TriMesh::new(
+        coords,
+        Some(normals),
+        None,
+        Some(IndexBuffer::Split(indices)),
+    ))
+}
+
+// A file is binary if its size matches the triangle count of its header. Otherwise it must be an
+// ASCII file starting with `solid`. Some binary files also start with `solid` so the size has to
+// be checked first.
+fn is_binary(data: &[u8]) -> bool {
+    if data.len() >= HEADER_LEN + 4 {
+        let num_triangles = read_u32(&data[HEADER_LEN..]) as usize;
+
+        if data.len() == HEADER_LEN + 4 + num_triangles * TRIANGLE_LEN {
+            return true;
+        }
+    }
+
+    let start = data
+        .iter()
+        .position(|b| !b.is_ascii_whitespace())
+        .unwrap_or(data.len());
+    !data[start..].starts_with(b"solid")
+}
+
+fn read_u32(data: &[u8]) -> u32 {
+    u32::from_le_bytes([data[0], data[1], data[2], data[3]])
+}
+
+fn read_f32<N: RealField>(data: &[u8]) -> N {
+    na::convert(f32::from_le_bytes([data[0], data[1], data[2], data[3]]) as f64)
+}
+
+fn read_binary_facets<N: RealField>(data: &[u8]) -> Result<Vec<Facet<N>>, MeshIoError> {
+    if data.len() < HEADER_LEN + 4 {
+        return Err(MeshIoError::malformed(None, "truncated binary STL header"));
+    }
+
+    let num_triangles = read_u32(&data[HEADER_LEN..]) as usize;
+    let body = &data[HEADER_LEN + 4..];
+
+    if body.len() != num_triangles * TRIANGLE_LEN {
+        return Err(MeshIoError::malformed(
+            None,
+            format!(
+                "binary STL declares {} triangles but contains {} bytes of triangle data",
+                num_triangles,
+                body.len()
+            ),
+        ));
+    }
+
+    let read_vector =
+        |data: &[u8]| Vector3::new(read_f32(data), read_f32(&data[4..]), read_f32(&data[8..]));
+
+    Ok(body
+        .chunks(TRIANGLE_LEN)
+        .map(|tri| {
+            let normal = read_vector(tri);
+            let a = Point3::from(read_vector(&tri[12..]));
+            let b = Point3::from(read_vector(&tri[24..]));
+            let c = Point3::from(read_vector(&tri[36..]));
+            (normal, [a, b, c])
+        })
+        .collect())
+}
+
+fn read_ascii_facets<N: RealField>(data: &[u8]) -> Result<Vec<Facet<N>>, MeshIoError> {
+    let text = str::from_utf8(data)
+        .map_err(|_| MeshIoError::malformed(None, "ASCII STL file is not valid UTF-8"))?;
+    let mut facets = Vec::new();
+    let mut normal = None;
+    let mut vertices = Vec::new();
+
+    for (i, line) in text.lines().enumerate() {
+        let line_id = i + 1;
+        let mut words = line.split_whitespace();
+
+        match words.next() {
+            Some("facet") => {
+                if words.next() != Some("normal") {
+                    return Err(MeshIoError::malformed(Some(line_id), "expected `normal`"));
+                }
+
+                normal = Some(Vector3::new(
+                    parse_real(words.next(), line_id)?,
+                    parse_real(words.next(), line_id)?,
+                    parse_real(words.next(), line_id)?,
+                ));
+                vertices.clear();
+            }
+            Some("vertex") => vertices.push(Point3::new(
+                parse_real(words.next(), line_id)?,
+                parse_real(words.next(), line_id)?,
+                parse_real(words.next(), line_id)?,
+            )),
+            Some("endfacet") => {
+                let normal = normal.take().ok_or_else(|| {
+                    MeshIoError::malformed(Some(line_id), "`endfacet` without `facet`")
+                })?;
+
+                if vertices.len() != 3 {
+                    return Err(MeshIoError::malformed(
+                        Some(line_id),
+                        format!("a facet must have 3 vertices, found {}", vertices.len()),
+                    ));
+                }
+
+                facets.push((normal, [vertices[0], vertices[1], vertices[2]]));
+            }
+            Some("solid") | Some("endsolid") | Some("outer") | Some("endloop") | None => {}
+            Some(word) => {
+                return Err(MeshIoError::malformed(
+                    Some(line_id),
+                    format!("unexpected keyword `{}`", word),
+                ));
+            }
+        }
+    }
+
+    if normal.is_some() {
+        return Err(MeshIoError::malformed(None, "unterminated facet"));
+    }
+
+    Ok(facets)
+}
+
+fn facet_normal<N: RealField>(pts: &[Point3<N>; 3]) -> Vector3<N> {
+    (pts[1] - pts[0])
+        .cross(&(pts[2] - pts[0]))
+        .try_normalize(N::zero())
+        .unwrap_or_else(Vector3::zeros)
+}
+
+fn mesh_facets<'a, N: RealField>(
+    mesh: &'a TriMesh<N>,
+) -> impl Iterator<Item = [Point3<N>; 3]> + 'a {
+    let indices = mesh.flat_indices();
+    (0..indices.len() / 3).map(move |i| {
+        [
+            mesh.coords[indices[i * 3] as usize],
+            mesh.coords[indices[i * 3 + 1] as usize],
+            mesh.coords[indices[i * 3 + 2] as usize],
+        ]
+    })
+}
+
+/// Writes a mesh to an ASCII STL file.
+///
+/// The facet normals are computed from the triangle vertices.
+pub fn write_stl_ascii<N: RealField, W: Write>(mesh: &TriMesh<N>, writer: W) -> io::Result<()> {
+    let mut out = BufWriter::new(writer);
+    writeln!(out, "solid mesh")?;
+
+    for pts in mesh_facets(mesh) {
+        let n = facet_normal(&pts);
+        writeln!(out, "facet normal {} {} {}", n.x, n.y, n.z)?;
+        writeln!(out, "  outer loop")?;
+        for pt in &pts {
+            writeln!(out, "    vertex {} {} {}", pt.x, pt.y, pt.z)?;
+        }
+        writeln!(out, "  endloop")?;
+        writeln!(out, "endfacet")?;
+    }
+
+    writeln!(out, "endsolid mesh")?;
+    out.flush()
+}
+
+/// Writes a mesh to a binary STL file.
+///
+/// The facet normals are computed from the triangle vertices. Binary STL files store
+/// single-precision floating-point numbers, so the coordinates may lose some precision.
+pub fn write_stl_binary<N: RealField, W: Write>(mesh: &TriMesh<N>, writer: W) -> io::Result<()> {
+    let mut out = BufWriter::new(writer);
+    let mut header = [0u8; HEADER_LEN];
+    let title = b"binary STL";
+    header[..title.len()].copy_from_slice(title);
+    out.write_all(&header)?;
+    out.write_all(&(mesh.num_triangles() as u32).to_le_bytes())?;
+
+    let write_vector = |out: &mut BufWriter<W>, v: &Vector3<N>| -> io::Result<()> {
+        for i in 0..3 {
+            out.write_all(&(to_f64(v[i]) as f32).to_le_bytes())?;
+        }
+        Ok(())
+    };
+
+    for pts in mesh_facets(mesh) {
+        write_vector(&mut out, &facet_normal(&pts))?;
+        for pt in &pts {
+            write_vector(&mut out, &pt.coords)?;
+        }
+        // The attribute byte count.
+        out.write_all(&[0, 0])?;
+    }
+
+    out.flush()
+}
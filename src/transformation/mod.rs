@@ -1,5 +1,9 @@
 //! Transformation, simplification and decomposition of meshes.
 
+#[cfg(feature = "dim2")]
+pub use self::convex_decomposition2::{
+    convex_decomposition, convex_decomposition_compound, convex_decomposition_polyline,
+};
 #[cfg(feature = "dim3")]
 pub(crate) use self::convex_hull2::convex_hull2_idx;
 #[cfg(feature = "dim2")]
@@ -8,12 +12,24 @@ pub use self::convex_hull2::{convex_hull2 as convex_hull, convex_hull2_idx as co
 pub use self::convex_hull3::convex_hull3 as convex_hull;
 #[cfg(feature = "dim3")]
 pub use self::hacd::hacd;
+pub use self::half_space_intersection::{
+    half_space_intersection, HalfSpace, HalfSpaceIntersectionError,
+};
+#[cfg(feature = "dim3")]
+pub use self::polygon_triangulation::triangulate_polygon;
+pub use self::polygon_triangulation::triangulate_polygon_idx;
+#[cfg(feature = "dim3")]
+pub use self::simplify::{simplify, simplify_convex_hull, SimplificationParameters};
 #[cfg(feature = "dim2")]
 pub use self::to_polyline::ToPolyline;
 #[cfg(feature = "dim3")]
 pub use self::to_trimesh::ToTriMesh;
+#[cfg(feature = "dim3")]
+pub use self::vhacd::{vhacd, vhacd_compound, VHACDParameters};
 // pub use self::triangulate::triangulate;
 
+#[cfg(feature = "dim2")]
+mod convex_decomposition2;
 mod convex_hull2;
 #[cfg(feature = "dim3")]
 mod convex_hull3;
@@ -21,8 +37,16 @@ mod convex_hull3;
 pub mod convex_hull_utils; // Internal implementation details.
 #[cfg(feature = "dim3")]
 mod hacd;
+mod half_space_intersection;
+#[cfg(feature = "dim3")]
+pub mod io;
+mod polygon_triangulation;
+#[cfg(feature = "dim3")]
+mod simplify;
 #[cfg(feature = "dim2")]
 mod to_polyline;
 #[cfg(feature = "dim3")]
 mod to_trimesh;
+#[cfg(feature = "dim3")]
+mod vhacd;
 // mod triangulate;
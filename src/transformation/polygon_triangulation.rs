@@ -0,0 +1,519 @@
+#[cfg(feature = "dim3")]
+use crate::procedural::{IndexBuffer, TriMesh};
+use crate::utils::{DeterministicState, SortedPair};
+#[cfg(feature = "dim3")]
+use na::Vector3;
+use na::{self, Matrix3, Point2, Point3, RealField};
+use std::collections::hash_map::Entry;
+use std::collections::HashMap;
+
+/// Triangulates a polygon with holes and forced edges using a constrained Delaunay triangulation.
+///
+/// The polygon is delimited by the closed loop `outline` and the closed loops in `holes`. The
+/// orientation of those loops does not matter. Each segment of `forced_edges` lying inside of the
+/// polygon is guaranteed to be covered by edges of the triangulation. Duplicate points are
+/// merged, and the edges intersecting each other, or passing through other points, are split.
+/// Degenerate polygons, e.g., with all their points collinear, yield no triangles.
+///
+/// Returns the vertices of the triangulation, which include the intersections between edges, and
+/// the counterclockwise triangles indexing them.
+pub fn triangulate_polygon_idx<N: RealField>(
+    outline: &[Point2<N>],
+    holes: &[Vec<Point2<N>>],
+    forced_edges: &[(Point2<N>, Point2<N>)],
+) -> (Vec<Point2<N>>, Vec<Point3<usize>>) {
+    let all_points = || {
+        outline
+            .iter()
+            .chain(holes.iter().flatten())
+            .chain(forced_edges.iter().map(|e| &e.0))
+            .chain(forced_edges.iter().map(|e| &e.1))
+    };
+
+    let mut mins = Point2::new(N::max_value(), N::max_value());
+    let mut maxs = Point2::new(-N::max_value(), -N::max_value());
+
+    for pt in all_points() {
+        mins = mins.inf(pt);
+        maxs = maxs.sup(pt);
+    }
+
+    let size = na::distance(&mins, &maxs);
+
+    if outline.len() < 3 || size.is_zero() {
+        return (Vec::new(), Vec::new());
+    }
+
+    let mut cdt = Cdt::new(&na::center(&mins, &maxs), size);
+
+    for pts in std::iter::once(outline).chain(holes.iter().map(|h| &h[..])) {
+        let ids: Vec<usize> = pts.iter().map(|pt| cdt.insert_point(pt)).collect();
+
+        for i in 0..ids.len() {
+            cdt.insert_constraint(ids[i], ids[(i + 1) % ids.len()], EdgeKind::Boundary);
+        }
+    }
+
+    for (a, b) in forced_edges {
+        let a = cdt.insert_point(a);
+        let b = cdt.insert_point(b);
+        cdt.insert_constraint(a, b, EdgeKind::Forced);
+    }
+
+    cdt.into_interior()
+}
+
+/// Triangulates a polygon with holes and forced edges lying on the `xy` plane.
+///
+/// See `triangulate_polygon_idx` for details. The resulting mesh lies on the `z = 0` plane, and
+/// its triangles are counterclockwise when seen from the positive `z` axis.
+#[cfg(feature = "dim3")]
+pub fn triangulate_polygon<N: RealField>(
+    outline: &[Point2<N>],
+    holes: &[Vec<Point2<N>>],
+    forced_edges: &[(Point2<N>, Point2<N>)],
+) -> TriMesh<N> {
+    let (points, triangles) = triangulate_polygon_idx(outline, holes, forced_edges);
+    let coords = points
+        .iter()
+        .map(|pt| Point3::new(pt.x, pt.y, N::zero()))
+        .collect();
+    let normals = vec![Vector3::z(); points.len()];
+    let indices = triangles
+        .iter()
+        .map(|t| Point3::new(t.x as u32, t.y as u32, t.z as u32))
+        .collect();
+
+    TriMesh::new(
+        coords,
+        Some(normals),
+        None,
+        Some(IndexBuffer::Unified(indices)),
+    )
+}
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+enum EdgeKind {
+    // An edge of the outline or of a hole: crossing it toggles between inside and outside.
+    Boundary,
+    Forced,
+}
+
+// The three first vertices are the vertices of a triangle enclosing all the other ones.
+const NUM_SUPER_VERTICES: usize = 3;
+
+struct Cdt<N: RealField> {
+    vertices: Vec<Point2<N>>,
+    triangles: Vec<Option<[usize; 3]>>,
+    // Maps each directed edge to the counterclockwise triangle it belongs to.
+    edges: HashMap<(usize, usize), usize, DeterministicState>,
+    constraints: HashMap<SortedPair<usize>, EdgeKind, DeterministicState>,
+    // Tolerances on distances, and on orientation determinants.
+    eps: N,
+    orient_eps: N,
+}
+
+impl<N: RealField> Cdt<N> {
+    fn new(center: &Point2<N>, size: N) -> Self {
+        let extent = size * na::convert(100.0);
+        let vertices = vec![
+            Point2::new(center.x - extent, center.y - extent),
+            Point2::new(center.x + extent, center.y - extent),
+            Point2::new(center.x, center.y + extent),
+        ];
+        let eps = N::default_epsilon().sqrt() * size;
+        let mut res = Cdt {
+            vertices,
+            triangles: Vec::new(),
+            edges: HashMap::with_hasher(DeterministicState::new()),
+            constraints: HashMap::with_hasher(DeterministicState::new()),
+            eps,
+            orient_eps: eps * size,
+        };
+
+        let _ = res.add_triangle([0, 1, 2]);
+        res
+    }
+
+    fn orient(&self, a: usize, b: usize, c: usize) -> N {
+        orient(&self.vertices[a], &self.vertices[b], &self.vertices[c])
+    }
+
+    fn add_triangle(&mut self, tri: [usize; 3]) -> usize {
+        let id = self.triangles.len();
+
+        for k in 0..3 {
+            let _ = self.edges.insert((tri[k], tri[(k + 1) % 3]), id);
+        }
+
+        self.triangles.push(Some(tri));
+        id
+    }
+
+    fn remove_triangle(&mut self, id: usize) -> [usize; 3] {
+        let tri = self.triangles[id].take().unwrap();
+
+        for k in 0..3 {
+            let _ = self.edges.remove(&(tri[k], tri[(k + 1) % 3]));
+        }
+
+        tri
+    }
+
+    // The vertex opposite to the directed edge `(a, b)`, if any.
+    fn apex(&self, a: usize, b: usize) -> Option<usize> {
+        let tri = self.triangles[*self.edges.get(&(a, b))?].unwrap();
+        tri.iter().cloned().find(|v| *v != a && *v != b)
+    }
+
+    // Inserts a point and returns its index, or the index of an existing vertex at the same
+    // position.
+    fn insert_point(&mut self, pt: &Point2<N>) -> usize {
+        if let Some(existing) = (NUM_SUPER_VERTICES..self.vertices.len())
+            .find(|i| na::distance(&self.vertices[*i], pt) <= self.eps)
+        {
+            return existing;
+        }
+
+        let id = self.vertices.len();
+        self.vertices.push(*pt);
+
+        for t in 0..self.triangles.len() {
+            let tri = match self.triangles[t] {
+                Some(tri) => tri,
+                None => continue,
+            };
+            let orients = [
+                self.orient(tri[0], tri[1], id),
+                self.orient(tri[1], tri[2], id),
+                self.orient(tri[2], tri[0], id),
+            ];
+
+            if orients.iter().any(|o| *o < -self.orient_eps) {
+                continue;
+            }
+
+            match (0..3).find(|k| orients[*k] <= self.orient_eps) {
+                Some(k) => self.split_edge(tri[k], tri[(k + 1) % 3], id),
+                None => {
+                    let _ = self.remove_triangle(t);
+
+                    for k in 0..3 {
+                        let _ = self.add_triangle([tri[k], tri[(k + 1) % 3], id]);
+                    }
+
+                    for k in 0..3 {
+                        self.legalize(tri[k], tri[(k + 1) % 3]);
+                    }
+                }
+            }
+
+            break;
+        }
+
+        id
+    }
+
+    // Splits the edge `(a, b)` at the vertex `id` lying on it.
+    fn split_edge(&mut self, a: usize, b: usize, id: usize) {
+        let mut outer_edges = Vec::new();
+
+        for (u, v) in &[(a, b), (b, a)] {
+            if let Some(t) = self.edges.get(&(*u, *v)).cloned() {
+                let c = self.apex(*u, *v).unwrap();
+                let _ = self.remove_triangle(t);
+                let _ = self.add_triangle([*u, id, c]);
+                let _ = self.add_triangle([id, *v, c]);
+                outer_edges.push((*v, c));
+                outer_edges.push((c, *u));
+            }
+        }
+
+        if let Some(kind) = self.constraints.remove(&SortedPair::new(a, b)) {
+            let _ = self.constraints.insert(SortedPair::new(a, id), kind);
+            let _ = self.constraints.insert(SortedPair::new(id, b), kind);
+        }
+
+        for (u, v) in outer_edges {
+            self.legalize(u, v);
+        }
+    }
+
+    // Flips the edge `(a, b)` if it is not locally Delaunay, and recursively its neighbors.
+    //
+    // Edges next to the enclosing triangle are never flipped: its vertices are so far away that
+    // the in-circle test would be dominated by rounding errors. This only affects triangles
+    // outside of the convex hull of the input points.
+    fn legalize(&mut self, a: usize, b: usize) {
+        let mut stack = vec![(a, b)];
+
+        while let Some((a, b)) = stack.pop() {
+            if self.constraints.contains_key(&SortedPair::new(a, b)) {
+                continue;
+            }
+
+            let (p, q) = match (self.apex(a, b), self.apex(b, a)) {
+                (Some(p), Some(q)) => (p, q),
+                _ => continue,
+            };
+
+            if [a, b, p, q].iter().any(|v| *v < NUM_SUPER_VERTICES) {
+                continue;
+            }
+
+            if in_circle(
+                &self.vertices[a],
+                &self.vertices[b],
+                &self.vertices[p],
+                &self.vertices[q],
+            ) > self.orient_eps * self.orient_eps
+            {
+                let t1 = self.edges[&(a, b)];
+                let t2 = self.edges[&(b, a)];
+                let _ = self.remove_triangle(t1);
+                let _ = self.remove_triangle(t2);
+                let _ = self.add_triangle([p, a, q]);
+                let _ = self.add_triangle([p, q, b]);
+                stack.push((a, q));
+                stack.push((q, b));
+                stack.push((b, p));
+                stack.push((p, a));
+            }
+        }
+    }
+
+    fn is_on_segment(&self, a: usize, b: usize, c: usize) -> bool {
+        let (pa, pb, pc) = (&self.vertices[a], &self.vertices[b], &self.vertices[c]);
+        self.orient(a, b, c).abs() <= self.orient_eps
+            && (pc - pa).dot(&(pb - pa)) > N::zero()
+            && (pc - pb).dot(&(pa - pb)) > N::zero()
+    }
+
+    // Makes the segment `(a, b)` part of the triangulation.
+    fn insert_constraint(&mut self, a: usize, b: usize, kind: EdgeKind) {
+        if a == b {
+            return;
+        }
+
+        if self.edges.contains_key(&(a, b)) || self.edges.contains_key(&(b, a)) {
+            match self.constraints.entry(SortedPair::new(a, b)) {
+                Entry::Occupied(mut e) => {
+                    if kind == EdgeKind::Boundary {
+                        let _ = e.insert(kind);
+                    }
+                }
+                Entry::Vacant(e) => {
+                    let _ = e.insert(kind);
+                }
+            }
+
+            return;
+        }
+
+        // Find the edge crossed by the segment in the triangles around `a`.
+        let mut crossed = None;
+
+        let around: Vec<_> = self
+            .edges
+            .keys()
+            .filter(|(u, _)| *u == a)
+            .cloned()
+            .collect();
+
+        for (u, v) in around {
+            let c = self.apex(u, v).unwrap();
+
+            for w in &[v, c] {
+                if self.is_on_segment(a, b, *w) {
+                    // The segment passes through another vertex.
+                    let w = *w;
+                    self.insert_constraint(a, w, kind);
+                    self.insert_constraint(w, b, kind);
+                    return;
+                }
+            }
+
+            if self.orient(a, v, b) > self.orient_eps && self.orient(a, b, c) > self.orient_eps {
+                crossed = Some((v, c));
+            }
+        }
+
+        let (mut right, mut left) = match crossed {
+            Some(edge) => edge,
+            // Only happens if the points are inconsistent because of rounding errors.
+            None => return,
+        };
+
+        let mut removed = vec![self.edges[&(a, right)]];
+        let mut right_chain = vec![right];
+        let mut left_chain = vec![left];
+
+        loop {
+            if self.constraints.contains_key(&SortedPair::new(right, left)) {
+                // Split the crossed constraint at the intersection point.
+                let x = segment_intersection(
+                    &self.vertices[a],
+                    &self.vertices[b],
+                    &self.vertices[right],
+                    &self.vertices[left],
+                );
+                let x = self.insert_point(&x);
+
+                if x == a || x == b {
+                    // Only happens if the points are inconsistent because of rounding errors.
+                    return;
+                }
+
+                self.insert_constraint(a, x, kind);
+                self.insert_constraint(x, b, kind);
+                return;
+            }
+
+            let t = self.edges[&(left, right)];
+            let next = self.apex(left, right).unwrap();
+            removed.push(t);
+
+            if next == b {
+                break;
+            }
+
+            if self.is_on_segment(a, b, next) {
+                self.insert_constraint(a, next, kind);
+                self.insert_constraint(next, b, kind);
+                return;
+            }
+
+            if self.orient(a, b, next) < N::zero() {
+                right = next;
+                right_chain.push(right);
+            } else {
+                left = next;
+                left_chain.push(left);
+            }
+        }
+
+        for t in removed {
+            let _ = self.remove_triangle(t);
+        }
+
+        // Retriangulate the two pseudo-polygons on each side of the segment.
+        right_chain.reverse();
+        self.fill(a, b, &left_chain);
+        self.fill(b, a, &right_chain);
+        let _ = self.constraints.insert(SortedPair::new(a, b), kind);
+    }
+
+    // Triangulates the pseudo-polygon formed by the edge `(a, b)` and the chain of vertices on
+    // its left, ordered from `a` to `b`.
+    fn fill(&mut self, a: usize, b: usize, chain: &[usize]) {
+        if chain.is_empty() {
+            return;
+        }
+
+        let mut c = 0;
+
+        for i in 1..chain.len() {
+            if in_circle(
+                &self.vertices[a],
+                &self.vertices[b],
+                &self.vertices[chain[c]],
+                &self.vertices[chain[i]],
+            ) > N::zero()
+            {
+                c = i;
+            }
+        }
+
+        let _ = self.add_triangle([a, b, chain[c]]);
+        self.fill(a, chain[c], &chain[..c]);
+        self.fill(chain[c], b, &chain[c + 1..]);
+    }
+
+    // The vertices and triangles inside of the polygon.
+    fn into_interior(self) -> (Vec<Point2<N>>, Vec<Point3<usize>>) {
+        // Flood-fill from the triangles touching the enclosing triangle, toggling between the
+        // outside and the inside each time a boundary edge is crossed.
+        let mut inside = vec![None; self.triangles.len()];
+        let mut stack = Vec::new();
+
+        for (t, tri) in self.triangles.iter().enumerate() {
+            if let Some(tri) = tri {
+                if tri.iter().any(|v| *v < NUM_SUPER_VERTICES) {
+                    inside[t] = Some(false);
+                    stack.push(t);
+                }
+            }
+        }
+
+        while let Some(t) = stack.pop() {
+            let tri = self.triangles[t].unwrap();
+            let is_inside = inside[t].unwrap();
+
+            for k in 0..3 {
+                let (u, v) = (tri[k], tri[(k + 1) % 3]);
+
+                if let Some(&neighbor) = self.edges.get(&(v, u)) {
+                    if inside[neighbor].is_none() {
+                        let kind = self.constraints.get(&SortedPair::new(u, v));
+                        inside[neighbor] = Some(is_inside ^ (kind == Some(&EdgeKind::Boundary)));
+                        stack.push(neighbor);
+                    }
+                }
+            }
+        }
+
+        let mut new_ids = vec![usize::MAX; self.vertices.len()];
+        let mut vertices = Vec::new();
+        let mut triangles = Vec::new();
+
+        for (t, tri) in self.triangles.iter().enumerate() {
+            if let (Some(tri), Some(true)) = (tri, inside[t]) {
+                let mut ids = Point3::origin();
+
+                for k in 0..3 {
+                    if new_ids[tri[k]] == usize::MAX {
+                        new_ids[tri[k]] = vertices.len();
+                        vertices.push(self.vertices[tri[k]]);
+                    }
+
+                    ids[k] = new_ids[tri[k]];
+                }
+
+                triangles.push(ids);
+            }
+        }
+
+        (vertices, triangles)
+    }
+}
+
+fn orient<N: RealField>(a: &Point2<N>, b: &Point2<N>, c: &Point2<N>) -> N {
+    (b - a).perp(&(c - a))
+}
+
+// Positive if `d` lies inside of the circumcircle of the counterclockwise triangle `(a, b, c)`.
+fn in_circle<N: RealField>(a: &Point2<N>, b: &Point2<N>, c: &Point2<N>, d: &Point2<N>) -> N {
+    let row = |p: &Point2<N>| {
+        let dp = p - d;
+        [dp.x, dp.y, dp.norm_squared()]
+    };
+    let (ra, rb, rc) = (row(a), row(b), row(c));
+
+    Matrix3::new(
+        ra[0], ra[1], ra[2], rb[0], rb[1], rb[2], rc[0], rc[1], rc[2],
+    )
+    .determinant()
+}
+
+// The intersection of the lines `(a, b)` and `(c, d)`, which are assumed to cross.
+fn segment_intersection<N: RealField>(
+    a: &Point2<N>,
+    b: &Point2<N>,
+    c: &Point2<N>,
+    d: &Point2<N>,
+) -> Point2<N> {
+    let ab = b - a;
+    let cd = d - c;
+    let t = (c - a).perp(&cd) / ab.perp(&cd);
+    a + ab * t
+}
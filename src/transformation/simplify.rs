@@ -0,0 +1,710 @@
+use crate::procedural::{IndexBuffer, TriMesh};
+use crate::transformation::convex_hull3::convex_hull3;
+use crate::transformation::HalfSpace;
+use crate::utils::{DeterministicState, SortedPair};
+use na::{self, Matrix3, Matrix4, Point2, Point3, RealField, Unit, Vector3, Vector4};
+use std::cmp::Ordering;
+use std::collections::{BinaryHeap, HashMap, HashSet};
+
+/// Parameters of the mesh simplification performed by `simplify`.
+#[derive(Clone, Debug, PartialEq)]
+pub struct SimplificationParameters<N: RealField> {
+    /// The number of triangles below which the simplification stops.
+    pub target_triangles: usize,
+    /// The maximum error introduced by each edge collapse.
+    ///
+    /// This is the square root of the sum of the squared distances between the vertex resulting
+    /// from the collapse and the planes of the original triangles merged into it. With the
+    /// default value of zero, only the vertices lying on flat regions are removed.
+    pub max_error: N,
+    /// If `true`, the vertices lying on the boundary of the mesh are never moved nor removed.
+    /// Otherwise, moving them away from the boundary adds to the error of the edge collapses.
+    pub preserve_boundaries: bool,
+    /// If `true`, the texture coordinates of the mesh are kept and interpolated, and the vertices
+    /// lying on texture seams are never moved nor removed.
+    pub preserve_uvs: bool,
+}
+
+impl<N: RealField> Default for SimplificationParameters<N> {
+    fn default() -> Self {
+        SimplificationParameters {
+            target_triangles: 0,
+            max_error: N::zero(),
+            preserve_boundaries: true,
+            preserve_uvs: true,
+        }
+    }
+}
+
+/// Simplifies a triangle mesh by iteratively collapsing its edges using quadric error metrics.
+///
+/// Edges are collapsed, cheapest first, until the mesh has at most `params.target_triangles`
+/// triangles or no edge can be collapsed with an error smaller than `params.max_error`. Edge
+/// collapses that would make the mesh non-manifold or flip a triangle are rejected.
+///
+/// The connectivity of the mesh is deduced from the indices of its vertex coordinates, so
+/// distinct vertices sharing the same position are not merged. The returned mesh has a unified
+/// index buffer, and its normals are recomputed if the original mesh had normals.
+pub fn simplify<N: RealField>(
+    mesh: &TriMesh<N>,
+    params: &SimplificationParameters<N>,
+) -> TriMesh<N> {
+    let mut simplifier = Simplifier::new(mesh, params);
+    let max_cost = params.max_error * params.max_error;
+
+    while simplifier.num_triangles > params.target_triangles {
+        let collapse = match simplifier.queue.pop() {
+            Some(collapse) => collapse,
+            None => break,
+        };
+
+        if collapse.cost > max_cost {
+            break;
+        }
+
+        if simplifier.is_valid(&collapse) {
+            simplifier.collapse(&collapse);
+        }
+    }
+
+    simplifier.into_mesh(mesh.has_normals())
+}
+
+struct Vertex<N: RealField> {
+    position: Point3<N>,
+    uv: Option<Point2<N>>,
+    quadric: Matrix4<N>,
+    triangles: Vec<usize>,
+    locked: bool,
+    removed: bool,
+    timestamp: usize,
+}
+
+struct Collapse<N: RealField> {
+    cost: N,
+    v1: usize,
+    v2: usize,
+    // The position of the merged vertex, and its parameter along the edge.
+    target: Point3<N>,
+    t: N,
+    timestamps: (usize, usize),
+}
+
+impl<N: RealField> PartialEq for Collapse<N> {
+    #[inline]
+    fn eq(&self, other: &Collapse<N>) -> bool {
+        self.cost.eq(&other.cost)
+    }
+}
+
+impl<N: RealField> Eq for Collapse<N> {}
+
+impl<N: RealField> PartialOrd for Collapse<N> {
+    #[inline]
+    fn partial_cmp(&self, other: &Collapse<N>) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+// The ordering is reversed so that the binary heap pops the cheapest collapse first.
+impl<N: RealField> Ord for Collapse<N> {
+    #[inline]
+    fn cmp(&self, other: &Collapse<N>) -> Ordering {
+        if self.cost < other.cost {
+            Ordering::Greater
+        } else if self.cost > other.cost {
+            Ordering::Less
+        } else {
+            Ordering::Equal
+        }
+    }
+}
+
+struct Simplifier<N: RealField> {
+    vertices: Vec<Vertex<N>>,
+    triangles: Vec<Option<[usize; 3]>>,
+    num_triangles: usize,
+    queue: BinaryHeap<Collapse<N>>,
+}
+
+impl<N: RealField> Simplifier<N> {
+    fn new(mesh: &TriMesh<N>, params: &SimplificationParameters<N>) -> Self {
+        let mut vertices: Vec<_> = mesh
+            .coords
+            .iter()
+            .map(|pt| Vertex {
+                position: *pt,
+                uv: None,
+                quadric: Matrix4::zeros(),
+                triangles: Vec::new(),
+                locked: false,
+                removed: false,
+                timestamp: 0,
+            })
+            .collect();
+
+        // The triangles with their texture coordinate indices.
+        let corners: Vec<([u32; 3], Option<[u32; 3]>)> = match &mesh.indices {
+            IndexBuffer::Unified(idx) => idx
+                .iter()
+                .map(|t| ([t.x, t.y, t.z], Some([t.x, t.y, t.z])))
+                .collect(),
+            IndexBuffer::Split(idx) => idx
+                .iter()
+                .map(|t| ([t.x.x, t.y.x, t.z.x], Some([t.x.z, t.y.z, t.z.z])))
+                .collect(),
+        };
+
+        let uvs = if params.preserve_uvs {
+            mesh.uvs.as_ref()
+        } else {
+            None
+        };
+        let mut triangles = Vec::with_capacity(corners.len());
+
+        for (ids, uv_ids) in corners {
+            let tri = [ids[0] as usize, ids[1] as usize, ids[2] as usize];
+
+            if tri[0] == tri[1] || tri[1] == tri[2] || tri[2] == tri[0] {
+                continue;
+            }
+
+            if let (Some(uvs), Some(uv_ids)) = (uvs, uv_ids) {
+                for k in 0..3 {
+                    let uv = uvs[uv_ids[k] as usize];
+                    let vertex = &mut vertices[tri[k]];
+
+                    // A vertex with several texture coordinates lies on a seam.
+                    match vertex.uv {
+                        Some(old) if old != uv => vertex.locked = true,
+                        _ => vertex.uv = Some(uv),
+                    }
+                }
+            }
+
+            let quadric = plane_quadric(
+                &vertices[tri[0]].position,
+                &vertices[tri[1]].position,
+                &vertices[tri[2]].position,
+            );
+
+            for k in 0..3 {
+                vertices[tri[k]].quadric += quadric;
+                vertices[tri[k]].triangles.push(triangles.len());
+            }
+
+            triangles.push(Some(tri));
+        }
+
+        // Count the triangles adjacent to each edge to find the boundary edges.
+        let mut edges = HashMap::with_hasher(DeterministicState::new());
+
+        for (i, tri) in triangles.iter().enumerate() {
+            let tri = tri.unwrap();
+
+            for k in 0..3 {
+                edges
+                    .entry(SortedPair::new(tri[k], tri[(k + 1) % 3]))
+                    .or_insert((0, i))
+                    .0 += 1;
+            }
+        }
+
+        for (edge, (count, tri)) in &edges {
+            if *count != 1 {
+                continue;
+            }
+
+            if params.preserve_boundaries {
+                vertices[edge.0].locked = true;
+                vertices[edge.1].locked = true;
+            } else {
+                // Penalize the displacement of the vertex away from the boundary with the plane
+                // orthogonal to the triangle and containing the boundary edge.
+                let tri = triangles[*tri].unwrap();
+                let (a, b, c) = (
+                    vertices[tri[0]].position,
+                    vertices[tri[1]].position,
+                    vertices[tri[2]].position,
+                );
+                let p1 = vertices[edge.0].position;
+                let p2 = vertices[edge.1].position;
+                let normal = (b - a).cross(&(c - a));
+                let quadric = plane_quadric(&p1, &p2, &(p1 + normal));
+
+                vertices[edge.0].quadric += quadric;
+                vertices[edge.1].quadric += quadric;
+            }
+        }
+
+        let mut res = Simplifier {
+            vertices,
+            num_triangles: triangles.len(),
+            triangles,
+            queue: BinaryHeap::new(),
+        };
+
+        for edge in edges.keys() {
+            res.push_collapse(edge.0, edge.1);
+        }
+
+        res
+    }
+
+    fn push_collapse(&mut self, v1: usize, v2: usize) {
+        let vtx1 = &self.vertices[v1];
+        let vtx2 = &self.vertices[v2];
+
+        if vtx1.locked && vtx2.locked {
+            return;
+        }
+
+        let quadric = vtx1.quadric + vtx2.quadric;
+        let p1 = vtx1.position;
+        let p2 = vtx2.position;
+        let error = |pt: &Point3<N>| {
+            let h = Vector4::new(pt.x, pt.y, pt.z, N::one());
+            h.dot(&(quadric * h)).max(N::zero())
+        };
+
+        let target = if vtx1.locked {
+            p1
+        } else if vtx2.locked {
+            p2
+        } else {
+            let mid = na::center(&p1, &p2);
+            let mut best = [p1, p2, mid].iter().map(|pt| (*pt, error(pt))).fold(
+                (mid, N::max_value()),
+                |best, curr| {
+                    if curr.1 < best.1 {
+                        curr
+                    } else {
+                        best
+                    }
+                },
+            );
+
+            // The point minimizing the quadric error, if it is well-defined and not too far
+            // from the edge.
+            let a: Matrix3<N> = quadric.fixed_slice::<3, 3>(0, 0).into_owned();
+            let b: Vector3<N> = quadric.fixed_slice::<3, 1>(0, 3).into_owned();
+
+            if let Some(inv) = a.try_inverse() {
+                let optimal = Point3::from(-(inv * b));
+                let cost = error(&optimal);
+
+                if cost < best.1 && na::distance(&optimal, &mid) <= na::distance(&p1, &p2) {
+                    best = (optimal, cost);
+                }
+            }
+
+            best.0
+        };
+
+        let dir = p2 - p1;
+        let t = if dir.norm_squared().is_zero() {
+            N::zero()
+        } else {
+            ((target - p1).dot(&dir) / dir.norm_squared())
+                .max(N::zero())
+                .min(N::one())
+        };
+
+        self.queue.push(Collapse {
+            cost: error(&target),
+            v1,
+            v2,
+            target,
+            t,
+            timestamps: (vtx1.timestamp, vtx2.timestamp),
+        })
+    }
+
+    fn neighbors(&self, v: usize) -> HashSet<usize, DeterministicState> {
+        let mut res = HashSet::with_hasher(DeterministicState::new());
+
+        for t in &self.vertices[v].triangles {
+            if let Some(tri) = &self.triangles[*t] {
+                res.extend(tri.iter().cloned().filter(|i| *i != v));
+            }
+        }
+
+        res
+    }
+
+    fn is_valid(&self, collapse: &Collapse<N>) -> bool {
+        let vtx1 = &self.vertices[collapse.v1];
+        let vtx2 = &self.vertices[collapse.v2];
+
+        if vtx1.removed
+            || vtx2.removed
+            || vtx1.timestamp != collapse.timestamps.0
+            || vtx2.timestamp != collapse.timestamps.1
+        {
+            return false;
+        }
+
+        // Link condition: the vertices adjacent to both ends of the edge must be the apexes of
+        // the triangles sharing this edge. Otherwise, the collapse creates a non-manifold mesh.
+        let shared_triangles = vtx1
+            .triangles
+            .iter()
+            .filter(|t| vtx2.triangles.contains(t))
+            .count();
+        let neighbors1 = self.neighbors(collapse.v1);
+        let neighbors2 = self.neighbors(collapse.v2);
+
+        if shared_triangles == 0 || neighbors1.intersection(&neighbors2).count() != shared_triangles
+        {
+            return false;
+        }
+
+        // Reject the collapse if it flips or degenerates a triangle.
+        for (v, other) in &[(collapse.v1, collapse.v2), (collapse.v2, collapse.v1)] {
+            for t in &self.vertices[*v].triangles {
+                let tri = self.triangles[*t].unwrap();
+
+                if tri.contains(other) {
+                    continue;
+                }
+
+                let pts = [
+                    self.vertices[tri[0]].position,
+                    self.vertices[tri[1]].position,
+                    self.vertices[tri[2]].position,
+                ];
+                let mut new_pts = pts;
+
+                for k in 0..3 {
+                    if tri[k] == *v {
+                        new_pts[k] = collapse.target;
+                    }
+                }
+
+                let normal = (pts[1] - pts[0]).cross(&(pts[2] - pts[0]));
+                let new_normal = (new_pts[1] - new_pts[0]).cross(&(new_pts[2] - new_pts[0]));
+
+                if normal.dot(&new_normal) <= N::zero() {
+                    return false;
+                }
+            }
+        }
+
+        true
+    }
+
+    // Merges `collapse.v2` into `collapse.v1`.
+    fn collapse(&mut self, collapse: &Collapse<N>) {
+        let (v1, v2) = (collapse.v1, collapse.v2);
+        let triangles2 = std::mem::take(&mut self.vertices[v2].triangles);
+
+        for t in triangles2 {
+            let mut tri = self.triangles[t].unwrap();
+
+            if tri.contains(&v1) {
+                self.triangles[t] = None;
+                self.num_triangles -= 1;
+
+                for i in tri.iter() {
+                    self.vertices[*i].triangles.retain(|t2| *t2 != t);
+                }
+            } else {
+                for i in tri.iter_mut() {
+                    if *i == v2 {
+                        *i = v1;
+                    }
+                }
+
+                self.triangles[t] = Some(tri);
+                self.vertices[v1].triangles.push(t);
+            }
+        }
+
+        let (quadric2, uv2, locked2) = {
+            let vtx2 = &mut self.vertices[v2];
+            vtx2.removed = true;
+            (vtx2.quadric, vtx2.uv, vtx2.locked)
+        };
+
+        let vtx1 = &mut self.vertices[v1];
+        vtx1.position = collapse.target;
+        vtx1.quadric += quadric2;
+        vtx1.locked = vtx1.locked || locked2;
+        vtx1.timestamp += 1;
+
+        if let (Some(uv1), Some(uv2)) = (vtx1.uv, uv2) {
+            vtx1.uv = Some(uv1 + (uv2 - uv1) * collapse.t);
+        }
+
+        for neighbor in self.neighbors(v1) {
+            self.push_collapse(v1, neighbor);
+        }
+    }
+
+    fn into_mesh(self, with_normals: bool) -> TriMesh<N> {
+        let mut new_ids = vec![u32::MAX; self.vertices.len()];
+        let mut coords = Vec::new();
+        let mut uvs = Vec::new();
+        let mut indices = Vec::with_capacity(self.num_triangles);
+        let has_uvs = self.vertices.iter().any(|v| v.uv.is_some());
+
+        for tri in self.triangles.iter().flatten() {
+            let mut ids = Point3::origin();
+
+            for k in 0..3 {
+                if new_ids[tri[k]] == u32::MAX {
+                    new_ids[tri[k]] = coords.len() as u32;
+                    coords.push(self.vertices[tri[k]].position);
+                    uvs.push(self.vertices[tri[k]].uv.unwrap_or_else(Point2::origin));
+                }
+
+                ids[k] = new_ids[tri[k]];
+            }
+
+            indices.push(ids);
+        }
+
+        let uvs = if has_uvs { Some(uvs) } else { None };
+        let mut res = TriMesh::new(coords, None, uvs, Some(IndexBuffer::Unified(indices)));
+
+        if with_normals {
+            res.recompute_normals();
+        }
+
+        res
+    }
+}
+
+// The fundamental error quadric of the plane of a triangle.
+fn plane_quadric<N: RealField>(a: &Point3<N>, b: &Point3<N>, c: &Point3<N>) -> Matrix4<N> {
+    match (b - a).cross(&(c - a)).try_normalize(N::zero()) {
+        Some(n) => {
+            let plane = Vector4::new(n.x, n.y, n.z, -n.dot(&a.coords));
+            plane * plane.transpose()
+        }
+        None => Matrix4::zeros(),
+    }
+}
+
+/// Computes a convex polyhedron with at most `max_vertices` vertices containing the convex hull
+/// of the given points.
+///
+/// The result is the intersection of half-spaces bounded by planes of the faces of the convex
+/// hull of `points`. Starting with a tetrahedron, the face planes farthest from the current
+/// polyhedron are added one by one while the polyhedron has at most `max_vertices` vertices.
+/// The vertices of the resulting polyhedron are returned.
+pub fn simplify_convex_hull<N: RealField>(
+    points: &[Point3<N>],
+    max_vertices: usize,
+) -> Vec<Point3<N>> {
+    assert!(
+        max_vertices >= 4,
+        "A convex polyhedron must have at least 4 vertices."
+    );
+
+    if points.len() <= max_vertices {
+        return points.to_vec();
+    }
+
+    let hull = convex_hull3(points);
+    let mut used = vec![false; hull.coords.len()];
+
+    for i in hull.flat_indices() {
+        used[i as usize] = true;
+    }
+
+    let vertices: Vec<_> = hull
+        .coords
+        .iter()
+        .zip(used.iter())
+        .filter(|(_, used)| **used)
+        .map(|(pt, _)| *pt)
+        .collect();
+
+    if vertices.len() <= max_vertices {
+        return vertices;
+    }
+
+    let support_plane = |dir: Vector3<N>| {
+        let normal = Unit::new_normalize(dir);
+        let offset = vertices
+            .iter()
+            .map(|pt| normal.dot(&pt.coords))
+            .fold(-N::max_value(), |a, b| a.max(b));
+        HalfSpace::new(normal, offset)
+    };
+
+    let diameter = vertices
+        .iter()
+        .map(|pt| na::distance(pt, &vertices[0]))
+        .fold(N::zero(), |a, b| a.max(b));
+    let tolerance = N::default_epsilon().sqrt() * diameter;
+
+    // The planes of the faces of the hull, without duplicates.
+    let mut candidates: Vec<HalfSpace<N>> = Vec::new();
+
+    for t in hull.flat_indices().chunks(3) {
+        let (a, b, c) = (
+            hull.coords[t[0] as usize],
+            hull.coords[t[1] as usize],
+            hull.coords[t[2] as usize],
+        );
+
+        if let Some(normal) = Unit::try_new((b - a).cross(&(c - a)), N::default_epsilon()) {
+            let plane = HalfSpace::from_point(normal, &a);
+
+            if candidates.iter().all(|h| {
+                (h.normal.into_inner() - plane.normal.into_inner()).norm() > tolerance
+                    || (h.offset - plane.offset).abs() > tolerance
+            }) {
+                candidates.push(plane)
+            }
+        }
+    }
+
+    let one = N::one();
+    let mut polyhedron = Polyhedron::tetrahedron([
+        support_plane(Vector3::new(one, one, one)),
+        support_plane(Vector3::new(one, -one, -one)),
+        support_plane(Vector3::new(-one, one, -one)),
+        support_plane(Vector3::new(-one, -one, one)),
+    ]);
+
+    loop {
+        let depth = |plane: &HalfSpace<N>| {
+            polyhedron
+                .vertices
+                .iter()
+                .map(|(pt, _)| plane.signed_distance_to_point(pt))
+                .fold(-N::max_value(), |a, b| a.max(b))
+        };
+
+        let farthest = candidates
+            .iter()
+            .enumerate()
+            .map(|(i, plane)| (i, depth(plane)))
+            .fold(None, |best: Option<(usize, N)>, curr| match best {
+                Some(best) if best.1 >= curr.1 => Some(best),
+                _ => Some(curr),
+            });
+
+        let plane = match farthest {
+            Some((i, depth)) if depth > tolerance => candidates.swap_remove(i),
+            _ => break,
+        };
+
+        let clipped = polyhedron.clip(plane, tolerance);
+
+        if clipped.vertices.len() > max_vertices {
+            break;
+        }
+
+        polyhedron = clipped;
+    }
+
+    polyhedron.vertices.into_iter().map(|(pt, _)| pt).collect()
+}
+
+// A convex polyhedron given by its bounding planes and its vertices, each with the indices of
+// the planes it lies on.
+#[derive(Clone)]
+struct Polyhedron<N: RealField> {
+    planes: Vec<HalfSpace<N>>,
+    vertices: Vec<(Point3<N>, Vec<usize>)>,
+}
+
+impl<N: RealField> Polyhedron<N> {
+    fn tetrahedron(planes: [HalfSpace<N>; 4]) -> Self {
+        let mut vertices = Vec::new();
+
+        for excluded in 0..4 {
+            let ids: Vec<usize> = (0..4).filter(|i| *i != excluded).collect();
+            let mat = Matrix3::from_fn(|r, c| planes[ids[r]].normal[c]);
+            let rhs = Vector3::from_fn(|r, _| planes[ids[r]].offset);
+            // The normals of a regular tetrahedron are linearly independent.
+            let pt = Point3::from(mat.try_inverse().unwrap() * rhs);
+            vertices.push((pt, ids));
+        }
+
+        Polyhedron {
+            planes: planes.to_vec(),
+            vertices,
+        }
+    }
+
+    // Intersects this polyhedron with the given half-space.
+    fn clip(&self, plane: HalfSpace<N>, tolerance: N) -> Self {
+        let plane_id = self.planes.len();
+        let dists: Vec<N> = self
+            .vertices
+            .iter()
+            .map(|(pt, _)| plane.signed_distance_to_point(pt))
+            .collect();
+        let mut vertices = Vec::new();
+
+        for (i, (pt, ids)) in self.vertices.iter().enumerate() {
+            if dists[i] <= tolerance {
+                let mut ids = ids.clone();
+
+                if dists[i] >= -tolerance {
+                    ids.push(plane_id);
+                }
+
+                vertices.push((*pt, ids));
+            }
+        }
+
+        // Two vertices are joined by an edge if they share two planes.
+        for (i, (pt1, ids1)) in self.vertices.iter().enumerate() {
+            if dists[i] >= -tolerance {
+                continue;
+            }
+
+            for (j, (pt2, ids2)) in self.vertices.iter().enumerate() {
+                if dists[j] <= tolerance {
+                    continue;
+                }
+
+                let shared: Vec<usize> = ids1
+                    .iter()
+                    .filter(|id| ids2.contains(id))
+                    .cloned()
+                    .collect();
+
+                if shared.len() >= 2 {
+                    let t = dists[i] / (dists[i] - dists[j]);
+                    let mut ids = shared;
+                    ids.push(plane_id);
+                    vertices.push((pt1 + (pt2 - pt1) * t, ids));
+                }
+            }
+        }
+
+        // Several edges may be cut at the same point if it lies on more than three planes.
+        let mut merged: Vec<(Point3<N>, Vec<usize>)> = Vec::new();
+
+        for (pt, ids) in vertices {
+            match merged
+                .iter_mut()
+                .find(|(other, _)| na::distance(other, &pt) <= tolerance)
+            {
+                Some((_, other_ids)) => {
+                    for id in ids {
+                        if !other_ids.contains(&id) {
+                            other_ids.push(id);
+                        }
+                    }
+                }
+                None => merged.push((pt, ids)),
+            }
+        }
+
+        let mut planes = self.planes.clone();
+        planes.push(plane);
+
+        Polyhedron {
+            planes,
+            vertices: merged,
+        }
+    }
+}
@@ -0,0 +1,13 @@
+use super::ToPolyline;
+use crate::procedural::{self, Polyline};
+use crate::shape::Ellipsoid;
+use na;
+use simba::scalar::RealField;
+
+impl<N: RealField> ToPolyline<N> for Ellipsoid<N> {
+    type DiscretizationParameter = u32;
+
+    fn to_polyline(&self, nsubdiv: u32) -> Polyline<N> {
+        procedural::circle(&na::convert(2.0f64), nsubdiv).scaled(&self.radii)
+    }
+}
@@ -0,0 +1,24 @@
+use super::ToTriMesh;
+use crate::procedural;
+use crate::procedural::TriMesh;
+use crate::shape::Ellipsoid;
+use na;
+use simba::scalar::RealField;
+
+impl<N: RealField> ToTriMesh<N> for Ellipsoid<N> {
+    type DiscretizationParameter = (u32, u32);
+
+    fn to_trimesh(&self, (ntheta_subdiv, nphi_subdiv): (u32, u32)) -> TriMesh<N> {
+        let mut mesh = procedural::sphere(na::convert(2.0f64), ntheta_subdiv, nphi_subdiv, true);
+        mesh.scale_by(&self.radii);
+
+        // Scaling does not preserve normals: they have to be scaled by the inverse radii.
+        if let Some(normals) = &mut mesh.normals {
+            for n in normals.iter_mut() {
+                *n = n.component_div(&self.radii).normalize();
+            }
+        }
+
+        mesh
+    }
+}
@@ -0,0 +1,18 @@
+use super::ToTriMesh;
+use crate::procedural;
+use crate::procedural::TriMesh;
+use crate::shape::Torus;
+use simba::scalar::RealField;
+
+impl<N: RealField> ToTriMesh<N> for Torus<N> {
+    type DiscretizationParameter = (u32, u32);
+
+    fn to_trimesh(&self, (nmajor_subdiv, nminor_subdiv): (u32, u32)) -> TriMesh<N> {
+        procedural::torus(
+            self.major_radius,
+            self.minor_radius,
+            nmajor_subdiv,
+            nminor_subdiv,
+        )
+    }
+}
@@ -0,0 +1,596 @@
+use crate::bounding_volume;
+use crate::math::Isometry;
+use crate::shape::{Compound, ConvexHull, ShapeHandle};
+use crate::transformation::convex_hull3::convex_hull3;
+use na::{self, Point3, RealField, Vector3};
+
+// Weight of the term of the splitting cost favoring parts with similar volumes.
+const BALANCE_WEIGHT: f64 = 0.05;
+
+/// Parameters of the volumetric approximate convex decomposition computed by `vhacd`.
+#[derive(Clone, Debug, PartialEq)]
+pub struct VHACDParameters<N: RealField> {
+    /// The number of voxels along the largest dimension of the AABB of the mesh.
+    pub resolution: u32,
+    /// The maximum concavity of each part, as a fraction of the volume of the voxelized mesh.
+    ///
+    /// The concavity of a part is the difference between the volume of its convex hull and its
+    /// own volume.
+    pub concavity: N,
+    /// The distance, in voxels, between two consecutive candidate splitting planes.
+    pub plane_downsampling: u32,
+    /// The maximum number of convex hulls generated.
+    pub max_convex_hulls: usize,
+    /// The maximum number of vertices of each convex hull.
+    pub max_vertices_per_hull: usize,
+}
+
+impl<N: RealField> Default for VHACDParameters<N> {
+    fn default() -> Self {
+        VHACDParameters {
+            resolution: 64,
+            concavity: na::convert(0.01),
+            plane_downsampling: 4,
+            max_convex_hulls: 1024,
+            max_vertices_per_hull: 64,
+        }
+    }
+}
+
+/// Volumetric approximate convex decomposition of a triangle mesh.
+///
+/// The mesh is voxelized, and the resulting set of voxels is recursively split by axis-aligned
+/// planes until the concavity of each part is smaller than `params.concavity`, or until
+/// `params.max_convex_hulls` parts are generated. The convex hull of each part is then
+/// computed. The mesh does not need to be manifold nor to have normals. If it is not closed,
+/// only its surface is voxelized.
+pub fn vhacd<N: RealField>(
+    points: &[Point3<N>],
+    indices: &[Point3<u32>],
+    params: &VHACDParameters<N>,
+) -> Vec<ConvexHull<N>> {
+    assert!(
+        params.resolution > 0 && params.plane_downsampling > 0,
+        "The voxelization resolution and plane downsampling must be strictly positive."
+    );
+
+    if points.is_empty() || indices.is_empty() {
+        return Vec::new();
+    }
+
+    let grid = VoxelGrid::new(points, indices, params.resolution);
+    let voxels = grid.filled_voxels();
+    let total_volume = voxels.len() as f64;
+    let mut parts = vec![Part::new(voxels, total_volume)];
+
+    while parts.len() < params.max_convex_hulls {
+        let (worst, concavity) = parts
+            .iter()
+            .enumerate()
+            .filter(|(_, part)| part.splittable)
+            .map(|(i, part)| (i, part.concavity))
+            .fold((None, 0.0), |best, (i, c)| {
+                if best.0.is_none() || c > best.1 {
+                    (Some(i), c)
+                } else {
+                    best
+                }
+            });
+
+        let worst = match worst {
+            Some(worst) if na::convert::<_, N>(concavity) > params.concavity => worst,
+            _ => break,
+        };
+
+        match parts[worst].split(params.plane_downsampling, total_volume) {
+            Some((left, right)) => {
+                parts[worst] = left;
+                parts.push(right);
+            }
+            None => parts[worst].splittable = false,
+        }
+    }
+
+    parts
+        .iter()
+        .filter_map(|part| {
+            let hull_points = grid.hull_points(part, points);
+            let hull_points = limit_vertices(&hull_points, params.max_vertices_per_hull);
+            ConvexHull::try_from_points(&hull_points)
+        })
+        .collect()
+}
+
+/// Volumetric approximate convex decomposition of a triangle mesh, returned as a compound shape.
+///
+/// See `vhacd` for details.
+pub fn vhacd_compound<N: RealField>(
+    points: &[Point3<N>],
+    indices: &[Point3<u32>],
+    params: &VHACDParameters<N>,
+) -> Compound<N> {
+    let shapes = vhacd(points, indices, params)
+        .into_iter()
+        .map(|hull| (Isometry::identity(), ShapeHandle::new(hull)))
+        .collect();
+    Compound::new(shapes)
+}
+
+#[derive(Copy, Clone, PartialEq, Eq)]
+enum VoxelState {
+    Unknown,
+    Outside,
+    Surface,
+    Inside,
+}
+
+struct VoxelGrid<N: RealField> {
+    origin: Point3<N>,
+    voxel_size: N,
+    dims: [i32; 3],
+    states: Vec<VoxelState>,
+}
+
+impl<N: RealField> VoxelGrid<N> {
+    fn new(points: &[Point3<N>], indices: &[Point3<u32>], resolution: u32) -> Self {
+        let aabb = bounding_volume::local_point_cloud_aabb(points);
+        let extents = aabb.extents();
+        let mut voxel_size = extents.max() / na::convert(resolution as f64);
+
+        if voxel_size.is_zero() {
+            voxel_size = N::one();
+        }
+
+        // Add one layer of empty voxels on each side so the exterior is connected. The grid is
+        // also shifted by half a voxel so that the faces of the AABB pass through voxel centers.
+        let origin = aabb.mins - Vector3::repeat(voxel_size * na::convert(1.5));
+        let mut dims = [0; 3];
+
+        for i in 0..3 {
+            let n: f64 = na::try_convert((extents[i] / voxel_size).ceil()).unwrap_or(0.0);
+            dims[i] = n as i32 + 3;
+        }
+
+        let num_voxels = (dims[0] * dims[1] * dims[2]) as usize;
+        let mut grid = VoxelGrid {
+            origin,
+            voxel_size,
+            dims,
+            states: vec![VoxelState::Unknown; num_voxels],
+        };
+
+        for t in indices {
+            let tri = [
+                grid.grid_coords(&points[t.x as usize]),
+                grid.grid_coords(&points[t.y as usize]),
+                grid.grid_coords(&points[t.z as usize]),
+            ];
+            grid.rasterize_triangle(&tri);
+        }
+
+        grid.flood_fill();
+        grid
+    }
+
+    fn grid_coords(&self, pt: &Point3<N>) -> Point3<N> {
+        Point3::from((pt - self.origin) / self.voxel_size)
+    }
+
+    fn world_coords(&self, pt: &Point3<N>) -> Point3<N> {
+        self.origin + pt.coords * self.voxel_size
+    }
+
+    fn linear_index(&self, v: &Point3<i32>) -> Option<usize> {
+        if (0..3).all(|i| v[i] >= 0 && v[i] < self.dims[i]) {
+            Some((v.x + self.dims[0] * (v.y + self.dims[1] * v.z)) as usize)
+        } else {
+            None
+        }
+    }
+
+    fn rasterize_triangle(&mut self, tri: &[Point3<N>; 3]) {
+        let mut mins = [0; 3];
+        let mut maxs = [0; 3];
+
+        for i in 0..3 {
+            let min = tri[0][i].min(tri[1][i]).min(tri[2][i]);
+            let max = tri[0][i].max(tri[1][i]).max(tri[2][i]);
+            mins[i] = na::try_convert::<_, f64>(min.floor()).unwrap_or(0.0) as i32;
+            maxs[i] = na::try_convert::<_, f64>(max.floor()).unwrap_or(0.0) as i32;
+        }
+
+        for x in mins[0]..=maxs[0] {
+            for y in mins[1]..=maxs[1] {
+                for z in mins[2]..=maxs[2] {
+                    let voxel = Point3::new(x, y, z);
+
+                    if let Some(id) = self.linear_index(&voxel) {
+                        if self.states[id] != VoxelState::Surface
+                            && triangle_intersects_unit_voxel(tri, &voxel)
+                        {
+                            self.states[id] = VoxelState::Surface;
+                        }
+                    }
+                }
+            }
+        }
+    }
+
+    // Marks the voxels reachable from the border of the grid without crossing the surface as
+    // outside, and the remaining unknown voxels as inside.
+    fn flood_fill(&mut self) {
+        let mut stack = vec![Point3::new(0, 0, 0)];
+        let id = self.linear_index(&stack[0]).unwrap();
+        self.states[id] = VoxelState::Outside;
+
+        while let Some(v) = stack.pop() {
+            for neighbor in neighbors(&v).iter() {
+                if let Some(id) = self.linear_index(neighbor) {
+                    if self.states[id] == VoxelState::Unknown {
+                        self.states[id] = VoxelState::Outside;
+                        stack.push(*neighbor);
+                    }
+                }
+            }
+        }
+
+        for state in &mut self.states {
+            if *state == VoxelState::Unknown {
+                *state = VoxelState::Inside;
+            }
+        }
+    }
+
+    fn filled_voxels(&self) -> Vec<Point3<i32>> {
+        let mut res = Vec::new();
+
+        for z in 0..self.dims[2] {
+            for y in 0..self.dims[1] {
+                for x in 0..self.dims[0] {
+                    let voxel = Point3::new(x, y, z);
+                    let state = self.states[self.linear_index(&voxel).unwrap()];
+
+                    if state == VoxelState::Surface || state == VoxelState::Inside {
+                        res.push(voxel);
+                    }
+                }
+            }
+        }
+
+        res
+    }
+
+    // The points whose convex hull approximates the given part: the mesh vertices lying in
+    // its voxels, the centers of its voxels lying on the surface of the mesh, and the centers of
+    // the voxel faces it shares with other parts, so that adjacent parts leave no gap in-between.
+    fn hull_points(&self, part: &Part, points: &[Point3<N>]) -> Vec<Point3<N>> {
+        let occupancy = Occupancy::new(&part.voxels);
+        let mut res = Vec::new();
+
+        for pt in points {
+            let grid_pt = self.grid_coords(pt);
+            let voxel = grid_pt
+                .coords
+                .map(|e| na::try_convert::<_, f64>(e.floor()).unwrap_or(0.0) as i32);
+
+            if occupancy.contains(&Point3::from(voxel)) {
+                res.push(*pt);
+            }
+        }
+
+        let to_world = |pt: Point3<f64>| self.world_coords(&pt.map(|e| na::convert::<_, N>(e)));
+
+        for voxel in &part.voxels {
+            let center = voxel.map(|e| e as f64 + 0.5);
+            let mut on_surface = false;
+
+            for (k, neighbor) in neighbors(voxel).iter().enumerate() {
+                if occupancy.contains(neighbor) {
+                    continue;
+                }
+
+                let filled = self
+                    .linear_index(neighbor)
+                    .map(|id| self.states[id] != VoxelState::Outside)
+                    .unwrap_or(false);
+
+                if !filled {
+                    on_surface = true;
+                    continue;
+                }
+
+                let mut face_center = center;
+                face_center[k / 2] += (k % 2) as f64 - 0.5;
+                res.push(to_world(face_center));
+            }
+
+            if on_surface {
+                res.push(to_world(center));
+            }
+        }
+
+        res
+    }
+}
+
+fn neighbors(v: &Point3<i32>) -> [Point3<i32>; 6] {
+    [
+        Point3::new(v.x - 1, v.y, v.z),
+        Point3::new(v.x + 1, v.y, v.z),
+        Point3::new(v.x, v.y - 1, v.z),
+        Point3::new(v.x, v.y + 1, v.z),
+        Point3::new(v.x, v.y, v.z - 1),
+        Point3::new(v.x, v.y, v.z + 1),
+    ]
+}
+
+// Separating axis test between a triangle and the unit cube `[voxel, voxel + 1]`.
+fn triangle_intersects_unit_voxel<N: RealField>(tri: &[Point3<N>; 3], voxel: &Point3<i32>) -> bool {
+    let half = na::convert::<_, N>(0.5);
+    let center = voxel.coords.map(|e| na::convert::<_, N>(e as f64) + half);
+    let pts = [
+        tri[0].coords - center,
+        tri[1].coords - center,
+        tri[2].coords - center,
+    ];
+    let edges = [pts[1] - pts[0], pts[2] - pts[1], pts[0] - pts[2]];
+    let mut axes = vec![
+        Vector3::x(),
+        Vector3::y(),
+        Vector3::z(),
+        edges[0].cross(&edges[1]),
+    ];
+
+    for edge in &edges {
+        for i in 0..3 {
+            axes.push(Vector3::ith(i, N::one()).cross(edge));
+        }
+    }
+
+    axes.iter().all(|axis| {
+        let radius = (axis.x.abs() + axis.y.abs() + axis.z.abs()) * half;
+        let proj = [axis.dot(&pts[0]), axis.dot(&pts[1]), axis.dot(&pts[2])];
+        let min = proj[0].min(proj[1]).min(proj[2]);
+        let max = proj[0].max(proj[1]).max(proj[2]);
+        min <= radius && max >= -radius
+    })
+}
+
+struct Part {
+    voxels: Vec<Point3<i32>>,
+    concavity: f64,
+    splittable: bool,
+}
+
+impl Part {
+    fn new(voxels: Vec<Point3<i32>>, total_volume: f64) -> Self {
+        let concavity = concavity(&voxels, total_volume);
+
+        Part {
+            splittable: voxels.len() > 1,
+            voxels,
+            concavity,
+        }
+    }
+
+    // Splits this part along the axis-aligned plane minimizing the sum of the concavities of
+    // the two resulting parts.
+    fn split(&self, plane_downsampling: u32, total_volume: f64) -> Option<(Part, Part)> {
+        let mut best = None;
+        let mut best_cost = f64::MAX;
+
+        for axis in 0..3 {
+            let min = self.voxels.iter().map(|v| v[axis]).min()?;
+            let max = self.voxels.iter().map(|v| v[axis]).max()?;
+
+            if min == max {
+                continue;
+            }
+
+            // Always try the middle plane so thin parts can be split too.
+            let mut planes: Vec<i32> = (min + 1..=max)
+                .step_by(plane_downsampling as usize)
+                .collect();
+            planes.push((min + max + 1) / 2);
+
+            for plane in planes {
+                let (left, right): (Vec<_>, Vec<_>) =
+                    self.voxels.iter().partition(|v| v[axis] < plane);
+
+                if left.is_empty() || right.is_empty() {
+                    continue;
+                }
+
+                let balance = (left.len() as f64 - right.len() as f64).abs() / total_volume;
+                let cost = concavity(&left, total_volume)
+                    + concavity(&right, total_volume)
+                    + balance * BALANCE_WEIGHT;
+
+                if cost < best_cost {
+                    best_cost = cost;
+                    best = Some((axis, plane));
+                }
+            }
+        }
+
+        let (axis, plane) = best?;
+        let (left, right) = self.voxels.iter().partition(|v| v[axis] < plane);
+
+        Some((
+            Part::new(left, total_volume),
+            Part::new(right, total_volume),
+        ))
+    }
+}
+
+// The volume of the convex hull of the voxel centers minus the volume of the part, relative to
+// `total_volume`. The hull of the voxel centers is used instead of the hull of the voxels
+// themselves so that the voxelization of a convex shape has an almost zero concavity. For
+// consistency, the volume of the part is measured from the voxel centers too: each voxel face
+// exposed to the outside removes a quarter of a voxel, which slightly overestimates the volume
+// of both boxes (half a voxel per face) and spheres (about a fifth of a voxel per face).
+fn concavity(voxels: &[Point3<i32>], total_volume: f64) -> f64 {
+    let occupancy = Occupancy::new(voxels);
+    let exposed_faces = voxels
+        .iter()
+        .map(|v| {
+            neighbors(v)
+                .iter()
+                .filter(|n| !occupancy.contains(n))
+                .count()
+        })
+        .sum::<usize>();
+    let volume = voxels.len() as f64 - exposed_faces as f64 / 4.0;
+
+    let centers: Vec<Point3<f64>> = occupancy
+        .extreme_voxels(voxels)
+        .map(|v| v.map(|e| e as f64))
+        .collect();
+    let hull_volume = if centers.len() < 4 {
+        0.0
+    } else {
+        let hull = convex_hull3(&centers);
+        let center = hull.coords[0];
+        hull.flat_indices()
+            .chunks(3)
+            .map(|t| {
+                let a = hull.coords[t[0] as usize] - center;
+                let b = hull.coords[t[1] as usize] - center;
+                let c = hull.coords[t[2] as usize] - center;
+                a.dot(&b.cross(&c)).abs() / 6.0
+            })
+            .sum()
+    };
+
+    (hull_volume - volume).max(0.0) / total_volume
+}
+
+// A dense occupancy grid covering the AABB of a set of voxels.
+struct Occupancy {
+    mins: Point3<i32>,
+    dims: Vector3<i32>,
+    filled: Vec<bool>,
+}
+
+impl Occupancy {
+    fn new(voxels: &[Point3<i32>]) -> Self {
+        let mut mins = Point3::new(i32::MAX, i32::MAX, i32::MAX);
+        let mut maxs = Point3::new(i32::MIN, i32::MIN, i32::MIN);
+
+        for v in voxels {
+            mins = mins.inf(v);
+            maxs = maxs.sup(v);
+        }
+
+        let dims = (maxs - mins).map(|e| (e + 1).max(0));
+        let mut res = Occupancy {
+            mins,
+            dims,
+            filled: vec![false; (dims.x * dims.y * dims.z) as usize],
+        };
+
+        for v in voxels {
+            let id = res.linear_index(v).unwrap();
+            res.filled[id] = true;
+        }
+
+        res
+    }
+
+    fn linear_index(&self, v: &Point3<i32>) -> Option<usize> {
+        let local = v - self.mins;
+
+        if (0..3).all(|i| local[i] >= 0 && local[i] < self.dims[i]) {
+            Some((local.x + self.dims.x * (local.y + self.dims.y * local.z)) as usize)
+        } else {
+            None
+        }
+    }
+
+    fn contains(&self, v: &Point3<i32>) -> bool {
+        self.linear_index(v)
+            .map(|id| self.filled[id])
+            .unwrap_or(false)
+    }
+
+    // The voxels that are the first or last of their row along each coordinate axis. Only those
+    // can be vertices of the convex hull of the voxels.
+    fn extreme_voxels<'a>(
+        &'a self,
+        voxels: &'a [Point3<i32>],
+    ) -> impl Iterator<Item = Point3<i32>> + 'a {
+        let mut row_bounds = Vec::with_capacity(3);
+
+        for axis in 0..3 {
+            let (i1, i2) = ((axis + 1) % 3, (axis + 2) % 3);
+            let mut bounds = vec![(i32::MAX, i32::MIN); (self.dims[i1] * self.dims[i2]) as usize];
+
+            for v in voxels {
+                let local = v - self.mins;
+                let row = &mut bounds[(local[i1] + self.dims[i1] * local[i2]) as usize];
+                row.0 = row.0.min(local[axis]);
+                row.1 = row.1.max(local[axis]);
+            }
+
+            row_bounds.push(bounds);
+        }
+
+        voxels.iter().cloned().filter(move |v| {
+            let local = v - self.mins;
+
+            (0..3).all(|axis| {
+                let (i1, i2) = ((axis + 1) % 3, (axis + 2) % 3);
+                let row = row_bounds[axis][(local[i1] + self.dims[i1] * local[i2]) as usize];
+                local[axis] == row.0 || local[axis] == row.1
+            })
+        })
+    }
+}
+
+// Selects at most `max_vertices` vertices of the convex hull of `points` by farthest point
+// sampling.
+fn limit_vertices<N: RealField>(points: &[Point3<N>], max_vertices: usize) -> Vec<Point3<N>> {
+    if points.len() < 4 {
+        return points.to_vec();
+    }
+
+    let hull = convex_hull3(points);
+    let mut used = vec![false; hull.coords.len()];
+
+    for i in hull.flat_indices() {
+        used[i as usize] = true;
+    }
+
+    let vertices: Vec<_> = hull
+        .coords
+        .iter()
+        .zip(used.iter())
+        .filter(|(_, used)| **used)
+        .map(|(pt, _)| *pt)
+        .collect();
+
+    if vertices.len() <= max_vertices.max(4) {
+        return vertices;
+    }
+
+    let mut selected = vec![vertices[0]];
+    let mut dists: Vec<N> = vertices
+        .iter()
+        .map(|pt| na::distance_squared(pt, &vertices[0]))
+        .collect();
+
+    while selected.len() < max_vertices.max(4) {
+        let (farthest, _) =
+            dists.iter().enumerate().fold(
+                (0, N::zero()),
+                |best, (i, d)| if *d > best.1 { (i, *d) } else { best },
+            );
+        selected.push(vertices[farthest]);
+
+        for (dist, pt) in dists.iter_mut().zip(vertices.iter()) {
+            *dist = dist.min(na::distance_squared(pt, &vertices[farthest]));
+        }
+    }
+
+    selected
+}